@@ -29,12 +29,20 @@ pub struct BuildProfile {
     pub metrics_outfile: Option<String>,
     #[serde(default)]
     pub include_tests: bool,
+    /// Whether to describe the functions, variables and types of the program in its debug info,
+    /// for debuggers.
+    #[serde(default)]
+    pub generate_debug_info: bool,
     #[serde(default)]
     pub error_on_warnings: bool,
     #[serde(default)]
     pub reverse_results: bool,
     #[serde(default)]
     pub optimization_level: OptLevel,
+    /// Custom IR pass pipeline, e.g. `mem2reg,inline,(ccp,simplify-cfg)*`, replacing the one
+    /// selected by the `optimization_level`.
+    #[serde(default)]
+    pub pass_pipeline: Option<String>,
 }
 
 impl BuildProfile {
@@ -57,9 +65,11 @@ impl BuildProfile {
             profile: false,
             metrics_outfile: None,
             include_tests: false,
+            generate_debug_info: false,
             error_on_warnings: false,
             reverse_results: false,
             optimization_level: OptLevel::Opt0,
+            pass_pipeline: None,
         }
     }
 
@@ -78,9 +88,11 @@ impl BuildProfile {
             profile: false,
             metrics_outfile: None,
             include_tests: false,
+            generate_debug_info: false,
             error_on_warnings: false,
             reverse_results: false,
             optimization_level: OptLevel::Opt1,
+            pass_pipeline: None,
         }
     }
 }
@@ -100,7 +112,7 @@ mod tests {
     fn test_build_profiles() {
         let manifest = PackageManifest::from_dir("./tests/sections").expect("manifest");
         let build_profiles = manifest.build_profile.expect("build profile");
-        assert_eq!(build_profiles.len(), 4);
+        assert_eq!(build_profiles.len(), 5);
 
         // Standard debug profile without adaptations.
         let expected = BuildProfile::debug();
@@ -124,6 +136,8 @@ mod tests {
                 r#final: false,
                 modified_only: true,
                 passes: vec!["dce".to_string(), "sroa".to_string()],
+                passes_before: vec![],
+                diff: false,
             },
             ..BuildProfile::debug()
         };
@@ -147,11 +161,30 @@ mod tests {
             profile: false,
             metrics_outfile: Some("metrics_outfile".into()),
             include_tests: true,
+            generate_debug_info: false,
             error_on_warnings: true,
             reverse_results: true,
             optimization_level: OptLevel::Opt0,
+            pass_pipeline: None,
         };
         let profile = build_profiles.get("release").expect("release profile");
         assert_eq!(*profile, expected);
+
+        // Profile based on debug profile with a custom pass pipeline and IR diffs around it.
+        let expected = BuildProfile {
+            name: "".into(),
+            print_ir: PrintIr {
+                passes: vec!["ccp".to_string()],
+                passes_before: vec!["mem2reg".to_string()],
+                diff: true,
+                ..PrintIr::default()
+            },
+            pass_pipeline: Some("mem2reg,inline,(ccp,simplify-cfg)*".into()),
+            ..BuildProfile::debug()
+        };
+        let profile = build_profiles
+            .get("custom_pipeline")
+            .expect("custom profile for the pass pipeline");
+        assert_eq!(*profile, expected);
     }
 }
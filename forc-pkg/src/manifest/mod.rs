@@ -187,6 +187,7 @@ pub struct PackageManifest {
     build_profile: Option<BTreeMap<String, BuildProfile>>,
     pub contract_dependencies: Option<BTreeMap<String, ContractDependency>>,
     pub proxy: Option<Proxy>,
+    pub test: Option<Test>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -288,6 +289,15 @@ pub struct Proxy {
     pub address: Option<String>,
 }
 
+/// Describes the tests of the package run by `forc test`, besides its unit tests.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Test {
+    /// Paths to the test scenario files of the package, relative to its manifest.
+    #[serde(default)]
+    pub scenarios: Vec<PathBuf>,
+}
+
 impl DependencyDetails {
     /// Checks if dependency details reserved for a specific dependency type used without the main
     /// detail for that type.
@@ -670,6 +680,11 @@ impl PackageManifest {
         self.proxy.as_ref()
     }
 
+    /// Retrieve the test table for the package.
+    pub fn test(&self) -> Option<&Test> {
+        self.test.as_ref()
+    }
+
     /// Check for the `core` and `std` packages under `[dependencies]`. If both are missing, add
     /// `std` implicitly.
     ///
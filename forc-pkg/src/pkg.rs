@@ -40,7 +40,7 @@ use sway_core::{
         fuel_crypto,
         fuel_tx::{self, Contract, ContractId, StorageSlot},
     },
-    language::{parsed::TreeType, Visibility},
+    language::{parsed::TreeType, ty::TyProgram, Visibility},
     semantic_analysis::namespace,
     source_map::SourceMap,
     transform::AttributeKind,
@@ -109,12 +109,19 @@ pub struct BuiltPackage {
     pub source_map: SourceMap,
     pub tree_type: TreeType,
     pub bytecode: BuiltPackageBytecode,
-    /// `Some` for contract member builds where tests were included. This is
+    /// `Some` for contract and predicate member builds where tests were included. This is
     /// required so that we can deploy once instance of the contract (without
-    /// tests) with a valid contract ID before executing the tests as scripts.
+    /// tests) with a valid contract ID before executing the tests as scripts, and
+    /// verify the predicate (without tests) against the transactions declared by its tests.
     ///
-    /// For non-contract members, this is always `None`.
+    /// For other members, this is always `None`.
     pub bytecode_without_tests: Option<BuiltPackageBytecode>,
+    /// The source map of `bytecode_without_tests`, used to map the instructions of the deployed
+    /// contract back to its source, e.g. for code coverage.
+    pub source_map_without_tests: Option<SourceMap>,
+    /// The ABI of `bytecode_without_tests`, whose configurables are laid out differently from the
+    /// ones of the bytecode with tests.
+    pub program_abi_without_tests: Option<ProgramABI>,
 }
 
 /// The package descriptors that a `BuiltPackage` holds so that the source used for building the
@@ -161,6 +168,19 @@ pub struct PkgTestEntry {
     pub pass_condition: TestPassCondition,
     pub span: Span,
     pub file_path: Arc<PathBuf>,
+    /// Present if the test is a parameterized (fuzz) test, i.e. `#[test(fuzz)]`.
+    pub fuzz: Option<FuzzTestEntry>,
+    /// The gas limit requested with `#[test(gas_limit = "<gas>")]`, if any.
+    pub gas_limit: Option<u64>,
+}
+
+/// Data specific to parameterized (fuzz) tests.
+#[derive(Debug, Clone)]
+pub struct FuzzTestEntry {
+    /// The number of runs requested with `#[test(fuzz = "<runs>")]`, if any.
+    pub runs: Option<u64>,
+    /// The ABI of the test function, describing the types of its parameters.
+    pub abi: fuel_abi_types::abi::program::ProgramABI,
 }
 
 /// The result of successfully compiling a workspace.
@@ -1562,10 +1582,12 @@ pub fn sway_build_config(
     )
     .with_print_ir(build_profile.print_ir.clone())
     .with_include_tests(build_profile.include_tests)
+    .with_generate_debug_info(build_profile.generate_debug_info)
     .with_time_phases(build_profile.time_phases)
     .with_profile(build_profile.profile)
     .with_metrics(build_profile.metrics_outfile.clone())
-    .with_optimization_level(build_profile.optimization_level);
+    .with_optimization_level(build_profile.optimization_level)
+    .with_pass_pipeline(build_profile.pass_pipeline.clone());
     Ok(build_config)
 }
 
@@ -1733,6 +1755,10 @@ fn find_core_dep(graph: &Graph, node: NodeIx) -> Option<NodeIx> {
     None
 }
 
+const ENCODING_V0: &str = "0";
+const ENCODING_V1: &str = "1";
+const SPEC_VERSION: &str = "1";
+
 /// Compiles the given package.
 ///
 /// ## Program Types
@@ -1837,10 +1863,6 @@ pub fn compile(
         metrics
     );
 
-    const ENCODING_V0: &str = "0";
-    const ENCODING_V1: &str = "1";
-    const SPEC_VERSION: &str = "1";
-
     let mut program_abi = match pkg.target {
         BuildTarget::Fuel => {
             let program_abi_res = time_expr!(
@@ -1903,7 +1925,9 @@ pub fn compile(
         .unwrap_or_default();
     let entries = entries
         .iter()
-        .map(|finalized_entry| PkgEntry::from_finalized_entry(finalized_entry, engines))
+        .map(|finalized_entry| {
+            PkgEntry::from_finalized_entry(finalized_entry, engines, typed_program, experimental)
+        })
         .collect::<anyhow::Result<_>>()?;
 
     let mut asm = match asm_res {
@@ -2067,10 +2091,16 @@ impl PkgEntry {
         self.kind.test().is_some()
     }
 
-    fn from_finalized_entry(finalized_entry: &FinalizedEntry, engines: &Engines) -> Result<Self> {
+    fn from_finalized_entry(
+        finalized_entry: &FinalizedEntry,
+        engines: &Engines,
+        typed_program: &TyProgram,
+        experimental: ExperimentalFeatures,
+    ) -> Result<Self> {
         let pkg_entry_kind = match &finalized_entry.test_decl_ref {
             Some(test_decl_ref) => {
-                let pkg_test_entry = PkgTestEntry::from_decl(test_decl_ref, engines)?;
+                let pkg_test_entry =
+                    PkgTestEntry::from_decl(test_decl_ref, engines, typed_program, experimental)?;
                 PkgEntryKind::Test(pkg_test_entry)
             }
             None => PkgEntryKind::Main,
@@ -2094,11 +2124,18 @@ impl PkgEntryKind {
 }
 
 impl PkgTestEntry {
-    fn from_decl(decl_ref: &DeclRefFunction, engines: &Engines) -> Result<Self> {
+    fn from_decl(
+        decl_ref: &DeclRefFunction,
+        engines: &Engines,
+        typed_program: &TyProgram,
+        experimental: ExperimentalFeatures,
+    ) -> Result<Self> {
         let span = decl_ref.span();
         let test_function_decl = engines.de().get_function(decl_ref);
 
         const FAILING_TEST_KEYWORD: &str = "should_revert";
+        const FUZZ_TEST_KEYWORD: &str = "fuzz";
+        const GAS_LIMIT_TEST_KEYWORD: &str = "gas_limit";
 
         let test_args: HashMap<String, Option<String>> = test_function_decl
             .attributes
@@ -2116,21 +2153,78 @@ impl PkgTestEntry {
             })
             .collect();
 
-        let pass_condition = if test_args.is_empty() {
-            anyhow::Ok(TestPassCondition::ShouldNotRevert)
-        } else if let Some(args) = test_args.get(FAILING_TEST_KEYWORD) {
-            let expected_revert_code = args
-                .as_ref()
-                .map(|arg| {
-                    let arg_str = arg.replace('"', "");
-                    arg_str.parse::<u64>()
-                })
-                .transpose()?;
-            anyhow::Ok(TestPassCondition::ShouldRevert(expected_revert_code))
-        } else {
-            let test_name = &test_function_decl.name;
+        let test_name = &test_function_decl.name;
+        if test_args.keys().any(|arg| {
+            arg != FAILING_TEST_KEYWORD && arg != FUZZ_TEST_KEYWORD && arg != GAS_LIMIT_TEST_KEYWORD
+        }) {
             bail!("Invalid test argument(s) for test: {test_name}.")
-        }?;
+        }
+
+        let pass_condition = match test_args.get(FAILING_TEST_KEYWORD) {
+            Some(args) => {
+                let expected_revert_code = args
+                    .as_ref()
+                    .map(|arg| {
+                        let arg_str = arg.replace('"', "");
+                        arg_str.parse::<u64>()
+                    })
+                    .transpose()?;
+                TestPassCondition::ShouldRevert(expected_revert_code)
+            }
+            None => TestPassCondition::ShouldNotRevert,
+        };
+
+        let gas_limit = match test_args.get(GAS_LIMIT_TEST_KEYWORD) {
+            Some(Some(arg)) => {
+                let arg_str = arg.replace('"', "");
+                let gas_limit = arg_str
+                    .parse::<u64>()
+                    .map_err(|_| anyhow!("Invalid gas limit for test {test_name}: {arg_str}."))?;
+                Some(gas_limit)
+            }
+            Some(None) => bail!("Test {test_name} requires a value for `gas_limit`."),
+            None => None,
+        };
+
+        let fuzz = match (
+            test_args.get(FUZZ_TEST_KEYWORD),
+            test_function_decl.parameters.is_empty(),
+        ) {
+            (None, true) => None,
+            (None, false) => {
+                bail!("Test {test_name} has parameters, but is not a fuzz test. Use `#[test(fuzz)]` to run it with generated inputs.")
+            }
+            (Some(_), true) => {
+                bail!("Fuzz test {test_name} must have at least one parameter.")
+            }
+            (Some(_), false) if !experimental.new_encoding => {
+                bail!("Fuzz test {test_name} requires the `new_encoding` experimental feature.")
+            }
+            (Some(runs), false) => {
+                let runs = runs
+                    .as_ref()
+                    .map(|arg| {
+                        let arg_str = arg.replace('"', "");
+                        arg_str.parse::<u64>()
+                    })
+                    .transpose()?;
+                let handler = Handler::default();
+                let abi = fuel_abi::generate_test_function_abi(
+                    &handler,
+                    &mut AbiContext {
+                        program: typed_program,
+                        abi_with_callpaths: true,
+                        type_ids_to_full_type_str: HashMap::<String, String>::new(),
+                    },
+                    engines,
+                    &test_function_decl,
+                    ENCODING_V1.into(),
+                    SPEC_VERSION.into(),
+                )
+                .map_err(|_| anyhow!("Failed to generate the ABI of fuzz test {test_name}."))?;
+                Some(FuzzTestEntry { runs, abi })
+            }
+        };
 
         let file_path = Arc::new(
             engines.se().get_path(
@@ -2142,6 +2236,8 @@ impl PkgTestEntry {
             pass_condition,
             span,
             file_path,
+            fuzz,
+            gas_limit,
         })
     }
 }
@@ -2167,6 +2263,7 @@ fn build_profile_from_opts(
         build_profile,
         release,
         metrics_outfile,
+        debug_outfile,
         tests,
         error_on_warnings,
         ..
@@ -2209,6 +2306,8 @@ fn build_profile_from_opts(
         profile.metrics_outfile.clone_from(metrics_outfile);
     }
     profile.include_tests |= tests;
+    // The debug info is only generated when it is written out.
+    profile.generate_debug_info |= debug_outfile.is_some();
     profile.error_on_warnings |= error_on_warnings;
     // profile.experimental = *experimental;
 
@@ -2469,10 +2568,13 @@ pub fn build(
         };
 
         let is_contract_dependency = is_contract_dependency(plan.graph(), node);
-        // If we are building a contract and tests are enabled or we are building a contract
-        // dependency, we need the tests excluded bytecode.
-        let bytecode_without_tests = if (include_tests
-            && matches!(manifest.program_type(), Ok(TreeType::Contract)))
+        // If we are building a contract or a predicate and tests are enabled or we are building a
+        // contract dependency, we need the tests excluded bytecode.
+        let compiled_without_tests = if (include_tests
+            && matches!(
+                manifest.program_type(),
+                Ok(TreeType::Contract | TreeType::Predicate)
+            ))
             || is_contract_dependency
         {
             // We will build a contract with tests enabled, we will also need the same contract with tests
@@ -2481,6 +2583,8 @@ pub fn build(
             //   1. Interpreter deployment in `forc-test`.
             //   2. Contract ID injection in `forc-pkg` if this is a contract dependency to any
             //      other pkg, so that injected contract id is not effected by the tests.
+            //
+            // Predicates are verified by `forc-test` with their bytecode without tests.
             let profile = BuildProfile {
                 include_tests: false,
                 ..profile.clone()
@@ -2501,12 +2605,14 @@ pub fn build(
                 Err(errs) => return fail(&[], &errs),
             };
 
+            // The bytecode without tests gets its own source map, as its instructions are laid
+            // out differently from the ones of the bytecode with tests.
             let compiled_without_tests = compile(
                 &descriptor,
                 &profile,
                 &engines,
                 &mut dep_namespace,
-                &mut source_map,
+                &mut SourceMap::new(),
                 experimental,
             )?;
 
@@ -2527,7 +2633,7 @@ pub fn build(
                     storage_slots: compiled_without_tests.storage_slots.clone(),
                 };
                 compiled_contract_deps.insert(node, compiled_contract_dep);
-            } else {
+            } else if matches!(manifest.program_type(), Ok(TreeType::Contract)) {
                 // `forc-test` interpreter deployments are done with zeroed salt.
                 let contract_id = contract_id(
                     &compiled_without_tests.bytecode.bytes,
@@ -2537,7 +2643,7 @@ pub fn build(
                 // We finally set the contract ID value here to use for compilation later if tests are enabled.
                 contract_id_value = Some(format!("0x{contract_id}"));
             }
-            Some(compiled_without_tests.bytecode)
+            Some(compiled_without_tests)
         } else {
             None
         };
@@ -2596,6 +2702,15 @@ pub fn build(
         }
         source_map.insert_dependency(descriptor.manifest_file.dir());
 
+        let (bytecode_without_tests, source_map_without_tests, program_abi_without_tests) =
+            match compiled_without_tests {
+                Some(compiled) => (
+                    Some(compiled.bytecode),
+                    Some(compiled.source_map),
+                    Some(compiled.program_abi),
+                ),
+                None => (None, None, None),
+            };
         let built_pkg = BuiltPackage {
             descriptor,
             program_abi: compiled.program_abi,
@@ -2605,6 +2720,8 @@ pub fn build(
             bytecode: compiled.bytecode,
             warnings: compiled.warnings,
             bytecode_without_tests,
+            source_map_without_tests,
+            program_abi_without_tests,
         };
 
         if outputs.contains(&node) {
@@ -74,6 +74,6 @@ fn main() {
     let test_path = PathBuf::from("tests/deploy.rs");
     update_proxy_abi_decl_with_file(&test_path, &minified_json);
 
-    let deploy_path = PathBuf::from("src/op/deploy.rs");
+    let deploy_path = PathBuf::from("src/op/deploy/mod.rs");
     update_proxy_abi_decl_with_file(&deploy_path, &minified_json);
 }
@@ -5,12 +5,15 @@ use forc_pkg::BuildProfile;
 pub use forc_tx::{Gas, Maturity};
 pub use forc_util::tx_utils::Salt;
 use fuel_crypto::SecretKey;
+use std::path::PathBuf;
 
 forc_util::cli_examples! {
    super::Command {
         [ Deploy a single contract => "forc deploy bc09bfa7a11a04ce42b0a5abf04fd437387ee49bf4561d575177e2946468b408" ]
         [ Deploy a single contract from a different path => "forc deploy bc09bfa7a11a04ce42b0a5abf04fd437387ee49bf4561d575177e2946468b408 --path {path}" ]
         [ Deploy to a custom network => "forc deploy --node-url https://testnet.fuel.network/graphql" ]
+        [ Deploy all contracts including the ones unchanged since their last deployment => "forc deploy --redeploy" ]
+        [ Build the deployment transactions without a node estimating fees from the given consensus parameters => "forc deploy --dry-run --default-salt --consensus-params {path}/consensus_parameters.json" ]
     }
 }
 
@@ -58,6 +61,29 @@ pub struct Command {
     /// Submit the deployment transaction(s) without waiting for execution to complete.
     #[clap(long)]
     pub submit_only: bool,
+    /// Build the deployment transaction(s) without connecting to a node, signing or submitting
+    /// them.
+    ///
+    /// The unsigned transactions are written as JSON to the `deployments/dry-run` directory of the
+    /// output directory, in the format read by `forc submit`, along with a summary of the
+    /// deployment with the contract IDs, storage roots, bytecode sizes and estimated max fees.
+    /// Proxy contracts are neither deployed nor updated.
+    #[clap(long, conflicts_with = "submit_only")]
+    pub dry_run: bool,
+    /// Path to a JSON file with the consensus parameters of the target network, used by
+    /// `--dry-run` for the chain ID and to estimate the max fees of the transactions.
+    ///
+    /// Defaults to the default consensus parameters.
+    #[clap(long, requires = "dry_run")]
+    pub consensus_params: Option<PathBuf>,
+    /// Deploy contracts even if the deployment manifest of the network records an identical
+    /// deployment.
+    ///
+    /// By default, contracts whose bytecode, storage, salt and proxy are unchanged since their
+    /// deployment recorded in `deployments/<network>.json`, and that still exist on chain, are not
+    /// deployed again.
+    #[clap(long, conflicts_with = "dry_run")]
+    pub redeploy: bool,
     /// Set the key to be used for signing.
     pub signing_key: Option<SecretKey>,
     /// Sign the deployment transaction manually.
@@ -0,0 +1,370 @@
+//! Dry-run deployments, which build the deployment transactions of packages without a node.
+//!
+//! The transactions are written unsigned and unfunded as JSON, in the format read by
+//! `forc submit`. Their max fee is estimated from the consensus parameters of the network,
+//! excluding the inputs and witnesses added when funding and signing them.
+
+use super::{resolve_salts, resolve_storage_slots, DeployedContract, DeployedExecutable};
+use crate::cmd;
+use anyhow::{Context, Result};
+use forc_pkg::manifest::GenericManifestFile;
+use forc_pkg::BuiltPackage;
+use forc_tracing::{println_action_green, println_warning};
+use forc_util::default_output_directory;
+use fuel_crypto::fuel_types::canonical::Serialize as _;
+use fuel_tx::{
+    field::MaxFeeLimit, BlobBody, BlobId, BlobIdExt, Bytes32, Chargeable, ConsensusParameters,
+    Contract, ContractId, Finalizable, Output, Salt, StorageSlot, Transaction, TransactionBuilder,
+    UniqueIdentifier,
+};
+use fuels::{
+    programs::{contract::Contract as SdkContract, executable::Executable},
+    types::transaction_builders::Blob,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// The gas price the max fee of the transactions is estimated at, unless `--gas-price` is given.
+const DEFAULT_GAS_PRICE: u64 = 1;
+
+/// The name of the directory, within the deployments directory, dry-run deployments are written to.
+const DRY_RUN_DIR_NAME: &str = "dry-run";
+
+/// The summary of the dry-run deployment of a package, written next to its transactions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryRunDeployment {
+    pub pkg_name: String,
+    pub chain_id: u64,
+    /// The id of the deployed contract, or of the loader contract of a chunked contract.
+    pub contract_id: Option<String>,
+    pub salt: Option<String>,
+    /// The root of the initial storage of the deployed contract.
+    pub state_root: Option<String>,
+    /// The size of the bytecode of the package, in bytes.
+    pub bytecode_size: usize,
+    /// Whether the contract is deployed in chunks, as blobs loaded by a loader contract.
+    pub chunked: bool,
+    /// The transactions of the deployment, in the order they must be submitted.
+    pub transactions: Vec<DryRunTransaction>,
+}
+
+/// A transaction of a dry-run deployment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DryRunTransaction {
+    /// The kind of the transaction, i.e. `create` or `blob`.
+    pub kind: String,
+    /// The file the transaction is written to, relative to the summary.
+    pub file: String,
+    pub transaction_id: String,
+    /// The size of the transaction, in bytes.
+    pub size: usize,
+    /// The estimated max gas of the transaction.
+    pub max_gas: u64,
+    /// The max fee of the transaction, as given by `--max-fee` or estimated.
+    pub max_fee: u64,
+}
+
+/// Builds the deployment transactions of the given packages without connecting to a node, and
+/// writes them to the `deployments/dry-run` directory of the output directory of each package.
+pub fn dry_run_contracts(
+    command: &cmd::Deploy,
+    contracts_to_deploy: &[Arc<BuiltPackage>],
+) -> Result<Vec<DeployedContract>> {
+    let params = consensus_params(command)?;
+    let salts = resolve_salts(command, contracts_to_deploy)?;
+    let mut deployed_contracts = vec![];
+    for (pkg, salt) in contracts_to_deploy.iter().zip(salts) {
+        let pkg_name = &pkg.descriptor.name;
+        if pkg
+            .descriptor
+            .manifest_file
+            .proxy()
+            .is_some_and(|proxy| proxy.enabled)
+        {
+            println_warning(&format!(
+                "The proxy contract of {pkg_name} is neither deployed nor updated in a dry run."
+            ));
+        }
+        let storage_slots = resolve_storage_slots(command, pkg)?;
+        let bytecode = &pkg.bytecode.bytes;
+        let chunked = bytecode.len() > super::MAX_CONTRACT_SIZE;
+
+        let mut transactions = vec![];
+        let contract_bytecode = if chunked {
+            let blobs = bytecode
+                .chunks(super::MAX_CONTRACT_SIZE)
+                .map(|chunk| Blob::new(chunk.to_vec()))
+                .collect::<Vec<_>>();
+            for blob in &blobs {
+                transactions.push(("blob", blob_transaction(blob.bytes(), command, &params)));
+            }
+            SdkContract::loader_from_blobs(blobs, salt, storage_slots.clone())?.code()
+        } else {
+            bytecode.clone()
+        };
+        let (contract_id, state_root, create_tx) =
+            create_transaction(contract_bytecode, salt, storage_slots, command, &params);
+        transactions.push(("create", create_tx));
+
+        let deployment = DryRunDeployment {
+            pkg_name: pkg_name.clone(),
+            chain_id: *params.chain_id(),
+            contract_id: Some(format!("0x{contract_id}")),
+            salt: Some(format!("0x{salt}")),
+            state_root: Some(format!("0x{state_root}")),
+            bytecode_size: bytecode.len(),
+            chunked,
+            transactions: vec![],
+        };
+        write_deployment(command, pkg, deployment, transactions, &params)?;
+        deployed_contracts.push(DeployedContract {
+            id: contract_id,
+            proxy: None,
+            chunked,
+        });
+    }
+    Ok(deployed_contracts)
+}
+
+/// Builds the transactions uploading the given executables (scripts and predicates) as blobs
+/// without connecting to a node, and writes them like [dry_run_contracts]. Returns the loaders of
+/// the executables.
+pub fn dry_run_executables(
+    command: &cmd::Deploy,
+    executables_to_deploy: &[Arc<BuiltPackage>],
+) -> Result<Vec<DeployedExecutable>> {
+    let params = consensus_params(command)?;
+    let mut deployed_executables = vec![];
+    for pkg in executables_to_deploy {
+        let loader = Executable::from_bytes(pkg.bytecode.bytes.clone()).convert_to_loader()?;
+        let blob_tx = blob_transaction(loader.blob().bytes(), command, &params);
+        let deployment = DryRunDeployment {
+            pkg_name: pkg.descriptor.name.clone(),
+            chain_id: *params.chain_id(),
+            contract_id: None,
+            salt: None,
+            state_root: None,
+            bytecode_size: pkg.bytecode.bytes.len(),
+            chunked: false,
+            transactions: vec![],
+        };
+        write_deployment(command, pkg, deployment, vec![("blob", blob_tx)], &params)?;
+        deployed_executables.push(DeployedExecutable {
+            bytecode: loader.code(),
+        });
+    }
+    Ok(deployed_executables)
+}
+
+/// Reads the consensus parameters given by `--consensus-params`, or returns the default ones.
+fn consensus_params(command: &cmd::Deploy) -> Result<ConsensusParameters> {
+    match &command.consensus_params {
+        Some(path) => {
+            let params = std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            serde_json::from_str(&params).with_context(|| {
+                format!(
+                    "failed to parse consensus parameters from {}",
+                    path.display()
+                )
+            })
+        }
+        None => Ok(ConsensusParameters::default()),
+    }
+}
+
+/// Builds the `Create` transaction deploying the given bytecode, and returns it along with the
+/// id and the state root of the contract.
+fn create_transaction(
+    bytecode: Vec<u8>,
+    salt: Salt,
+    storage_slots: Vec<StorageSlot>,
+    command: &cmd::Deploy,
+    params: &ConsensusParameters,
+) -> (ContractId, Bytes32, Transaction) {
+    let contract = Contract::from(bytecode.clone());
+    let root = contract.root();
+    let state_root = Contract::initial_state_root(storage_slots.iter());
+    let contract_id = contract.id(&salt, &root, &state_root);
+
+    let mut builder = TransactionBuilder::create(bytecode.into(), salt, storage_slots);
+    builder
+        .with_params(params.clone())
+        .maturity(command.maturity.maturity.into())
+        .add_output(Output::contract_created(contract_id, state_root));
+    // The policy is set before estimating, as it is part of the size of the transaction.
+    builder.max_fee_limit(0);
+    let max_fee = command
+        .gas
+        .max_fee
+        .unwrap_or_else(|| estimate_max_fee(&builder.finalize(), command, params));
+    builder.max_fee_limit(max_fee);
+    (contract_id, state_root, builder.finalize_as_transaction())
+}
+
+/// Builds the `Blob` transaction uploading the given bytes.
+fn blob_transaction(
+    bytes: &[u8],
+    command: &cmd::Deploy,
+    params: &ConsensusParameters,
+) -> Transaction {
+    let mut builder = TransactionBuilder::blob(BlobBody {
+        id: BlobId::compute(bytes),
+        witness_index: 0,
+    });
+    builder
+        .with_params(params.clone())
+        .maturity(command.maturity.maturity.into())
+        .add_witness(bytes.to_vec().into());
+    builder.max_fee_limit(0);
+    let max_fee = command
+        .gas
+        .max_fee
+        .unwrap_or_else(|| estimate_max_fee(&builder.finalize(), command, params));
+    builder.max_fee_limit(max_fee);
+    builder.finalize_as_transaction()
+}
+
+/// Estimates the max fee of the transaction at the gas price given by `--gas-price`.
+fn estimate_max_fee(
+    tx: &impl Chargeable,
+    command: &cmd::Deploy,
+    params: &ConsensusParameters,
+) -> u64 {
+    let gas_price = command.gas.price.unwrap_or(DEFAULT_GAS_PRICE);
+    let max_fee = tx.max_fee(params.gas_costs(), params.fee_params(), gas_price);
+    u64::try_from(max_fee).unwrap_or(u64::MAX)
+}
+
+/// Writes the transactions of a dry-run deployment, followed by its summary.
+fn write_deployment(
+    command: &cmd::Deploy,
+    pkg: &BuiltPackage,
+    mut deployment: DryRunDeployment,
+    transactions: Vec<(&str, Transaction)>,
+    params: &ConsensusParameters,
+) -> Result<()> {
+    let manifest = &pkg.descriptor.manifest_file;
+    let output_dir = dry_run_dir(command, manifest.dir());
+    std::fs::create_dir_all(&output_dir)?;
+    let pkg_name = &deployment.pkg_name;
+    for (index, (kind, tx)) in transactions.iter().enumerate() {
+        let file = format!("{pkg_name}-{index}-{kind}.json");
+        std::fs::write(output_dir.join(&file), serde_json::to_string_pretty(tx)?)?;
+        let (size, max_gas, max_fee) = match tx {
+            Transaction::Create(tx) => (
+                tx.size(),
+                tx.max_gas(params.gas_costs(), params.fee_params()),
+                tx.max_fee_limit(),
+            ),
+            Transaction::Blob(tx) => (
+                tx.size(),
+                tx.max_gas(params.gas_costs(), params.fee_params()),
+                tx.max_fee_limit(),
+            ),
+            _ => unreachable!("deployments only consist of create and blob transactions"),
+        };
+        deployment.transactions.push(DryRunTransaction {
+            kind: kind.to_string(),
+            file,
+            transaction_id: format!("0x{}", tx.id(&params.chain_id())),
+            size,
+            max_gas,
+            max_fee,
+        });
+    }
+
+    let summary_path = output_dir.join(format!("{pkg_name}-dry-run.json"));
+    std::fs::write(&summary_path, serde_json::to_string_pretty(&deployment)?)?;
+    let total_max_fee = deployment
+        .transactions
+        .iter()
+        .map(|tx| tx.max_fee)
+        .fold(0u64, u64::saturating_add);
+    let target = match &deployment.contract_id {
+        Some(contract_id) => format!("contract {pkg_name} ({contract_id})"),
+        None => format!("executable {pkg_name}"),
+    };
+    println_action_green(
+        "Dry-run",
+        &format!(
+            "deployment of {target}: {} bytes of bytecode, {} {}, max fee {total_max_fee}",
+            deployment.bytecode_size,
+            deployment.transactions.len(),
+            match deployment.transactions.len() {
+                1 => "transaction",
+                _ => "transactions",
+            }
+        ),
+    );
+    println_action_green("Saved", &format!("{}", summary_path.display()));
+    Ok(())
+}
+
+/// The directory the dry-run deployments of the package in `manifest_dir` are written to.
+fn dry_run_dir(command: &cmd::Deploy, manifest_dir: &Path) -> PathBuf {
+    command
+        .pkg
+        .output_directory
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_output_directory(manifest_dir))
+        .join("deployments")
+        .join(DRY_RUN_DIR_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_tx::field::{Inputs, Witnesses};
+
+    #[test]
+    fn create_transaction_deploys_contract() {
+        let command = cmd::Deploy::default();
+        let params = ConsensusParameters::default();
+        let bytecode = vec![0u8; 64];
+        let salt = Salt::zeroed();
+        let storage_slots = vec![StorageSlot::new(Bytes32::zeroed(), Bytes32::new([1; 32]))];
+
+        let (contract_id, state_root, tx) = create_transaction(
+            bytecode.clone(),
+            salt,
+            storage_slots.clone(),
+            &command,
+            &params,
+        );
+        let contract = Contract::from(bytecode);
+        assert_eq!(
+            state_root,
+            Contract::initial_state_root(storage_slots.iter())
+        );
+        assert_eq!(
+            contract_id,
+            contract.id(&salt, &contract.root(), &state_root)
+        );
+
+        let Transaction::Create(create) = &tx else {
+            panic!("expected a create transaction");
+        };
+        assert!(create.max_fee_limit() > 0);
+        assert!(create.inputs().is_empty());
+        // The transaction is written in the format read by `forc submit`.
+        let json = serde_json::to_string(&tx).unwrap();
+        assert_eq!(serde_json::from_str::<Transaction>(&json).unwrap(), tx);
+    }
+
+    #[test]
+    fn max_fee_overrides_estimate() {
+        let mut command = cmd::Deploy::default();
+        command.gas.max_fee = Some(42);
+        let tx = blob_transaction(&[1, 2, 3, 4], &command, &ConsensusParameters::default());
+        let Transaction::Blob(blob) = &tx else {
+            panic!("expected a blob transaction");
+        };
+        assert_eq!(blob.max_fee_limit(), 42);
+        assert_eq!(blob.witnesses()[0].as_vec(), &vec![1, 2, 3, 4]);
+    }
+}
@@ -1,28 +1,38 @@
+mod dry_run;
+
 use crate::{
     cmd,
     constants::TX_SUBMIT_TIMEOUT_MS,
     util::{
         account::ForcClientAccount,
+        deployments::{
+            configurable_values, network_name, ContractDeployment, DeploymentManifest,
+            ExecutableDeployment,
+        },
         node_url::get_node_url,
         pkg::{built_pkgs, create_proxy_contract, update_proxy_address_in_manifest},
         target::Target,
         tx::{
-            check_and_create_wallet_at_default_path, prompt_forc_wallet_password, select_account,
-            update_proxy_contract_target, SignerSelectionMode,
+            check_and_create_wallet_at_default_path, prompt_forc_wallet_password,
+            proxy_contract_target, select_account, update_proxy_contract_target,
+            SignerSelectionMode,
         },
     },
 };
 use anyhow::{bail, Context, Result};
 use forc_pkg::{self as pkg, PackageManifestFile};
-use forc_pkg::{manifest::GenericManifestFile, MemberFilter};
+use forc_pkg::{
+    manifest::{GenericManifestFile, ManifestFile},
+    MemberFilter,
+};
 use forc_tracing::{println_action_green, println_warning};
 use forc_util::default_output_directory;
 use forc_wallet::utils::default_wallet_path;
 use fuel_abi_types::abi::program::Configurable;
 use fuel_core_client::client::types::{ChainInfo, TransactionStatus};
 use fuel_core_client::client::FuelClient;
-use fuel_crypto::fuel_types::ChainId;
-use fuel_tx::{Salt, Transaction};
+use fuel_crypto::{fuel_types::ChainId, Hasher};
+use fuel_tx::{Salt, Transaction, TxId};
 use fuel_vm::{consts::WORD_SIZE, fuel_asm::op, prelude::*};
 use fuels::{
     macros::abigen,
@@ -290,6 +300,8 @@ pub async fn deploy(command: cmd::Deploy) -> Result<Vec<DeployedPackage>> {
     };
     let build_opts = build_opts_from_cmd(&command, MemberFilter::default());
     let built_pkgs = built_pkgs(&curr_dir, &build_opts)?;
+    // Deployment manifests are kept next to the manifest of the deployed package or workspace.
+    let project_dir = ManifestFile::from_dir(&curr_dir)?.dir().to_path_buf();
     let mut deployed_packages = Vec::new();
 
     let contracts_to_deploy = built_pkgs
@@ -330,21 +342,37 @@ pub async fn deploy(command: cmd::Deploy) -> Result<Vec<DeployedPackage>> {
         && predicates_to_deploy.is_empty()
     {
         println_warning("No deployable package was found in the current directory.");
+    } else if command.dry_run {
+        deployed_packages.extend(
+            dry_run::dry_run_contracts(&command, &contracts_to_deploy)?
+                .into_iter()
+                .map(DeployedPackage::Contract),
+        );
+        deployed_packages.extend(
+            dry_run::dry_run_executables(&command, &scripts_to_deploy)?
+                .into_iter()
+                .map(DeployedPackage::Script),
+        );
+        deployed_packages.extend(
+            dry_run::dry_run_executables(&command, &predicates_to_deploy)?
+                .into_iter()
+                .map(DeployedPackage::Predicate),
+        );
     } else {
         deployed_packages.extend(
-            deploy_contracts(&command, &contracts_to_deploy)
+            deploy_contracts(&command, &contracts_to_deploy, &project_dir)
                 .await?
                 .into_iter()
                 .map(DeployedPackage::Contract),
         );
         deployed_packages.extend(
-            deploy_executables(&command, &scripts_to_deploy)
+            deploy_executables(&command, &scripts_to_deploy, &project_dir)
                 .await?
                 .into_iter()
                 .map(DeployedPackage::Script),
         );
         deployed_packages.extend(
-            deploy_executables(&command, &predicates_to_deploy)
+            deploy_executables(&command, &predicates_to_deploy, &project_dir)
                 .await?
                 .into_iter()
                 .map(DeployedPackage::Predicate),
@@ -456,6 +484,7 @@ fn loader_data_offset(binary: &[u8], blob_id: &BlobId) -> Result<Option<usize>>
 pub async fn deploy_executables(
     command: &cmd::Deploy,
     executables_to_deploy: &[Arc<BuiltPackage>],
+    project_dir: &Path,
 ) -> Result<Vec<DeployedExecutable>> {
     let mut deployed_executable = vec![];
     if executables_to_deploy.is_empty() {
@@ -466,6 +495,8 @@ pub async fn deploy_executables(
     // We will have 1 transaction per executable as each deployment uses a single blob.
     let tx_count = executables_to_deploy.len();
     let account = setup_deployment_account(command, &node_url, tx_count).await?;
+    let provider = Provider::connect(node_url.clone()).await?;
+    let mut deployment_manifest = load_deployment_manifest(&provider, project_dir).await?;
 
     for pkg in executables_to_deploy {
         let script = Executable::from_bytes(pkg.bytecode.bytes.clone());
@@ -528,6 +559,14 @@ pub async fn deploy_executables(
                 &format!("loader root ({}) at {}", root, root_path.display()),
             );
         }
+        deployment_manifest.executables.insert(
+            pkg_name.to_string(),
+            ExecutableDeployment {
+                bytecode_hash: format!("0x{}", Hasher::hash(&pkg.bytecode.bytes)),
+                blob_id: format!("0x{}", hex::encode(loader.blob().id())),
+            },
+        );
+        deployment_manifest.to_file(project_dir)?;
         let deployed = DeployedExecutable {
             bytecode: loader_bytecode,
         };
@@ -550,22 +589,12 @@ fn extract_data_offset(binary: &[u8]) -> Result<usize> {
     Ok(u64::from_be_bytes(data_offset) as usize)
 }
 
-/// Builds and deploys contract(s). If the given path corresponds to a workspace, all deployable members
-/// will be built and deployed.
-///
-/// Upon success, returns the ID of each deployed contract in order of deployment.
-///
-/// When deploying a single contract, only that contract's ID is returned.
-pub async fn deploy_contracts(
+/// Resolves the salt of each of the contracts to deploy, from the `--salt` and `--default-salt`
+/// options. Without either option, contracts are given a random salt.
+fn resolve_salts(
     command: &cmd::Deploy,
     contracts_to_deploy: &[Arc<BuiltPackage>],
-) -> Result<Vec<DeployedContract>> {
-    let mut deployed_contracts = Vec::new();
-
-    if contracts_to_deploy.is_empty() {
-        return Ok(deployed_contracts);
-    }
-
+) -> Result<Vec<Salt>> {
     let contract_salt_map = if let Some(salt_input) = &command.salt {
         // If we're building 1 package, we just parse the salt as a string, ie. 0x00...
         // If we're building >1 package, we must parse the salt as a pair of strings, ie. contract_name:0x00...
@@ -605,35 +634,186 @@ pub async fn deploy_contracts(
         None
     };
 
+    contracts_to_deploy
+        .iter()
+        .map(|pkg| {
+            let salt = match (&contract_salt_map, command.default_salt) {
+                (Some(map), false) => {
+                    if let Some(salt) = map.get(pkg.descriptor.manifest_file.project_name()) {
+                        *salt
+                    } else {
+                        Default::default()
+                    }
+                }
+                (None, true) => Default::default(),
+                (None, false) => rand::random(),
+                (Some(_), true) => {
+                    bail!("Both `--salt` and `--default-salt` were specified: must choose one")
+                }
+            };
+            Ok(salt)
+        })
+        .collect()
+}
+
+/// Returns the recorded deployment of the given contract if it does not need to be deployed again.
+///
+/// A contract is unchanged if its bytecode, storage slots and proxy are the ones recorded in the
+/// deployment manifest, and its salt was either left to be random or is the recorded one. Recorded
+/// contracts that no longer exist on chain, or whose proxy no longer targets them, are reported as
+/// drift and deployed again.
+async fn unchanged_deployment(
+    command: &cmd::Deploy,
+    pkg: &BuiltPackage,
+    salt: Salt,
+    deployment_manifest: &DeploymentManifest,
+    provider: &Provider,
+    client: &FuelClient,
+) -> Result<Option<DeployedContract>> {
+    let pkg_name = &pkg.descriptor.name;
+    let Some(recorded) = deployment_manifest.contracts.get(pkg_name) else {
+        return Ok(None);
+    };
+
+    let storage_slots = resolve_storage_slots(command, pkg)?;
+    let state_root = Contract::initial_state_root(storage_slots.iter());
+    let recorded_proxy = recorded
+        .proxy
+        .as_deref()
+        .map(ContractId::from_str)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!(e))?;
+    let proxy_unchanged = match &pkg.descriptor.manifest_file.proxy {
+        Some(forc_pkg::manifest::Proxy {
+            enabled: true,
+            address: Some(proxy_addr),
+        }) => ContractId::from_str(proxy_addr).ok() == recorded_proxy,
+        // A new proxy contract is deployed.
+        Some(forc_pkg::manifest::Proxy {
+            enabled: true,
+            address: None,
+        }) => false,
+        _ => recorded_proxy.is_none(),
+    };
+    // Without `--salt` or `--default-salt` the salt is random, so any recorded salt will do.
+    let salt_unchanged =
+        (command.salt.is_none() && !command.default_salt) || recorded.salt == format!("0x{salt}");
+    if recorded.bytecode_hash != format!("0x{}", Hasher::hash(&pkg.bytecode.bytes))
+        || recorded.state_root != format!("0x{state_root}")
+        || !salt_unchanged
+        || !proxy_unchanged
+    {
+        return Ok(None);
+    }
+
+    let contract_id =
+        ContractId::from_str(&recorded.contract_id).map_err(|e| anyhow::anyhow!(e))?;
+    if client.contract(&contract_id).await?.is_none() {
+        println_warning(&format!(
+            "Contract {pkg_name} is recorded as deployed at 0x{contract_id} on {}, but it does not exist on chain. Deploying it again.",
+            deployment_manifest.network
+        ));
+        return Ok(None);
+    }
+    if let Some(proxy_id) = recorded_proxy {
+        if proxy_contract_target(provider, proxy_id).await? != Some(contract_id) {
+            println_warning(&format!(
+                "Proxy contract 0x{proxy_id} of {pkg_name} no longer targets 0x{contract_id} on {}. Deploying it again.",
+                deployment_manifest.network
+            ));
+            return Ok(None);
+        }
+    }
+
+    println_action_green(
+        "Skipping",
+        &format!("{pkg_name}, unchanged since its deployment at 0x{contract_id}"),
+    );
+    Ok(Some(DeployedContract {
+        id: contract_id,
+        proxy: recorded_proxy,
+        chunked: recorded.chunked,
+    }))
+}
+
+/// Builds and deploys contract(s). If the given path corresponds to a workspace, all deployable members
+/// will be built and deployed.
+///
+/// Contracts that are unchanged since their deployment recorded in the deployment manifest of the
+/// network are not deployed again, unless `--redeploy` is passed. The manifest is updated after
+/// each deployment.
+///
+/// Upon success, returns the ID of each deployed contract in order of deployment.
+///
+/// When deploying a single contract, only that contract's ID is returned.
+pub async fn deploy_contracts(
+    command: &cmd::Deploy,
+    contracts_to_deploy: &[Arc<BuiltPackage>],
+    project_dir: &Path,
+) -> Result<Vec<DeployedContract>> {
+    let mut deployed_contracts = Vec::new();
+
+    if contracts_to_deploy.is_empty() {
+        return Ok(deployed_contracts);
+    }
+
+    let salts = resolve_salts(command, contracts_to_deploy)?;
+
     let node_url = validate_and_get_node_url(command, contracts_to_deploy).await?;
     let provider = Provider::connect(node_url.clone()).await?;
+    let client = FuelClient::new(&node_url)?;
+    let mut deployment_manifest = load_deployment_manifest(&provider, project_dir).await?;
+
+    let mut unchanged_contracts = BTreeMap::new();
+    if !command.redeploy {
+        for (pkg, salt) in contracts_to_deploy.iter().zip(&salts) {
+            if let Some(deployed_contract) = unchanged_deployment(
+                command,
+                pkg,
+                *salt,
+                &deployment_manifest,
+                &provider,
+                &client,
+            )
+            .await?
+            {
+                unchanged_contracts.insert(pkg.descriptor.name.clone(), deployed_contract);
+            }
+        }
+    }
+    let changed_contracts = contracts_to_deploy
+        .iter()
+        .filter(|pkg| !unchanged_contracts.contains_key(&pkg.descriptor.name))
+        .cloned()
+        .collect::<Vec<_>>();
+    if changed_contracts.is_empty() {
+        return Ok(contracts_to_deploy
+            .iter()
+            .filter_map(|pkg| unchanged_contracts.remove(&pkg.descriptor.name))
+            .collect());
+    }
 
     // Confirmation step. Summarize the transaction(s) for the deployment.
     let account = confirm_transaction_details(
-        contracts_to_deploy,
+        &changed_contracts,
         command,
         node_url.clone(),
         MAX_CONTRACT_SIZE,
     )
     .await?;
 
-    for pkg in contracts_to_deploy {
-        let salt = match (&contract_salt_map, command.default_salt) {
-            (Some(map), false) => {
-                if let Some(salt) = map.get(pkg.descriptor.manifest_file.project_name()) {
-                    *salt
-                } else {
-                    Default::default()
-                }
-            }
-            (None, true) => Default::default(),
-            (None, false) => rand::random(),
-            (Some(_), true) => {
-                bail!("Both `--salt` and `--default-salt` were specified: must choose one")
-            }
-        };
+    for (pkg, salt) in contracts_to_deploy.iter().zip(salts) {
+        if let Some(deployed_contract) = unchanged_contracts.remove(&pkg.descriptor.name) {
+            deployed_contracts.push(deployed_contract);
+            continue;
+        }
+
+        // Decoded before deploying, so that a deployed contract is always recorded.
+        let configurables = configurable_values(&pkg.program_abi, &pkg.bytecode.bytes)?;
         let bytecode_size = pkg.bytecode.bytes.len();
-        let deployed_contract_id = if bytecode_size > MAX_CONTRACT_SIZE {
+        let chunked = bytecode_size > MAX_CONTRACT_SIZE;
+        let mut transaction_ids = vec![];
+        let deployed_contract_id = if chunked {
             // Deploy chunked
             let node_url = get_node_url(&command.node, &pkg.descriptor.manifest_file.network)?;
             let provider = Provider::connect(node_url).await?;
@@ -648,7 +828,9 @@ pub async fn deploy_contracts(
             )
             .await?
         } else {
-            deploy_pkg(command, pkg, salt, &provider, &account).await?
+            let (contract_id, tx_id) = deploy_pkg(command, pkg, salt, &provider, &account).await?;
+            transaction_ids.push(format!("0x{tx_id}"));
+            contract_id
         };
 
         let proxy_id = match &pkg.descriptor.manifest_file.proxy {
@@ -663,8 +845,10 @@ pub async fn deploy_contracts(
                 let proxy_contract =
                     ContractId::from_str(proxy_addr).map_err(|e| anyhow::anyhow!(e))?;
 
-                update_proxy_contract_target(&account, proxy_contract, deployed_contract_id)
-                    .await?;
+                let response =
+                    update_proxy_contract_target(&account, proxy_contract, deployed_contract_id)
+                        .await?;
+                transaction_ids.extend(response.tx_id.map(|tx_id| format!("0x{tx_id}")));
                 Some(proxy_contract)
             }
             Some(forc_pkg::manifest::Proxy {
@@ -695,10 +879,36 @@ pub async fn deploy_contracts(
             _ => None,
         };
 
+        let storage_slots = resolve_storage_slots(command, pkg)?;
+        let blob_ids = if chunked {
+            pkg.bytecode
+                .bytes
+                .chunks(MAX_CONTRACT_SIZE)
+                .map(|chunk| format!("0x{}", hex::encode(Blob::new(chunk.to_vec()).id())))
+                .collect()
+        } else {
+            vec![]
+        };
+        deployment_manifest.contracts.insert(
+            pkg.descriptor.name.clone(),
+            ContractDeployment {
+                contract_id: format!("0x{deployed_contract_id}"),
+                salt: format!("0x{salt}"),
+                bytecode_hash: format!("0x{}", Hasher::hash(&pkg.bytecode.bytes)),
+                state_root: format!("0x{}", Contract::initial_state_root(storage_slots.iter())),
+                chunked,
+                blob_ids,
+                proxy: proxy_id.map(|proxy_id| format!("0x{proxy_id}")),
+                configurables,
+                transaction_ids,
+            },
+        );
+        deployment_manifest.to_file(project_dir)?;
+
         let deployed_contract = DeployedContract {
             id: deployed_contract_id,
             proxy: proxy_id,
-            chunked: bytecode_size > MAX_CONTRACT_SIZE,
+            chunked,
         };
         deployed_contracts.push(deployed_contract);
     }
@@ -761,14 +971,16 @@ async fn confirm_transaction_details(
     Ok(account)
 }
 
-/// Deploy a single pkg given deploy command and the manifest file
+/// Deploy a single pkg given deploy command and the manifest file.
+///
+/// Returns the ID of the deployed contract and of the deployment transaction.
 pub async fn deploy_pkg(
     command: &cmd::Deploy,
     compiled: &BuiltPackage,
     salt: Salt,
     provider: &Provider,
     account: &ForcClientAccount,
-) -> Result<fuel_tx::ContractId> {
+) -> Result<(fuel_tx::ContractId, TxId)> {
     let manifest = &compiled.descriptor.manifest_file;
     let node_url = provider.url();
     let client = FuelClient::new(node_url)?;
@@ -799,6 +1011,7 @@ pub async fn deploy_pkg(
 
     let chain_info = client.chain_info().await?;
     let chain_id = chain_info.consensus_parameters.chain_id();
+    let tx_id = tx.id(&chain_id);
 
     // If only submitting the transaction, don't wait for the deployment to complete
     let contract_id: ContractId = if command.submit_only {
@@ -840,7 +1053,7 @@ pub async fn deploy_pkg(
                     // Create a deployment artifact.
                     create_deployment_artifact(
                         DeploymentArtifact {
-                            transaction_id: format!("0x{}", tx.id(&chain_id)),
+                            transaction_id: format!("0x{}", tx_id),
                             salt: format!("0x{}", salt),
                             network_endpoint: node_url.to_string(),
                             chain_id,
@@ -878,7 +1091,18 @@ pub async fn deploy_pkg(
             })??
     };
 
-    Ok(contract_id)
+    Ok((contract_id, tx_id))
+}
+
+/// Loads the deployment manifest of the network the given provider is connected to.
+async fn load_deployment_manifest(
+    provider: &Provider,
+    project_dir: &Path,
+) -> Result<DeploymentManifest> {
+    let chain_info = provider.chain_info().await?;
+    let chain_id = chain_info.consensus_parameters.chain_id();
+    let network = network_name(&chain_info.name, chain_id);
+    DeploymentManifest::load(project_dir, &network, chain_id)
 }
 
 fn tx_policies_from_cmd(cmd: &cmd::Deploy) -> TxPolicies {
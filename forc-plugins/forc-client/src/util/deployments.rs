@@ -0,0 +1,332 @@
+//! Deployment manifests record, per network, the packages deployed by `forc deploy`.
+//!
+//! A manifest is written to `deployments/<network>.json` within the deployed project's root
+//! directory, and is meant to be checked in alongside the project so that other tools can look up
+//! the address of a deployed contract by its package name.
+use crate::util::target::Target;
+use anyhow::{bail, Context, Result};
+use fuel_abi_types::abi::unified_program::UnifiedProgramABI;
+use fuel_crypto::fuel_types::ChainId;
+use fuel_tx::ContractId;
+use fuels_core::{
+    codec::{ABIDecoder, ABIEncoder, EncoderConfig},
+    types::param_types::ParamType,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use sway_core::asm_generation::ProgramABI;
+
+/// The name of the directory, relative to the project's root directory, that deployment manifests
+/// are written to.
+pub const DEPLOYMENTS_DIR_NAME: &str = "deployments";
+
+/// The packages deployed to a single network.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentManifest {
+    /// The name of the network, e.g. `testnet`, also used as the name of the manifest file.
+    pub network: String,
+    pub chain_id: ChainId,
+    /// The deployed contracts, by package name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub contracts: BTreeMap<String, ContractDeployment>,
+    /// The deployed scripts and predicates, by package name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub executables: BTreeMap<String, ExecutableDeployment>,
+}
+
+/// The latest deployment of a contract package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractDeployment {
+    pub contract_id: String,
+    pub salt: String,
+    /// The SHA-256 hash of the contract's bytecode, including its configurables.
+    pub bytecode_hash: String,
+    /// The initial state root of the contract's storage slots.
+    pub state_root: String,
+    pub chunked: bool,
+    /// The ids of the blobs loaded by a chunked contract.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blob_ids: Vec<String>,
+    /// The proxy contract whose target is set to this contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// The encoded values of the contract's configurables, by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub configurables: BTreeMap<String, String>,
+    /// The ids of the transactions submitted to deploy the contract.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transaction_ids: Vec<String>,
+}
+
+/// The latest deployment of a script or predicate package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutableDeployment {
+    /// The SHA-256 hash of the executable's bytecode.
+    pub bytecode_hash: String,
+    /// The id of the blob containing the executable's code.
+    pub blob_id: String,
+}
+
+impl DeploymentManifest {
+    /// Creates an empty manifest for the given network.
+    pub fn new(network: &str, chain_id: ChainId) -> Self {
+        Self {
+            network: network.to_string(),
+            chain_id,
+            contracts: BTreeMap::default(),
+            executables: BTreeMap::default(),
+        }
+    }
+
+    /// The path of the manifest of the given network, within the given project directory.
+    pub fn path(project_dir: &Path, network: &str) -> PathBuf {
+        project_dir
+            .join(DEPLOYMENTS_DIR_NAME)
+            .join(network)
+            .with_extension("json")
+    }
+
+    /// Reads the manifest at the given path.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let manifest_str = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read deployment manifest {}", path.display()))?;
+        serde_json::from_str(&manifest_str)
+            .with_context(|| format!("failed to parse deployment manifest {}", path.display()))
+    }
+
+    /// Reads the manifest of the given network within the given project directory, or creates an
+    /// empty one if nothing has been deployed to the network yet.
+    ///
+    /// Returns an error if the recorded chain id does not match the network's chain id.
+    pub fn load(project_dir: &Path, network: &str, chain_id: ChainId) -> Result<Self> {
+        let path = Self::path(project_dir, network);
+        if !path.exists() {
+            return Ok(Self::new(network, chain_id));
+        }
+        let manifest = Self::from_file(&path)?;
+        if manifest.chain_id != chain_id {
+            bail!(
+                "deployment manifest {} was recorded for chain id {}, but the node reports chain id {}",
+                path.display(),
+                u64::from(manifest.chain_id),
+                u64::from(chain_id)
+            );
+        }
+        Ok(manifest)
+    }
+
+    /// Writes the manifest to `deployments/<network>.json` within the given project directory.
+    pub fn to_file(&self, project_dir: &Path) -> Result<()> {
+        let path = Self::path(project_dir, &self.network);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let manifest_file = std::fs::File::create(&path)?;
+        serde_json::to_writer_pretty(&manifest_file, self)?;
+        Ok(())
+    }
+
+    /// The id of the deployed contract of the given package.
+    pub fn contract_id(&self, pkg_name: &str) -> Option<ContractId> {
+        let deployment = self.contracts.get(pkg_name)?;
+        ContractId::from_str(&deployment.contract_id).ok()
+    }
+
+    /// The address to call into for the given contract package: its proxy contract if it has
+    /// one, or the deployed contract otherwise.
+    pub fn contract_address(&self, pkg_name: &str) -> Option<ContractId> {
+        let deployment = self.contracts.get(pkg_name)?;
+        let address = deployment.proxy.as_ref().unwrap_or(&deployment.contract_id);
+        ContractId::from_str(address).ok()
+    }
+}
+
+/// The name of a network as used for its deployment manifest, derived from the name of its chain.
+///
+/// The known targets are named `testnet`, `mainnet` and `local`; other chain names are lowercased
+/// with every character other than an ASCII letter or digit replaced by `-`.
+pub fn network_name(chain_name: &str, chain_id: ChainId) -> String {
+    match Target::from_str(chain_name) {
+        Ok(Target::Testnet) => "testnet".to_string(),
+        Ok(Target::Mainnet) => "mainnet".to_string(),
+        Ok(Target::Local) => "local".to_string(),
+        Err(_) => {
+            let name = chain_name
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_lowercase()
+                    } else {
+                        '-'
+                    }
+                })
+                .collect::<String>();
+            let name = name.trim_matches('-');
+            if name.is_empty() {
+                format!("chain-{}", u64::from(chain_id))
+            } else {
+                name.to_string()
+            }
+        }
+    }
+}
+
+/// The encoded values of the configurables of a program, by name.
+///
+/// Each value is decoded from the bytecode at its offset according to its type in the ABI, so that
+/// only the bytes of the value itself are recorded.
+pub fn configurable_values(abi: &ProgramABI, bytecode: &[u8]) -> Result<BTreeMap<String, String>> {
+    let ProgramABI::Fuel(abi) = abi else {
+        return Ok(BTreeMap::default());
+    };
+    let abi = UnifiedProgramABI::from_counterpart(abi)?;
+    let Some(configurables) = &abi.configurables else {
+        return Ok(BTreeMap::default());
+    };
+    let type_lookup = abi
+        .types
+        .iter()
+        .map(|decl| (decl.type_id, decl.clone()))
+        .collect::<HashMap<_, _>>();
+    let abi_decoder = ABIDecoder::default();
+    let abi_encoder = ABIEncoder::new(EncoderConfig::default());
+    configurables
+        .iter()
+        .map(|configurable| {
+            let param_type =
+                ParamType::try_from_type_application(&configurable.application, &type_lookup)?;
+            let bytes = bytecode
+                .get(configurable.offset as usize..)
+                .with_context(|| {
+                    format!(
+                        "the offset of configurable {} is past the end of the bytecode",
+                        configurable.name
+                    )
+                })?;
+            // Encoding the decoded value again yields exactly the bytes it was decoded from.
+            let value = abi_encoder.encode(&[abi_decoder.decode(&param_type, bytes)?])?;
+            Ok((
+                configurable.name.clone(),
+                format!("0x{}", hex::encode(value)),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_deployment(contract_id: &str, proxy: Option<&str>) -> ContractDeployment {
+        ContractDeployment {
+            contract_id: contract_id.to_string(),
+            salt: format!("0x{}", fuel_tx::Salt::default()),
+            bytecode_hash: format!("0x{}", fuel_tx::Bytes32::default()),
+            state_root: format!("0x{}", fuel_tx::Bytes32::default()),
+            chunked: false,
+            blob_ids: vec![],
+            proxy: proxy.map(str::to_string),
+            configurables: BTreeMap::default(),
+            transaction_ids: vec![],
+        }
+    }
+
+    #[test]
+    fn test_deployment_manifest_round_trip() {
+        let project_dir = tempfile::tempdir().unwrap();
+        let contract_id = format!("0x{}", ContractId::from([1u8; 32]));
+        let proxy_id = format!("0x{}", ContractId::from([2u8; 32]));
+
+        let mut manifest = DeploymentManifest::new("testnet", ChainId::new(0));
+        manifest.contracts.insert(
+            "contract_a".to_string(),
+            contract_deployment(&contract_id, None),
+        );
+        manifest.contracts.insert(
+            "contract_b".to_string(),
+            contract_deployment(&contract_id, Some(&proxy_id)),
+        );
+        manifest.to_file(project_dir.path()).unwrap();
+        assert!(project_dir
+            .path()
+            .join("deployments")
+            .join("testnet.json")
+            .exists());
+
+        let loaded =
+            DeploymentManifest::load(project_dir.path(), "testnet", ChainId::new(0)).unwrap();
+        assert_eq!(loaded, manifest);
+        assert_eq!(
+            loaded.contract_id("contract_a"),
+            Some(ContractId::from([1u8; 32]))
+        );
+        assert_eq!(
+            loaded.contract_address("contract_a"),
+            Some(ContractId::from([1u8; 32]))
+        );
+        assert_eq!(
+            loaded.contract_address("contract_b"),
+            Some(ContractId::from([2u8; 32]))
+        );
+        assert_eq!(loaded.contract_id("contract_c"), None);
+
+        // A manifest recorded for another chain is rejected.
+        assert!(DeploymentManifest::load(project_dir.path(), "testnet", ChainId::new(1)).is_err());
+        // Networks without a manifest start out empty.
+        let empty = DeploymentManifest::load(project_dir.path(), "local", ChainId::new(0)).unwrap();
+        assert!(empty.contracts.is_empty());
+    }
+
+    #[test]
+    fn test_configurable_values() {
+        let abi = serde_json::from_str(
+            r#"{
+                "programType": "contract",
+                "specVersion": "1",
+                "encodingVersion": "1",
+                "concreteTypes": [
+                    { "type": "u64", "concreteTypeId": "1506e6f44c1d6291cdf46395a8e573276a4fa79e8ace3fc891e092ef32d1b0a0" },
+                    { "type": "bool", "concreteTypeId": "b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903" }
+                ],
+                "metadataTypes": [],
+                "functions": [],
+                "loggedTypes": [],
+                "messagesTypes": [],
+                "configurables": [
+                    { "name": "AMOUNT", "concreteTypeId": "1506e6f44c1d6291cdf46395a8e573276a4fa79e8ace3fc891e092ef32d1b0a0", "offset": 8 },
+                    { "name": "ENABLED", "concreteTypeId": "b760f44fa5965c2474a3b471467a22c43185152129295af588b022ae50b50903", "offset": 16 }
+                ]
+            }"#,
+        )
+        .unwrap();
+        // The values are followed by padding and the rest of the data section.
+        let mut bytecode = vec![0xff; 8];
+        bytecode.extend(42u64.to_be_bytes());
+        bytecode.push(1);
+        bytecode.extend([0; 7]);
+        bytecode.extend([0xff; 8]);
+
+        let values = configurable_values(&ProgramABI::Fuel(abi), &bytecode).unwrap();
+        assert_eq!(
+            values,
+            BTreeMap::from([
+                ("AMOUNT".to_string(), "0x000000000000002a".to_string()),
+                ("ENABLED".to_string(), "0x01".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_network_name() {
+        let chain_id = ChainId::new(9889);
+        assert_eq!(network_name("Fuel Sepolia Testnet", chain_id), "testnet");
+        assert_eq!(network_name("Ignition", chain_id), "mainnet");
+        assert_eq!(network_name("local", chain_id), "local");
+        assert_eq!(network_name("My Devnet (v2)", chain_id), "my-devnet--v2");
+        assert_eq!(network_name("", chain_id), "chain-9889");
+    }
+}
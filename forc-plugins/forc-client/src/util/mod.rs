@@ -1,5 +1,6 @@
 pub mod account;
 pub mod aws;
+pub mod deployments;
 pub(crate) mod encode;
 pub(crate) mod node_url;
 pub(crate) mod pkg;
@@ -2,7 +2,10 @@ use forc::cli::shared::Pkg;
 use forc_client::{
     cmd,
     op::{deploy, DeployedContract, DeployedExecutable, DeployedPackage},
-    util::{account::ForcClientAccount, tx::update_proxy_contract_target},
+    util::{
+        account::ForcClientAccount, deployments::DeploymentManifest,
+        tx::update_proxy_contract_target,
+    },
     NodeTarget,
 };
 use forc_pkg::manifest::Proxy;
@@ -426,6 +429,105 @@ async fn test_deploy_submit_only() {
     assert_eq!(contract_ids, expected)
 }
 
+#[tokio::test]
+async fn test_redeploy_unchanged_contract_is_skipped() {
+    let (mut node, port) = run_node();
+    let tmp_dir = tempdir().unwrap();
+    let project_dir = test_data_path().join("standalone_contract");
+    copy_dir(&project_dir, tmp_dir.path()).unwrap();
+    patch_manifest_file_with_path_std(tmp_dir.path()).unwrap();
+
+    let node_url = format!("http://127.0.0.1:{}/v1/graphql", port);
+    let deploy_cmd = || cmd::Deploy {
+        pkg: Pkg {
+            path: Some(tmp_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        salt: Some(vec![format!("{}", Salt::default())]),
+        node: NodeTarget {
+            node_url: Some(node_url.clone()),
+            target: None,
+            testnet: false,
+            mainnet: false,
+        },
+        default_signer: true,
+        ..Default::default()
+    };
+    let first = deploy(deploy_cmd()).await.unwrap();
+    // Deploying the same contract with the same salt again would fail, as it already exists.
+    let second = deploy(deploy_cmd()).await.unwrap();
+    node.kill().unwrap();
+    assert_eq!(first, second);
+
+    let manifest_paths = fs::read_dir(tmp_dir.path().join("deployments"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    assert_eq!(manifest_paths.len(), 1);
+    let manifest = DeploymentManifest::from_file(&manifest_paths[0]).unwrap();
+    let deployment = &manifest.contracts["standalone_contract"];
+    assert_eq!(deployment.salt, format!("0x{}", Salt::default()));
+    assert_eq!(deployment.transaction_ids.len(), 1);
+    assert_eq!(
+        manifest.contract_address("standalone_contract"),
+        Some(
+            ContractId::from_str(
+                "02a7e78ef0514b80ab56b409f06f895d8939640b6f6d746fcbb15d3e0c6a1a3b",
+            )
+            .unwrap()
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_deploy_dry_run() {
+    let tmp_dir = tempdir().unwrap();
+    let project_dir = test_data_path().join("standalone_contract");
+    copy_dir(&project_dir, tmp_dir.path()).unwrap();
+    patch_manifest_file_with_path_std(tmp_dir.path()).unwrap();
+
+    let pkg = Pkg {
+        path: Some(tmp_dir.path().display().to_string()),
+        ..Default::default()
+    };
+    // No node is running, the transactions are only built.
+    let cmd = cmd::Deploy {
+        pkg,
+        salt: Some(vec![format!("{}", Salt::default())]),
+        dry_run: true,
+        ..Default::default()
+    };
+    let contract_ids = deploy(cmd).await.unwrap();
+    let expected = vec![DeployedPackage::Contract(DeployedContract {
+        id: ContractId::from_str(
+            "02a7e78ef0514b80ab56b409f06f895d8939640b6f6d746fcbb15d3e0c6a1a3b",
+        )
+        .unwrap(),
+        proxy: None,
+        chunked: false,
+    })];
+    assert_eq!(contract_ids, expected);
+
+    let dry_run_dir = tmp_dir
+        .path()
+        .join("out")
+        .join("deployments")
+        .join("dry-run");
+    let summary: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(dry_run_dir.join("standalone_contract-dry-run.json")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        summary["contract_id"],
+        "0x02a7e78ef0514b80ab56b409f06f895d8939640b6f6d746fcbb15d3e0c6a1a3b"
+    );
+    let tx_file = summary["transactions"][0]["file"].as_str().unwrap();
+    assert_eq!(tx_file, "standalone_contract-0-create.json");
+    let tx: fuel_tx::Transaction =
+        serde_json::from_str(&fs::read_to_string(dry_run_dir.join(tx_file)).unwrap()).unwrap();
+    assert!(tx.is_create());
+}
+
 #[tokio::test]
 async fn test_deploy_fresh_proxy() {
     let (mut node, port) = run_node();
@@ -1,51 +1,97 @@
 use crate::{
     error::{ArgumentError, Error, Result},
+    local::{LocalRunResult, LocalVm},
     names::{register_index, register_name},
     ContractId, FuelClient, RunResult, Transaction,
 };
-use fuel_vm::consts::{VM_MAX_RAM, VM_REGISTER_COUNT, WORD_SIZE};
+use fuel_vm::{
+    consts::{VM_MAX_RAM, VM_REGISTER_COUNT, WORD_SIZE},
+    fuel_asm::Instruction,
+    fuel_tx::Receipt,
+};
 use shellfish::{handler::DefaultAsyncHandler, input_handler::IO, Command as ShCommand, Shell};
+use sway_core::source_map::SourceMap;
 
 pub struct State {
-    client: FuelClient,
-    session_id: String,
+    backend: Backend,
+    /// The source maps of the script and of the contracts, used to resolve source-level
+    /// breakpoints. The script is identified by the zero contract id.
+    source_maps: Vec<(ContractId, SourceMap)>,
+}
+
+/// Where transactions are executed.
+enum Backend {
+    /// A node, debugged through its GraphQL API.
+    Client {
+        client: FuelClient,
+        session_id: String,
+    },
+    /// A VM embedded in the debugger.
+    Local(Box<LocalVm>),
 }
 
 /// Start the CLI debug interface
 pub async fn start_cli(api_url: &str) -> Result<()> {
     let mut shell = Shell::new_async(
         State {
-            client: FuelClient::new(api_url).map_err(|e| Error::FuelClientError(e.to_string()))?,
-            session_id: String::new(), // Placeholder
+            backend: Backend::Client {
+                client: FuelClient::new(api_url)
+                    .map_err(|e| Error::FuelClientError(e.to_string()))?,
+                session_id: String::new(), // Placeholder
+            },
+            source_maps: vec![],
         },
         ">> ",
     );
 
     register_commands(&mut shell);
 
-    let session_id = shell
-        .state
-        .client
+    let Backend::Client { client, session_id } = &mut shell.state.backend else {
+        unreachable!("the shell was created with a client");
+    };
+    *session_id = client
         .start_session()
         .await
         .map_err(|e| Error::FuelClientError(e.to_string()))?;
-    shell.state.session_id.clone_from(&session_id);
 
     shell
         .run_async()
         .await
         .map_err(|e| Error::FuelClientError(e.to_string()))?;
 
-    shell
-        .state
-        .client
-        .end_session(&session_id)
+    let Backend::Client { client, session_id } = &shell.state.backend else {
+        unreachable!("the shell was created with a client");
+    };
+    client
+        .end_session(session_id)
         .await
         .map_err(|e| Error::FuelClientError(e.to_string()))?;
 
     Ok(())
 }
 
+/// Start the CLI debug interface on an embedded VM, without a running node.
+///
+/// The source maps are used to resolve breakpoints set on source lines.
+pub async fn start_local_cli(vm: LocalVm, source_maps: Vec<(ContractId, SourceMap)>) -> Result<()> {
+    let mut shell = Shell::new_async(
+        State {
+            backend: Backend::Local(Box::new(vm)),
+            source_maps,
+        },
+        ">> ",
+    );
+
+    register_commands(&mut shell);
+
+    shell
+        .run_async()
+        .await
+        .map_err(|e| Error::SessionError(e.to_string()))?;
+
+    Ok(())
+}
+
 fn register_commands(shell: &mut Shell<'_, State, &str, DefaultAsyncHandler, IO>) {
     // Registers an async command by wrapping the handler function `$f`,
     // converting its error type into `Box<dyn std::error::Error>`, and
@@ -89,7 +135,7 @@ fn register_commands(shell: &mut Shell<'_, State, &str, DefaultAsyncHandler, IO>
     );
     command!(
         cmd_breakpoint,
-        "[contract_id] offset -- set a breakpoint",
+        "[contract_id] offset | path/to/file.sw:line -- set a breakpoint",
         ["b", "breakpoint"]
     );
     command!(
@@ -111,13 +157,19 @@ async fn cmd_start_tx(state: &mut State, mut args: Vec<String>) -> Result<()> {
     let tx: Transaction = serde_json::from_slice(&tx_json).map_err(Error::JsonError)?;
 
     // Start the transaction
-    let status = state
-        .client
-        .start_tx(&state.session_id, &tx)
-        .await
-        .map_err(|e| Error::FuelClientError(e.to_string()))?;
-
-    pretty_print_run_result(&status);
+    match &mut state.backend {
+        Backend::Client { client, session_id } => {
+            let status = client
+                .start_tx(session_id, &tx)
+                .await
+                .map_err(|e| Error::FuelClientError(e.to_string()))?;
+            pretty_print_run_result(state, &status);
+        }
+        Backend::Local(vm) => {
+            let result = vm.start_tx(tx)?;
+            print_local_run_result(state, &result);
+        }
+    }
     Ok(())
 }
 
@@ -126,11 +178,15 @@ async fn cmd_reset(state: &mut State, mut args: Vec<String>) -> Result<()> {
     ArgumentError::ensure_arg_count(&args, 0, 0)?; // Ensure no extra arguments
 
     // Reset the session
-    state
-        .client
-        .reset(&state.session_id)
-        .await
-        .map_err(|e| Error::FuelClientError(e.to_string()))?;
+    match &mut state.backend {
+        Backend::Client { client, session_id } => {
+            client
+                .reset(session_id)
+                .await
+                .map_err(|e| Error::FuelClientError(e.to_string()))?;
+        }
+        Backend::Local(vm) => vm.reset(),
+    }
 
     Ok(())
 }
@@ -140,13 +196,19 @@ async fn cmd_continue(state: &mut State, mut args: Vec<String>) -> Result<()> {
     ArgumentError::ensure_arg_count(&args, 0, 0)?; // Ensure no extra arguments
 
     // Continue the transaction
-    let status = state
-        .client
-        .continue_tx(&state.session_id)
-        .await
-        .map_err(|e| Error::FuelClientError(e.to_string()))?;
-
-    pretty_print_run_result(&status);
+    match &mut state.backend {
+        Backend::Client { client, session_id } => {
+            let status = client
+                .continue_tx(session_id)
+                .await
+                .map_err(|e| Error::FuelClientError(e.to_string()))?;
+            pretty_print_run_result(state, &status);
+        }
+        Backend::Local(vm) => {
+            let result = vm.continue_tx()?;
+            print_local_run_result(state, &result);
+        }
+    }
     Ok(())
 }
 
@@ -160,11 +222,13 @@ async fn cmd_step(state: &mut State, mut args: Vec<String>) -> Result<()> {
         .map_or(true, |v| !["off", "no", "disable"].contains(&v.as_str()));
 
     // Call the client
-    state
-        .client
-        .set_single_stepping(&state.session_id, enable)
-        .await
-        .map_err(|e| Error::FuelClientError(e.to_string()))?;
+    match &mut state.backend {
+        Backend::Client { client, session_id } => client
+            .set_single_stepping(session_id, enable)
+            .await
+            .map_err(|e| Error::FuelClientError(e.to_string()))?,
+        Backend::Local(vm) => vm.set_single_stepping(enable),
+    }
 
     Ok(())
 }
@@ -173,23 +237,45 @@ async fn cmd_breakpoint(state: &mut State, mut args: Vec<String>) -> Result<()>
     args.remove(0); // Remove command name
     ArgumentError::ensure_arg_count(&args, 1, 2)?;
 
-    let offset_str = args.pop().unwrap(); // Safe due to arg count check
-    let offset = parse_int(&offset_str).ok_or(ArgumentError::InvalidNumber(offset_str))?;
-
-    let contract = if let Some(contract_id) = args.pop() {
-        contract_id
-            .parse::<ContractId>()
-            .map_err(|_| ArgumentError::Invalid(format!("Invalid contract ID: {}", contract_id)))?
-    } else {
-        ContractId::zeroed()
+    // Source locations are resolved to the first instruction of the line, in each program
+    // compiled from the source file.
+    let source_location = match args.as_slice() {
+        [location] if location.contains(':') => Some(location.clone()),
+        _ => None,
+    };
+    let breakpoints = match source_location {
+        Some(location) => {
+            let breakpoints = state.resolve_source_location(&location)?;
+            for (contract, offset) in &breakpoints {
+                println!("Breakpoint set at address {offset} of contract {contract:#x}");
+            }
+            breakpoints
+        }
+        None => {
+            let offset_str = args.pop().unwrap(); // Safe due to arg count check
+            let offset = parse_int(&offset_str).ok_or(ArgumentError::InvalidNumber(offset_str))?;
+
+            let contract = if let Some(contract_id) = args.pop() {
+                contract_id.parse::<ContractId>().map_err(|_| {
+                    ArgumentError::Invalid(format!("Invalid contract ID: {}", contract_id))
+                })?
+            } else {
+                ContractId::zeroed()
+            };
+            vec![(contract, offset as u64)]
+        }
     };
 
     // Call client
-    state
-        .client
-        .set_breakpoint(&state.session_id, contract, offset as u64)
-        .await
-        .map_err(|e| Error::FuelClientError(e.to_string()))?;
+    for (contract, offset) in breakpoints {
+        match &mut state.backend {
+            Backend::Client { client, session_id } => client
+                .set_breakpoint(session_id, contract, offset)
+                .await
+                .map_err(|e| Error::FuelClientError(e.to_string()))?,
+            Backend::Local(vm) => vm.set_breakpoint(contract, offset),
+        }
+    }
 
     Ok(())
 }
@@ -200,11 +286,7 @@ async fn cmd_registers(state: &mut State, mut args: Vec<String>) -> Result<()> {
     if args.is_empty() {
         // Print all registers
         for r in 0..VM_REGISTER_COUNT {
-            let value = state
-                .client
-                .register(&state.session_id, r as u32)
-                .await
-                .map_err(|e| Error::FuelClientError(e.to_string()))?;
+            let value = state.register(r).await?;
             println!("reg[{:#x}] = {:<8} # {}", r, value, register_name(r));
         }
     } else {
@@ -212,11 +294,7 @@ async fn cmd_registers(state: &mut State, mut args: Vec<String>) -> Result<()> {
         for arg in &args {
             if let Some(v) = parse_int(arg) {
                 if v < VM_REGISTER_COUNT {
-                    let value = state
-                        .client
-                        .register(&state.session_id, v as u32)
-                        .await
-                        .map_err(|e| Error::FuelClientError(e.to_string()))?;
+                    let value = state.register(v).await?;
                     println!("reg[{:#02x}] = {:<8} # {}", v, value, register_name(v));
                 } else {
                     return Err(ArgumentError::InvalidNumber(format!(
@@ -225,11 +303,7 @@ async fn cmd_registers(state: &mut State, mut args: Vec<String>) -> Result<()> {
                     .into());
                 }
             } else if let Some(index) = register_index(arg) {
-                let value = state
-                    .client
-                    .register(&state.session_id, index as u32)
-                    .await
-                    .map_err(|e| Error::FuelClientError(e.to_string()))?;
+                let value = state.register(index).await?;
                 println!("reg[{index:#02x}] = {value:<8} # {arg}");
             } else {
                 return Err(ArgumentError::Invalid(format!("Unknown register name: {arg}")).into());
@@ -260,11 +334,13 @@ async fn cmd_memory(state: &mut State, mut args: Vec<String>) -> Result<()> {
     ArgumentError::ensure_arg_count(&args, 0, 0)?;
 
     // Fetch memory from the client
-    let mem = state
-        .client
-        .memory(&state.session_id, offset as u32, limit as u32)
-        .await
-        .map_err(|e| Error::FuelClientError(e.to_string()))?;
+    let mem = match &state.backend {
+        Backend::Client { client, session_id } => client
+            .memory(session_id, offset as u32, limit as u32)
+            .await
+            .map_err(|e| Error::FuelClientError(e.to_string()))?,
+        Backend::Local(vm) => vm.memory(offset, limit)?,
+    };
 
     // Print memory contents
     for (i, chunk) in mem.chunks(WORD_SIZE).enumerate() {
@@ -277,19 +353,96 @@ async fn cmd_memory(state: &mut State, mut args: Vec<String>) -> Result<()> {
     Ok(())
 }
 
+impl State {
+    async fn register(&self, index: usize) -> Result<u64> {
+        match &self.backend {
+            Backend::Client { client, session_id } => client
+                .register(session_id, index as u32)
+                .await
+                .map_err(|e| Error::FuelClientError(e.to_string())),
+            Backend::Local(vm) => Ok(vm.register(index)),
+        }
+    }
+
+    /// Resolves a `path/to/file.sw:line` location to the offsets, in bytes, of the first
+    /// instruction of the line in the script and in each contract.
+    fn resolve_source_location(&self, location: &str) -> Result<Vec<(ContractId, u64)>> {
+        let (file, line) = location
+            .rsplit_once(':')
+            .and_then(|(file, line)| Some((file, parse_int(line)?)))
+            .ok_or_else(|| {
+                ArgumentError::Invalid(format!("Invalid source location: {location}"))
+            })?;
+
+        let breakpoints = self
+            .source_maps
+            .iter()
+            .filter_map(|(contract, source_map)| {
+                source_map
+                    .map
+                    .iter()
+                    .find(|(_, span)| {
+                        let (path, range) =
+                            span.to_span(&source_map.paths, &source_map.dependency_paths);
+                        range.start.line == line && path.ends_with(file)
+                    })
+                    .map(|(index, _)| (*contract, (*index * Instruction::SIZE) as u64))
+            })
+            .collect::<Vec<_>>();
+
+        if breakpoints.is_empty() {
+            return Err(ArgumentError::Invalid(format!(
+                "No instructions found at {location} in the loaded source maps"
+            ))
+            .into());
+        }
+        Ok(breakpoints)
+    }
+
+    /// Returns the source location of the instruction at the given offset of a contract, if its
+    /// source map is loaded.
+    fn source_location(&self, contract: &ContractId, offset: u64) -> Option<String> {
+        let (_, source_map) = self.source_maps.iter().find(|(id, _)| id == contract)?;
+        let (path, range) = source_map.addr_to_span(offset as usize / Instruction::SIZE)?;
+        Some(format!(
+            "{}:{}:{}",
+            path.display(),
+            range.start.line,
+            range.start.col
+        ))
+    }
+}
+
 /// Pretty-prints the result of a run, including receipts and breakpoint information.
 ///
 /// Outputs each receipt in the `RunResult` and details about the breakpoint if present.
 /// If the execution terminated without hitting a breakpoint, it prints "Terminated".
-fn pretty_print_run_result(rr: &RunResult) {
-    for receipt in rr.receipts() {
+fn pretty_print_run_result(state: &State, rr: &RunResult) {
+    let breakpoint = rr.breakpoint.as_ref().map(|bp| {
+        let contract = bp.contract.to_string().parse().unwrap_or_default();
+        (contract, bp.pc.0)
+    });
+    print_run_result(state, rr.receipts(), breakpoint);
+}
+
+/// Pretty-prints the result of a run on the embedded VM, as [pretty_print_run_result] does.
+fn print_local_run_result(state: &State, result: &LocalRunResult) {
+    print_run_result(state, result.receipts.iter().cloned(), result.breakpoint);
+}
+
+fn print_run_result(
+    state: &State,
+    receipts: impl Iterator<Item = Receipt>,
+    breakpoint: Option<(ContractId, u64)>,
+) {
+    for receipt in receipts {
         println!("Receipt: {receipt:?}");
     }
-    if let Some(bp) = &rr.breakpoint {
-        println!(
-            "Stopped on breakpoint at address {} of contract {}",
-            bp.pc.0, bp.contract
-        );
+    if let Some((contract, pc)) = breakpoint {
+        println!("Stopped on breakpoint at address {pc} of contract {contract:#x}");
+        if let Some(location) = state.source_location(&contract, pc) {
+            println!("  at {location}");
+        }
     } else {
         println!("Terminated");
     }
@@ -1,5 +1,6 @@
 pub mod cli;
 pub mod error;
+pub mod local;
 pub mod names;
 pub mod server;
 pub mod types;
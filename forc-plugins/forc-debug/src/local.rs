@@ -0,0 +1,279 @@
+//! Debugging of transactions on an embedded VM, against a snapshot of the chain state, without a
+//! running node.
+
+use crate::{
+    error::{ArgumentError, Error, Result},
+    ContractId, Transaction,
+};
+use fuel_vm::{
+    checked_transaction::IntoChecked,
+    consts::VM_MAX_RAM,
+    fuel_asm::RegId,
+    fuel_tx::{
+        field::Script as _, AssetId, Bytes32, ConsensusParameters, Contract, Receipt, Script,
+        StorageSlot,
+    },
+    interpreter::{Interpreter, InterpreterParams, MemoryInstance},
+    prelude::Breakpoint,
+    state::{DebugEval, ProgramState},
+    storage::{ContractsAssetsStorage, InterpreterStorage, MemoryStorage},
+};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// The gas price of the transactions executed on the embedded VM.
+const GAS_PRICE: u64 = 0;
+
+/// A snapshot of the chain state transactions are executed against.
+///
+/// The snapshot is read from a JSON file:
+///
+/// ```json
+/// {
+///     "block_height": 1000,
+///     "contracts": {
+///         "0x<contract id>": {
+///             "storage": { "0x<key>": "0x<value>" },
+///             "balances": { "0x<asset id>": 100 }
+///         }
+///     }
+/// }
+/// ```
+///
+/// The bytecode of each contract is loaded separately.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StateSnapshot {
+    /// The height of the block the transactions are executed in.
+    #[serde(default)]
+    pub block_height: u32,
+    /// The state of the deployed contracts.
+    #[serde(default)]
+    pub contracts: BTreeMap<ContractId, ContractSnapshot>,
+}
+
+/// The state of a deployed contract.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContractSnapshot {
+    /// The storage slots of the contract.
+    #[serde(default)]
+    pub storage: BTreeMap<Bytes32, Bytes32>,
+    /// The balances of the contract, by asset.
+    #[serde(default)]
+    pub balances: BTreeMap<AssetId, u64>,
+}
+
+impl StateSnapshot {
+    /// Reads a snapshot from a JSON file.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let json = std::fs::read(path).map_err(Error::IoError)?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+}
+
+/// The result of running a transaction until it ends or stops on a breakpoint.
+#[derive(Debug, Clone)]
+pub struct LocalRunResult {
+    /// The receipts produced so far.
+    pub receipts: Vec<Receipt>,
+    /// The contract and the offset of the breakpoint execution stopped on, if any.
+    pub breakpoint: Option<(ContractId, u64)>,
+}
+
+/// A VM embedded in the debugger, executing transactions against a state snapshot.
+///
+/// Each transaction starts from the state of the snapshot, so a transaction can be debugged again
+/// after a reset.
+pub struct LocalVm {
+    /// The state of the snapshot, with the contracts deployed.
+    storage: MemoryStorage,
+    params: ConsensusParameters,
+    block_height: u32,
+    interpreter: Interpreter<MemoryInstance, MemoryStorage, Script>,
+    breakpoints: Vec<Breakpoint>,
+    single_stepping: bool,
+}
+
+impl LocalVm {
+    /// Deploys the given contracts with the state of the snapshot.
+    ///
+    /// Every contract of the snapshot must have its bytecode given, while contracts missing from
+    /// the snapshot start with an empty state.
+    pub fn new(snapshot: StateSnapshot, contracts: Vec<(ContractId, Vec<u8>)>) -> Result<Self> {
+        let mut storage = MemoryStorage::default();
+        storage.set_block_height(snapshot.block_height.into());
+
+        if let Some(id) = snapshot
+            .contracts
+            .keys()
+            .find(|id| !contracts.iter().any(|(contract_id, _)| contract_id == *id))
+        {
+            return Err(ArgumentError::Invalid(format!(
+                "No bytecode given for contract {id:#x} of the state snapshot"
+            ))
+            .into());
+        }
+
+        for (id, bytecode) in contracts {
+            let state = snapshot.contracts.get(&id).cloned().unwrap_or_default();
+            let slots = state
+                .storage
+                .into_iter()
+                .map(|(key, value)| StorageSlot::new(key, value))
+                .collect::<Vec<_>>();
+            storage
+                .deploy_contract_with_id(&slots, &Contract::from(bytecode), &id)
+                .map_err(|e| Error::VMError(format!("{e:?}")))?;
+            for (asset_id, amount) in state.balances {
+                storage
+                    .contract_asset_id_balance_insert(&id, &asset_id, amount)
+                    .map_err(|e| Error::VMError(format!("{e:?}")))?;
+            }
+        }
+
+        let params = ConsensusParameters::default();
+        let interpreter = new_interpreter(&storage, &params);
+        Ok(Self {
+            storage,
+            params,
+            block_height: snapshot.block_height,
+            interpreter,
+            breakpoints: vec![],
+            single_stepping: false,
+        })
+    }
+
+    /// Starts executing a script transaction, from the state of the snapshot.
+    pub fn start_tx(&mut self, tx: Transaction) -> Result<LocalRunResult> {
+        let Transaction::Script(script) = tx else {
+            return Err(ArgumentError::Invalid(
+                "Only script transactions can be debugged".to_string(),
+            )
+            .into());
+        };
+        if script.script().is_empty() {
+            return Err(ArgumentError::Invalid("The script is empty".to_string()).into());
+        }
+
+        // Signatures and predicates are not checked, as the transaction may have been signed for
+        // another chain.
+        let tx = script
+            .into_checked_basic(self.block_height.into(), &self.params)
+            .map_err(|e| Error::VMError(format!("Invalid transaction: {e:?}")))?
+            .into_ready(GAS_PRICE, self.params.gas_costs(), self.params.fee_params())
+            .map_err(|e| Error::VMError(format!("Invalid transaction: {e:?}")))?;
+
+        self.interpreter = new_interpreter(&self.storage, &self.params);
+        self.interpreter.set_single_stepping(self.single_stepping);
+        for breakpoint in &self.breakpoints {
+            self.interpreter.set_breakpoint(*breakpoint);
+        }
+
+        let state = *self
+            .interpreter
+            .transact(tx)
+            .map_err(|e| Error::VMError(e.to_string()))?
+            .state();
+        Ok(self.run_result(state))
+    }
+
+    /// Continues executing the current transaction.
+    pub fn continue_tx(&mut self) -> Result<LocalRunResult> {
+        let state = self
+            .interpreter
+            .resume()
+            .map_err(|e| Error::VMError(e.to_string()))?;
+        Ok(self.run_result(state))
+    }
+
+    /// Removes the breakpoints and turns single-stepping off.
+    pub fn reset(&mut self) {
+        self.breakpoints.clear();
+        self.single_stepping = false;
+        self.interpreter = new_interpreter(&self.storage, &self.params);
+    }
+
+    pub fn set_single_stepping(&mut self, enable: bool) {
+        self.single_stepping = enable;
+        self.interpreter.set_single_stepping(enable);
+    }
+
+    /// Sets a breakpoint at the given offset, in bytes, of the contract code. The zero contract
+    /// id stands for the script.
+    pub fn set_breakpoint(&mut self, contract: ContractId, offset: u64) {
+        let breakpoint = Breakpoint::new(contract, offset);
+        self.interpreter.set_breakpoint(breakpoint);
+        self.breakpoints.push(breakpoint);
+    }
+
+    pub fn register(&self, index: usize) -> u64 {
+        self.interpreter.registers()[index]
+    }
+
+    /// Reads up to `limit` bytes of memory at `offset`. Reads starting in the stack stop at its
+    /// end, as the memory between the stack and the heap is not allocated.
+    pub fn memory(&self, offset: usize, limit: usize) -> Result<Vec<u8>> {
+        let stack_end = self.interpreter.registers()[RegId::SP] as usize;
+        let memory_end = if offset < stack_end {
+            stack_end
+        } else {
+            VM_MAX_RAM as usize
+        };
+        let limit = limit.min(memory_end.saturating_sub(offset));
+        self.interpreter
+            .memory()
+            .read(offset, limit)
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| Error::VMError(format!("{e:?}")))
+    }
+
+    fn run_result(&self, state: ProgramState) -> LocalRunResult {
+        let breakpoint = match state {
+            ProgramState::RunProgram(DebugEval::Breakpoint(breakpoint))
+            | ProgramState::VerifyPredicate(DebugEval::Breakpoint(breakpoint)) => {
+                Some((*breakpoint.contract(), breakpoint.pc()))
+            }
+            _ => None,
+        };
+        LocalRunResult {
+            receipts: self.interpreter.receipts().to_vec(),
+            breakpoint,
+        }
+    }
+}
+
+fn new_interpreter(
+    storage: &MemoryStorage,
+    params: &ConsensusParameters,
+) -> Interpreter<MemoryInstance, MemoryStorage, Script> {
+    let mut interpreter = Interpreter::with_storage(
+        MemoryInstance::new(),
+        storage.clone(),
+        InterpreterParams::new(GAS_PRICE, params),
+    );
+    // The registers are only initialized once a transaction starts, but the `one` register reads
+    // as one before, as it does in the debug sessions of a node.
+    interpreter.registers_mut()[RegId::ONE] = 1;
+    interpreter
+}
+
+/// Parses a `<CONTRACT_ID>=<PATH>` argument.
+pub fn parse_contract_path(arg: &str) -> Result<(ContractId, PathBuf)> {
+    let (id, path) = arg.split_once('=').ok_or_else(|| {
+        ArgumentError::Invalid(format!("Expected <CONTRACT_ID>=<PATH>, got: {arg}"))
+    })?;
+    let id = id
+        .parse::<ContractId>()
+        .map_err(|_| ArgumentError::Invalid(format!("Invalid contract ID: {id}")))?;
+    Ok((id, PathBuf::from(path)))
+}
+
+/// Parses a `[<CONTRACT_ID>=]<PATH>` argument. Paths without a contract id belong to the script,
+/// identified by the zero contract id.
+pub fn parse_optional_contract_path(arg: &str) -> (ContractId, PathBuf) {
+    parse_contract_path(arg).unwrap_or_else(|_| (ContractId::zeroed(), PathBuf::from(arg)))
+}
@@ -1,5 +1,10 @@
 use clap::Parser;
+use forc_debug::{
+    error::{Error, Result},
+    local::{parse_contract_path, parse_optional_contract_path, LocalVm, StateSnapshot},
+};
 use forc_tracing::{init_tracing_subscriber, println_error, TracingSubscriberOptions};
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[clap(name = "forc-debug", version)]
@@ -11,6 +16,27 @@ pub struct Opt {
     /// Start the DAP server
     #[clap(short, long)]
     pub serve: bool,
+    /// Execute transactions on an embedded VM instead of a running node
+    #[clap(long, conflicts_with = "serve")]
+    pub offline: bool,
+    /// The state snapshot offline transactions are executed against, as a JSON file
+    #[clap(long, value_name = "PATH", requires = "offline")]
+    pub state: Option<PathBuf>,
+    /// The bytecode of a contract deployed for offline transactions
+    #[clap(
+        long = "contract",
+        value_name = "CONTRACT_ID=PATH",
+        requires = "offline"
+    )]
+    pub contracts: Vec<String>,
+    /// The source map of the script, or of a contract if its id is given, for source-level
+    /// breakpoints in offline mode. Written by `forc build --output-debug <file>.json`
+    #[clap(
+        long = "source-map",
+        value_name = "[CONTRACT_ID=]PATH",
+        requires = "offline"
+    )]
+    pub source_maps: Vec<String>,
 }
 
 #[tokio::main]
@@ -20,6 +46,8 @@ async fn main() {
 
     let result = if config.serve {
         forc_debug::server::DapServer::default().start()
+    } else if config.offline {
+        start_offline(&config).await
     } else {
         forc_debug::cli::start_cli(&config.api_url).await
     };
@@ -29,3 +57,35 @@ async fn main() {
         std::process::exit(1);
     }
 }
+
+/// Loads the state snapshot, the contracts and the source maps, and starts the CLI on an
+/// embedded VM.
+async fn start_offline(config: &Opt) -> Result<()> {
+    let snapshot = config
+        .state
+        .as_deref()
+        .map(StateSnapshot::from_file)
+        .transpose()?
+        .unwrap_or_default();
+    let contracts = config
+        .contracts
+        .iter()
+        .map(|arg| {
+            let (id, path) = parse_contract_path(arg)?;
+            let bytecode = std::fs::read(path).map_err(Error::IoError)?;
+            Ok((id, bytecode))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let source_maps = config
+        .source_maps
+        .iter()
+        .map(|arg| {
+            let (id, path) = parse_optional_contract_path(arg);
+            let json = std::fs::read(path).map_err(Error::IoError)?;
+            Ok((id, serde_json::from_slice(&json)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let vm = LocalVm::new(snapshot, contracts)?;
+    forc_debug::cli::start_local_cli(vm, source_maps).await
+}
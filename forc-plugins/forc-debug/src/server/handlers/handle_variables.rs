@@ -1,15 +1,23 @@
 use crate::{
     names::register_name,
     server::{
-        AdapterError, DapServer, HandlerResult, INSTRUCTIONS_VARIABLE_REF, REGISTERS_VARIABLE_REF,
+        util,
+        values::{Children, Place, TypedValue},
+        AdapterError, DapServer, HandlerResult, INSTRUCTIONS_VARIABLE_REF, LOCALS_VARIABLE_REF,
+        REGISTERS_VARIABLE_REF,
     },
 };
 use dap::{requests::VariablesArguments, responses::ResponseBody, types::Variable};
+use forc_test::execute::TestExecutor;
 use fuel_vm::fuel_asm::{Imm06, Imm12, Imm18, Imm24, Instruction, RawInstruction, RegId};
+use sway_core::{asm_generation::fuel::compiler_constants, source_map::VariableLocation};
+
+/// The register holding the base address of the local variables of the current function.
+const LOCALS_BASE_REGISTER: usize = compiler_constants::LOCALS_BASE as usize;
 
 impl DapServer {
     /// Processes a variables request, returning all variables and their current values.
-    pub(crate) fn handle_variables_command(&self, args: &VariablesArguments) -> HandlerResult {
+    pub(crate) fn handle_variables_command(&mut self, args: &VariablesArguments) -> HandlerResult {
         let result = self.get_variables(args).map(|variables| {
             ResponseBody::Variables(dap::responses::VariablesResponse { variables })
         });
@@ -21,7 +29,7 @@ impl DapServer {
 
     /// Returns the list of [Variable]s for the current execution state.
     pub(crate) fn get_variables(
-        &self,
+        &mut self,
         args: &VariablesArguments,
     ) -> Result<Vec<Variable>, AdapterError> {
         let executor = self
@@ -73,9 +81,96 @@ impl DapServer {
         match args.variables_reference {
             REGISTERS_VARIABLE_REF => Ok(register_variables),
             INSTRUCTIONS_VARIABLE_REF => Ok(instruction_variables),
-            _ => Ok(vec![]),
+            LOCALS_VARIABLE_REF => Ok(self.local_variables()),
+            reference => Ok(self.child_variables(reference)),
         }
     }
+
+    /// Returns the arguments and the local variables of the function being executed, decoded with
+    /// their Sway types.
+    fn local_variables(&mut self) -> Vec<Variable> {
+        let (Some(executor), Some(built_package)) =
+            (self.state.executors.first(), &self.state.built_package)
+        else {
+            return vec![];
+        };
+        let registers = executor.interpreter.registers();
+        let opcode_index = util::current_instruction(registers) / 4;
+        let Some(function) = built_package
+            .source_map
+            .addr_to_function(opcode_index as usize)
+        else {
+            return vec![];
+        };
+
+        function
+            .variables
+            .iter()
+            .map(|variable| {
+                let place = match variable.location {
+                    VariableLocation::Stack { offset } => {
+                        Place::Memory(registers[LOCALS_BASE_REGISTER] + offset)
+                    }
+                    VariableLocation::Register { index } => Place::Word(registers[index as usize]),
+                    VariableLocation::Constant { value } => Place::Word(value),
+                };
+                let value = TypedValue {
+                    ty: variable.ty.clone(),
+                    place,
+                };
+                to_variable(
+                    executor,
+                    &mut self.state.variable_children,
+                    variable.name.clone(),
+                    &value,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the fields, variants or elements of a local variable shown before.
+    fn child_variables(&mut self, reference: i64) -> Vec<Variable> {
+        let Some(executor) = self.state.executors.first() else {
+            return vec![];
+        };
+        let Some(children) = usize::try_from(reference - LOCALS_VARIABLE_REF - 1)
+            .ok()
+            .and_then(|idx| self.state.variable_children.get(idx))
+            .cloned()
+        else {
+            return vec![];
+        };
+        children
+            .into_iter()
+            .map(|(name, value)| {
+                to_variable(executor, &mut self.state.variable_children, name, &value)
+            })
+            .collect()
+    }
+}
+
+/// Decodes a value into a [Variable], remembering its children so that they can be requested
+/// later through the variables reference of the [Variable].
+fn to_variable(
+    executor: &TestExecutor,
+    variable_children: &mut Vec<Children>,
+    name: String,
+    value: &TypedValue,
+) -> Variable {
+    let (rendered, children) = value.decode(executor);
+    let variables_reference = if children.is_empty() {
+        0
+    } else {
+        variable_children.push(children);
+        LOCALS_VARIABLE_REF + variable_children.len() as i64
+    };
+    Variable {
+        name,
+        value: rendered,
+        type_field: Some(value.ty.name.clone()),
+        variables_reference,
+        ..Default::default()
+    }
 }
 
 fn reg_id_to_string(reg_id: Option<RegId>) -> Option<String> {
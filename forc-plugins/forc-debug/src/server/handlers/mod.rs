@@ -1,8 +1,8 @@
 use crate::{
     error::AdapterError,
     server::{
-        AdditionalData, DapServer, HandlerResult, INSTRUCTIONS_VARIABLE_REF,
-        REGISTERS_VARIABLE_REF, THREAD_ID,
+        step::StepKind, AdditionalData, DapServer, HandlerResult, INSTRUCTIONS_VARIABLE_REF,
+        LOCALS_VARIABLE_REF, REGISTERS_VARIABLE_REF, THREAD_ID,
     },
 };
 use dap::{
@@ -48,13 +48,27 @@ impl DapServer {
         HandlerResult::err_with_exit(AdapterError::MissingConfiguration, 1)
     }
 
-    /// Handles a `next` request. Returns true if the server should continue running.
+    /// Handles a `next` request, stepping over the calls made by the current source line.
     pub(crate) fn handle_next(&mut self) -> HandlerResult {
-        match self.continue_debugging_tests(true) {
-            Ok(true) => HandlerResult::ok(ResponseBody::Next),
+        self.handle_step(StepKind::Over, ResponseBody::Next)
+    }
+
+    /// Handles a `stepIn` request, stepping into the calls made by the current source line.
+    pub(crate) fn handle_step_in(&mut self) -> HandlerResult {
+        self.handle_step(StepKind::In, ResponseBody::StepIn)
+    }
+
+    /// Handles a `stepOut` request, running until the current function returns.
+    pub(crate) fn handle_step_out(&mut self) -> HandlerResult {
+        self.handle_step(StepKind::Out, ResponseBody::StepOut)
+    }
+
+    fn handle_step(&mut self, kind: StepKind, response: ResponseBody) -> HandlerResult {
+        match self.step_debugging_tests(kind) {
+            Ok(true) => HandlerResult::ok(response),
             Ok(false) => {
                 // The tests finished executing
-                HandlerResult::ok_with_exit(ResponseBody::Next, 0)
+                HandlerResult::ok_with_exit(response, 0)
             }
             Err(e) => HandlerResult::err_with_exit(e, 1),
         }
@@ -101,8 +115,16 @@ impl DapServer {
     }
 
     pub(crate) fn handle_scopes(&mut self) -> HandlerResult {
+        // Scopes are requested whenever the VM stops, so the variables shown before are stale.
+        self.state.variable_children.clear();
         HandlerResult::ok(ResponseBody::Scopes(responses::ScopesResponse {
             scopes: vec![
+                Scope {
+                    name: "Locals".into(),
+                    presentation_hint: Some(types::ScopePresentationhint::Locals),
+                    variables_reference: LOCALS_VARIABLE_REF,
+                    ..Default::default()
+                },
                 Scope {
                     name: "Current VM Instruction".into(),
                     presentation_hint: Some(types::ScopePresentationhint::Registers),
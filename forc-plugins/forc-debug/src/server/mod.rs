@@ -1,10 +1,16 @@
 mod handlers;
 mod state;
+mod step;
 mod util;
+mod values;
 
 use crate::{
     error::{self, AdapterError, Error},
-    server::{state::ServerState, util::IdGenerator},
+    server::{
+        state::ServerState,
+        step::{Step, StepKind, StepRegisters},
+        util::IdGenerator,
+    },
     types::{ExitCode, Instruction},
 };
 use dap::{
@@ -35,6 +41,7 @@ use sway_types::LineCol;
 pub const THREAD_ID: i64 = 0;
 pub const REGISTERS_VARIABLE_REF: i64 = 1;
 pub const INSTRUCTIONS_VARIABLE_REF: i64 = 2;
+pub const LOCALS_VARIABLE_REF: i64 = 3;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AdditionalData {
@@ -145,14 +152,8 @@ impl DapServer {
             Command::Scopes(_) => self.handle_scopes(),
             Command::SetBreakpoints(ref args) => self.handle_set_breakpoints_command(args),
             Command::StackTrace(_) => self.handle_stack_trace_command(),
-            Command::StepIn(_) => {
-                self.error("This feature is not currently supported.".into());
-                HandlerResult::ok(ResponseBody::StepIn)
-            }
-            Command::StepOut(_) => {
-                self.error("This feature is not currently supported.".into());
-                HandlerResult::ok(ResponseBody::StepOut)
-            }
+            Command::StepIn(_) => self.handle_step_in(),
+            Command::StepOut(_) => self.handle_step_out(),
             Command::Terminate(_) => HandlerResult::ok_with_exit(ResponseBody::Terminate, 0),
             Command::TerminateThreads(_) => {
                 HandlerResult::ok_with_exit(ResponseBody::TerminateThreads, 0)
@@ -317,6 +318,7 @@ impl DapServer {
             &BuildProfile {
                 optimization_level: sway_core::OptLevel::Opt0,
                 include_tests: true,
+                generate_debug_info: true,
                 ..Default::default()
             },
             &outputs,
@@ -476,6 +478,50 @@ impl DapServer {
         self.log_test_results();
         Ok(false)
     }
+
+    /// Steps through the current test by source line, following the calls and returns made along
+    /// the way, and starts the next test if the current one finishes.
+    ///
+    /// Returns true if it has stopped on a source line or false if all tests have finished.
+    fn step_debugging_tests(&mut self, kind: StepKind) -> Result<bool, AdapterError> {
+        self.state.update_vm_breakpoints();
+
+        let Some(executor) = self.state.executors.first() else {
+            self.log_test_results();
+            return Ok(false);
+        };
+        let start = self
+            .state
+            .vm_pc_to_source_location(util::current_instruction(executor.interpreter.registers()))
+            .ok()
+            .map(|(source_path, line)| (source_path.clone(), line));
+        let mut step = Step::new(kind, start);
+
+        while let Some(executor) = self.state.executors.first_mut() {
+            let before = StepRegisters::capture(executor);
+            executor.interpreter.set_single_stepping(true);
+            match executor.continue_debugging()? {
+                DebugResult::TestComplete(result) => {
+                    self.state.test_complete(result);
+                    // The current test has finished, so stop at the start of the next one.
+                    return self.start_debugging_tests(true);
+                }
+                DebugResult::Breakpoint(pc) => {
+                    step.executed(&before, executor.interpreter.registers());
+                    let on_breakpoint = self.state.vm_pc_to_breakpoint_id(pc).is_ok();
+                    let location = self.state.vm_pc_to_source_location(pc).ok();
+                    if step.is_done(location, on_breakpoint) {
+                        if let Some(executor) = self.state.executor() {
+                            executor.interpreter.set_single_stepping(false);
+                        }
+                        return self.stop(pc);
+                    }
+                }
+            }
+        }
+        self.log_test_results();
+        Ok(false)
+    }
 }
 
 /// Represents the result of a DAP handler operation, combining the response/error and an optional exit code
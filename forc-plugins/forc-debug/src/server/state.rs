@@ -1,5 +1,6 @@
 use crate::{
     error::AdapterError,
+    server::values::Children,
     types::{Breakpoints, Instruction, SourceMap},
 };
 use dap::types::StartDebuggingRequestKind;
@@ -29,6 +30,11 @@ pub struct ServerState {
     pub test_results: Vec<forc_test::TestResult>,
     pub executors: Vec<TestExecutor>,
     original_executors: Vec<TestExecutor>,
+
+    // Variables state
+    /// The children of the local variables shown since the last stop. The children at index `i`
+    /// are shown for the variables reference `LOCALS_VARIABLE_REF + 1 + i`.
+    pub(crate) variable_children: Vec<Children>,
 }
 
 impl ServerState {
@@ -41,6 +47,7 @@ impl ServerState {
         self.test_results = vec![];
         self.stopped_on_breakpoint_id = None;
         self.breakpoints_need_update = true;
+        self.variable_children.clear();
     }
 
     /// Initializes the executor stores.
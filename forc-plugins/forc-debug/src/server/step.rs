@@ -0,0 +1,117 @@
+use crate::types::Line;
+use forc_test::execute::TestExecutor;
+use fuel_vm::fuel_asm::{Instruction, Opcode, RawInstruction, RegId};
+use std::path::PathBuf;
+use sway_core::asm_generation::fuel::compiler_constants;
+
+/// The register holding the return address of Sway function calls, as an instruction index
+/// relative to `$is`. Calls set it right before jumping to the function, and functions return by
+/// jumping to it.
+const RETURN_ADDRESS_REGISTER: RegId = RegId::new(compiler_constants::RETURN_ADDRESS_REGISTER);
+
+/// The kinds of source-level steps requested by the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StepKind {
+    /// Runs until the next source line of the current function, stepping over calls.
+    Over,
+    /// Runs until the next source line, entering calls.
+    In,
+    /// Runs until the current function returns to its caller.
+    Out,
+}
+
+/// The registers of the VM which are needed to follow calls and returns, captured before
+/// executing an instruction.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StepRegisters {
+    pc: u64,
+    is: u64,
+    fp: u64,
+    instruction: Option<Instruction>,
+}
+
+impl StepRegisters {
+    /// Captures the registers and the instruction about to be executed.
+    pub(crate) fn capture(executor: &TestExecutor) -> Self {
+        let registers = executor.interpreter.registers();
+        let pc = registers[RegId::PC];
+        let instruction = executor
+            .interpreter
+            .memory()
+            .read(pc, Instruction::SIZE)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .and_then(|bytes| Instruction::try_from(RawInstruction::from_be_bytes(bytes)).ok());
+        Self {
+            pc,
+            is: registers[RegId::IS],
+            fp: registers[RegId::FP],
+            instruction,
+        }
+    }
+}
+
+/// Follows the calls and returns made while single-stepping, to decide where a step ends.
+#[derive(Debug, Clone)]
+pub(crate) struct Step {
+    kind: StepKind,
+    /// The source line the step started from, if it is known.
+    start: Option<(PathBuf, Line)>,
+    /// The number of calls entered and not yet returned from since the step started. It becomes
+    /// negative once the function the step started in returns.
+    depth: i64,
+}
+
+impl Step {
+    pub(crate) fn new(kind: StepKind, start: Option<(PathBuf, Line)>) -> Self {
+        Self {
+            kind,
+            start,
+            depth: 0,
+        }
+    }
+
+    /// Updates the call depth after executing the instruction captured in `before`.
+    pub(crate) fn executed(&mut self, before: &StepRegisters, registers: &[u64]) {
+        let Some(instruction) = before.instruction else {
+            return;
+        };
+        match instruction.opcode() {
+            // Contract calls change the frame pointer, unless the call failed.
+            Opcode::CALL if registers[RegId::FP] != before.fp => self.depth += 1,
+            // Returns from a contract. Returns from the script end the test instead.
+            Opcode::RET | Opcode::RETD if before.fp != 0 => self.depth -= 1,
+            Opcode::JMP if instruction.reg_ids()[0] == Some(RETURN_ADDRESS_REGISTER) => {
+                self.depth -= 1
+            }
+            _ => {
+                // A jump which sets the return address to the next instruction calls a function.
+                let next_pc = before.pc + Instruction::SIZE as u64;
+                let return_index = (next_pc - before.is) / Instruction::SIZE as u64;
+                if registers[RegId::PC] != next_pc
+                    && registers[RegId::FP] == before.fp
+                    && registers[RETURN_ADDRESS_REGISTER] == return_index
+                {
+                    self.depth += 1;
+                }
+            }
+        }
+    }
+
+    /// Whether the step ends at an instruction of the given source line.
+    pub(crate) fn is_done(&self, location: Option<(&PathBuf, Line)>, on_breakpoint: bool) -> bool {
+        let Some(location) = location else {
+            return false;
+        };
+        let new_line = self
+            .start
+            .as_ref()
+            .map_or(true, |(path, line)| (path, *line) != location);
+        match self.kind {
+            _ if on_breakpoint && new_line => true,
+            StepKind::In => new_line,
+            StepKind::Over => self.depth < 0 || (self.depth == 0 && new_line),
+            StepKind::Out => self.depth < 0,
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use forc_test::execute::TestExecutor;
+use sway_core::source_map::{DebugType, DebugTypeKind};
+
+/// The maximum number of elements shown for a `Vec`, and of bytes shown for strings and `Bytes`.
+const MAX_ELEMENTS: u64 = 256;
+
+/// Where a value is stored in the VM.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Place {
+    /// In memory, at the given address.
+    Memory(u64),
+    /// In a register, or as a constant. Only values which fit in a word are stored this way.
+    Word(u64),
+}
+
+/// A value of the VM, with its Sway type.
+#[derive(Debug, Clone)]
+pub(crate) struct TypedValue {
+    pub(crate) ty: DebugType,
+    pub(crate) place: Place,
+}
+
+/// The fields, variants or elements of a value, shown as children of its variable.
+pub(crate) type Children = Vec<(String, TypedValue)>;
+
+impl TypedValue {
+    /// Renders the value, and returns its children if it has any.
+    pub(crate) fn decode(&self, executor: &TestExecutor) -> (String, Children) {
+        let addr = match self.place {
+            Place::Memory(addr) => addr,
+            Place::Word(word) => return (self.render_word(word), vec![]),
+        };
+        self.decode_at(executor, addr)
+            .unwrap_or_else(|| ("<unavailable>".into(), vec![]))
+    }
+
+    fn decode_at(&self, executor: &TestExecutor, addr: u64) -> Option<(String, Children)> {
+        let in_memory = |ty: &DebugType, addr| TypedValue {
+            ty: ty.clone(),
+            place: Place::Memory(addr),
+        };
+        Some(match &self.ty.kind {
+            DebugTypeKind::Bool | DebugTypeKind::Uint { bits: 8 } => {
+                let byte = read(executor, addr, 1)?[0];
+                (self.render_word(byte as u64), vec![])
+            }
+            DebugTypeKind::Uint { .. } | DebugTypeKind::Pointer => {
+                (self.render_word(read_word(executor, addr)?), vec![])
+            }
+            DebugTypeKind::Bytes32 => (to_hex(read(executor, addr, 32)?), vec![]),
+            DebugTypeKind::Slice => {
+                let (ptr, len) = (read_word(executor, addr)?, read_word(executor, addr + 8)?);
+                (format!("0x{ptr:x} (len {len})"), vec![])
+            }
+            DebugTypeKind::StringSlice => {
+                let (ptr, len) = (read_word(executor, addr)?, read_word(executor, addr + 8)?);
+                (
+                    to_string(read(executor, ptr, len.min(MAX_ELEMENTS))?),
+                    vec![],
+                )
+            }
+            DebugTypeKind::StringArray { len } => (
+                to_string(read(executor, addr, (*len).min(MAX_ELEMENTS))?),
+                vec![],
+            ),
+            DebugTypeKind::Array { elem, len } => (
+                self.ty.name.clone(),
+                (0..(*len).min(MAX_ELEMENTS))
+                    .map(|idx| (format!("[{idx}]"), in_memory(elem, addr + idx * elem.size)))
+                    .collect(),
+            ),
+            DebugTypeKind::Struct { fields } => (
+                self.ty.name.clone(),
+                fields
+                    .iter()
+                    .map(|field| {
+                        (
+                            field.name.clone(),
+                            in_memory(&field.ty, addr + field.offset),
+                        )
+                    })
+                    .collect(),
+            ),
+            DebugTypeKind::Enum { variants } => {
+                let tag = read_word(executor, addr)?;
+                let variant = variants.get(tag as usize)?;
+                let children = match variant.ty.kind {
+                    DebugTypeKind::Opaque => vec![],
+                    _ => vec![(
+                        variant.name.clone(),
+                        in_memory(&variant.ty, addr + variant.offset),
+                    )],
+                };
+                (variant.name.clone(), children)
+            }
+            // `Vec`, `Bytes` and `String` start with a pointer to their buffer, followed by its
+            // capacity and by their length.
+            DebugTypeKind::Vec { elem } => {
+                let (ptr, len) = (read_word(executor, addr)?, read_word(executor, addr + 16)?);
+                (
+                    format!("len = {len}"),
+                    (0..len.min(MAX_ELEMENTS))
+                        .map(|idx| (format!("[{idx}]"), in_memory(elem, ptr + idx * elem.size)))
+                        .collect(),
+                )
+            }
+            DebugTypeKind::Bytes { utf8 } => {
+                let (ptr, len) = (read_word(executor, addr)?, read_word(executor, addr + 16)?);
+                let bytes = read(executor, ptr, len.min(MAX_ELEMENTS))?;
+                let value = if *utf8 {
+                    to_string(bytes)
+                } else {
+                    to_hex(bytes)
+                };
+                (value, vec![])
+            }
+            DebugTypeKind::Opaque => (self.ty.name.clone(), vec![]),
+        })
+    }
+
+    fn render_word(&self, word: u64) -> String {
+        match self.ty.kind {
+            DebugTypeKind::Bool => (word != 0).to_string(),
+            DebugTypeKind::Uint { .. } => word.to_string(),
+            _ => format!("0x{word:x}"),
+        }
+    }
+}
+
+fn read(executor: &TestExecutor, addr: u64, len: u64) -> Option<&[u8]> {
+    executor.interpreter.memory().read(addr, len).ok()
+}
+
+fn read_word(executor: &TestExecutor, addr: u64) -> Option<u64> {
+    let bytes = read(executor, addr, 8)?;
+    Some(u64::from_be_bytes(bytes.try_into().ok()?))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("0x{hex}")
+}
+
+fn to_string(bytes: &[u8]) -> String {
+    format!("{:?}", String::from_utf8_lossy(bytes))
+}
@@ -58,3 +58,43 @@ fn test_cli() {
 
     fuel_core.kill().expect("Couldn't kill fuel-core");
 }
+
+#[test]
+fn test_cli_offline() {
+    let mut run_cmd = CargoBuild::new()
+        .bin("forc-debug")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap()
+        .command();
+
+    run_cmd.arg("--offline");
+
+    let mut cmd = spawn_command(run_cmd, Some(2000)).unwrap();
+
+    cmd.exp_regex(r"^>> ").unwrap();
+    cmd.send_line("reg 1").unwrap();
+    cmd.exp_regex(r"reg\[0x1\] = 1\s+# one").unwrap();
+    cmd.send_line("breakpoint 0").unwrap();
+    cmd.exp_regex(r">> ").unwrap();
+    cmd.send_line("start_tx examples/example_tx.json").unwrap();
+    cmd.exp_regex(r"Stopped on breakpoint at address 0 of contract 0x0{64}")
+        .unwrap();
+    cmd.send_line("step on").unwrap();
+    cmd.exp_regex(r">> ").unwrap();
+    cmd.send_line("continue").unwrap();
+    cmd.exp_regex(r"Stopped on breakpoint at address 16 of contract 0x0{64}")
+        .unwrap();
+    cmd.send_line("step off").unwrap();
+    cmd.exp_regex(r">> ").unwrap();
+    cmd.send_line("continue").unwrap();
+    cmd.exp_regex(r"Receipt: Return").unwrap();
+    cmd.send_line("breakpoint src/main.sw:1").unwrap();
+    cmd.exp_regex(r"No instructions found at src/main.sw:1")
+        .unwrap();
+    cmd.send_line("reset").unwrap();
+    cmd.send_line("start_tx examples/example_tx.json").unwrap();
+    cmd.exp_regex(r"Receipt: Return").unwrap();
+    cmd.send_line(r"exit").unwrap();
+}
@@ -5,7 +5,8 @@ use dap::{
     types::{OutputEventCategory, Source, SourceBreakpoint, StoppedEventReason, Variable},
 };
 use forc_debug::server::{
-    AdditionalData, DapServer, INSTRUCTIONS_VARIABLE_REF, REGISTERS_VARIABLE_REF,
+    AdditionalData, DapServer, INSTRUCTIONS_VARIABLE_REF, LOCALS_VARIABLE_REF,
+    REGISTERS_VARIABLE_REF,
 };
 use std::{
     env,
@@ -181,7 +182,7 @@ fn test_server_launch_mode() {
         .into_tuple();
     match result.expect("scopes result") {
         ResponseBody::Scopes(res) => {
-            assert_eq!(res.scopes.len(), 2);
+            assert_eq!(res.scopes.len(), 3);
         }
         other => panic!("Expected Scopes response, got {:?}", other),
     }
@@ -231,21 +232,42 @@ fn test_server_launch_mode() {
     assert!(exit_code.is_none());
     assert_stopped_next_event(output_capture.take_event());
 
-    // Step In request
-    let (result, exit_code) = server
-        .handle_command(&Command::StepIn(Default::default()))
-        .into_tuple();
-    assert!(result.is_ok());
-    assert!(exit_code.is_none());
-    assert_not_supported_event(output_capture.take_event());
+    assert_ne!(stack_trace_line(&mut server), 21);
+
+    // Variables request - locals, decoded with their Sway types
+    let locals = local_variables(&mut server);
+    let hi = locals
+        .iter()
+        .find(|variable| variable.name == "hi")
+        .expect("local variable hi");
+    assert_eq!(hi.value, "1");
+    assert_eq!(hi.type_field.as_deref(), Some("u64"));
+    assert!(locals.iter().any(|variable| variable.name == "hey"));
+
+    // Step In requests, should stop on each source line until entering the helper function
+    let mut lines = vec![];
+    while !(11..=16).contains(lines.last().unwrap_or(&0)) {
+        assert!(
+            lines.len() < 10,
+            "never entered helper, stopped on lines {lines:?}"
+        );
+        let (result, exit_code) = server
+            .handle_command(&Command::StepIn(Default::default()))
+            .into_tuple();
+        assert!(matches!(result, Ok(ResponseBody::StepIn)));
+        assert!(exit_code.is_none());
+        assert_stopped_next_event(output_capture.take_event());
+        lines.push(stack_trace_line(&mut server));
+    }
 
-    // Step Out request
+    // Step Out request, should leave the helper function
     let (result, exit_code) = server
         .handle_command(&Command::StepOut(Default::default()))
         .into_tuple();
-    assert!(result.is_ok());
+    assert!(matches!(result, Ok(ResponseBody::StepOut)));
     assert!(exit_code.is_none());
-    assert_not_supported_event(output_capture.take_event());
+    assert_stopped_next_event(output_capture.take_event());
+    assert!(!(11..=16).contains(&stack_trace_line(&mut server)));
 
     // Continue request, should hit 2nd breakpoint
     let (result, exit_code) = server
@@ -279,6 +301,35 @@ fn test_server_launch_mode() {
     assert!(body.output.contains("Result: OK. 3 passed. 0 failed"));
 }
 
+/// Returns the local variables of the function being executed.
+fn local_variables(server: &mut DapServer) -> Vec<Variable> {
+    let (result, _) = server
+        .handle_command(&Command::Variables(VariablesArguments {
+            variables_reference: LOCALS_VARIABLE_REF,
+            ..Default::default()
+        }))
+        .into_tuple();
+    match result.expect("locals variables result") {
+        ResponseBody::Variables(res) => res.variables,
+        other => panic!("Expected Variables response, got {:?}", other),
+    }
+}
+
+/// Returns the source line of the only stack frame, or 0 if the location is unknown.
+fn stack_trace_line(server: &mut DapServer) -> i64 {
+    let (result, _) = server
+        .handle_command(&Command::StackTrace(Default::default()))
+        .into_tuple();
+    match result.expect("stack trace result") {
+        ResponseBody::StackTrace(res) => res
+            .stack_frames
+            .first()
+            .map(|frame| frame.line)
+            .unwrap_or_default(),
+        other => panic!("Expected StackTrace response, got {:?}", other),
+    }
+}
+
 /// Asserts that the given event is a Stopped event with a breakpoint reason and the given breakpoint ID.
 fn assert_stopped_breakpoint_event(event: Option<Event>, breakpoint_id: i64) {
     match event.expect("received event") {
@@ -1,5 +1,5 @@
 //! The command line interface for `forc doc`.
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use forc_pkg::source::IPFSNode;
 
 forc_util::cli_examples! {
@@ -9,6 +9,7 @@ forc_util::cli_examples! {
         [ Build the docs for a project located in another path => "forc doc --path {path}" ]
         [ Build the docs for the current project exporting private types => "forc doc --document-private-items" ]
         [ Build the docs offline without downloading any dependency from the network => "forc doc --offline" ]
+        [ Build the docs as JSON to be consumed by other tools => "forc doc --output-format json" ]
     }
 }
 
@@ -50,6 +51,12 @@ pub struct Command {
     /// Possible values: PUBLIC, LOCAL, <GATEWAY_URL>
     #[clap(long)]
     pub ipfs_node: Option<IPFSNode>,
+    /// The format of the documentation.
+    ///
+    /// `json` writes the documentation of each package to `<package_name>.json`, in a versioned
+    /// format meant to be consumed by other tools.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Html)]
+    pub output_format: OutputFormat,
 
     #[cfg(test)]
     pub(crate) doc_path: Option<String>,
@@ -57,3 +64,13 @@ pub struct Command {
     #[clap(flatten)]
     pub experimental: sway_features::CliFields,
 }
+
+/// The format `forc doc` outputs the documentation in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// HTML pages, browsable with a web browser.
+    #[default]
+    Html,
+    /// A JSON file per package.
+    Json,
+}
@@ -78,6 +78,7 @@ impl Descriptor {
                                 context_opt: context,
                                 ..Default::default()
                             },
+                            source: None,
                         },
                         raw_attributes: attrs_opt,
                     }))
@@ -115,6 +116,7 @@ impl Descriptor {
                                 context_opt: context,
                                 ..Default::default()
                             },
+                            source: None,
                         },
                         raw_attributes: attrs_opt,
                     }))
@@ -163,6 +165,7 @@ impl Descriptor {
                                 context_opt: context,
                                 ..Default::default()
                             },
+                            source: None,
                         },
                         raw_attributes: attrs_opt,
                     }))
@@ -175,7 +178,7 @@ impl Descriptor {
                     (!abi_decl.attributes.is_empty()).then(|| abi_decl.attributes.to_html_string());
                 let context = (!abi_decl.interface_surface.is_empty()).then_some(Context::new(
                     module_info.clone(),
-                    ContextType::RequiredMethods(
+                    ContextType::AbiMethods(
                         abi_decl
                             .interface_surface
                             .into_iter()
@@ -205,6 +208,7 @@ impl Descriptor {
                             context_opt: context,
                             ..Default::default()
                         },
+                        source: None,
                     },
                     raw_attributes: attrs_opt,
                 }))
@@ -240,13 +244,18 @@ impl Descriptor {
                             context_opt: context,
                             ..Default::default()
                         },
+                        source: None,
                     },
                     raw_attributes: attrs_opt,
                 }))
             }
             ty::TyDecl::FunctionDecl(ty::FunctionDecl { decl_id, .. }) => {
                 let fn_decl = decl_engine.get_function(decl_id);
-                if !document_private_items && fn_decl.visibility.is_private() {
+                // The entry function of contracts, scripts and predicates is generated by the
+                // compiler, and is not documented.
+                if fn_decl.is_entry()
+                    || (!document_private_items && fn_decl.visibility.is_private())
+                {
                     Ok(Descriptor::NonDocumentable)
                 } else {
                     let item_name = fn_decl.name.clone();
@@ -272,6 +281,7 @@ impl Descriptor {
                                 context_opt: None,
                                 ..Default::default()
                             },
+                            source: None,
                         },
                         raw_attributes: attrs_opt,
                     }))
@@ -302,6 +312,7 @@ impl Descriptor {
                             )?,
                             attrs_opt: attrs_opt.clone(),
                             item_context: Default::default(),
+                            source: None,
                         },
                         raw_attributes: attrs_opt,
                     }))
@@ -362,6 +373,7 @@ impl Descriptor {
                     code_str: item_name.clone().to_string(),
                     attrs_opt: attrs_opt.clone(),
                     item_context: Default::default(),
+                    source: None,
                 },
                 raw_attributes: attrs_opt,
             })),
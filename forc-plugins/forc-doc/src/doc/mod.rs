@@ -1,6 +1,6 @@
 //! Handles conversion of compiled typed Sway programs into [Document]s that can be rendered into HTML.
 use crate::{
-    doc::{descriptor::Descriptor, module::ModuleInfo},
+    doc::{descriptor::Descriptor, module::ModuleInfo, source::SourceMap},
     render::{
         item::{components::*, context::DocImplTrait, documentable_type::DocumentableType},
         link::DocLink,
@@ -9,19 +9,21 @@ use crate::{
 };
 use anyhow::Result;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ops::{Deref, DerefMut},
     option::Option,
 };
 use sway_core::{
-    decl_engine::DeclEngine,
+    engine_threading::SpannedWithEngines,
     language::ty::{TyAstNodeContent, TyDecl, TyImplSelfOrTrait, TyModule, TyProgram, TySubmodule},
+    namespace::TraitMap,
     Engines,
 };
 use sway_types::BaseIdent;
 
 mod descriptor;
 pub mod module;
+pub mod source;
 
 #[derive(Default, Clone)]
 pub struct Documentation(pub Vec<Document>);
@@ -38,8 +40,10 @@ impl Documentation {
         let mut docs = Documentation::default();
         let mut impl_traits: Vec<(TyImplSelfOrTrait, ModuleInfo)> = Vec::new();
         let module_info = ModuleInfo::from_ty_module(vec![project_name.to_owned()], None);
+        let source_map = SourceMap::new(engines, project_name, &typed_program.root);
         Documentation::from_ty_module(
-            engines.de(),
+            engines,
+            &source_map,
             &module_info,
             &typed_program.root,
             &mut docs,
@@ -54,7 +58,8 @@ impl Documentation {
             let module_prefix =
                 ModuleInfo::from_ty_module(vec![project_name.to_owned()], attributes);
             Documentation::from_ty_submodule(
-                engines.de(),
+                engines,
+                &source_map,
                 typed_submodule,
                 &mut docs,
                 &mut impl_traits,
@@ -84,48 +89,73 @@ impl Documentation {
             }
         }
 
-        // match for the spans to add the impl_traits to their corresponding doc:
-        // currently this compares the spans as str, but this needs to change
-        // to compare the actual types
+        // Implementations can be declared in any module of the package,
+        // so the trait maps of all of the modules are searched.
+        let ty_modules = std::iter::once(&typed_program.root)
+            .chain(
+                typed_program
+                    .root
+                    .submodules_recursive()
+                    .map(|(_, submodule)| &*submodule.module),
+            )
+            .collect::<Vec<_>>();
+
         for doc in docs.iter_mut() {
             let mut impl_trait_vec: Vec<DocImplTrait> = Vec::new();
             let mut inherent_impl_vec: Vec<DocImplTrait> = Vec::new();
 
-            // Check for implementations of the current struct/enum/primitive.
-            match doc.item_body.ty {
-                DocumentableType::Declared(TyDecl::StructDecl(_))
-                | DocumentableType::Declared(TyDecl::EnumDecl(_))
-                | DocumentableType::Primitive(_) => {
-                    let item_name = doc.item_header.item_name.as_str().to_string();
-                    for (impl_trait, _) in impl_traits.iter_mut() {
-                        // Check if this implementation is for this struct/enum.
-                        if item_name.as_str() == impl_trait.implementing_for.span.as_str() {
-                            let module_info_override = if let Some(decl_module_info) =
-                                trait_decls.get(&impl_trait.trait_name.suffix)
-                            {
-                                Some(decl_module_info.module_prefixes.clone())
-                            } else {
-                                impl_trait.trait_name = impl_trait
-                                    .trait_name
-                                    .to_fullpath(engines, &typed_program.root.namespace);
-                                None
-                            };
+            // The implementations of a struct/enum are found via the trait maps. Primitives
+            // are not declared, so their implementations are matched by name instead.
+            let impl_spans = match &doc.item_body.ty {
+                DocumentableType::Declared(
+                    decl @ (TyDecl::StructDecl(_) | TyDecl::EnumDecl(_)),
+                ) => Some(
+                    ty_modules
+                        .iter()
+                        .flat_map(|ty_module| {
+                            TraitMap::get_impl_spans_for_decl(
+                                ty_module.namespace.module(engines),
+                                engines,
+                                decl,
+                            )
+                        })
+                        .collect::<HashSet<_>>(),
+                ),
+                DocumentableType::Primitive(_) => None,
+                _ => continue,
+            };
+            let item_name = doc.item_header.item_name.as_str().to_string();
+            for (impl_trait, _) in impl_traits.iter_mut() {
+                // Check if this implementation is for this struct/enum/primitive.
+                let is_impl_for_item = match &impl_spans {
+                    Some(impl_spans) => impl_spans.contains(&impl_trait.span),
+                    None => item_name.as_str() == impl_trait.implementing_for.span.as_str(),
+                };
+                if !is_impl_for_item {
+                    continue;
+                }
+                let module_info_override = if let Some(decl_module_info) =
+                    trait_decls.get(&impl_trait.trait_name.suffix)
+                {
+                    Some(decl_module_info.module_prefixes.clone())
+                } else {
+                    impl_trait.trait_name = impl_trait
+                        .trait_name
+                        .to_fullpath(engines, &typed_program.root.namespace);
+                    None
+                };
 
-                            let doc_impl_trait = DocImplTrait {
-                                impl_for_module: doc.module_info.clone(),
-                                impl_trait: impl_trait.clone(),
-                                module_info_override,
-                            };
+                let doc_impl_trait = DocImplTrait {
+                    impl_for_module: doc.module_info.clone(),
+                    impl_trait: impl_trait.clone(),
+                    module_info_override,
+                };
 
-                            if doc_impl_trait.is_inherent() {
-                                inherent_impl_vec.push(doc_impl_trait);
-                            } else {
-                                impl_trait_vec.push(doc_impl_trait);
-                            }
-                        }
-                    }
+                if doc_impl_trait.is_inherent() {
+                    inherent_impl_vec.push(doc_impl_trait);
+                } else {
+                    impl_trait_vec.push(doc_impl_trait);
                 }
-                _ => {}
             }
 
             if !impl_trait_vec.is_empty() {
@@ -140,13 +170,15 @@ impl Documentation {
         Ok(docs)
     }
     fn from_ty_module(
-        decl_engine: &DeclEngine,
+        engines: &Engines,
+        source_map: &SourceMap,
         module_info: &ModuleInfo,
         ty_module: &TyModule,
         docs: &mut Documentation,
         impl_traits: &mut Vec<(TyImplSelfOrTrait, ModuleInfo)>,
         document_private_items: bool,
     ) -> Result<()> {
+        let decl_engine = engines.de();
         for ast_node in &ty_module.all_nodes {
             if let TyAstNodeContent::Declaration(ref decl) = ast_node.content {
                 if let TyDecl::ImplSelfOrTrait(impl_trait) = decl {
@@ -162,7 +194,8 @@ impl Documentation {
                         document_private_items,
                     )?;
 
-                    if let Descriptor::Documentable(doc) = desc {
+                    if let Descriptor::Documentable(mut doc) = desc {
+                        doc.item_body.source = source_map.location(&decl.span(engines));
                         docs.push(doc);
                     }
                 }
@@ -172,7 +205,8 @@ impl Documentation {
         Ok(())
     }
     fn from_ty_submodule(
-        decl_engine: &DeclEngine,
+        engines: &Engines,
+        source_map: &SourceMap,
         typed_submodule: &TySubmodule,
         docs: &mut Documentation,
         impl_traits: &mut Vec<(TyImplSelfOrTrait, ModuleInfo)>,
@@ -184,7 +218,8 @@ impl Documentation {
             .module_prefixes
             .push(typed_submodule.mod_name_span.as_str().to_owned());
         Documentation::from_ty_module(
-            decl_engine,
+            engines,
+            source_map,
             &module_info.clone(),
             &typed_submodule.module,
             docs,
@@ -194,7 +229,8 @@ impl Documentation {
 
         for (_, submodule) in &typed_submodule.module.submodules {
             Documentation::from_ty_submodule(
-                decl_engine,
+                engines,
+                source_map,
                 submodule,
                 docs,
                 impl_traits,
@@ -0,0 +1,135 @@
+//! Handles the gathering of Sway source files and of the locations of items within them.
+use crate::doc::module::ModuleInfo;
+use std::{
+    collections::BTreeMap,
+    path::{Component, PathBuf},
+    sync::Arc,
+};
+use sway_core::{language::ty::TyModule, Engines};
+use sway_types::{SourceId, Span};
+
+/// The name of the directory, relative to the doc root, that source pages are written to.
+pub(crate) const SOURCE_DIR_NAME: &str = "src";
+
+/// A Sway source file of a documented package, rendered into a source page.
+#[derive(Clone, Debug)]
+pub struct SourceFile {
+    /// The location of the source page, e.g. `src/project_name/module`.
+    pub module_info: ModuleInfo,
+    /// The file name of the source page, e.g. `submodule.sw.html`.
+    pub html_filename: String,
+    /// The contents of the source file.
+    pub source: Arc<str>,
+}
+
+/// The location of an item declaration within its source page.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub module_info: ModuleInfo,
+    pub html_filename: String,
+    /// The 1-based line the declaration starts on.
+    pub line: usize,
+}
+impl SourceLocation {
+    /// Creates a link to the declaration, relative to a page of the given module.
+    pub(crate) fn href(&self, current_module_info: &ModuleInfo) -> String {
+        format!(
+            "{}/{}/{}#L{}",
+            current_module_info.path_to_root(),
+            self.module_info.module_prefixes.join("/"),
+            self.html_filename,
+            self.line
+        )
+    }
+    /// The path of the source file, relative to the directory containing the root module of
+    /// its package, e.g. `module/submodule.sw`.
+    pub(crate) fn file_path(&self) -> String {
+        // Skip the source directory and the project name.
+        self.module_info
+            .module_prefixes
+            .iter()
+            .skip(2)
+            .map(String::as_str)
+            .chain(std::iter::once(
+                self.html_filename.trim_end_matches(".html"),
+            ))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+/// Maps the source files of a package to their source pages.
+pub(crate) struct SourceMap<'e> {
+    engines: &'e Engines,
+    project_name: String,
+    /// The directory containing the root module of the package.
+    src_dir: Option<PathBuf>,
+}
+impl<'e> SourceMap<'e> {
+    pub(crate) fn new(engines: &'e Engines, project_name: &str, root: &TyModule) -> Self {
+        let src_dir = root
+            .span
+            .source_id()
+            .and_then(|source_id| engines.se().get_path(source_id).parent().map(Into::into));
+        Self {
+            engines,
+            project_name: project_name.to_owned(),
+            src_dir,
+        }
+    }
+    /// The location and file name of the source page of a source file.
+    ///
+    /// Returns `None` if the file does not belong to the package.
+    fn page(&self, source_id: &SourceId) -> Option<(ModuleInfo, String)> {
+        let path = self.engines.se().get_path(source_id);
+        let relative_path = path.strip_prefix(self.src_dir.as_ref()?).ok()?;
+        let mut module_prefixes = vec![SOURCE_DIR_NAME.to_owned(), self.project_name.clone()];
+        if let Some(parent) = relative_path.parent() {
+            for component in parent.components() {
+                match component {
+                    Component::Normal(dir) => module_prefixes.push(dir.to_str()?.to_owned()),
+                    _ => return None,
+                }
+            }
+        }
+        let file_name = relative_path.file_name()?.to_str()?;
+        Some((
+            ModuleInfo::from_vec_str(&module_prefixes),
+            format!("{file_name}.html"),
+        ))
+    }
+    /// The location of the declaration spanning `span` within its source page.
+    pub(crate) fn location(&self, span: &Span) -> Option<SourceLocation> {
+        let (module_info, html_filename) = self.page(span.source_id()?)?;
+        Some(SourceLocation {
+            module_info,
+            html_filename,
+            line: span.start_pos().line_col().line,
+        })
+    }
+    /// Gathers the source files of `module` and all of its submodules.
+    pub(crate) fn source_files(&self, module: &TyModule) -> Vec<SourceFile> {
+        let mut files = BTreeMap::new();
+        self.gather_source_files(module, &mut files);
+        files.into_values().collect()
+    }
+    fn gather_source_files(&self, module: &TyModule, files: &mut BTreeMap<String, SourceFile>) {
+        if let Some((module_info, html_filename)) =
+            module.span.source_id().and_then(|id| self.page(id))
+        {
+            let key = format!(
+                "{}/{}",
+                module_info.module_prefixes.join("/"),
+                html_filename
+            );
+            files.entry(key).or_insert_with(|| SourceFile {
+                module_info,
+                html_filename,
+                source: module.span.src().clone(),
+            });
+        }
+        for (_, submodule) in &module.submodules {
+            self.gather_source_files(&submodule.module, files);
+        }
+    }
+}
@@ -0,0 +1,450 @@
+//! Serializes [Documentation] into a versioned JSON format that other tools can be built on.
+use crate::{
+    doc::{Document, Documentation},
+    render::{
+        item::{context::DocImplTrait, documentable_type::DocumentableType},
+        util::format::docstring::{format_attributes, DocStrings},
+    },
+};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+use sway_core::{
+    language::ty::{self, TyFunctionParameter, TyModule, TyTraitInterfaceItem, TyTraitItem},
+    transform::AttributesMap,
+    Engines, TypeArgument, TypeInfo, TypeParameter,
+};
+use sway_types::Ident;
+
+/// The version of the JSON documentation format.
+///
+/// Incremented whenever the format changes in a way that is not backwards compatible.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Writes the JSON documentation of a package to `<doc_path>/<package_name>.json`.
+pub fn write_json_docs(doc_path: &Path, json_docs: &JsonDocumentation) -> Result<()> {
+    let json_data = serde_json::to_string(json_docs)?;
+    Ok(fs::write(
+        doc_path.join(format!("{}.json", json_docs.name)),
+        json_data,
+    )?)
+}
+
+/// The documentation of a package.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonDocumentation {
+    /// The version of the format, see [FORMAT_VERSION].
+    pub format_version: u32,
+    /// The name of the package.
+    pub name: String,
+    /// The version of forc the package requires, e.g. `Forc v0.60.0`.
+    pub forc_version: Option<String>,
+    pub modules: Vec<JsonModule>,
+    pub items: Vec<JsonItem>,
+}
+impl JsonDocumentation {
+    pub fn new(
+        engines: &Engines,
+        project_name: &str,
+        root: &TyModule,
+        docs: &Documentation,
+        forc_version: Option<String>,
+    ) -> Self {
+        let mut modules = Vec::new();
+        gather_modules(vec![project_name.to_owned()], root, &mut modules);
+        Self {
+            format_version: FORMAT_VERSION,
+            name: project_name.to_owned(),
+            forc_version,
+            modules,
+            items: docs
+                .iter()
+                .filter_map(|doc| JsonItem::from_doc(doc, engines))
+                .collect(),
+        }
+    }
+}
+
+fn gather_modules(module_path: Vec<String>, module: &TyModule, modules: &mut Vec<JsonModule>) {
+    modules.push(JsonModule {
+        path: module_path.clone(),
+        docs: module.attributes.to_raw_string(),
+    });
+    for (_, submodule) in &module.submodules {
+        let mut submodule_path = module_path.clone();
+        submodule_path.push(submodule.mod_name_span.as_str().to_owned());
+        gather_modules(submodule_path, &submodule.module, modules);
+    }
+}
+
+/// A module of the package.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonModule {
+    /// The path of the module, starting with the package name, e.g. `["std", "hash"]`.
+    pub path: Vec<String>,
+    /// The markdown of the module level doc comments.
+    pub docs: String,
+}
+
+/// A documented item.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonItem {
+    pub name: String,
+    /// The path of the module the item is declared in.
+    pub module: Vec<String>,
+    /// The markdown of the doc comments.
+    pub docs: String,
+    /// The attributes other than doc comments, e.g. `storage(read, write)`.
+    pub attributes: Vec<String>,
+    /// The formatted declaration of the item, without the bodies of functions.
+    pub declaration: String,
+    /// Where the item is declared, if it is declared in the package's source.
+    pub source: Option<JsonSource>,
+    /// The kind of the item and its kind specific information.
+    pub inner: JsonItemKind,
+}
+impl JsonItem {
+    /// Creates a [JsonItem] from a [Document].
+    ///
+    /// Returns `None` if the [Document] is of a declaration that cannot be documented.
+    fn from_doc(doc: &Document, engines: &Engines) -> Option<Self> {
+        let decl_engine = engines.de();
+        let (attributes, inner) = match &doc.item_body.ty {
+            DocumentableType::Declared(ty::TyDecl::StructDecl(ty::StructDecl {
+                decl_id, ..
+            })) => {
+                let struct_decl = decl_engine.get_struct(decl_id);
+                let inner = JsonItemKind::Struct {
+                    generic_params: generic_params(&struct_decl.type_parameters),
+                    fields: struct_decl
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            JsonField::new(&field.name, &field.type_argument, &field.attributes)
+                        })
+                        .collect(),
+                    impls: JsonImpl::from_doc(doc, engines),
+                };
+                (Some(struct_decl.attributes.clone()), inner)
+            }
+            DocumentableType::Declared(ty::TyDecl::EnumDecl(ty::EnumDecl { decl_id, .. })) => {
+                let enum_decl = decl_engine.get_enum(decl_id);
+                let inner = JsonItemKind::Enum {
+                    generic_params: generic_params(&enum_decl.type_parameters),
+                    variants: enum_decl
+                        .variants
+                        .iter()
+                        .map(|variant| {
+                            JsonField::new(
+                                &variant.name,
+                                &variant.type_argument,
+                                &variant.attributes,
+                            )
+                        })
+                        .collect(),
+                    impls: JsonImpl::from_doc(doc, engines),
+                };
+                (Some(enum_decl.attributes.clone()), inner)
+            }
+            DocumentableType::Declared(ty::TyDecl::TraitDecl(ty::TraitDecl {
+                decl_id, ..
+            })) => {
+                let trait_decl = decl_engine.get_trait(decl_id);
+                let inner = JsonItemKind::Trait {
+                    generic_params: generic_params(&trait_decl.type_parameters),
+                    required_methods: required_methods(&trait_decl.interface_surface, engines),
+                    provided_methods: provided_methods(&trait_decl.items, engines),
+                };
+                (Some(trait_decl.attributes.clone()), inner)
+            }
+            DocumentableType::Declared(ty::TyDecl::AbiDecl(ty::AbiDecl { decl_id, .. })) => {
+                let abi_decl = decl_engine.get_abi(decl_id);
+                let inner = JsonItemKind::Abi {
+                    required_methods: required_methods(&abi_decl.interface_surface, engines),
+                    provided_methods: provided_methods(&abi_decl.items, engines),
+                };
+                (Some(abi_decl.attributes.clone()), inner)
+            }
+            DocumentableType::Declared(ty::TyDecl::StorageDecl(ty::StorageDecl {
+                decl_id,
+                ..
+            })) => {
+                let storage_decl = decl_engine.get_storage(decl_id);
+                let inner = JsonItemKind::Storage {
+                    fields: storage_decl
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            JsonField::new(&field.name, &field.type_argument, &field.attributes)
+                        })
+                        .collect(),
+                };
+                (Some(storage_decl.attributes.clone()), inner)
+            }
+            DocumentableType::Declared(ty::TyDecl::FunctionDecl(ty::FunctionDecl {
+                decl_id,
+                ..
+            })) => {
+                let fn_decl = decl_engine.get_function(decl_id);
+                let inner = JsonItemKind::Function(JsonFunction::from_fn_decl(&fn_decl, engines));
+                (Some(fn_decl.attributes.clone()), inner)
+            }
+            DocumentableType::Declared(ty::TyDecl::ConstantDecl(ty::ConstantDecl {
+                decl_id,
+                ..
+            })) => {
+                let const_decl = decl_engine.get_constant(decl_id);
+                let inner = JsonItemKind::Constant {
+                    ty: const_decl.type_ascription.span.as_str().to_owned(),
+                };
+                (Some(const_decl.attributes.clone()), inner)
+            }
+            DocumentableType::Declared(_) => return None,
+            DocumentableType::Primitive(_) => (
+                None,
+                JsonItemKind::Primitive {
+                    impls: JsonImpl::from_doc(doc, engines),
+                },
+            ),
+        };
+        // Primitives have no doc comments, only a plain text description.
+        let docs = match &attributes {
+            Some(attributes) => attributes.to_raw_string(),
+            None => doc.raw_attributes.clone().unwrap_or_default(),
+        };
+
+        Some(Self {
+            name: doc.item_header.item_name.as_str().to_owned(),
+            module: doc.module_info.module_prefixes.clone(),
+            docs,
+            attributes: attributes
+                .as_ref()
+                .map(format_attributes)
+                .unwrap_or_default(),
+            declaration: doc.item_body.code_str.clone(),
+            source: doc.item_body.source.as_ref().map(|source| JsonSource {
+                file: source.file_path(),
+                line: source.line,
+            }),
+            inner,
+        })
+    }
+}
+
+/// The location of an item declaration.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonSource {
+    /// The path of the source file, relative to the directory containing the root module
+    /// of the package, e.g. `module/submodule.sw`.
+    pub file: String,
+    /// The 1-based line the declaration starts on.
+    pub line: usize,
+}
+
+/// The kind of an item, serialized as e.g. `{"struct": {"fields": [...], ...}}`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JsonItemKind {
+    Struct {
+        generic_params: Vec<String>,
+        fields: Vec<JsonField>,
+        impls: Vec<JsonImpl>,
+    },
+    Enum {
+        generic_params: Vec<String>,
+        variants: Vec<JsonField>,
+        impls: Vec<JsonImpl>,
+    },
+    Trait {
+        generic_params: Vec<String>,
+        required_methods: Vec<JsonFunction>,
+        provided_methods: Vec<JsonFunction>,
+    },
+    Abi {
+        required_methods: Vec<JsonFunction>,
+        provided_methods: Vec<JsonFunction>,
+    },
+    Storage {
+        fields: Vec<JsonField>,
+    },
+    Function(JsonFunction),
+    Constant {
+        #[serde(rename = "type")]
+        ty: String,
+    },
+    Primitive {
+        impls: Vec<JsonImpl>,
+    },
+}
+
+/// A field of a struct or storage, or a variant of an enum.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The markdown of the doc comments.
+    pub docs: String,
+}
+impl JsonField {
+    fn new(name: &Ident, type_argument: &TypeArgument, attributes: &AttributesMap) -> Self {
+        Self {
+            name: name.as_str().to_owned(),
+            ty: type_argument.span.as_str().to_owned(),
+            docs: attributes.to_raw_string(),
+        }
+    }
+}
+
+/// A function, or a method of a trait, ABI or implementation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonFunction {
+    pub name: String,
+    /// The markdown of the doc comments.
+    pub docs: String,
+    /// The attributes other than doc comments, e.g. `payable`.
+    pub attributes: Vec<String>,
+    pub generic_params: Vec<String>,
+    pub inputs: Vec<JsonParam>,
+    /// The return type, `()` if the function does not return a value.
+    pub output: String,
+}
+impl JsonFunction {
+    fn from_fn_decl(fn_decl: &ty::TyFunctionDecl, engines: &Engines) -> Self {
+        Self::new(
+            &fn_decl.name,
+            &fn_decl.attributes,
+            &fn_decl.type_parameters,
+            &fn_decl.parameters,
+            &fn_decl.return_type,
+            engines,
+        )
+    }
+    fn from_trait_fn(trait_fn: &ty::TyTraitFn, engines: &Engines) -> Self {
+        Self::new(
+            &trait_fn.name,
+            &trait_fn.attributes,
+            &[],
+            &trait_fn.parameters,
+            &trait_fn.return_type,
+            engines,
+        )
+    }
+    fn new(
+        name: &Ident,
+        attributes: &AttributesMap,
+        type_parameters: &[TypeParameter],
+        parameters: &[TyFunctionParameter],
+        return_type: &TypeArgument,
+        engines: &Engines,
+    ) -> Self {
+        let output = match &*engines.te().get(return_type.type_id) {
+            TypeInfo::Tuple(fields) if fields.is_empty() => "()".to_owned(),
+            _ => return_type.span.as_str().to_owned(),
+        };
+        Self {
+            name: name.as_str().to_owned(),
+            docs: attributes.to_raw_string(),
+            attributes: format_attributes(attributes),
+            generic_params: generic_params(type_parameters),
+            inputs: parameters
+                .iter()
+                .map(|param| JsonParam {
+                    name: param.name.as_str().to_owned(),
+                    ty: if param.is_self() {
+                        "Self".to_owned()
+                    } else {
+                        param.type_argument.span.as_str().to_owned()
+                    },
+                    is_reference: param.is_reference,
+                    is_mutable: param.is_mutable,
+                })
+                .collect(),
+            output,
+        }
+    }
+}
+
+/// A parameter of a function.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonParam {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: String,
+    pub is_reference: bool,
+    pub is_mutable: bool,
+}
+
+/// An implementation block for a struct, enum or primitive.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonImpl {
+    /// The path of the implemented trait, `None` for inherent implementations.
+    pub trait_path: Option<String>,
+    pub trait_type_args: Vec<String>,
+    pub methods: Vec<JsonFunction>,
+}
+impl JsonImpl {
+    /// The inherent and trait implementations of the item of a [Document].
+    fn from_doc(doc: &Document, engines: &Engines) -> Vec<Self> {
+        let item_context = &doc.item_body.item_context;
+        item_context
+            .inherent_impls
+            .iter()
+            .chain(item_context.impl_traits.iter())
+            .flatten()
+            .map(|doc_impl_trait| Self::from_doc_impl_trait(doc_impl_trait, engines))
+            .collect()
+    }
+    fn from_doc_impl_trait(doc_impl_trait: &DocImplTrait, engines: &Engines) -> Self {
+        let trait_path =
+            (!doc_impl_trait.is_inherent()).then(|| match &doc_impl_trait.module_info_override {
+                Some(module_prefixes) => format!(
+                    "{}::{}",
+                    module_prefixes.join("::"),
+                    doc_impl_trait.short_name()
+                ),
+                None => doc_impl_trait.impl_trait.trait_name.to_string(),
+            });
+        Self {
+            trait_path,
+            trait_type_args: doc_impl_trait.type_args(),
+            methods: provided_methods(&doc_impl_trait.impl_trait.items, engines),
+        }
+    }
+}
+
+fn required_methods(
+    interface_surface: &[TyTraitInterfaceItem],
+    engines: &Engines,
+) -> Vec<JsonFunction> {
+    interface_surface
+        .iter()
+        .filter_map(|item| match item {
+            TyTraitInterfaceItem::TraitFn(decl_ref) => Some(JsonFunction::from_trait_fn(
+                &engines.de().get_trait_fn(decl_ref),
+                engines,
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn provided_methods(items: &[TyTraitItem], engines: &Engines) -> Vec<JsonFunction> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            TyTraitItem::Fn(decl_ref) => Some(JsonFunction::from_fn_decl(
+                &engines.de().get_function(decl_ref.id()),
+                engines,
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn generic_params(type_parameters: &[TypeParameter]) -> Vec<String> {
+    type_parameters
+        .iter()
+        .map(|type_param| type_param.name.as_str().to_owned())
+        .collect()
+}
@@ -1,12 +1,13 @@
 pub mod cli;
 pub mod doc;
+pub mod json;
 pub mod render;
 pub mod search;
 pub mod tests;
 
-use anyhow::{bail, Result};
-use cli::Command;
-use doc::Documentation;
+use anyhow::{anyhow, bail, Result};
+use cli::{Command, OutputFormat};
+use doc::{source::SourceMap, Documentation};
 use forc_pkg as pkg;
 use forc_pkg::{
     manifest::{GenericManifestFile, ManifestFile},
@@ -14,12 +15,16 @@ use forc_pkg::{
 };
 use forc_tracing::println_action_green;
 use forc_util::default_output_directory;
-use render::RenderedDocumentation;
+use json::JsonDocumentation;
+use render::{
+    util::format::intra_doc_link::IntraDocLinks, RenderedDocument, RenderedDocumentation,
+};
 use std::{
     fs,
     path::{Path, PathBuf},
 };
 use sway_core::{language::ty::TyProgram, BuildTarget, Engines};
+use sway_features::ExperimentalFeatures;
 
 pub const ASSETS_DIR_NAME: &str = "static.files";
 
@@ -29,6 +34,9 @@ pub struct RenderPlan<'e> {
     no_deps: bool,
     document_private_items: bool,
     engines: &'e Engines,
+    /// The experimental features the package is compiled with, which determine e.g. how
+    /// storage slot keys and ABI method selectors are computed.
+    experimental: ExperimentalFeatures,
 }
 
 impl<'e> RenderPlan<'e> {
@@ -36,11 +44,13 @@ impl<'e> RenderPlan<'e> {
         no_deps: bool,
         document_private_items: bool,
         engines: &'e Engines,
+        experimental: ExperimentalFeatures,
     ) -> RenderPlan<'e> {
         Self {
             no_deps,
             document_private_items,
             engines,
+            experimental,
         }
     }
 }
@@ -128,7 +138,12 @@ pub fn compile_html(
             manifest: &manifest,
             pkg_manifest,
         };
-        build_docs(program_info, &doc_path, build_instructions)?
+        build_docs(
+            program_info,
+            &doc_path,
+            build_instructions,
+            &Documentation::default(),
+        )?
     } else {
         let order = plan.compilation_order();
         let graph = plan.graph();
@@ -152,14 +167,16 @@ pub fn compile_html(
                     manifest: &manifest_file,
                     pkg_manifest: pkg_manifest_file,
                 };
-                raw_docs
-                    .0
-                    .extend(build_docs(program_info, &doc_path, build_instructions)?.0);
+                // Dependencies are documented first, so intra-doc links can be resolved to their items.
+                let docs = build_docs(program_info, &doc_path, build_instructions, &raw_docs)?;
+                raw_docs.0.extend(docs.0);
             }
         }
         raw_docs
     };
-    search::write_search_index(&doc_path, &raw_docs)?;
+    if build_instructions.output_format == OutputFormat::Html {
+        search::write_search_index(&doc_path, &raw_docs, &engines)?;
+    }
 
     Ok((doc_path, pkg_manifest.to_owned()))
 }
@@ -168,10 +185,13 @@ fn build_docs(
     program_info: ProgramInfo,
     doc_path: &Path,
     build_instructions: &Command,
+    dependency_docs: &Documentation,
 ) -> Result<Documentation> {
     let Command {
         document_private_items,
         no_deps,
+        output_format,
+        ref experimental,
         ..
     } = *build_instructions;
     let ProgramInfo {
@@ -196,21 +216,50 @@ fn build_docs(
         &ty_program,
         document_private_items,
     )?;
-    let root_attributes =
-        (!ty_program.root.attributes.is_empty()).then_some(ty_program.root.attributes);
     let forc_version = pkg_manifest
         .project
         .forc_version
         .as_ref()
         .map(|ver| format!("Forc v{}.{}.{}", ver.major, ver.minor, ver.patch));
+    if output_format == OutputFormat::Json {
+        let json_docs = JsonDocumentation::new(
+            engines,
+            pkg_manifest.project_name(),
+            &ty_program.root,
+            &raw_docs,
+            forc_version,
+        );
+        json::write_json_docs(doc_path, &json_docs)?;
+        println_action_green("Finished", pkg_manifest.project_name());
+        return Ok(raw_docs);
+    }
+    let source_files = SourceMap::new(engines, pkg_manifest.project_name(), &ty_program.root)
+        .source_files(&ty_program.root);
+    let root_attributes =
+        (!ty_program.root.attributes.is_empty()).then_some(ty_program.root.attributes);
     // render docs to HTML
-    let rendered_docs = RenderedDocumentation::from_raw_docs(
+    let experimental = ExperimentalFeatures::new(
+        &pkg_manifest.project.experimental,
+        &experimental.experimental,
+        &experimental.no_experimental,
+    )
+    .map_err(|err| anyhow!("{err}"))?;
+    let render_plan = RenderPlan::new(no_deps, document_private_items, engines, experimental);
+    let intra_doc_links = IntraDocLinks::new(dependency_docs.iter().chain(raw_docs.iter()));
+    let mut rendered_docs = RenderedDocumentation::from_raw_docs(
         raw_docs.clone(),
-        RenderPlan::new(no_deps, document_private_items, engines),
+        render_plan.clone(),
         root_attributes,
         &ty_program.kind,
         forc_version,
+        &intra_doc_links,
     )?;
+    for source_file in source_files {
+        rendered_docs.push(RenderedDocument::from_source_file(
+            source_file,
+            render_plan.clone(),
+        )?);
+    }
 
     // write file contents to doc folder
     write_content(rendered_docs, doc_path)?;
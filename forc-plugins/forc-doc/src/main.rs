@@ -1,7 +1,10 @@
 use anyhow::{bail, Result};
 use clap::Parser;
 use forc_doc::{
-    cli::Command, compile_html, get_doc_dir, render::constant::INDEX_FILENAME, ASSETS_DIR_NAME,
+    cli::{Command, OutputFormat},
+    compile_html, get_doc_dir,
+    render::constant::INDEX_FILENAME,
+    ASSETS_DIR_NAME,
 };
 use include_dir::{include_dir, Dir};
 use std::{
@@ -11,8 +14,14 @@ use std::{
 
 pub fn main() -> Result<()> {
     let build_instructions = Command::parse();
+    if build_instructions.open && build_instructions.output_format == OutputFormat::Json {
+        bail!("`--open` is not supported with `--output-format json`");
+    }
 
     let (doc_path, pkg_manifest) = compile_html(&build_instructions, &get_doc_dir)?;
+    if build_instructions.output_format == OutputFormat::Json {
+        return Ok(());
+    }
 
     // CSS, icons and logos
     static ASSETS_DIR: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/src/static.files");
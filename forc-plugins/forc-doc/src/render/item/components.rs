@@ -1,6 +1,6 @@
 //! Handles creation of the head and body of an HTML doc.
 use crate::{
-    doc::module::ModuleInfo,
+    doc::{module::ModuleInfo, source::SourceLocation},
     render::{
         constant::IDENTITY,
         item::context::ItemContext,
@@ -83,6 +83,8 @@ pub struct ItemBody {
     pub code_str: String,
     pub attrs_opt: Option<String>,
     pub item_context: ItemContext,
+    /// The location of the declaration in its source page, if any.
+    pub source: Option<SourceLocation>,
 }
 impl SidebarNav for ItemBody {
     fn sidebar(&self) -> Sidebar {
@@ -109,6 +111,7 @@ impl Renderable for ItemBody {
             code_str,
             attrs_opt,
             item_context,
+            source,
         } = self;
 
         let doc_name = ty.doc_name().to_string();
@@ -120,6 +123,7 @@ impl Renderable for ItemBody {
         let sway_hjs =
             module_info.to_html_shorthand_path_string(&format!("{ASSETS_DIR_NAME}/highlight.js"));
         let rendered_module_anchors = module_info.get_anchors()?;
+        let source_href = source.map(|source| source.href(&module_info));
 
         Ok(box_html! {
             body(class=format!("swaydoc {doc_name}")) {
@@ -140,6 +144,13 @@ impl Renderable for ItemBody {
                                             : item_name.as_str();
                                         }
                                     }
+                                    @ if let Some(source_href) = source_href {
+                                        span(class="out-of-band") {
+                                            a(class="srclink", href=source_href) {
+                                                : "source";
+                                            }
+                                        }
+                                    }
                                 }
                             }
                             div(class="docblock item-decl") {
@@ -7,7 +7,7 @@ use crate::{
         link::{DocLink, DocLinks},
         title::BlockTitle,
         title::DocBlock,
-        util::format::docstring::DocStrings,
+        util::format::docstring::{format_attributes, DocStrings},
         DocStyle, Renderable,
     },
     RenderPlan,
@@ -18,6 +18,7 @@ use std::{collections::BTreeMap, fmt::Write};
 use sway_core::language::ty::{
     TyEnumVariant, TyImplSelfOrTrait, TyStorageField, TyStructField, TyTraitFn, TyTraitItem,
 };
+use sway_error::handler::Handler;
 
 /// The actual context of the item displayed by [ItemContext].
 /// This uses [ContextType] to determine how to represent the context of an item.
@@ -90,6 +91,7 @@ impl Renderable for Context {
                         &render_plan,
                         &self.module_info,
                     );
+                    let slot_key = storage_slot_key(field, &render_plan);
                     rendered_list.push(box_html! {
                         span(id=&storage_field_id, class="storagefield small-section-header") {
                             a(class="anchor field", href=format!("{IDENTITY}{storage_field_id}"));
@@ -102,6 +104,12 @@ impl Renderable for Context {
                                 }
                             }
                         }
+                        div(class="item-info") {
+                            span(class="storage-slot") {
+                                : "Slot: ";
+                                code { : slot_key; }
+                            }
+                        }
                         @ if !field.attributes.is_empty() {
                             div(class="docblock") {
                                 : Raw(field.attributes.to_html_string());
@@ -138,8 +146,9 @@ impl Renderable for Context {
                     }.into_string()?);
                 }
             }
-            ContextType::RequiredMethods(methods) => {
+            ContextType::RequiredMethods(methods) | ContextType::AbiMethods(methods) => {
                 is_method_block = true;
+                let is_abi = matches!(self.context_type, ContextType::AbiMethods(_));
                 for method in methods {
                     let mut fn_sig = format!("fn {}(", method.name.as_str());
                     for param in &method.parameters {
@@ -166,6 +175,14 @@ impl Renderable for Context {
                     let fn_sig = format!("fn {}(", method.name);
                     let method_id = format!("tymethod.{}", method.name.as_str());
                     let method_attrs = method.attributes.clone();
+                    // ABI methods also show how they are called and their attributes,
+                    // e.g. `#[storage(read)]` and `#[payable]`.
+                    let abi_method_info = is_abi.then(|| {
+                        (
+                            format_attributes(&method.attributes),
+                            abi_method_selector(method, &render_plan),
+                        )
+                    });
 
                     let rendered_method = box_html! {
                         div(id=&method_id, class="method has-srclink") {
@@ -226,6 +243,21 @@ impl Renderable for Context {
                                     : method.return_type.span.as_str();
                                 }
                             }
+                            @ if let Some((attributes, selector)) = &abi_method_info {
+                                div(class="item-info") {
+                                    @ for attribute in attributes {
+                                        code(class="attribute") {
+                                            : format!("#[{attribute}]");
+                                        }
+                                    }
+                                    @ if let Some((label, selector)) = selector {
+                                        span(class="selector") {
+                                            : format!("{label}: ");
+                                            code { : selector; }
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }.into_string()?;
 
@@ -265,6 +297,51 @@ impl Renderable for Context {
     }
 }
 
+/// The key of the storage slot a storage field starts at.
+///
+/// If the key is set by an `in` expression other than a literal, the expression is returned.
+fn storage_slot_key(field: &TyStorageField, render_plan: &RenderPlan) -> String {
+    match field.storage_key(render_plan.experimental) {
+        Some(key) => format!("{key:#x}"),
+        // The key is given by an expression, e.g. a constant, which is shown as is.
+        None => field
+            .key_expression
+            .as_ref()
+            .map(|key_expression| key_expression.span.as_str().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+/// The label and value contract calls identify an ABI method by.
+///
+/// With the new encoding, that is the encoded method name. Otherwise, it is the selector,
+/// the first four bytes of the SHA-256 hash of the method name and parameter types.
+fn abi_method_selector(
+    method: &TyTraitFn,
+    render_plan: &RenderPlan,
+) -> Option<(&'static str, String)> {
+    let to_hex = |bytes: &[u8]| {
+        bytes
+            .iter()
+            .fold(String::from("0x"), |hex, byte| format!("{hex}{byte:02x}"))
+    };
+    if render_plan.experimental.new_encoding {
+        // A `str` is encoded as its length, followed by its bytes.
+        let name = method.name.as_str();
+        let encoded_name = [
+            (name.len() as u64).to_be_bytes().as_slice(),
+            name.as_bytes(),
+        ]
+        .concat();
+        Some(("Encoded name", to_hex(&encoded_name)))
+    } else {
+        let selector = method
+            .to_fn_selector_value(&Handler::default(), render_plan.engines)
+            .ok()?;
+        Some(("Selector", to_hex(&selector)))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DocImplTrait {
     pub impl_for_module: ModuleInfo,
@@ -362,7 +439,7 @@ impl ItemContext {
                         .collect();
                     links.insert(BlockTitle::Variants, doc_links);
                 }
-                ContextType::RequiredMethods(methods) => {
+                ContextType::RequiredMethods(methods) | ContextType::AbiMethods(methods) => {
                     let doc_links = methods
                         .iter()
                         .map(|method| DocLink {
@@ -588,8 +665,30 @@ impl Renderable for TyTraitItem {
     fn render(self, render_plan: RenderPlan) -> Result<Box<dyn RenderBox>> {
         let item = match self {
             TyTraitItem::Fn(item_fn) => item_fn,
-            TyTraitItem::Constant(_) => unimplemented!("Constant Trait items not yet implemented"),
-            TyTraitItem::Type(_) => unimplemented!("Type Trait items not yet implemented"),
+            TyTraitItem::Constant(item_const) => {
+                let const_decl = render_plan.engines.de().get_constant(item_const.id());
+                return render_associated_item(
+                    "const",
+                    "associatedconstant",
+                    const_decl.call_path.suffix.as_str(),
+                    format!(": {}", const_decl.type_ascription.span.as_str()),
+                    const_decl.attributes.to_html_string(),
+                );
+            }
+            TyTraitItem::Type(item_type) => {
+                let type_decl = render_plan.engines.de().get_type(item_type.id());
+                let ty = match &type_decl.ty {
+                    Some(ty) => format!(" = {}", ty.span.as_str()),
+                    None => String::new(),
+                };
+                return render_associated_item(
+                    "type",
+                    "associatedtype",
+                    type_decl.name.as_str(),
+                    ty,
+                    type_decl.attributes.to_html_string(),
+                );
+            }
         };
         let method = render_plan.engines.de().get_function(item.id());
         let attributes = method.attributes.to_html_string();
@@ -698,6 +797,46 @@ impl Renderable for TyTraitItem {
     }
 }
 
+/// Renders an associated const or type of an implementation, e.g. `type Item = T`.
+fn render_associated_item(
+    keyword: &'static str,
+    id_prefix: &'static str,
+    name: &str,
+    rest: String,
+    attributes: String,
+) -> Result<Box<dyn RenderBox>> {
+    let id = format!("{id_prefix}.{name}");
+    let name = name.to_string();
+    let item = box_html! {
+        div(id=&id, class="associated-item trait-impl") {
+            a(href=format!("{IDENTITY}{id}"), class="anchor");
+            h4(class="code-header") {
+                : format!("{keyword} ");
+                a(class="associatedname", href=format!("{IDENTITY}{id}")) {
+                    : &name;
+                }
+                : &rest;
+            }
+        }
+    }
+    .into_string()?;
+
+    Ok(box_html! {
+        @ if !attributes.is_empty() {
+            details(class="swaydoc-toggle method-toggle", open) {
+                summary {
+                    : Raw(item);
+                }
+                div(class="docblock") {
+                    : Raw(attributes);
+                }
+            }
+        } else {
+            : Raw(item);
+        }
+    })
+}
+
 #[derive(Clone, Debug)]
 /// Represents the type of [Context] for item declarations that have
 /// fields, variants or methods, and acts as a wrapper for those values for rendering.
@@ -708,15 +847,19 @@ pub enum ContextType {
     StorageFields(Vec<TyStorageField>),
     /// Stores the variants of an enum to be rendered.
     EnumVariants(Vec<TyEnumVariant>),
-    /// Stores the methods of a trait or abi to be rendered.
+    /// Stores the methods of a trait to be rendered.
     RequiredMethods(Vec<TyTraitFn>),
+    /// Stores the methods of an abi to be rendered, along with how they are called.
+    AbiMethods(Vec<TyTraitFn>),
 }
 impl DocBlock for ContextType {
     fn title(&self) -> BlockTitle {
         match self {
             ContextType::StructFields(_) | ContextType::StorageFields(_) => BlockTitle::Fields,
             ContextType::EnumVariants(_) => BlockTitle::Variants,
-            ContextType::RequiredMethods(_) => BlockTitle::RequiredMethods,
+            ContextType::RequiredMethods(_) | ContextType::AbiMethods(_) => {
+                BlockTitle::RequiredMethods
+            }
         }
     }
 
@@ -726,6 +869,7 @@ impl DocBlock for ContextType {
             ContextType::StorageFields(_) => "storage_fields",
             ContextType::EnumVariants(_) => "enum_variants",
             ContextType::RequiredMethods(_) => "required_methods",
+            ContextType::AbiMethods(_) => "abi_methods",
         }
     }
 }
@@ -2,6 +2,7 @@
 use crate::{
     doc::{
         module::{ModuleInfo, ModulePrefixes},
+        source::SourceFile,
         Document, Documentation,
     },
     render::{
@@ -9,7 +10,7 @@ use crate::{
         index::{AllDocIndex, ModuleIndex},
         link::{DocLink, DocLinks},
         title::BlockTitle,
-        util::format::docstring::DocStrings,
+        util::format::{docstring::DocStrings, intra_doc_link::IntraDocLinks},
     },
     RenderPlan,
 };
@@ -28,6 +29,7 @@ pub mod item;
 pub mod link;
 mod search;
 mod sidebar;
+mod source;
 mod title;
 pub mod util;
 
@@ -61,6 +63,17 @@ impl RenderedDocument {
             file_contents: HTMLString::from_rendered_content(doc.clone().render(render_plan)?)?,
         })
     }
+    /// Renders the source page of a [SourceFile].
+    pub(crate) fn from_source_file(
+        source_file: SourceFile,
+        render_plan: RenderPlan,
+    ) -> Result<Self> {
+        Ok(Self {
+            module_info: source_file.module_info.clone(),
+            html_filename: source_file.html_filename.clone(),
+            file_contents: HTMLString::from_rendered_content(source_file.render(render_plan)?)?,
+        })
+    }
 }
 
 #[derive(Default)]
@@ -68,12 +81,15 @@ pub struct RenderedDocumentation(pub Vec<RenderedDocument>);
 
 impl RenderedDocumentation {
     /// Top level HTML rendering for all [Documentation] of a program.
-    pub fn from_raw_docs(
+    ///
+    /// Intra-doc links are resolved to the items in `intra_doc_links`.
+    pub(crate) fn from_raw_docs(
         raw_docs: Documentation,
         render_plan: RenderPlan,
         root_attributes: Option<AttributesMap>,
         program_kind: &TyProgramKind,
         forc_version: Option<String>,
+        intra_doc_links: &IntraDocLinks,
     ) -> Result<RenderedDocumentation> {
         let mut rendered_docs: RenderedDocumentation = RenderedDocumentation::default();
         let root_module = match raw_docs.0.first() {
@@ -180,6 +196,11 @@ impl RenderedDocumentation {
             )?,
         });
 
+        for rendered_doc in rendered_docs.iter_mut() {
+            rendered_doc.file_contents.0 =
+                intra_doc_links.render(&rendered_doc.file_contents.0, &rendered_doc.module_info);
+        }
+
         Ok(rendered_docs)
     }
 }
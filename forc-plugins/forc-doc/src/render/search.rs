@@ -39,10 +39,7 @@ pub(crate) fn generate_searchbar(module_info: &ModuleInfo) -> Box<dyn RenderBox>
                 const searchInput = document.getElementById('search-input');
                 if (query) {{
                     searchInput.value = query;
-                    const results = Object.values(SEARCH_INDEX).flat().filter(item => {{
-                        const lowerQuery = query.toLowerCase();
-                        return item.name.toLowerCase().includes(lowerQuery);
-                    }});
+                    const results = searchDocs(query);
                     const header = `<h1>Results for ${{query}}</h1>`;
                     if (results.length > 0) {{
                         const resultList = results.map(item => {{
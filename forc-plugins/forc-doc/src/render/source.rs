@@ -0,0 +1,88 @@
+//! Handles creation of the source pages of Sway files.
+use crate::{
+    doc::source::SourceFile,
+    render::{search::generate_searchbar, Renderable},
+    RenderPlan, ASSETS_DIR_NAME,
+};
+use anyhow::Result;
+use horrorshow::{box_html, RenderBox};
+
+impl Renderable for SourceFile {
+    fn render(self, _render_plan: RenderPlan) -> Result<Box<dyn RenderBox>> {
+        let SourceFile {
+            module_info,
+            html_filename,
+            source,
+        } = self;
+        // Skip the source directory, e.g. `project_name/module/submodule.sw`.
+        let file_path = module_info
+            .module_prefixes
+            .iter()
+            .skip(1)
+            .map(String::as_str)
+            .chain(std::iter::once(html_filename.trim_end_matches(".html")))
+            .collect::<Vec<_>>()
+            .join("/");
+        let line_count = source.lines().count();
+
+        let favicon =
+            module_info.to_html_shorthand_path_string(&format!("{ASSETS_DIR_NAME}/sway-logo.svg"));
+        let normalize =
+            module_info.to_html_shorthand_path_string(&format!("{ASSETS_DIR_NAME}/normalize.css"));
+        let swaydoc =
+            module_info.to_html_shorthand_path_string(&format!("{ASSETS_DIR_NAME}/swaydoc.css"));
+        let ayu = module_info.to_html_shorthand_path_string(&format!("{ASSETS_DIR_NAME}/ayu.css"));
+        let ayu_hjs =
+            module_info.to_html_shorthand_path_string(&format!("{ASSETS_DIR_NAME}/ayu.min.css"));
+        let sway_hjs =
+            module_info.to_html_shorthand_path_string(&format!("{ASSETS_DIR_NAME}/highlight.js"));
+
+        Ok(box_html! {
+            head {
+                meta(charset="utf-8");
+                meta(name="viewport", content="width=device-width, initial-scale=1.0");
+                meta(name="generator", content="swaydoc");
+                meta(name="description", content=format!("Source of the Sway file `{file_path}`."));
+                meta(name="keywords", content="sway, swaylang, sway-lang");
+                link(rel="icon", href=favicon);
+                title: format!("{file_path} - source");
+                link(rel="stylesheet", type="text/css", href=normalize);
+                link(rel="stylesheet", type="text/css", href=swaydoc, id="mainThemeStyle");
+                link(rel="stylesheet", type="text/css", href=ayu);
+                link(rel="stylesheet", href=ayu_hjs);
+            }
+            body(class="swaydoc source") {
+                main {
+                    div(class="width-limiter") {
+                        : generate_searchbar(&module_info);
+                        section(id="main-content", class="content") {
+                            div(class="main-heading") {
+                                h1(class="fqn") {
+                                    span(class="in-band") { : &file_path; }
+                                }
+                            }
+                            div(class="example-wrap") {
+                                pre(class="line-numbers") {
+                                    @ for line in 1..=line_count {
+                                        a(id=format!("L{line}"), href=format!("#L{line}")) {
+                                            : line.to_string();
+                                        }
+                                        : "\n";
+                                    }
+                                }
+                                pre(class="sway") {
+                                    code { : source.as_ref(); }
+                                }
+                            }
+                        }
+                        section(id="search", class="search-results");
+                    }
+                }
+                script(src=sway_hjs);
+                script {
+                    : "hljs.highlightAll();";
+                }
+            }
+        })
+    }
+}
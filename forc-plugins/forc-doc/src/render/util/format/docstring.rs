@@ -40,6 +40,27 @@ impl DocStrings for AttributesMap {
     }
 }
 
+/// Formats the attributes other than doc comments, e.g. `#[storage(read, write)]` becomes
+/// `storage(read, write)`.
+pub(crate) fn format_attributes(attributes: &AttributesMap) -> Vec<String> {
+    attributes
+        .iter()
+        .filter(|(kind, _)| !matches!(kind, AttributeKind::Doc | AttributeKind::DocComment))
+        .flat_map(|(_, attributes)| attributes)
+        .map(|attribute| {
+            let args = attribute
+                .args
+                .iter()
+                .map(|arg| arg.name.as_str())
+                .collect::<Vec<_>>();
+            match args.is_empty() {
+                true => attribute.name.as_str().to_owned(),
+                false => format!("{}({})", attribute.name.as_str(), args.join(", ")),
+            }
+        })
+        .collect()
+}
+
 /// Create a docstring preview from raw html attributes.
 ///
 /// Returns `None` if there are no attributes.
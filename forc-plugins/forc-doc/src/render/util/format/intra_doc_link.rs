@@ -0,0 +1,231 @@
+//! Resolution of intra-doc links, e.g. [`StorageMap::insert`], to the pages of documented items.
+use crate::doc::{module::ModuleInfo, Document};
+use std::{collections::HashMap, fmt::Write};
+
+const CODE_OPEN: &str = "[<code>";
+const CODE_CLOSE: &str = "</code>]";
+/// Intra-doc links are not resolved within these elements.
+const SKIPPED_ELEMENTS: &[&str] = &["a", "code", "pre", "script"];
+
+/// The documented items intra-doc links can be resolved to, which include the items of
+/// previously documented dependencies, e.g. `std` and `core`.
+pub(crate) struct IntraDocLinks<'a> {
+    /// The documented items by name.
+    items: HashMap<&'a str, Vec<&'a Document>>,
+}
+
+impl<'a> IntraDocLinks<'a> {
+    pub(crate) fn new(docs: impl IntoIterator<Item = &'a Document>) -> Self {
+        let mut items: HashMap<&str, Vec<&Document>> = HashMap::new();
+        for doc in docs {
+            items
+                .entry(doc.item_header.item_name.as_str())
+                .or_default()
+                .push(doc);
+        }
+        Self { items }
+    }
+
+    /// Replaces the intra-doc links of an HTML page, written as ``[`path`]`` or `[path]` in
+    /// doc comments, with links to the items they refer to.
+    ///
+    /// Links that cannot be resolved are left as is.
+    pub(crate) fn render(&self, html: &str, current_module_info: &ModuleInfo) -> String {
+        let mut result = String::with_capacity(html.len());
+        let mut skipped_depth = 0usize;
+        let mut rest = html;
+        while let Some(index) = rest.find(['<', '[']) {
+            result.push_str(&rest[..index]);
+            rest = &rest[index..];
+            if rest.starts_with('<') {
+                let tag_len = rest.find('>').map_or(rest.len(), |end| end + 1);
+                let tag = &rest[..tag_len];
+                if SKIPPED_ELEMENTS
+                    .iter()
+                    .any(|name| is_opening_tag(tag, name))
+                {
+                    skipped_depth += 1;
+                } else if SKIPPED_ELEMENTS
+                    .iter()
+                    .any(|name| is_closing_tag(tag, name))
+                {
+                    skipped_depth = skipped_depth.saturating_sub(1);
+                }
+                result.push_str(tag);
+                rest = &rest[tag_len..];
+                continue;
+            }
+            if skipped_depth == 0 {
+                if let Some((len, path, is_code)) = parse_link(rest) {
+                    if let Some(href) = self.resolve(path, current_module_info) {
+                        let _ = if is_code {
+                            write!(result, "<a href=\"{href}\"><code>{path}</code></a>")
+                        } else {
+                            write!(result, "<a href=\"{href}\">{path}</a>")
+                        };
+                        rest = &rest[len..];
+                        continue;
+                    }
+                }
+            }
+            result.push('[');
+            rest = &rest[1..];
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// Resolves a path to an item, e.g. `std::hash::sha256` or `StorageMap`, or to a method of
+    /// an item, e.g. `StorageMap::insert`, to a link relative to the current page.
+    fn resolve(&self, path: &str, current_module_info: &ModuleInfo) -> Option<String> {
+        let segments = path
+            .trim_start_matches("::")
+            .split("::")
+            .collect::<Vec<_>>();
+        if let Some(doc) = self.find(&segments, current_module_info) {
+            return Some(href(doc, current_module_info));
+        }
+        let (method, item) = segments.split_last()?;
+        let doc = self.find(item, current_module_info)?;
+        let anchor = match doc.item_header.friendly_name {
+            "trait" | "abi" => "tymethod",
+            _ => "method",
+        };
+        Some(format!(
+            "{}#{anchor}.{method}",
+            href(doc, current_module_info)
+        ))
+    }
+
+    /// Finds the item named by the last segment, within a module ending with the preceding
+    /// segments. Items of the current package take precedence.
+    fn find(&self, segments: &[&str], current_module_info: &ModuleInfo) -> Option<&'a Document> {
+        let (name, module) = segments.split_last()?;
+        let mut candidates = self.items.get(name)?.iter().copied().filter(|doc| {
+            let prefixes = &doc.module_info.module_prefixes;
+            prefixes.len() >= module.len()
+                && prefixes[prefixes.len() - module.len()..]
+                    .iter()
+                    .zip(module)
+                    .all(|(prefix, segment)| prefix.as_str() == *segment)
+        });
+        candidates
+            .clone()
+            .find(|doc| doc.module_info.project_name() == current_module_info.project_name())
+            .or_else(|| candidates.next())
+    }
+}
+
+/// The link to an item's page, relative to a page of the current module.
+fn href(doc: &Document, current_module_info: &ModuleInfo) -> String {
+    format!(
+        "{}/{}/{}",
+        current_module_info.path_to_root(),
+        doc.module_info.module_prefixes.join("/"),
+        doc.html_filename()
+    )
+}
+
+/// Parses a link at the start of `html`, rendered from ``[`path`]`` or `[path]`.
+///
+/// Returns the length of the link in `html`, the path and whether it is rendered as code.
+fn parse_link(html: &str) -> Option<(usize, &str, bool)> {
+    let (len, path, is_code) = match html.strip_prefix(CODE_OPEN) {
+        Some(rest) => {
+            let path = &rest[..rest.find(CODE_CLOSE)?];
+            (CODE_OPEN.len() + path.len() + CODE_CLOSE.len(), path, true)
+        }
+        None => {
+            let path = &html[1..html.find(']')?];
+            (path.len() + 2, path, false)
+        }
+    };
+    is_path(path).then_some((len, path, is_code))
+}
+
+/// Checks that `path` is a path of identifiers, e.g. `std::hash::Hasher`.
+fn is_path(path: &str) -> bool {
+    path.trim_start_matches("::").split("::").all(|segment| {
+        let mut chars = segment.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+fn is_opening_tag(tag: &str, name: &str) -> bool {
+    tag.strip_prefix('<')
+        .and_then(|tag| tag.strip_prefix(name))
+        .is_some_and(|rest| rest.starts_with(['>', ' ']))
+}
+
+fn is_closing_tag(tag: &str, name: &str) -> bool {
+    tag.strip_prefix("</")
+        .and_then(|tag| tag.strip_prefix(name))
+        .is_some_and(|rest| rest.starts_with('>'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntraDocLinks;
+    use crate::{
+        doc::{module::ModuleInfo, Document},
+        render::item::{
+            components::{ItemBody, ItemHeader},
+            documentable_type::DocumentableType,
+        },
+    };
+    use sway_core::TypeInfo;
+    use sway_types::BaseIdent;
+
+    fn primitive_doc(module_prefixes: &[&str], name: &str) -> Document {
+        let module_info = ModuleInfo::from_vec_str(
+            &module_prefixes
+                .iter()
+                .map(|prefix| prefix.to_string())
+                .collect::<Vec<_>>(),
+        );
+        let item_name = BaseIdent::new_no_span(name.into());
+        Document {
+            module_info: module_info.clone(),
+            item_header: ItemHeader {
+                module_info: module_info.clone(),
+                friendly_name: "primitive",
+                item_name: item_name.clone(),
+            },
+            item_body: ItemBody {
+                module_info,
+                ty: DocumentableType::Primitive(TypeInfo::Boolean),
+                item_name,
+                code_str: name.into(),
+                attrs_opt: None,
+                item_context: Default::default(),
+                source: None,
+            },
+            raw_attributes: None,
+        }
+    }
+
+    #[test]
+    fn test_intra_doc_links() {
+        let docs = [
+            primitive_doc(&["core"], "bool"),
+            primitive_doc(&["project", "module"], "bool"),
+        ];
+        let links = IntraDocLinks::new(docs.iter());
+        let current = ModuleInfo::from_vec_str(&["project".into(), "other".into()]);
+
+        assert_eq!(
+            links.render("<p>See [<code>bool</code>].</p>", &current),
+            "<p>See <a href=\"../../project/module/primitive.bool.html\"><code>bool</code></a>.</p>"
+        );
+        assert_eq!(
+            links.render("<p>[core::bool] and [bool::not]</p>", &current),
+            "<p><a href=\"../../core/primitive.bool.html\">core::bool</a> and <a href=\"../../project/module/primitive.bool.html#method.not\">bool::not</a></p>"
+        );
+        // Unresolved links, and links in code, are left as is.
+        let html = "<p>[Unknown] [u8; 32]</p><pre><code>[bool]</code></pre>";
+        assert_eq!(links.render(html, &current), html);
+    }
+}
@@ -2,3 +2,4 @@
 pub mod code_block;
 mod constant;
 pub mod docstring;
+pub(crate) mod intra_doc_link;
@@ -1,4 +1,7 @@
-use crate::doc::{module::ModuleInfo, Document, Documentation};
+use crate::{
+    doc::{module::ModuleInfo, Document, Documentation},
+    render::item::documentable_type::DocumentableType,
+};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -6,29 +9,42 @@ use std::{
     fs,
     path::Path,
 };
+use sway_core::{
+    language::ty::{FunctionDecl, TyDecl},
+    Engines, TypeInfo,
+};
 
 const JS_SEARCH_FILE_NAME: &str = "search.js";
 
+/// Searches the index for a query, returning the matching items.
+///
+/// Queries containing `->` search function signatures, e.g. `(u64, b256) -> bool` or `-> Identity`.
+/// Other queries match item names first, then argument and return types, then doc comment text.
+const JS_SEARCH_FUNCTION: &str = r#"function searchDocs(query){const q=query.trim().toLowerCase();const items=Object.values(SEARCH_INDEX).flat();if(q.includes("->")){const [args,output]=q.split("->").map(s=>s.trim());const inputs=args.replace(/^\(|\)$/g,"").split(",").map(s=>s.trim()).filter(s=>s);return items.filter(item=>{const sig=item.signature;if(!sig){return false}const sigInputs=sig.inputs.map(s=>s.toLowerCase());return inputs.every(i=>sigInputs.includes(i))&&(!output||sig.output.toLowerCase()===output)})}const rank=item=>{if(item.name.toLowerCase().includes(q)){return 0}const sig=item.signature;if(sig&&[...sig.inputs,sig.output].some(t=>t.toLowerCase()===q)){return 1}if(item.doc&&item.doc.toLowerCase().includes(q)){return 2}return 3};return items.map(item=>[rank(item),item]).filter(([r])=>r<3).sort((a,b)=>a[0]-b[0]).map(([,item])=>item)}"#;
+
 /// Creates the search index javascript file for the search bar.
-pub fn write_search_index(doc_path: &Path, docs: &Documentation) -> Result<()> {
-    let json_data = docs.to_search_index_json_value()?;
+pub fn write_search_index(doc_path: &Path, docs: &Documentation, engines: &Engines) -> Result<()> {
+    let json_data = docs.to_search_index_json_value(engines)?;
     let module_export =
         "\"object\"==typeof exports&&\"undefined\"!=typeof module&&(module.exports=SEARCH_INDEX);";
-    let js_data = format!("var SEARCH_INDEX={json_data};\n{module_export}");
+    let js_data = format!("var SEARCH_INDEX={json_data};\n{module_export}\n{JS_SEARCH_FUNCTION}");
     Ok(fs::write(doc_path.join(JS_SEARCH_FILE_NAME), js_data)?)
 }
 
 impl Documentation {
     /// Generates a mapping of program name to a vector of documentable items within the program
     /// and returns the map as a `serde_json::Value`.
-    fn to_search_index_json_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+    fn to_search_index_json_value(
+        &self,
+        engines: &Engines,
+    ) -> Result<serde_json::Value, serde_json::Error> {
         let mut map = HashMap::with_capacity(self.len());
         let mut modules = BTreeMap::new();
         for doc in self.iter() {
             let project_name = doc.module_info.project_name().to_string();
             map.entry(project_name)
                 .or_insert_with(Vec::new)
-                .push(JsonSearchItem::from(doc));
+                .push(JsonSearchItem::from_doc(doc, engines));
             modules.insert(
                 doc.module_info.module_prefixes.join("::"),
                 doc.module_info.clone(),
@@ -48,8 +64,8 @@ impl Documentation {
 }
 
 /// Item information used in the `search_pool.json`.
-/// The item name is what the fuzzy search will be
-/// matching on, all other information will be used
+/// The item name, doc comment text and function signature are what
+/// the search will be matching on, all other information will be used
 /// in generating links to the item.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 struct JsonSearchItem {
@@ -58,9 +74,43 @@ struct JsonSearchItem {
     module_info: Vec<String>,
     preview: String,
     type_name: String,
+    /// The plain text of the doc comment.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    doc: String,
+    /// The signature of a function.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<JsonFnSignature>,
 }
-impl<'a> From<&'a Document> for JsonSearchItem {
-    fn from(value: &'a Document) -> Self {
+
+/// The argument and return types of a function, as written in its declaration.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct JsonFnSignature {
+    inputs: Vec<String>,
+    output: String,
+}
+impl JsonFnSignature {
+    fn from_doc(doc: &Document, engines: &Engines) -> Option<Self> {
+        let DocumentableType::Declared(TyDecl::FunctionDecl(FunctionDecl { decl_id, .. })) =
+            &doc.item_body.ty
+        else {
+            return None;
+        };
+        let fn_decl = engines.de().get_function(decl_id);
+        let inputs = fn_decl
+            .parameters
+            .iter()
+            .map(|param| param.type_argument.span.as_str().to_string())
+            .collect();
+        let output = match &*engines.te().get(fn_decl.return_type.type_id) {
+            TypeInfo::Tuple(fields) if fields.is_empty() => "()".to_string(),
+            _ => fn_decl.return_type.span.as_str().to_string(),
+        };
+        Some(Self { inputs, output })
+    }
+}
+
+impl JsonSearchItem {
+    fn from_doc(value: &Document, engines: &Engines) -> Self {
         Self {
             name: value.item_body.item_name.to_string(),
             html_filename: value.html_filename(),
@@ -72,6 +122,12 @@ impl<'a> From<&'a Document> for JsonSearchItem {
                 .replace("<p>", "")
                 .replace("</p>", ""),
             type_name: value.item_body.ty.friendly_type_name().into(),
+            doc: value
+                .raw_attributes
+                .as_deref()
+                .map(html_to_text)
+                .unwrap_or_default(),
+            signature: JsonFnSignature::from_doc(value, engines),
         }
     }
 }
@@ -93,6 +149,48 @@ impl<'a> From<&'a ModuleInfo> for JsonSearchItem {
                 .replace("<p>", "")
                 .replace("</p>", ""),
             type_name: "module".into(),
+            doc: value
+                .attributes
+                .as_deref()
+                .map(html_to_text)
+                .unwrap_or_default(),
+            signature: None,
         }
     }
 }
+
+/// Converts rendered HTML docs to plain text for the search index, removing tags and
+/// collapsing whitespace.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::html_to_text;
+
+    #[test]
+    fn test_html_to_text() {
+        assert_eq!(
+            html_to_text("<p>Returns a <code>Vec&lt;T&gt;</code>.</p>\n<h1>Examples</h1>\n"),
+            "Returns a Vec<T>. Examples"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+contract;
+
+/// Counts the calls it gets.
+abi Counter {
+    /// Returns the current count.
+    fn count() -> u64;
+
+    /// Adds `amount` to the count.
+    fn increment(amount: u64);
+}
+
+storage {
+    /// The current count.
+    count: u64 = 0,
+    /// The owner, stored at a fixed slot.
+    owner in 0x0000000000000000000000000000000000000000000000000000000000000001: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000,
+    stats {
+        /// The number of increments.
+        increments: u64 = 0,
+    },
+}
+
+impl Counter for Contract {
+    fn count() -> u64 {
+        0
+    }
+
+    fn increment(_amount: u64) {}
+}
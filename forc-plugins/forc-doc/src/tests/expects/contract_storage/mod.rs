@@ -0,0 +1,58 @@
+#![cfg(test)]
+use crate::{
+    cli::Command,
+    compile_html,
+    tests::expects::{check_file, get_doc_dir},
+};
+use expect_test::expect;
+use std::path::PathBuf;
+
+const DATA_DIR: &str = "src/tests/data";
+
+#[test]
+fn test_contract_storage_and_abi() {
+    let doc_dir_name: &str = "contract_storage";
+    let project_name = "contract_storage";
+    let command = Command {
+        path: Some(format!("{}/{}", DATA_DIR, project_name)),
+        doc_path: Some(doc_dir_name.into()),
+        no_deps: true,
+        ..Default::default()
+    };
+    let (doc_path, _) = compile_html(&command, &get_doc_dir).unwrap();
+
+    // The storage fields list the keys of their slots.
+    check_file(
+        &doc_path,
+        &PathBuf::from(format!("{project_name}/contract_storage.html")),
+        &expect![[r##"
+            <!DOCTYPE html><html><head><meta charset="utf-8"><meta name="viewport" content="width=device-width, initial-scale=1.0"><meta name="generator" content="swaydoc"><meta name="description" content="API documentation for the Sway `Contract Storage` contract storage in `contract_storage`."><meta name="keywords" content="sway, swaylang, sway-lang, Contract Storage"><link rel="icon" href="../static.files/sway-logo.svg"><title>Contract Storage in contract_storage - Sway</title><link rel="stylesheet" type="text/css" href="../static.files/normalize.css"><link rel="stylesheet" type="text/css" href="../static.files/swaydoc.css" id="mainThemeStyle"><link rel="stylesheet" type="text/css" href="../static.files/ayu.css"><link rel="stylesheet" href="../static.files/ayu.min.css"></head><body class="swaydoc contract_storage"><nav class="sidebar"><a class="sidebar-logo" href="../contract_storage/index.html"><div class="logo-container"><img class="sway-logo" src="../static.files/sway-logo.svg" alt="logo"></div></a><h2 class="location">Contract Storage Contract Storage</h2><div class="sidebar-elems"><section><h3><a href="#fields">Fields</a></h3><ul class="block method"><li><a href="#storagefield.count">count</a></li><li><a href="#storagefield.owner">owner</a></li><li><a href="#storagefield.increments">increments</a></li></ul></section></div></nav><main><div class="width-limiter"><script src="../search.js" type="text/javascript"></script><script>function onSearchFormSubmit(event){event.preventDefault();const searchQuery=document.getElementById("search-input").value;const url=new URL(window.location.href);if(searchQuery){url.searchParams.set('search',searchQuery)}else{url.searchParams.delete('search')}history.pushState({search:searchQuery},"",url);window.dispatchEvent(new HashChangeEvent("hashchange"))}document.addEventListener('DOMContentLoaded',()=>{const searchbar=document.getElementById("search-input");const searchForm=document.getElementById("search-form");searchbar.addEventListener("keyup",function(event){onSearchFormSubmit(event)});searchbar.addEventListener("search",function(event){onSearchFormSubmit(event)});function onQueryParamsChange(){const searchParams=new URLSearchParams(window.location.search);const query=searchParams.get("search");const searchSection=document.getElementById('search');const mainSection=document.getElementById('main-content');const searchInput=document.getElementById('search-input');if(query){searchInput.value=query;const results=searchDocs(query);const header=`<h1>Results for ${query}</h1>`;if(results.length>0){const resultList=results.map(item=>{const formattedName=`<span class="type ${item.type_name}">${item.name}</span>`;const name=item.type_name==="module"?[...item.module_info.slice(0,-1),formattedName].join("::"):[...item.module_info,formattedName].join("::");const path=["..",...item.module_info,item.html_filename].join("/");const left=`<td><span>${name}</span></td>`;const right=`<td><p>${item.preview}</p></td>`;return`<tr onclick="window.location='${path}';">${left}${right}</tr>`}).join('');searchSection.innerHTML=`${header}<table>${resultList}</table>`}else{searchSection.innerHTML=`${header}<p>No results found.</p>`}searchSection.setAttribute("class","search-results");mainSection.setAttribute("class","content hidden")}else{searchSection.setAttribute("class","search-results hidden");mainSection.setAttribute("class","content")}}window.addEventListener('hashchange',onQueryParamsChange);onQueryParamsChange()})</script><nav class="sub"><form id="search-form" class="search-form" onsubmit="onSearchFormSubmit(event)"><div class="search-container"><input id="search-input" class="search-input" name="search" autocomplete="off" spellcheck="false" placeholder="Search the docs..." type="search"></div></form></nav><section id="main-content" class="content"><div class="main-heading"><h1 class="fqn"><span class="in-band">Contract Storage <a class="mod" href="index.html">contract_storage</a><span>::</span><a class="contract_storage" href="#">Contract Storage</a></span><span class="out-of-band"><a class="srclink" href="../src/contract_storage/main.sw.html#L12">source</a></span></h1></div><div class="docblock item-decl"><pre class="sway contract_storage"><code>storage {
+                /// The current count.
+                count: u64 = 0,
+                /// The owner, stored at a fixed slot.
+                owner in 0x0000000000000000000000000000000000000000000000000000000000000001: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000,
+                stats {
+                    /// The number of increments.
+                    increments: u64 = 0,
+                },
+            }</code></pre></div><h2 id="fields" class="fields small-section-header">Fields<a class="anchor" href="#fields"></a></h2><span id="storagefield.count" class="storagefield small-section-header"><a class="anchor field" href="#storagefield.count"></a><code>count: u64</code></span><div class="item-info"><span class="storage-slot">Slot: <code>0xb2acc2f4f92a1bcad651875271c1e0baf81414efd8884327e48f180a97fd7eb6</code></span></div><div class="docblock"><p>The current count.</p>
+            </div><span id="storagefield.owner" class="storagefield small-section-header"><a class="anchor field" href="#storagefield.owner"></a><code>owner: b256</code></span><div class="item-info"><span class="storage-slot">Slot: <code>0x0000000000000000000000000000000000000000000000000000000000000001</code></span></div><div class="docblock"><p>The owner, stored at a fixed slot.</p>
+            </div><span id="storagefield.increments" class="storagefield small-section-header"><a class="anchor field" href="#storagefield.increments"></a><code>increments: u64</code></span><div class="item-info"><span class="storage-slot">Slot: <code>0xd47c234223984f143b40f732a6c0796655c19687ef222af2306a7a9a7f943a69</code></span></div><div class="docblock"><p>The number of increments.</p>
+            </div></section><section id="search" class="search-results"></section></div></main><script src="../static.files/highlight.js"></script><script>hljs.highlightAll();</script></body></html>"##]],
+    );
+    // The ABI methods list how contract calls identify them.
+    check_file(
+        &doc_path,
+        &PathBuf::from(format!("{project_name}/abi.Counter.html")),
+        &expect![[r##"
+            <!DOCTYPE html><html><head><meta charset="utf-8"><meta name="viewport" content="width=device-width, initial-scale=1.0"><meta name="generator" content="swaydoc"><meta name="description" content="API documentation for the Sway `Counter` abi in `contract_storage`."><meta name="keywords" content="sway, swaylang, sway-lang, Counter"><link rel="icon" href="../static.files/sway-logo.svg"><title>Counter in contract_storage - Sway</title><link rel="stylesheet" type="text/css" href="../static.files/normalize.css"><link rel="stylesheet" type="text/css" href="../static.files/swaydoc.css" id="mainThemeStyle"><link rel="stylesheet" type="text/css" href="../static.files/ayu.css"><link rel="stylesheet" href="../static.files/ayu.min.css"></head><body class="swaydoc abi"><nav class="sidebar"><a class="sidebar-logo" href="../contract_storage/index.html"><div class="logo-container"><img class="sway-logo" src="../static.files/sway-logo.svg" alt="logo"></div></a><h2 class="location">Abi Counter</h2><div class="sidebar-elems"><section><h3><a href="#required-methods">Required Methods</a></h3><ul class="block method"><li><a href="#structfield.count">count</a></li><li><a href="#structfield.increment">increment</a></li></ul></section></div></nav><main><div class="width-limiter"><script src="../search.js" type="text/javascript"></script><script>function onSearchFormSubmit(event){event.preventDefault();const searchQuery=document.getElementById("search-input").value;const url=new URL(window.location.href);if(searchQuery){url.searchParams.set('search',searchQuery)}else{url.searchParams.delete('search')}history.pushState({search:searchQuery},"",url);window.dispatchEvent(new HashChangeEvent("hashchange"))}document.addEventListener('DOMContentLoaded',()=>{const searchbar=document.getElementById("search-input");const searchForm=document.getElementById("search-form");searchbar.addEventListener("keyup",function(event){onSearchFormSubmit(event)});searchbar.addEventListener("search",function(event){onSearchFormSubmit(event)});function onQueryParamsChange(){const searchParams=new URLSearchParams(window.location.search);const query=searchParams.get("search");const searchSection=document.getElementById('search');const mainSection=document.getElementById('main-content');const searchInput=document.getElementById('search-input');if(query){searchInput.value=query;const results=searchDocs(query);const header=`<h1>Results for ${query}</h1>`;if(results.length>0){const resultList=results.map(item=>{const formattedName=`<span class="type ${item.type_name}">${item.name}</span>`;const name=item.type_name==="module"?[...item.module_info.slice(0,-1),formattedName].join("::"):[...item.module_info,formattedName].join("::");const path=["..",...item.module_info,item.html_filename].join("/");const left=`<td><span>${name}</span></td>`;const right=`<td><p>${item.preview}</p></td>`;return`<tr onclick="window.location='${path}';">${left}${right}</tr>`}).join('');searchSection.innerHTML=`${header}<table>${resultList}</table>`}else{searchSection.innerHTML=`${header}<p>No results found.</p>`}searchSection.setAttribute("class","search-results");mainSection.setAttribute("class","content hidden")}else{searchSection.setAttribute("class","search-results hidden");mainSection.setAttribute("class","content")}}window.addEventListener('hashchange',onQueryParamsChange);onQueryParamsChange()})</script><nav class="sub"><form id="search-form" class="search-form" onsubmit="onSearchFormSubmit(event)"><div class="search-container"><input id="search-input" class="search-input" name="search" autocomplete="off" spellcheck="false" placeholder="Search the docs..." type="search"></div></form></nav><section id="main-content" class="content"><div class="main-heading"><h1 class="fqn"><span class="in-band">Abi <a class="mod" href="index.html">contract_storage</a><span>::</span><a class="abi" href="#">Counter</a></span><span class="out-of-band"><a class="srclink" href="../src/contract_storage/main.sw.html#L4">source</a></span></h1></div><div class="docblock item-decl"><pre class="sway abi"><code>abi Counter {
+                /// Returns the current count.
+                fn count() -&gt; u64;
+                /// Adds `amount` to the count.
+                fn increment(amount: u64);
+            }</code></pre></div><details class="swaydoc-toggle top-doc" open><summary class="hideme"><span>Expand description</span></summary><div class="docblock"><p>Counts the calls it gets.</p>
+            </div></details><h2 id="required-methods" class="required-methods small-section-header">Required Methods<a class="anchor" href="#required-methods"></a></h2><div class="methods"><details class="swaydoc-toggle open"><summary><div id="tymethod.count" class="method has-srclink"><a href="#tymethod.count" class="anchor"></a><h4 class="code-header">fn <a class="fnname" href="#tymethod.count">count</a>() -&gt; u64</h4><div class="item-info"><span class="selector">Encoded name: <code>0x0000000000000005636f756e74</code></span></div></div></summary><div class="docblock"><p>Returns the current count.</p>
+            </div></details><details class="swaydoc-toggle open"><summary><div id="tymethod.increment" class="method has-srclink"><a href="#tymethod.increment" class="anchor"></a><h4 class="code-header">fn <a class="fnname" href="#tymethod.increment">increment</a>(amount: u64)</h4><div class="item-info"><span class="selector">Encoded name: <code>0x0000000000000009696e6372656d656e74</code></span></div></div></summary><div class="docblock"><p>Adds <code>amount</code> to the count.</p>
+            </div></details></div></section><section id="search" class="search-results"></section></div></main><script src="../static.files/highlight.js"></script><script>hljs.highlightAll();</script></body></html>"##]],
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(test)]
+use crate::{
+    cli::{Command, OutputFormat},
+    compile_html,
+    json::{JsonDocumentation, JsonItemKind, FORMAT_VERSION},
+    tests::expects::get_doc_dir,
+};
+use dir_indexer::get_relative_file_paths_set;
+use std::{collections::HashSet, path::PathBuf};
+
+const DATA_DIR: &str = "src/tests/data";
+
+#[test]
+fn test_json_output() {
+    let doc_dir_name: &str = "json_output";
+    let project_name = "impl_traits_clone";
+    let command = Command {
+        path: Some(format!("{}/{}", DATA_DIR, project_name)),
+        doc_path: Some(doc_dir_name.into()),
+        no_deps: true,
+        output_format: OutputFormat::Json,
+        ..Default::default()
+    };
+    let (doc_path, _) = compile_html(&command, &get_doc_dir).unwrap();
+
+    // Only the JSON file is written, no HTML pages or search index.
+    assert_eq!(
+        get_relative_file_paths_set(doc_path.clone()),
+        HashSet::from([PathBuf::from("impl_traits_clone.json")])
+    );
+
+    let json_data = std::fs::read_to_string(doc_path.join("impl_traits_clone.json")).unwrap();
+    let json_docs: JsonDocumentation = serde_json::from_str(&json_data).unwrap();
+    assert_eq!(json_docs.format_version, FORMAT_VERSION);
+    assert_eq!(json_docs.name, project_name);
+    assert_eq!(
+        json_docs
+            .modules
+            .iter()
+            .map(|module| module.path.join("::"))
+            .collect::<Vec<_>>(),
+        vec![
+            "impl_traits_clone",
+            "impl_traits_clone::foo",
+            "impl_traits_clone::bar"
+        ]
+    );
+
+    let foo = json_docs
+        .items
+        .iter()
+        .find(|item| item.name == "Foo")
+        .unwrap();
+    assert_eq!(foo.module, vec!["impl_traits_clone", "foo"]);
+    let JsonItemKind::Trait {
+        required_methods, ..
+    } = &foo.inner
+    else {
+        panic!("expected `Foo` to be a trait");
+    };
+    assert_eq!(required_methods.len(), 1);
+    assert_eq!(required_methods[0].name, "foo");
+    assert_eq!(required_methods[0].docs.trim(), "something about foo...");
+    assert_eq!(required_methods[0].output, "()");
+
+    let bar = json_docs
+        .items
+        .iter()
+        .find(|item| item.name == "Bar")
+        .unwrap();
+    assert_eq!(bar.declaration, "pub struct Bar {}");
+    let source = bar.source.as_ref().unwrap();
+    assert_eq!((source.file.as_str(), source.line), ("bar.sw", 6));
+    let JsonItemKind::Struct { impls, .. } = &bar.inner else {
+        panic!("expected `Bar` to be a struct");
+    };
+    // The inherent implementation comes first.
+    assert_eq!(impls[0].trait_path, None);
+    assert_eq!(impls[0].methods[0].name, "foo_bar");
+    let foo_impl = impls
+        .iter()
+        .find(|imp| imp.trait_path.as_deref() == Some("impl_traits_clone::foo::Foo"))
+        .unwrap();
+    assert_eq!(
+        foo_impl.methods[0].docs.trim(),
+        "something more about foo();"
+    );
+}
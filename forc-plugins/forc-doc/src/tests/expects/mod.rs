@@ -3,7 +3,9 @@ use crate::cli::Command;
 use expect_test::Expect;
 use std::path::{Path, PathBuf};
 
+mod contract_storage;
 mod impl_trait;
+mod json;
 
 pub(crate) fn check_file(doc_path: &Path, path_to_file: &PathBuf, expect: &Expect) {
     let path = doc_path.join(path_to_file);
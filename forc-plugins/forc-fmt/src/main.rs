@@ -1,18 +1,27 @@
 //! A `forc` plugin for running the Sway code formatter.
 
-use anyhow::{bail, Result};
-use clap::Parser;
+use anyhow::{anyhow, bail, Result};
+use clap::{Parser, ValueEnum};
 use forc_pkg::{
     manifest::{GenericManifestFile, ManifestFile},
     WorkspaceManifestFile,
 };
-use forc_tracing::{init_tracing_subscriber, println_error, println_green, println_red};
+use forc_tracing::{
+    init_tracing_subscriber, println_error, println_green, println_red, TracingSubscriberOptions,
+    TracingWriterMode,
+};
 use forc_util::fs_locking::PidFileLocking;
-use prettydiff::{basic::DiffOp, diff_lines};
+use prettydiff::{
+    basic::{diff, DiffOp},
+    diff_lines,
+};
+use serde::Serialize;
 use std::{
     default::Default,
     fs,
+    io::{self, Read},
     path::{Path, PathBuf},
+    str::FromStr,
     sync::Arc,
 };
 use sway_utils::{constants, find_parent_manifest_dir, get_sway_files, is_sway_file};
@@ -28,6 +37,10 @@ forc_util::cli_examples! {
         [ Run formatter against a given file with short format => "forc fmt -f {path}/src/main.sw"]
         [ Run formatter against a given dir => "forc fmt --path {path}"]
         [ Run formatter against a given dir with short format => "forc fmt -p {path}"]
+        [ Format Sway code read from stdin and print it to stdout => "forc fmt -"]
+        [ Format only a range of lines of a given file => "forc fmt --lines {path}/src/main.sw:10-20"]
+        [ Print the formatting changes as JSON without touching the files => "forc fmt --emit json-diff"]
+        [ List the files changed by the formatter => "forc fmt --check --files-with-diff"]
     }
 }
 
@@ -55,19 +68,204 @@ pub struct App {
     /// If not specified, current working directory will be formatted using a Forc.toml
     /// configuration.
     pub file: Option<String>,
+    /// Pass `-` to read Sway code from stdin and write the formatted code to stdout.
+    ///
+    /// The formatter configuration is looked up from `--path`, or the current working
+    /// directory.
+    #[clap(value_name = "-", value_parser = ["-"], conflicts_with = "file")]
+    pub stdin: Option<String>,
+    /// Only format the given range of lines of a file, e.g. `src/main.sw:10-20`.
+    ///
+    /// Lines are 1-based and the range is inclusive. Changes touching the range are
+    /// applied, the rest of the file is left as is. Can be repeated. If no `--file` is
+    /// given, only the listed files are formatted. Use `-` as the file to restrict
+    /// formatting of stdin.
+    #[clap(long, value_name = "FILE:START-END")]
+    pub lines: Vec<LineRange>,
+    /// Where the formatted code is emitted.
+    #[clap(long, value_enum, default_value_t = Emit::Files)]
+    pub emit: Emit,
+    /// Print the paths of the files changed by the formatter, one per line.
+    #[clap(short = 'l', long)]
+    pub files_with_diff: bool,
+}
+
+/// Where the formatted code is emitted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Emit {
+    /// Write the formatted code back to the files.
+    #[default]
+    Files,
+    /// Print the formatted code to stdout, leaving the files untouched.
+    Stdout,
+    /// Print the changes as JSON to stdout, leaving the files untouched.
+    JsonDiff,
+}
+
+/// A range of lines of a file, given as `FILE:START-END`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineRange {
+    pub file: PathBuf,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl FromStr for LineRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid line range '{s}', expected `FILE:START-END`");
+        let (file, range) = s.rsplit_once(':').ok_or_else(invalid)?;
+        let (start, end) = range.split_once('-').ok_or_else(invalid)?;
+        let start: usize = start.trim().parse().map_err(|_| invalid())?;
+        let end: usize = end.trim().parse().map_err(|_| invalid())?;
+        if file.is_empty() || start == 0 || start > end {
+            return Err(invalid());
+        }
+        Ok(LineRange {
+            file: PathBuf::from(file),
+            start,
+            end,
+        })
+    }
+}
+
+/// A file whose content is changed by the formatter, as emitted by `--emit json-diff`.
+#[derive(Debug, Serialize)]
+struct FileDiff {
+    name: String,
+    mismatches: Vec<Mismatch>,
+}
+
+/// A run of changed lines. Line numbers are 1-based and inclusive, so a pure insertion
+/// has an `original_end_line` one less than its `original_begin_line`.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+struct Mismatch {
+    original_begin_line: usize,
+    original_end_line: usize,
+    expected_begin_line: usize,
+    expected_end_line: usize,
+    original: String,
+    expected: String,
+}
+
+/// A run of lines of the original content, and the lines of the formatted content
+/// that take its place.
+struct Hunk<'a> {
+    original_begin_line: usize,
+    original: &'a [&'a str],
+    expected_begin_line: usize,
+    expected: &'a [&'a str],
+}
+
+impl Hunk<'_> {
+    fn is_change(&self) -> bool {
+        self.original != self.expected
+    }
+}
+
+/// Splits `original` and `expected` into the hunks of their line diff. Lines keep
+/// their line endings, so concatenating the hunks gives back the content.
+fn hunks<'a>(original: &'a [&'a str], expected: &'a [&'a str]) -> Vec<Hunk<'a>> {
+    let mut original_line = 1;
+    let mut expected_line = 1;
+    diff(original, expected)
+        .into_iter()
+        .map(|op| {
+            let (original, expected): (&[&str], &[&str]) = match op {
+                DiffOp::Equal(lines) => (lines, lines),
+                DiffOp::Insert(new) => (&[], new),
+                DiffOp::Remove(old) => (old, &[]),
+                DiffOp::Replace(old, new) => (old, new),
+            };
+            let hunk = Hunk {
+                original_begin_line: original_line,
+                original,
+                expected_begin_line: expected_line,
+                expected,
+            };
+            original_line += original.len();
+            expected_line += expected.len();
+            hunk
+        })
+        .collect()
+}
+
+impl FileDiff {
+    fn new(name: String, original: &str, formatted: &str) -> Self {
+        let original: Vec<&str> = original.split_inclusive('\n').collect();
+        let formatted: Vec<&str> = formatted.split_inclusive('\n').collect();
+        let mismatches = hunks(&original, &formatted)
+            .into_iter()
+            .filter(|hunk| hunk.is_change())
+            .map(|hunk| Mismatch {
+                original_begin_line: hunk.original_begin_line,
+                original_end_line: hunk.original_begin_line + hunk.original.len() - 1,
+                expected_begin_line: hunk.expected_begin_line,
+                expected_end_line: hunk.expected_begin_line + hunk.expected.len() - 1,
+                original: hunk.original.concat(),
+                expected: hunk.expected.concat(),
+            })
+            .collect();
+        FileDiff { name, mismatches }
+    }
+}
+
+/// Applies the changes of `formatted` that touch one of the given inclusive line
+/// `ranges` to `original`, and keeps the original lines everywhere else.
+fn format_lines(original: &str, formatted: &str, ranges: &[(usize, usize)]) -> String {
+    let original: Vec<&str> = original.split_inclusive('\n').collect();
+    let formatted: Vec<&str> = formatted.split_inclusive('\n').collect();
+    let mut result = String::new();
+    for hunk in hunks(&original, &formatted) {
+        let begin = hunk.original_begin_line;
+        let end = begin + hunk.original.len();
+        let touches_range = ranges.iter().any(|&(start, last)| {
+            if hunk.original.is_empty() {
+                // Lines inserted before `begin`.
+                start <= begin && begin <= last
+            } else {
+                begin <= last && start < end
+            }
+        });
+        let lines = if touches_range {
+            hunk.expected
+        } else {
+            hunk.original
+        };
+        result.extend(lines.iter().copied());
+    }
+    result
 }
 
 fn main() {
-    init_tracing_subscriber(Default::default());
-    if let Err(err) = run() {
+    let app = App::parse();
+    // Keep stdout clean for the formatted code or the list of changes, if any.
+    let writer_mode = if app.stdin.is_some() || app.emit != Emit::Files || app.files_with_diff {
+        TracingWriterMode::Stderr
+    } else {
+        TracingWriterMode::Stdio
+    };
+    init_tracing_subscriber(TracingSubscriberOptions {
+        writer_mode: Some(writer_mode),
+        ..Default::default()
+    });
+    if let Err(err) = run(app) {
         println_error("Formatting skipped due to error.");
         println_error(&format!("{}", err));
         std::process::exit(1);
     }
 }
 
-fn run() -> Result<()> {
-    let app = App::parse();
+fn run(mut app: App) -> Result<()> {
+    for range in app.lines.iter_mut() {
+        if range.file != Path::new("-") {
+            range.file = range
+                .file
+                .canonicalize()
+                .map_err(|err| anyhow!("Invalid file '{}': {}", range.file.display(), err))?;
+        }
+    }
 
     let dir = match app.path.as_ref() {
         Some(path) => PathBuf::from(path),
@@ -75,29 +273,56 @@ fn run() -> Result<()> {
     };
 
     let mut formatter = Formatter::from_dir(&dir)?;
-    if let Some(f) = app.file.as_ref() {
+    let mut changes = vec![];
+    if app.stdin.is_some() {
+        format_stdin(&app, &mut formatter, &mut changes)?;
+    } else if let Some(f) = app.file.as_ref() {
         let file_path = &PathBuf::from(f);
 
-        if is_sway_file(file_path) {
-            format_file(&app, file_path.to_path_buf(), &mut formatter)?;
-            return Ok(());
+        if !is_sway_file(file_path) {
+            bail!(
+                "Provided file '{}' is not a valid Sway file",
+                file_path.display()
+            );
         }
-
-        bail!(
-            "Provided file '{}' is not a valid Sway file",
-            file_path.display()
-        );
-    };
-
-    let manifest_file = forc_pkg::manifest::ManifestFile::from_dir(&dir)?;
-    match manifest_file {
-        ManifestFile::Workspace(ws) => {
-            format_workspace_at_dir(&app, &ws, &dir)?;
+        format_file(&app, file_path.to_path_buf(), &mut formatter, &mut changes)?;
+    } else if !app.lines.is_empty() {
+        let mut files: Vec<PathBuf> = app.lines.iter().map(|range| range.file.clone()).collect();
+        files.sort();
+        files.dedup();
+        for file in files {
+            if !is_sway_file(&file) {
+                bail!(
+                    "Provided file '{}' is not a valid Sway file",
+                    file.display()
+                );
+            }
+            format_file(&app, file, &mut formatter, &mut changes)?;
         }
-        ManifestFile::Package(_) => {
-            format_pkg_at_dir(&app, &dir, &mut formatter)?;
+    } else {
+        let manifest_file = forc_pkg::manifest::ManifestFile::from_dir(&dir)?;
+        match manifest_file {
+            ManifestFile::Workspace(ws) => {
+                format_workspace_at_dir(&app, &ws, &dir, &mut changes)?;
+            }
+            ManifestFile::Package(_) => {
+                format_pkg_at_dir(&app, &dir, &mut formatter, &mut changes)?;
+            }
         }
     }
+
+    if app.emit == Emit::JsonDiff {
+        println!("{}", serde_json::to_string_pretty(&changes)?);
+    }
+    if app.files_with_diff {
+        for file_diff in &changes {
+            println!("{}", file_diff.name);
+        }
+    }
+    if app.check && !changes.is_empty() {
+        // One or more files are not formatted, exit with error
+        bail!("Files contain formatting violations.");
+    }
     Ok(())
 }
 
@@ -133,12 +358,88 @@ fn get_sway_dirs(workspace_dir: PathBuf) -> Vec<PathBuf> {
     dirs_to_format
 }
 
-/// Format a file, given its path.
-/// Returns:
-/// - Ok(true) if executed successfully and formatted,
-/// - Ok(false) if executed successfully and not formatted,
-/// - Err if it fails to execute at all.
-fn format_file(app: &App, file: PathBuf, formatter: &mut Formatter) -> Result<bool> {
+/// Returns the line ranges given with `--lines` for `file`, or `None` if the whole
+/// file is to be formatted.
+fn line_ranges(app: &App, file: &Path) -> Option<Vec<(usize, usize)>> {
+    if app.lines.is_empty() {
+        return None;
+    }
+    Some(
+        app.lines
+            .iter()
+            .filter(|range| range.file == file)
+            .map(|range| (range.start, range.end))
+            .collect(),
+    )
+}
+
+/// Emits the formatted content of a file according to `app`, and records it in
+/// `changes` if it differs from the original content.
+fn emit_formatted(
+    app: &App,
+    name: &str,
+    file: Option<&Path>,
+    original: &str,
+    formatted: &str,
+    changes: &mut Vec<FileDiff>,
+) -> Result<()> {
+    let edited = original != formatted;
+    if edited {
+        changes.push(FileDiff::new(name.to_string(), original, formatted));
+    }
+
+    if app.check {
+        if edited && app.emit != Emit::JsonDiff {
+            info!("File was edited by formatter: \n{:?}\n", name);
+            display_file_diff(original, formatted)?;
+        }
+        return Ok(());
+    }
+
+    match (app.emit, file) {
+        (Emit::Files, Some(file)) => {
+            if edited {
+                write_file_formatted(file, formatted)?;
+            }
+        }
+        (Emit::Files, None) | (Emit::Stdout, None) => print!("{formatted}"),
+        (Emit::Stdout, Some(_)) => {
+            println!("{name}:\n");
+            print!("{formatted}");
+        }
+        (Emit::JsonDiff, _) => {}
+    }
+
+    Ok(())
+}
+
+/// Format the Sway code read from stdin, and emit it to stdout unless checking.
+fn format_stdin(app: &App, formatter: &mut Formatter, changes: &mut Vec<FileDiff>) -> Result<()> {
+    let mut content = String::new();
+    io::stdin().read_to_string(&mut content)?;
+    let content: Arc<str> = Arc::from(content);
+    let formatted_content = match Formatter::format(formatter, content.clone()) {
+        Ok(formatted_content) => formatted_content,
+        Err(err) => {
+            debug!("{}", err);
+            bail!("Failed to compile stdin\n{}", err);
+        }
+    };
+    let formatted_content = match line_ranges(app, Path::new("-")) {
+        Some(ranges) => format_lines(&content, &formatted_content, &ranges),
+        None => formatted_content,
+    };
+    emit_formatted(app, "<stdin>", None, &content, &formatted_content, changes)
+}
+
+/// Format a file, given its path, recording it in `changes` if it is edited.
+/// Returns an error if formatting fails to execute at all.
+fn format_file(
+    app: &App,
+    file: PathBuf,
+    formatter: &mut Formatter,
+    changes: &mut Vec<FileDiff>,
+) -> Result<()> {
     let file = file.canonicalize()?;
     if is_file_dirty(&file) {
         bail!(
@@ -149,21 +450,21 @@ fn format_file(app: &App, file: PathBuf, formatter: &mut Formatter) -> Result<bo
         );
     }
     if let Ok(file_content) = fs::read_to_string(&file) {
-        let mut edited = false;
         let file_content: Arc<str> = Arc::from(file_content);
         match Formatter::format(formatter, file_content.clone()) {
             Ok(formatted_content) => {
-                if app.check {
-                    if *file_content != formatted_content {
-                        info!("File was edited by formatter: \n{:?}\n", file);
-                        display_file_diff(&file_content, &formatted_content)?;
-                        edited = true;
-                    }
-                } else {
-                    write_file_formatted(&file, &formatted_content)?;
-                }
-
-                return Ok(edited);
+                let formatted_content = match line_ranges(app, &file) {
+                    Some(ranges) => format_lines(&file_content, &formatted_content, &ranges),
+                    None => formatted_content,
+                };
+                return emit_formatted(
+                    app,
+                    &file.display().to_string(),
+                    Some(&file),
+                    &file_content,
+                    &formatted_content,
+                    changes,
+                );
             }
             Err(err) => {
                 // TODO: Support formatting for incomplete/invalid sway code.
@@ -182,8 +483,12 @@ fn format_file(app: &App, file: PathBuf, formatter: &mut Formatter) -> Result<bo
 }
 
 /// Format the workspace at the given directory.
-fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &Path) -> Result<()> {
-    let mut contains_edits = false;
+fn format_workspace_at_dir(
+    app: &App,
+    workspace: &WorkspaceManifestFile,
+    dir: &Path,
+    changes: &mut Vec<FileDiff>,
+) -> Result<()> {
     let mut formatter = Formatter::from_dir(dir)?;
     let mut members = vec![];
 
@@ -197,7 +502,7 @@ fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &P
         for entry in read_dir.filter_map(|res| res.ok()) {
             let path = entry.path();
             if is_sway_file(&path) {
-                format_file(app, path, &mut formatter)?;
+                format_file(app, path, &mut formatter, changes)?;
             }
         }
     }
@@ -213,78 +518,67 @@ fn format_workspace_at_dir(app: &App, workspace: &WorkspaceManifestFile, dir: &P
             // In order of priority: member > workspace > default.
             formatter = Formatter::from_dir(&sub_dir)?;
         }
-        format_pkg_at_dir(app, &sub_dir, &mut formatter)?;
+        format_pkg_at_dir(app, &sub_dir, &mut formatter, changes)?;
     }
 
     let manifest_file = dir.join(constants::MANIFEST_FILE_NAME);
 
     // Finally, format the root manifest using taplo formatter
-    contains_edits |= format_manifest(app, manifest_file)?;
-
-    if app.check && contains_edits {
-        // One or more files are not formatted, exit with error
-        bail!("Files contain formatting violations.");
-    }
-
-    Ok(())
+    format_manifest(app, manifest_file, changes)
 }
 
-/// Format the given manifest at a path.
-/// Returns:
-/// - Ok(true) if executed successfully and formatted,
-/// - Ok(false) if executed successfully and not formatted,
-/// - Err if it fails to execute at all.
-fn format_manifest(app: &App, manifest_file: PathBuf) -> Result<bool> {
+/// Format the given manifest at a path, recording it in `changes` if it is edited.
+/// Returns an error if formatting fails to execute at all.
+fn format_manifest(app: &App, manifest_file: PathBuf, changes: &mut Vec<FileDiff>) -> Result<()> {
     if let Ok(manifest_content) = fs::read_to_string(&manifest_file) {
-        let mut edited = false;
         let taplo_alphabetize = taplo_fmt::Options {
             reorder_keys: true,
             ..Default::default()
         };
         let formatted_content = taplo_fmt::format(&manifest_content, taplo_alphabetize);
-        if !app.check {
-            write_file_formatted(&manifest_file, &formatted_content)?;
-        } else if formatted_content != manifest_content {
-            edited = true;
-            println_error(&format!(
-                "Improperly formatted manifest file: {}",
-                manifest_file.display()
-            ));
-            display_file_diff(&manifest_content, &formatted_content)?;
-        } else {
+        if formatted_content == manifest_content {
             info!(
                 "Manifest Forc.toml formatted correctly: {}",
                 manifest_file.display()
             )
+        } else if app.check && app.emit != Emit::JsonDiff {
+            println_error(&format!(
+                "Improperly formatted manifest file: {}",
+                manifest_file.display()
+            ));
         }
 
-        return Ok(edited);
+        return emit_formatted(
+            app,
+            &manifest_file.display().to_string(),
+            Some(&manifest_file),
+            &manifest_content,
+            &formatted_content,
+            changes,
+        );
     };
 
     bail!("failed to format manifest: {:?}", manifest_file)
 }
 
 /// Format the package at the given directory.
-fn format_pkg_at_dir(app: &App, dir: &Path, formatter: &mut Formatter) -> Result<()> {
+fn format_pkg_at_dir(
+    app: &App,
+    dir: &Path,
+    formatter: &mut Formatter,
+    changes: &mut Vec<FileDiff>,
+) -> Result<()> {
     match find_parent_manifest_dir(dir) {
         Some(path) => {
             let manifest_path = path.clone();
             let manifest_file = manifest_path.join(constants::MANIFEST_FILE_NAME);
             let files = get_sway_files(path);
-            let mut contains_edits = false;
 
             for file in files {
-                contains_edits |= format_file(app, file, formatter)?;
+                format_file(app, file, formatter, changes)?;
             }
             // format manifest using taplo formatter
-            contains_edits |= format_manifest(app, manifest_file)?;
-
-            if app.check && contains_edits {
-                // One or more files are not formatted, exit with error
-                bail!("Files contain formatting violations.");
-            }
-
-            Ok(())
+            format_manifest(app, manifest_file, changes)
         }
         _ => bail!("Manifest file does not exist"),
     }
@@ -338,8 +632,53 @@ fn write_file_formatted(file: &Path, formatted_content: &str) -> Result<()> {
 
 #[cfg(test)]
 mod tests {
-    use super::taplo_fmt;
-    use std::default::Default;
+    use super::{format_lines, taplo_fmt, FileDiff, LineRange, Mismatch};
+    use std::{default::Default, path::PathBuf};
+
+    #[test]
+    fn test_line_range_parsing() {
+        assert_eq!(
+            "src/main.sw:10-20".parse::<LineRange>(),
+            Ok(LineRange {
+                file: PathBuf::from("src/main.sw"),
+                start: 10,
+                end: 20,
+            })
+        );
+        assert!("src/main.sw".parse::<LineRange>().is_err());
+        assert!("src/main.sw:10".parse::<LineRange>().is_err());
+        assert!("src/main.sw:0-2".parse::<LineRange>().is_err());
+        assert!("src/main.sw:3-2".parse::<LineRange>().is_err());
+        assert!(":1-2".parse::<LineRange>().is_err());
+    }
+
+    #[test]
+    fn test_format_lines() {
+        let original = "a  \nb\nc  \nd\n";
+        let formatted = "a\nb\nc\nd\n";
+        assert_eq!(format_lines(original, formatted, &[]), original);
+        assert_eq!(
+            format_lines(original, formatted, &[(2, 3)]),
+            "a  \nb\nc\nd\n"
+        );
+        assert_eq!(format_lines(original, formatted, &[(1, 4)]), formatted);
+    }
+
+    #[test]
+    fn test_file_diff() {
+        let file_diff = FileDiff::new("main.sw".into(), "a\nb  \nc\n", "a\nb\nc\n");
+        assert_eq!(
+            file_diff.mismatches,
+            vec![Mismatch {
+                original_begin_line: 2,
+                original_end_line: 2,
+                expected_begin_line: 2,
+                expected_end_line: 2,
+                original: "b  \n".into(),
+                expected: "b\n".into(),
+            }]
+        );
+    }
 
     #[test]
     fn test_forc_indentation() {
@@ -0,0 +1,439 @@
+//! Code coverage of the tests of a package.
+//!
+//! With coverage enabled, the executor records how many times each instruction is executed, in
+//! the test itself and in the contracts it calls. The instructions are mapped back to source lines
+//! through the source maps of the executed programs. The resulting [Coverage] can be rendered as
+//! an lcov tracefile or as an HTML report.
+//!
+//! Branch coverage is derived from the conditional jump instructions. Each of them is a branch
+//! point with two branches: the jump being taken, and the execution falling through to the next
+//! instruction.
+
+use crate::report::escape_xml;
+use fuel_tx::ContractId;
+use fuel_vm::prelude::Instruction;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+use sway_core::source_map::SourceMap;
+
+/// The name of the lcov tracefile written to the coverage directory.
+pub const LCOV_FILE_NAME: &str = "lcov.info";
+/// The name of the HTML report index written to the coverage directory.
+pub const HTML_INDEX_FILE_NAME: &str = "index.html";
+
+/// The instructions executed by a test, by contract id and by program counter relative to the
+/// start of the program.
+///
+/// The instructions of the test itself are recorded with the zeroed contract id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstructionHits {
+    /// The number of times each instruction was executed.
+    executed: BTreeMap<(ContractId, u64), u64>,
+    /// The number of times each instruction was not followed by the next instruction of the same
+    /// program, i.e. the number of times it jumped.
+    jumped: BTreeMap<(ContractId, u64), u64>,
+    /// The last recorded instruction.
+    last: Option<(ContractId, u64)>,
+}
+
+/// A program executed by tests, together with what is needed to map its instructions to source
+/// lines.
+#[derive(Debug, Clone, Copy)]
+pub struct CoveredProgram<'a> {
+    /// The id of the contract, or the zeroed contract id for the test itself.
+    pub contract_id: ContractId,
+    /// The bytecode of the program.
+    pub bytecode: &'a [u8],
+    /// The source map of the bytecode.
+    pub source_map: &'a SourceMap,
+}
+
+/// The line and branch coverage of source files.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    /// The coverage of each source file, by path.
+    pub files: BTreeMap<PathBuf, FileCoverage>,
+}
+
+/// The line and branch coverage of a single source file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileCoverage {
+    /// The number of times each line with instructions was executed, by 1-based line number.
+    pub lines: BTreeMap<usize, u64>,
+    /// The branch points of each line, in the order of their instructions.
+    pub branches: BTreeMap<usize, Vec<BranchCoverage>>,
+}
+
+/// A branch point, i.e. a conditional jump instruction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BranchCoverage {
+    /// The number of times the branch point was executed.
+    pub executed: u64,
+    /// The number of times the jump was taken.
+    pub taken: u64,
+}
+
+impl InstructionHits {
+    /// Records the execution of the instruction at `pc` of the given contract.
+    pub fn record(&mut self, contract_id: ContractId, pc: u64) {
+        if let Some((last_contract_id, last_pc)) = self.last {
+            if last_contract_id != contract_id || last_pc + Instruction::SIZE as u64 != pc {
+                *self.jumped.entry((last_contract_id, last_pc)).or_default() += 1;
+            }
+        }
+        *self.executed.entry((contract_id, pc)).or_default() += 1;
+        self.last = Some((contract_id, pc));
+    }
+
+    /// Adds the hits of another execution, e.g. of another run of a fuzz test.
+    pub fn merge(&mut self, other: &InstructionHits) {
+        for (instruction, count) in &other.executed {
+            *self.executed.entry(*instruction).or_default() += count;
+        }
+        for (instruction, count) in &other.jumped {
+            *self.jumped.entry(*instruction).or_default() += count;
+        }
+    }
+
+    /// The number of times the instruction at `pc` of the given contract was executed.
+    pub fn executed(&self, contract_id: ContractId, pc: u64) -> u64 {
+        self.executed
+            .get(&(contract_id, pc))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The number of times the instruction at `pc` of the given contract jumped.
+    pub fn jumped(&self, contract_id: ContractId, pc: u64) -> u64 {
+        self.jumped
+            .get(&(contract_id, pc))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl Coverage {
+    /// Maps the instruction hits to the source lines of the given programs.
+    ///
+    /// Only the source files for which `include` returns `true` are part of the coverage. A line
+    /// is executed as many times as the most executed of its instructions. A source file compiled
+    /// into several programs, e.g. a contract compiled with and without its tests, is covered by
+    /// all of them.
+    pub fn from_hits(
+        programs: &[CoveredProgram],
+        hits: &InstructionHits,
+        include: impl Fn(&Path) -> bool,
+    ) -> Self {
+        let mut coverage = Coverage::default();
+        for program in programs {
+            let mut program_coverage = Coverage::default();
+            for (opcode_index, span) in &program.source_map.map {
+                let (path, range) = span.to_span(
+                    &program.source_map.paths,
+                    &program.source_map.dependency_paths,
+                );
+                if !include(&path) {
+                    continue;
+                }
+                let pc = (opcode_index * Instruction::SIZE) as u64;
+                let executed = hits.executed(program.contract_id, pc);
+                let file = program_coverage.files.entry(path).or_default();
+                let line = file.lines.entry(range.start.line).or_default();
+                *line = (*line).max(executed);
+                if is_conditional_jump(program.bytecode, *opcode_index) {
+                    file.branches
+                        .entry(range.start.line)
+                        .or_default()
+                        .push(BranchCoverage {
+                            executed,
+                            taken: hits.jumped(program.contract_id, pc),
+                        });
+                }
+            }
+            coverage.merge(program_coverage);
+        }
+        coverage
+    }
+
+    /// Adds the coverage of another program or package.
+    pub fn merge(&mut self, other: Coverage) {
+        for (path, other_file) in other.files {
+            let file = self.files.entry(path).or_default();
+            for (line, executed) in other_file.lines {
+                let line = file.lines.entry(line).or_default();
+                *line = (*line).max(executed);
+            }
+            for (line, other_branches) in other_file.branches {
+                let branches = file.branches.entry(line).or_default();
+                for (ix, other_branch) in other_branches.into_iter().enumerate() {
+                    match branches.get_mut(ix) {
+                        Some(branch) => {
+                            branch.executed += other_branch.executed;
+                            branch.taken += other_branch.taken;
+                        }
+                        None => branches.push(other_branch),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Renders the coverage as an lcov tracefile.
+    pub fn to_lcov(&self) -> String {
+        let mut lcov = String::new();
+        for (path, file) in &self.files {
+            let _ = writeln!(lcov, "TN:");
+            let _ = writeln!(lcov, "SF:{}", path.display());
+            for (line, branches) in &file.branches {
+                for (block, branch) in branches.iter().enumerate() {
+                    let (taken, not_taken) = match branch.executed {
+                        0 => ("-".to_string(), "-".to_string()),
+                        _ => (branch.taken.to_string(), branch.not_taken().to_string()),
+                    };
+                    let _ = writeln!(lcov, "BRDA:{line},{block},0,{taken}");
+                    let _ = writeln!(lcov, "BRDA:{line},{block},1,{not_taken}");
+                }
+            }
+            let (branches_hit, branches_found) = file.branches_hit();
+            let _ = writeln!(lcov, "BRF:{branches_found}");
+            let _ = writeln!(lcov, "BRH:{branches_hit}");
+            for (line, executed) in &file.lines {
+                let _ = writeln!(lcov, "DA:{line},{executed}");
+            }
+            let (lines_hit, lines_found) = file.lines_hit();
+            let _ = writeln!(lcov, "LF:{lines_found}");
+            let _ = writeln!(lcov, "LH:{lines_hit}");
+            let _ = writeln!(lcov, "end_of_record");
+        }
+        lcov
+    }
+
+    /// Writes the lcov tracefile and the HTML report to the given directory.
+    ///
+    /// The HTML report consists of an index listing the coverage of each source file, and of a
+    /// page for each source file showing the number of times each line was executed.
+    pub fn write(&self, dir: &Path) -> anyhow::Result<()> {
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(LCOV_FILE_NAME), self.to_lcov())?;
+
+        let mut rows = String::new();
+        for (ix, (path, file)) in self.files.iter().enumerate() {
+            let page = format!("file-{ix}.html");
+            let source = fs::read_to_string(path).unwrap_or_default();
+            fs::write(dir.join(&page), file.to_html(path, &source))?;
+            let (lines_hit, lines_found) = file.lines_hit();
+            let (branches_hit, branches_found) = file.branches_hit();
+            let _ = writeln!(
+                rows,
+                r#"<tr><td><a href="{page}">{}</a></td><td>{}</td><td>{lines_hit}/{lines_found}</td><td>{}</td><td>{branches_hit}/{branches_found}</td></tr>"#,
+                escape_xml(&path.display().to_string()),
+                percent(lines_hit, lines_found),
+                percent(branches_hit, branches_found),
+            );
+        }
+        let index = html_page(
+            "Coverage",
+            &format!(
+                "<table>\n<tr><th>File</th><th>Lines</th><th></th><th>Branches</th><th></th></tr>\n{rows}</table>"
+            ),
+        );
+        fs::write(dir.join(HTML_INDEX_FILE_NAME), index)?;
+        Ok(())
+    }
+
+    /// The number of executed lines and the number of lines with instructions, over all files.
+    pub fn lines_hit(&self) -> (usize, usize) {
+        self.files
+            .values()
+            .map(FileCoverage::lines_hit)
+            .fold((0, 0), |(hit, found), (file_hit, file_found)| {
+                (hit + file_hit, found + file_found)
+            })
+    }
+}
+
+impl FileCoverage {
+    /// The number of executed lines and the number of lines with instructions.
+    pub fn lines_hit(&self) -> (usize, usize) {
+        let hit = self
+            .lines
+            .values()
+            .filter(|executed| **executed != 0)
+            .count();
+        (hit, self.lines.len())
+    }
+
+    /// The number of taken branches and the number of branches. Each branch point has two
+    /// branches.
+    pub fn branches_hit(&self) -> (usize, usize) {
+        self.branches
+            .values()
+            .flatten()
+            .fold((0, 0), |(hit, found), branch| {
+                let taken = usize::from(branch.taken != 0);
+                let not_taken = usize::from(branch.not_taken() != 0);
+                (hit + taken + not_taken, found + 2)
+            })
+    }
+
+    /// Renders the source of the file with the number of times each line was executed.
+    fn to_html(&self, path: &Path, source: &str) -> String {
+        let mut rows = String::new();
+        for (ix, text) in source.lines().enumerate() {
+            let line = ix + 1;
+            let (class, executed) = match self.lines.get(&line) {
+                Some(0) => ("uncovered", "0".to_string()),
+                Some(executed) => ("covered", executed.to_string()),
+                None => ("", String::new()),
+            };
+            let branches = self
+                .branches
+                .get(&line)
+                .map(|branches| {
+                    branches
+                        .iter()
+                        .map(|branch| match branch.executed {
+                            0 => "[- -]".to_string(),
+                            _ => format!("[{} {}]", branch.taken, branch.not_taken()),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .unwrap_or_default();
+            let _ = writeln!(
+                rows,
+                r##"<tr id="L{line}" class="{class}"><td class="line"><a href="#L{line}">{line}</a></td><td class="hits">{executed}</td><td class="branches">{branches}</td><td><pre>{}</pre></td></tr>"##,
+                escape_xml(text)
+            );
+        }
+        let title = escape_xml(&path.display().to_string());
+        html_page(
+            &title,
+            &format!("<p><a href=\"{HTML_INDEX_FILE_NAME}\">Coverage</a></p>\n<table class=\"source\">\n{rows}</table>"),
+        )
+    }
+}
+
+impl BranchCoverage {
+    /// The number of times the execution fell through to the next instruction.
+    pub fn not_taken(&self) -> u64 {
+        self.executed.saturating_sub(self.taken)
+    }
+}
+
+/// Whether the instruction at the given index of the bytecode is a conditional jump.
+fn is_conditional_jump(bytecode: &[u8], opcode_index: usize) -> bool {
+    let start = opcode_index * Instruction::SIZE;
+    let Some(bytes) = bytecode.get(start..start + Instruction::SIZE) else {
+        return false;
+    };
+    let bytes: [u8; Instruction::SIZE] = bytes.try_into().expect("instruction size");
+    matches!(
+        Instruction::try_from(bytes),
+        Ok(Instruction::JNE(_)
+            | Instruction::JNEI(_)
+            | Instruction::JNZI(_)
+            | Instruction::JNEB(_)
+            | Instruction::JNEF(_)
+            | Instruction::JNZB(_)
+            | Instruction::JNZF(_))
+    )
+}
+
+fn percent(hit: usize, found: usize) -> String {
+    match found {
+        0 => "-".to_string(),
+        _ => format!("{:.1}%", hit as f64 / found as f64 * 100.0),
+    }
+}
+
+fn html_page(title: &str, body: &str) -> String {
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; }}
+table {{ border-collapse: collapse; }}
+td, th {{ padding: 0 8px; text-align: left; }}
+pre {{ margin: 0; }}
+.source td.line, .source td.hits {{ text-align: right; color: #888; }}
+.source td.branches {{ color: #888; white-space: nowrap; }}
+tr.covered {{ background-color: #dfd; }}
+tr.uncovered {{ background-color: #fdd; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+{body}
+</body>
+</html>
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_jumps() {
+        let script = ContractId::zeroed();
+        let contract = ContractId::new([1; 32]);
+        let mut hits = InstructionHits::default();
+        for (contract_id, pc) in [
+            (script, 0),
+            (script, 4),
+            (script, 12),
+            (contract, 0),
+            (script, 16),
+            (script, 4),
+        ] {
+            hits.record(contract_id, pc);
+        }
+        assert_eq!(hits.executed(script, 4), 2);
+        assert_eq!(hits.jumped(script, 0), 0);
+        assert_eq!(hits.jumped(script, 4), 1);
+        assert_eq!(hits.jumped(script, 12), 1);
+        assert_eq!(hits.jumped(contract, 0), 1);
+        assert_eq!(hits.jumped(script, 16), 1);
+    }
+
+    #[test]
+    fn lcov_tracefile() {
+        let file = FileCoverage {
+            lines: [(3, 2), (4, 0), (6, 1)].into_iter().collect(),
+            branches: [(
+                3,
+                vec![
+                    BranchCoverage {
+                        executed: 2,
+                        taken: 2,
+                    },
+                    BranchCoverage {
+                        executed: 0,
+                        taken: 0,
+                    },
+                ],
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let coverage = Coverage {
+            files: [(PathBuf::from("/pkg/src/main.sw"), file)]
+                .into_iter()
+                .collect(),
+        };
+        assert_eq!(
+            coverage.to_lcov(),
+            "TN:\nSF:/pkg/src/main.sw\nBRDA:3,0,0,2\nBRDA:3,0,1,0\nBRDA:3,1,0,-\nBRDA:3,1,1,-\n\
+             BRF:4\nBRH:1\nDA:3,2\nDA:4,0\nDA:6,1\nLF:3\nLH:2\nend_of_record\n"
+        );
+    }
+}
@@ -0,0 +1,520 @@
+//! Doc tests, the ```` ```sway ```` code examples in the doc comments of a library package,
+//! compiled and run as unit tests.
+//!
+//! Each example is written to a package of its own, which depends on the documented package, and
+//! the packages are built together as a workspace. An example is wrapped in a `#[test]` function,
+//! unless it declares a `main` function, in which case the test calls `main`. The leading `use`
+//! statements of an example are placed outside of the test function.
+//!
+//! The fence of an example may be annotated to change how it is tested:
+//! - ```` ```sway,ignore ````: the example is neither compiled nor run.
+//! - ```` ```sway,no_run ````: the example is compiled, but not run.
+//! - ```` ```sway,should_revert ````: the example is expected to revert.
+
+use crate::{build, TestOpts, TestRunnerCount, Tested, TestedPackage};
+use forc_pkg::manifest::{
+    Dependency, DependencyDetails, GenericManifestFile, PackageManifestFile, Project,
+};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+};
+use sway_utils::constants::MANIFEST_FILE_NAME;
+
+/// The name of the directory, within the forc or output directory, that doc tests are written to.
+pub const DOC_TESTS_DIR_NAME: &str = "doc-tests";
+
+/// The name of the test function wrapping a doc test.
+const DOC_TEST_FN_NAME: &str = "doc_test";
+
+/// How a doc test is tested, as annotated on the fence of the code example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocTestMode {
+    /// The example is compiled and run, and must not revert.
+    Run,
+    /// The example is compiled and run, and must revert.
+    ShouldRevert,
+    /// The example is compiled, but not run.
+    NoRun,
+    /// The example is neither compiled nor run.
+    Ignore,
+}
+
+/// A code example in a doc comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocTest {
+    /// The source file containing the doc comment.
+    pub file_path: PathBuf,
+    /// The 1-based line of the opening fence of the example.
+    pub line: usize,
+    /// The code of the example, without the doc comment markers.
+    pub code: String,
+    pub mode: DocTestMode,
+}
+
+/// The result of testing a single doc test.
+#[derive(Debug)]
+pub enum DocTestOutcome {
+    /// The example ran to completion as expected.
+    Passed { gas_used: u64 },
+    /// The example did not run as expected, e.g. it reverted.
+    Failed { revert_code: Option<u64> },
+    /// The example failed to compile, with the error returned by the build.
+    CompileFailed(String),
+    /// The example compiled, and was not run as it is annotated with `no_run`.
+    Compiled,
+    /// The example is annotated with `ignore`.
+    Ignored,
+}
+
+/// A doc test along with the result of testing it.
+#[derive(Debug)]
+pub struct DocTestResult {
+    pub doc_test: DocTest,
+    pub outcome: DocTestOutcome,
+}
+
+/// The manifest of the workspace the doc tests are built in.
+#[derive(Serialize)]
+struct WorkspaceManifest {
+    workspace: Workspace,
+}
+
+#[derive(Serialize)]
+struct Workspace {
+    members: Vec<String>,
+}
+
+/// The manifest of the package of a single doc test.
+#[derive(Serialize)]
+struct MemberManifest {
+    project: Project,
+    dependencies: BTreeMap<String, Dependency>,
+}
+
+impl DocTest {
+    /// The location of the example, e.g. `src/hash.sw:42`, relative to the given directory.
+    pub fn location(&self, dir: &Path) -> String {
+        let file_path = self.file_path.strip_prefix(dir).unwrap_or(&self.file_path);
+        format!("{}:{}", file_path.display(), self.line)
+    }
+}
+
+impl DocTestResult {
+    pub fn passed(&self) -> bool {
+        !matches!(
+            self.outcome,
+            DocTestOutcome::Failed { .. } | DocTestOutcome::CompileFailed(_)
+        )
+    }
+}
+
+/// Collects the doc tests of all of the source files of the package.
+pub fn collect(pkg_manifest: &PackageManifestFile) -> anyhow::Result<Vec<DocTest>> {
+    let src_dir = pkg_manifest
+        .entry_path()
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| pkg_manifest.dir().to_path_buf());
+    let mut files = sway_utils::helpers::get_sway_files(src_dir);
+    files.sort();
+    let mut doc_tests = vec![];
+    for file_path in files {
+        let src = fs::read_to_string(&file_path)?;
+        doc_tests.extend(extract(&file_path, &src));
+    }
+    Ok(doc_tests)
+}
+
+/// Extracts the ```` ```sway ```` code examples from the `///` and `//!` doc comments of a source
+/// file.
+pub fn extract(file_path: &Path, src: &str) -> Vec<DocTest> {
+    /// A fenced code block being read, whose mode is `None` if it is not Sway code.
+    struct Block {
+        line: usize,
+        mode: Option<DocTestMode>,
+        lines: Vec<String>,
+    }
+
+    let mut doc_tests = vec![];
+    let mut block: Option<Block> = None;
+    for (index, line) in src.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let Some(content) = trimmed
+            .strip_prefix("///")
+            .or_else(|| trimmed.strip_prefix("//!"))
+        else {
+            // A doc comment ending within a code block leaves the block unterminated.
+            block = None;
+            continue;
+        };
+        let content = content.strip_prefix(' ').unwrap_or(content);
+        let fence_info = content.trim_start().strip_prefix("```");
+        match (block.take(), fence_info) {
+            (None, Some(info)) => {
+                block = Some(Block {
+                    line: index + 1,
+                    mode: parse_fence_info(info),
+                    lines: vec![],
+                });
+            }
+            (None, None) => {}
+            (Some(Block { line, mode, lines }), Some(info)) if info.trim().is_empty() => {
+                if let Some(mode) = mode {
+                    doc_tests.push(DocTest {
+                        file_path: file_path.to_path_buf(),
+                        line,
+                        code: lines.join("\n"),
+                        mode,
+                    });
+                }
+            }
+            (Some(mut open), _) => {
+                open.lines.push(content.to_string());
+                block = Some(open);
+            }
+        }
+    }
+    doc_tests
+}
+
+/// Parses the info string of a fence, e.g. `sway,no_run`, into the mode of the doc test.
+///
+/// Returns `None` if the code block is not Sway code.
+fn parse_fence_info(info: &str) -> Option<DocTestMode> {
+    let mut tokens = info
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty());
+    if tokens.next()? != "sway" {
+        return None;
+    }
+    let mut mode = DocTestMode::Run;
+    for token in tokens {
+        mode = match token {
+            "ignore" => DocTestMode::Ignore,
+            "no_run" if mode != DocTestMode::Ignore => DocTestMode::NoRun,
+            "should_revert" if mode == DocTestMode::Run => DocTestMode::ShouldRevert,
+            _ => mode,
+        };
+    }
+    Some(mode)
+}
+
+/// Builds and runs the doc tests of the package, within the given directory.
+///
+/// If the doc tests fail to build together, each of them is built on its own, to find the ones
+/// that fail to compile.
+pub fn run(
+    pkg_manifest: &PackageManifestFile,
+    doc_tests: Vec<DocTest>,
+    dir: &Path,
+    opts: TestOpts,
+    test_runner_count: TestRunnerCount,
+) -> anyhow::Result<Vec<DocTestResult>> {
+    let members = write_workspace(pkg_manifest, &doc_tests, dir)?;
+    let mut outcomes: HashMap<String, DocTestOutcome> = HashMap::new();
+    if !members.is_empty() {
+        match build_and_run(dir, &opts, test_runner_count) {
+            Ok(tested) => outcomes.extend(tested),
+            Err(_) => {
+                for member in &members {
+                    match build_and_run(&dir.join(member), &opts, test_runner_count) {
+                        Ok(tested) => outcomes.extend(tested),
+                        Err(err) => {
+                            outcomes.insert(
+                                member.clone(),
+                                DocTestOutcome::CompileFailed(err.to_string()),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let results = doc_tests
+        .into_iter()
+        .enumerate()
+        .map(|(index, doc_test)| {
+            let outcome = match doc_test.mode {
+                DocTestMode::Ignore => DocTestOutcome::Ignored,
+                _ => outcomes
+                    .remove(&member_name(index))
+                    .unwrap_or(DocTestOutcome::Compiled),
+            };
+            DocTestResult { doc_test, outcome }
+        })
+        .collect();
+    Ok(results)
+}
+
+/// Builds the package or workspace at `path` and runs its tests, returning the outcome of the
+/// doc test of each built package by the name of the package.
+fn build_and_run(
+    path: &Path,
+    opts: &TestOpts,
+    test_runner_count: TestRunnerCount,
+) -> anyhow::Result<Vec<(String, DocTestOutcome)>> {
+    let mut opts = opts.clone();
+    opts.pkg.path = Some(path.display().to_string());
+    // The packages of the doc tests are built within their own directories, and are locked
+    // within the workspace written for them.
+    opts.pkg.output_directory = None;
+    opts.pkg.locked = false;
+    let tested = build(opts)?.run(test_runner_count, None)?;
+    let tested_pkgs = match tested {
+        Tested::Package(pkg) => vec![*pkg],
+        Tested::Workspace(pkgs) => pkgs,
+    };
+    Ok(tested_pkgs.into_iter().map(pkg_outcome).collect())
+}
+
+fn pkg_outcome(tested_pkg: TestedPackage) -> (String, DocTestOutcome) {
+    let outcome = match tested_pkg
+        .tests
+        .iter()
+        .find(|test| test.name == DOC_TEST_FN_NAME)
+    {
+        Some(test) if test.passed() => DocTestOutcome::Passed {
+            gas_used: test.gas_used,
+        },
+        Some(test) => DocTestOutcome::Failed {
+            revert_code: test.revert_code(),
+        },
+        None => DocTestOutcome::Compiled,
+    };
+    (tested_pkg.built.descriptor.name.clone(), outcome)
+}
+
+/// The name of the package of the doc test at the given index.
+fn member_name(index: usize) -> String {
+    format!("doc_test_{index}")
+}
+
+/// Writes a workspace with a package for each doc test that is not ignored, replacing the
+/// contents of `dir`, and returns the names of the packages.
+fn write_workspace(
+    pkg_manifest: &PackageManifestFile,
+    doc_tests: &[DocTest],
+    dir: &Path,
+) -> anyhow::Result<Vec<String>> {
+    if dir.exists() {
+        fs::remove_dir_all(dir)?;
+    }
+    let mut members = vec![];
+    for (index, doc_test) in doc_tests.iter().enumerate() {
+        if doc_test.mode == DocTestMode::Ignore {
+            continue;
+        }
+        let name = member_name(index);
+        let member_dir = dir.join(&name);
+        fs::create_dir_all(member_dir.join("src"))?;
+        fs::write(
+            member_dir.join(MANIFEST_FILE_NAME),
+            member_manifest(&name, pkg_manifest)?,
+        )?;
+        fs::write(
+            member_dir.join("src").join("main.sw"),
+            member_source(doc_test, &doc_test.location(pkg_manifest.dir())),
+        )?;
+        members.push(name);
+    }
+    let workspace = WorkspaceManifest {
+        workspace: Workspace {
+            members: members.clone(),
+        },
+    };
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(MANIFEST_FILE_NAME), toml::to_string(&workspace)?)?;
+    Ok(members)
+}
+
+/// The manifest of the package of a doc test, which depends on the documented package and on
+/// its dependencies, and enables the same experimental features.
+fn member_manifest(name: &str, pkg_manifest: &PackageManifestFile) -> anyhow::Result<String> {
+    let path_dependency = |path: &Path| {
+        let mut details = DependencyDetails::default();
+        details.path = Some(path.display().to_string());
+        Dependency::Detailed(details)
+    };
+    let mut dependencies: BTreeMap<_, _> = pkg_manifest
+        .deps()
+        .map(|(dep_name, dep)| {
+            // Relative paths are relative to the documented package.
+            let dep = match pkg_manifest.dep_path(dep_name) {
+                Some(path) => path_dependency(&path),
+                None => dep.clone(),
+            };
+            (dep_name.clone(), dep)
+        })
+        .collect();
+    dependencies.insert(
+        pkg_manifest.project_name().to_string(),
+        path_dependency(pkg_manifest.dir()),
+    );
+    let manifest = MemberManifest {
+        project: Project {
+            authors: Some(vec![]),
+            name: name.to_string(),
+            organization: None,
+            license: "Apache-2.0".to_string(),
+            entry: "main.sw".to_string(),
+            implicit_std: Some(false),
+            forc_version: None,
+            experimental: pkg_manifest.project.experimental.clone(),
+            metadata: None,
+        },
+        dependencies,
+    };
+    Ok(toml::to_string(&manifest)?)
+}
+
+/// The source of the package of a doc test.
+fn member_source(doc_test: &DocTest, location: &str) -> String {
+    let (uses, body) = split_uses(&doc_test.code);
+    let mut src = format!("library;\n\n// The doc test at `{location}`.\n");
+    if !uses.trim().is_empty() {
+        src.push('\n');
+        src.push_str(uses.trim());
+        src.push('\n');
+    }
+    let declares_main = body
+        .lines()
+        .any(|line| line.trim_start().starts_with("fn main("));
+    let test_attribute = match doc_test.mode {
+        DocTestMode::ShouldRevert => "#[test(should_revert)]\n",
+        DocTestMode::NoRun => "",
+        _ => "#[test]\n",
+    };
+    src.push('\n');
+    if declares_main {
+        src.push_str(&body);
+        if !test_attribute.is_empty() {
+            src.push_str(&format!(
+                "\n\n{test_attribute}fn {DOC_TEST_FN_NAME}() {{\n    let _ = main();\n}}"
+            ));
+        }
+    } else {
+        src.push_str(&format!("{test_attribute}fn {DOC_TEST_FN_NAME}() {{\n"));
+        for line in body.lines() {
+            match line.is_empty() {
+                true => src.push('\n'),
+                false => src.push_str(&format!("    {line}\n")),
+            }
+        }
+        src.push('}');
+    }
+    src.push('\n');
+    src
+}
+
+/// Splits the leading `use` statements off of the code of a doc test.
+fn split_uses(code: &str) -> (String, String) {
+    let mut uses = vec![];
+    let mut lines = code.lines().peekable();
+    let mut in_use = false;
+    while let Some(line) = lines.peek() {
+        let trimmed = line.trim();
+        let starts_use = trimmed.starts_with("use ");
+        if !(in_use || starts_use || trimmed.is_empty()) {
+            break;
+        }
+        in_use = (in_use || starts_use) && !trimmed.ends_with(';');
+        uses.push(*line);
+        lines.next();
+    }
+    (uses.join("\n"), lines.collect::<Vec<_>>().join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_sway_code_blocks() {
+        let src = r#"library;
+
+/// Hashes a value.
+///
+/// ```sway
+/// use std::hash::*;
+///
+/// let hash = sha256(1);
+/// ```
+///
+/// ```sway,ignore
+/// storage.value.read();
+/// ```
+///
+/// ```text
+/// ```sway
+/// ```
+pub fn hash() {}
+
+//! ```sway, should_revert
+//! revert(0);
+//! ```
+"#;
+        let doc_tests = extract(Path::new("src/lib.sw"), src);
+        assert_eq!(
+            doc_tests
+                .iter()
+                .map(|doc_test| (doc_test.line, doc_test.mode))
+                .collect::<Vec<_>>(),
+            vec![
+                (5, DocTestMode::Run),
+                (11, DocTestMode::Ignore),
+                (20, DocTestMode::ShouldRevert)
+            ]
+        );
+        assert_eq!(
+            doc_tests[0].code,
+            "use std::hash::*;\n\nlet hash = sha256(1);"
+        );
+        assert_eq!(doc_tests[0].location(Path::new("")), "src/lib.sw:5");
+    }
+
+    #[test]
+    fn parse_fence_infos() {
+        assert_eq!(parse_fence_info("sway"), Some(DocTestMode::Run));
+        assert_eq!(parse_fence_info("sway,no_run"), Some(DocTestMode::NoRun));
+        assert_eq!(
+            parse_fence_info(" sway should_revert"),
+            Some(DocTestMode::ShouldRevert)
+        );
+        assert_eq!(
+            parse_fence_info("sway,no_run,ignore"),
+            Some(DocTestMode::Ignore)
+        );
+        assert_eq!(parse_fence_info("rust"), None);
+        assert_eq!(parse_fence_info(""), None);
+    }
+
+    #[test]
+    fn wrap_doc_tests() {
+        let doc_test = |code: &str, mode| DocTest {
+            file_path: PathBuf::from("src/lib.sw"),
+            line: 5,
+            code: code.to_string(),
+            mode,
+        };
+        assert_eq!(
+            member_source(
+                &doc_test(
+                    "use std::hash::{\n    sha256,\n};\n\nlet hash = sha256(1);\n\nassert(hash != b256::zero());",
+                    DocTestMode::Run
+                ),
+                "src/lib.sw:5"
+            ),
+            "library;\n\n// The doc test at `src/lib.sw:5`.\n\nuse std::hash::{\n    sha256,\n};\n\n#[test]\nfn doc_test() {\n    let hash = sha256(1);\n\n    assert(hash != b256::zero());\n}\n"
+        );
+        assert_eq!(
+            member_source(
+                &doc_test("fn main() -> u64 {\n    revert(0)\n}", DocTestMode::ShouldRevert),
+                "src/lib.sw:5"
+            ),
+            "library;\n\n// The doc test at `src/lib.sw:5`.\n\nfn main() -> u64 {\n    revert(0)\n}\n\n#[test(should_revert)]\nfn doc_test() {\n    let _ = main();\n}\n"
+        );
+    }
+}
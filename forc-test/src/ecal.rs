@@ -0,0 +1,322 @@
+//! The `ecal` handler implementing the cheatcodes of the `std::test` library.
+//!
+//! A cheatcode is executed by `ecal $id $b $c $d`, where `$id` holds the id of the cheatcode and
+//! the other registers hold its arguments, or pointers to them.
+//!
+//! The `ecal` handler of the VM has no access to its storage, so the cheatcodes reading or writing
+//! contract storage and balances are executed by the test executor with
+//! [execute_storage_cheatcode] while the VM is stopped right before their `ecal` instruction. The
+//! handler then only reports their outcome.
+
+use crate::predicate::{PredicateResult, PredicateTransaction};
+use fuel_tx::{Address, AssetId, Bytes32, ContractId, Receipt};
+use fuel_vm::{
+    error::SimpleResult,
+    fuel_asm::{Instruction, PanicReason, RegId},
+    interpreter::{EcalHandler, Interpreter, Memory},
+    storage::{ContractsAssetsStorage, InterpreterStorage},
+};
+
+/// Sets the block height, `$b` holds the height.
+pub const SET_BLOCK_HEIGHT: u64 = 0;
+/// Sets the block timestamp, `$b` holds the TAI64 timestamp.
+pub const SET_TIMESTAMP: u64 = 1;
+/// Sets the caller, `$b` points to its address.
+pub const SET_CALLER: u64 = 2;
+/// Mints coins to a contract, `$b` and `$c` point to the contract and asset ids, `$d` holds the
+/// amount.
+pub const MINT_TO_CONTRACT: u64 = 3;
+/// Mints coins to an address, `$b` and `$c` point to the address and asset id, `$d` holds the
+/// amount.
+pub const MINT_TO_ADDRESS: u64 = 4;
+/// Writes a storage slot, `$b`, `$c` and `$d` point to the contract id, the key and the value.
+pub const STORE: u64 = 5;
+/// Reads a storage slot, `$b` and `$c` point to the contract id and the key, the value is written
+/// to `$d`.
+pub const LOAD: u64 = 6;
+/// Expects a log, `$b` points to the encoded value and `$c` holds its length.
+pub const EXPECT_LOG: u64 = 7;
+/// Adds a coin owned by the predicate under test, `$b` points to the asset id and `$c` holds the
+/// amount.
+pub const PREDICATE_COIN: u64 = 8;
+/// Adds a message sent to the predicate under test, `$b` holds the amount, `$c` points to the data
+/// and `$d` holds its length.
+pub const PREDICATE_MESSAGE: u64 = 9;
+/// Sets the predicate data, `$b` points to the encoded data and `$c` holds its length.
+pub const PREDICATE_DATA: u64 = 10;
+/// Adds a witness, `$b` points to the data and `$c` holds its length.
+pub const ADD_WITNESS: u64 = 11;
+/// Adds a coin output, `$b` and `$c` point to the recipient and the asset id, `$d` holds the
+/// amount.
+pub const ADD_OUTPUT: u64 = 12;
+/// Adds a change output, `$b` and `$c` point to the recipient and the asset id.
+pub const ADD_CHANGE_OUTPUT: u64 = 13;
+/// Writes whether the predicate under test is verified to the word `$b` points to.
+pub const VERIFY_PREDICATE: u64 = 14;
+
+/// The TAI64 timestamp of the Unix epoch, which `MemoryStorage` produces its genesis block at.
+/// TAI is 37 seconds ahead of UTC, leap seconds included.
+const TAI64_UNIX_EPOCH: u64 = (1 << 62) + 37;
+/// The number of seconds between two blocks, as assumed by the `MemoryStorage` of the VM.
+const BLOCK_INTERVAL: u64 = 10;
+
+/// The environment of the transaction executing a test.
+///
+/// The default environment is the one a test runs in without cheatcodes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestEnvironment {
+    /// The block height, if set with `set_block_height` or `set_timestamp`.
+    pub block_height: Option<u32>,
+    /// The owner of the coin input of the transaction, if set with `set_caller`.
+    pub caller: Option<Address>,
+    /// The additional coin inputs of the transaction, minted with `mint`.
+    pub coins: Vec<(Address, AssetId, u64)>,
+    /// The transaction spending the predicate under test, if declared with the predicate
+    /// cheatcodes.
+    pub predicate: Option<PredicateTransaction>,
+}
+
+/// The `ecal` handler executing cheatcodes.
+///
+/// Cheatcodes changing the environment of the test are only recorded, as the environment cannot
+/// change during the execution of a transaction. The test is then executed again in the requested
+/// environment.
+#[derive(Debug, Clone, Default)]
+pub struct CheatcodeEcal {
+    /// The environment requested by the cheatcodes executed so far.
+    pub environment: TestEnvironment,
+    /// The encoded values expected to be logged by the test.
+    pub expected_logs: Vec<Vec<u8>>,
+    /// The result of verifying the predicate under test against the transaction declared in the
+    /// environment, if any.
+    pub predicate_result: Option<PredicateResult>,
+    /// The outcome of the storage cheatcode executed by `execute_storage_cheatcode` for the
+    /// `ecal` instruction about to be executed, if any.
+    storage_cheatcode: Option<Result<(), PanicReason>>,
+}
+
+impl CheatcodeEcal {
+    /// Describes the expectations of the executed cheatcodes which are not met by `receipts`.
+    pub fn unmet_expectations(&self, receipts: &[Receipt]) -> Vec<String> {
+        self.expected_logs
+            .iter()
+            .filter(|expected| {
+                !receipts.iter().any(|receipt| match receipt {
+                    Receipt::LogData {
+                        data: Some(data), ..
+                    } => data[..] == expected[..],
+                    _ => false,
+                })
+            })
+            .map(|expected| format!("expected a log of 0x{}", hex::encode(expected)))
+            .collect()
+    }
+}
+
+impl EcalHandler for CheatcodeEcal {
+    fn ecal<M, S, Tx>(
+        vm: &mut Interpreter<M, S, Tx, Self>,
+        a: RegId,
+        b: RegId,
+        c: RegId,
+        d: RegId,
+    ) -> SimpleResult<()>
+    where
+        M: Memory,
+    {
+        let [id, b, c, d] = [a, b, c, d].map(|reg| vm.registers()[reg]);
+        match id {
+            SET_BLOCK_HEIGHT => {
+                let block_height = u32::try_from(b).map_err(|_| PanicReason::ArithmeticOverflow)?;
+                vm.ecal_state_mut().environment.block_height = Some(block_height);
+            }
+            SET_TIMESTAMP => {
+                let block_height = b
+                    .checked_sub(TAI64_UNIX_EPOCH)
+                    .and_then(|secs| u32::try_from(secs / BLOCK_INTERVAL).ok())
+                    .ok_or(PanicReason::ArithmeticOverflow)?;
+                vm.ecal_state_mut().environment.block_height = Some(block_height);
+            }
+            SET_CALLER => {
+                let caller = Address::new(vm.memory().read_bytes(b)?);
+                vm.ecal_state_mut().environment.caller = Some(caller);
+            }
+            MINT_TO_ADDRESS => {
+                let address = Address::new(vm.memory().read_bytes(b)?);
+                let asset_id = AssetId::new(vm.memory().read_bytes(c)?);
+                vm.ecal_state_mut()
+                    .environment
+                    .coins
+                    .push((address, asset_id, d));
+            }
+            MINT_TO_CONTRACT | STORE | LOAD => {
+                // Executed by `execute_storage_cheatcode` before the `ecal` instruction.
+                vm.ecal_state_mut()
+                    .storage_cheatcode
+                    .take()
+                    .unwrap_or(Err(PanicReason::EcalError))?;
+            }
+            EXPECT_LOG => {
+                let expected = vm.memory().read(b, c)?.to_vec();
+                vm.ecal_state_mut().expected_logs.push(expected);
+            }
+            PREDICATE_COIN => {
+                let asset_id = AssetId::new(vm.memory().read_bytes(b)?);
+                predicate_transaction(vm).coins.push((asset_id, c));
+            }
+            PREDICATE_MESSAGE => {
+                let data = vm.memory().read(c, d)?.to_vec();
+                predicate_transaction(vm).messages.push((b, data));
+            }
+            PREDICATE_DATA => {
+                let data = vm.memory().read(b, c)?.to_vec();
+                predicate_transaction(vm).predicate_data = data;
+            }
+            ADD_WITNESS => {
+                let witness = vm.memory().read(b, c)?.to_vec();
+                predicate_transaction(vm).witnesses.push(witness);
+            }
+            ADD_OUTPUT => {
+                let to = Address::new(vm.memory().read_bytes(b)?);
+                let asset_id = AssetId::new(vm.memory().read_bytes(c)?);
+                predicate_transaction(vm).outputs.push((to, asset_id, d));
+            }
+            ADD_CHANGE_OUTPUT => {
+                let to = Address::new(vm.memory().read_bytes(b)?);
+                let asset_id = AssetId::new(vm.memory().read_bytes(c)?);
+                predicate_transaction(vm)
+                    .change_outputs
+                    .push((to, asset_id));
+            }
+            VERIFY_PREDICATE => {
+                // The predicate is not verified until the test is executed again with the
+                // declared transaction.
+                let verified = vm
+                    .ecal_state()
+                    .predicate_result
+                    .as_ref()
+                    .is_some_and(|result| result.verified);
+                vm.memory_mut()
+                    .write_noownerchecks(b, 8)?
+                    .copy_from_slice(&u64::from(verified).to_be_bytes());
+            }
+            _ => return Err(PanicReason::InvalidImmediateValue.into()),
+        }
+        Ok(())
+    }
+}
+
+/// The transaction spending the predicate under test requested by the cheatcodes executed so far.
+fn predicate_transaction<M, S, Tx>(
+    vm: &mut Interpreter<M, S, Tx, CheatcodeEcal>,
+) -> &mut PredicateTransaction {
+    vm.ecal_state_mut()
+        .environment
+        .predicate
+        .get_or_insert_with(Default::default)
+}
+
+/// Executes the storage cheatcode of the `ecal` instruction the VM is about to execute, if any.
+///
+/// The outcome is recorded for the `ecal` handler, which fails the instruction if the cheatcode
+/// failed.
+pub fn execute_storage_cheatcode<M, S, Tx>(vm: &mut Interpreter<M, S, Tx, CheatcodeEcal>)
+where
+    M: Memory,
+    S: InterpreterStorage,
+{
+    let pc = vm.registers()[RegId::PC];
+    let instruction = vm
+        .memory()
+        .read_bytes(pc)
+        .ok()
+        .and_then(|bytes: [u8; Instruction::SIZE]| Instruction::try_from(bytes).ok());
+    let Some(Instruction::ECAL(ecal)) = instruction else {
+        return;
+    };
+    let (a, b, c, d) = ecal.unpack();
+    let [id, b, c, d] = [a, b, c, d].map(|reg| vm.registers()[reg]);
+    let outcome = match id {
+        MINT_TO_CONTRACT => mint_to_contract(vm, b, c, d),
+        STORE => store(vm, b, c, d),
+        LOAD => load(vm, b, c, d),
+        _ => return,
+    };
+    vm.ecal_state_mut().storage_cheatcode = Some(outcome);
+}
+
+fn mint_to_contract<M, S, Tx>(
+    vm: &mut Interpreter<M, S, Tx, CheatcodeEcal>,
+    b: u64,
+    c: u64,
+    amount: u64,
+) -> Result<(), PanicReason>
+where
+    M: Memory,
+    S: InterpreterStorage,
+{
+    let contract_id = ContractId::new(vm.memory().read_bytes(b)?);
+    let asset_id = AssetId::new(vm.memory().read_bytes(c)?);
+    let storage = AsMut::<S>::as_mut(vm);
+    let balance = storage
+        .contract_asset_id_balance(&contract_id, &asset_id)
+        .map_err(|_| PanicReason::ContractNotFound)?
+        .unwrap_or_default()
+        .checked_add(amount)
+        .ok_or(PanicReason::ArithmeticOverflow)?;
+    storage
+        .contract_asset_id_balance_insert(&contract_id, &asset_id, balance)
+        .map_err(|_| PanicReason::ContractNotFound)
+}
+
+fn store<M, S, Tx>(
+    vm: &mut Interpreter<M, S, Tx, CheatcodeEcal>,
+    b: u64,
+    c: u64,
+    d: u64,
+) -> Result<(), PanicReason>
+where
+    M: Memory,
+    S: InterpreterStorage,
+{
+    let contract_id = ContractId::new(vm.memory().read_bytes(b)?);
+    let key = Bytes32::new(vm.memory().read_bytes(c)?);
+    let value: [u8; 32] = vm.memory().read_bytes(d)?;
+    AsMut::<S>::as_mut(vm)
+        .contract_state_insert(&contract_id, &key, &value)
+        .map_err(|_| PanicReason::ContractNotFound)
+}
+
+fn load<M, S, Tx>(
+    vm: &mut Interpreter<M, S, Tx, CheatcodeEcal>,
+    b: u64,
+    c: u64,
+    d: u64,
+) -> Result<(), PanicReason>
+where
+    M: Memory,
+    S: InterpreterStorage,
+{
+    let contract_id = ContractId::new(vm.memory().read_bytes(b)?);
+    let key = Bytes32::new(vm.memory().read_bytes(c)?);
+    let mut value = [0u8; 32];
+    if let Some(data) = AsRef::<S>::as_ref(vm)
+        .contract_state(&contract_id, &key)
+        .map_err(|_| PanicReason::ContractNotFound)?
+    {
+        let data: &[u8] = data.as_ref().as_ref();
+        let len = data.len().min(value.len());
+        value[..len].copy_from_slice(&data[..len]);
+    }
+    vm.memory_mut()
+        .write_noownerchecks(d, value.len())?
+        .copy_from_slice(&value);
+    Ok(())
+}
+
+/// Whether the given bytecode may execute cheatcodes, in which case the VM must stop before each
+/// instruction for [execute_storage_cheatcode] to run.
+pub fn uses_cheatcodes(bytecode: &[u8]) -> bool {
+    fuel_vm::fuel_asm::from_bytes(bytecode.iter().copied())
+        .any(|instruction| matches!(instruction, Ok(Instruction::ECAL(_))))
+}
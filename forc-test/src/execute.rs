@@ -1,5 +1,8 @@
+use crate::coverage::InstructionHits;
+use crate::ecal::{self, CheatcodeEcal, TestEnvironment};
 use crate::maxed_consensus_params;
 use crate::setup::TestSetup;
+use crate::trace::{TraceContext, TraceRecorder};
 use crate::TestResult;
 use crate::TEST_METADATA_SEED;
 use forc_pkg::PkgTestEntry;
@@ -7,31 +10,59 @@ use fuel_tx::{self as tx, output::contract::Contract, Chargeable, Finalizable};
 use fuel_vm::error::InterpreterError;
 use fuel_vm::fuel_asm;
 use fuel_vm::prelude::Instruction;
+use fuel_vm::prelude::PanicReason;
 use fuel_vm::prelude::RegId;
 use fuel_vm::{
     self as vm,
     checked_transaction::builder::TransactionBuilderExt,
-    interpreter::{Interpreter, NotSupportedEcal},
+    interpreter::Interpreter,
     prelude::SecretKey,
-    storage::MemoryStorage,
+    storage::{InterpreterStorage, MemoryStorage},
 };
 use rand::{Rng, SeedableRng};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use tx::Receipt;
 
 use vm::interpreter::{InterpreterParams, MemoryInstance};
 use vm::state::DebugEval;
+use vm::state::ExecuteState;
 use vm::state::ProgramState;
 
+/// The maximum number of times a test is executed again in the environment requested by its
+/// cheatcodes.
+const MAX_ENVIRONMENT_CHANGES: usize = 4;
+
 /// An interface for executing a test within a VM [Interpreter] instance.
 #[derive(Debug, Clone)]
 pub struct TestExecutor {
-    pub interpreter: Interpreter<MemoryInstance, MemoryStorage, tx::Script, NotSupportedEcal>,
+    pub interpreter: Interpreter<MemoryInstance, MemoryStorage, tx::Script, CheatcodeEcal>,
     pub tx: vm::checked_transaction::Ready<tx::Script>,
     pub test_entry: PkgTestEntry,
     pub name: String,
     pub jump_instruction_index: usize,
     pub relative_jump_in_bytes: u32,
+    /// The environment of the transaction executing the test.
+    pub environment: TestEnvironment,
+    /// Whether to record the instructions executed by the test, for code coverage.
+    pub coverage: bool,
+    /// The maximum time the test may run for, including its executions in other environments.
+    pub timeout: Option<Duration>,
+    /// The programs the test may execute, if its execution is traced.
+    pub trace: Option<Arc<TraceContext>>,
+    /// The bytecode of the tested predicate built without tests, if the tested package is a
+    /// predicate.
+    pub predicate_bytecode: Option<Arc<Vec<u8>>>,
+    /// The gas limit of the script executing the test.
+    script_gas_limit: u64,
+    /// The inputs the executor was built from, used to rebuild it in another environment.
+    bytecode: Vec<u8>,
+    test_instruction_index: u32,
+    test_setup: TestSetup,
+    script_input_data: Vec<u8>,
 }
 
 /// The result of executing a test with breakpoints enabled.
@@ -51,14 +82,60 @@ impl TestExecutor {
         test_entry: &PkgTestEntry,
         name: String,
     ) -> anyhow::Result<Self> {
-        let storage = test_setup.storage().clone();
+        Self::build_with_script_data(
+            bytecode,
+            test_instruction_index,
+            test_setup,
+            test_entry,
+            name,
+            vec![],
+        )
+    }
+
+    /// Builds the executor of a test which receives the given script data.
+    ///
+    /// Parameterized tests decode their arguments from the script data.
+    pub fn build_with_script_data(
+        bytecode: &[u8],
+        test_instruction_index: u32,
+        test_setup: TestSetup,
+        test_entry: &PkgTestEntry,
+        name: String,
+        script_input_data: Vec<u8>,
+    ) -> anyhow::Result<Self> {
+        Self::build_in_environment(
+            bytecode,
+            test_instruction_index,
+            test_setup,
+            test_entry,
+            name,
+            script_input_data,
+            TestEnvironment::default(),
+        )
+    }
+
+    /// Builds the executor of a test in the given environment.
+    ///
+    /// Tests change their environment through cheatcodes, e.g. `std::test::set_block_height`.
+    pub fn build_in_environment(
+        bytecode: &[u8],
+        test_instruction_index: u32,
+        test_setup: TestSetup,
+        test_entry: &PkgTestEntry,
+        name: String,
+        script_input_data: Vec<u8>,
+        environment: TestEnvironment,
+    ) -> anyhow::Result<Self> {
+        let mut storage = test_setup.storage().clone();
+        if let Some(block_height) = environment.block_height {
+            storage.set_block_height(block_height.into());
+        }
 
         // Find the instruction which we will jump into the
         // specified test
         let jump_instruction_index = find_jump_instruction_index(bytecode);
 
         // Create a transaction to execute the test function.
-        let script_input_data = vec![];
         let rng = &mut rand::rngs::StdRng::seed_from_u64(TEST_METADATA_SEED);
 
         // Prepare the transaction metadata.
@@ -74,16 +151,39 @@ impl TestExecutor {
         let block_height = (u32::MAX >> 1).into();
         let gas_price = 0;
 
-        let mut tx_builder = tx::TransactionBuilder::script(bytecode.to_vec(), script_input_data);
+        let mut tx_builder =
+            tx::TransactionBuilder::script(bytecode.to_vec(), script_input_data.clone());
 
         let params = maxed_consensus_params();
 
-        tx_builder
-            .with_params(params)
-            .add_unsigned_coin_input(secret_key, utxo_id, amount, asset_id, tx_pointer)
-            .maturity(maturity);
+        tx_builder.with_params(params).maturity(maturity);
+
+        // Coins owned by addresses set through cheatcodes cannot be signed, and share an empty
+        // witness.
+        let unsigned_coins = environment.caller.is_some() || !environment.coins.is_empty();
+        if unsigned_coins {
+            tx_builder.add_witness(tx::Witness::default());
+        }
+        match environment.caller {
+            Some(caller) => tx_builder.add_input(tx::Input::coin_signed(
+                utxo_id, caller, amount, asset_id, tx_pointer, 0,
+            )),
+            None => tx_builder
+                .add_unsigned_coin_input(secret_key, utxo_id, amount, asset_id, tx_pointer),
+        };
+        for (owner, asset_id, amount) in &environment.coins {
+            tx_builder.add_input(tx::Input::coin_signed(
+                rng.gen(),
+                *owner,
+                *amount,
+                *asset_id,
+                tx_pointer,
+                0,
+            ));
+        }
 
-        let mut output_index = 1;
+        // Contract inputs follow the coin inputs.
+        let mut output_index = 1 + environment.coins.len() as u16;
         // Insert contract ids into tx input
         for contract_id in test_setup.contract_ids() {
             tx_builder
@@ -108,14 +208,24 @@ impl TestExecutor {
         // Get `max_gas` used by everything except the script execution. Add `1` because of rounding.
         let max_gas =
             tmp_tx.max_gas(consensus_params.gas_costs(), consensus_params.fee_params()) + 1;
-        // Increase `script_gas_limit` to the maximum allowed value.
-        tx_builder.script_gas_limit(consensus_params.tx_params().max_gas_per_tx() - max_gas);
+        // Increase `script_gas_limit` to the maximum allowed value, unless the test requests a
+        // lower limit through `#[test(gas_limit = "<gas>")]`.
+        let max_script_gas_limit = consensus_params.tx_params().max_gas_per_tx() - max_gas;
+        let script_gas_limit = test_entry
+            .gas_limit
+            .map_or(max_script_gas_limit, |gas_limit| {
+                gas_limit.min(max_script_gas_limit)
+            });
+        tx_builder.script_gas_limit(script_gas_limit);
 
         // We need to increase the tx size limit as the default is 110 * 1024 and for big tests
         // such as std and core this is not enough.
 
-        let tx = tx_builder
-            .finalize_checked(block_height)
+        let checked_tx = match unsigned_coins {
+            true => tx_builder.finalize_checked_basic(block_height),
+            false => tx_builder.finalize_checked(block_height),
+        };
+        let tx = checked_tx
             .into_ready(
                 gas_price,
                 consensus_params.gas_costs(),
@@ -125,7 +235,12 @@ impl TestExecutor {
 
         let interpreter_params = InterpreterParams::new(gas_price, &consensus_params);
         let memory_instance = MemoryInstance::new();
-        let interpreter = Interpreter::with_storage(memory_instance, storage, interpreter_params);
+        let interpreter = Interpreter::with_storage_and_ecal(
+            memory_instance,
+            storage,
+            interpreter_params,
+            CheatcodeEcal::default(),
+        );
 
         Ok(TestExecutor {
             interpreter,
@@ -135,9 +250,47 @@ impl TestExecutor {
             jump_instruction_index,
             relative_jump_in_bytes: (test_instruction_index - jump_instruction_index as u32)
                 * Instruction::SIZE as u32,
+            environment,
+            coverage: false,
+            timeout: None,
+            trace: None,
+            predicate_bytecode: None,
+            script_gas_limit,
+            bytecode: bytecode.to_vec(),
+            test_instruction_index,
+            test_setup,
+            script_input_data,
         })
     }
 
+    /// Rebuilds the executor in the given environment.
+    fn rebuild_in_environment(&self, environment: TestEnvironment) -> anyhow::Result<Self> {
+        let mut executor = Self::build_in_environment(
+            &self.bytecode,
+            self.test_instruction_index,
+            self.test_setup.clone(),
+            &self.test_entry,
+            self.name.clone(),
+            self.script_input_data.clone(),
+            environment,
+        )?;
+        executor.coverage = self.coverage;
+        executor.timeout = self.timeout;
+        executor.trace = self.trace.clone();
+        executor.predicate_bytecode = self.predicate_bytecode.clone();
+        // The predicate under test is verified against the transaction declared by the test, and
+        // the result is read by the test through `std::test::verify_predicate`.
+        if let (Some(predicate), Some(bytecode)) = (
+            &executor.environment.predicate,
+            &executor.predicate_bytecode,
+        ) {
+            let storage: &MemoryStorage = executor.interpreter.as_ref();
+            let predicate_result = predicate.verify(bytecode, &executor.environment.coins, storage);
+            executor.interpreter.ecal_state_mut().predicate_result = Some(predicate_result);
+        }
+        Ok(executor)
+    }
+
     // single-step until the jump-to-test instruction, then
     // jump into the first instruction of the test
     fn single_step_until_test(&mut self) -> ProgramState {
@@ -198,6 +351,9 @@ impl TestExecutor {
 
         let duration = start.elapsed();
         let (gas_used, logs) = Self::get_gas_and_receipts(self.interpreter.receipts().to_vec())?;
+        let out_of_gas = self.ran_out_of_gas();
+        let unmet_expectations = self.unmet_expectations();
+        let predicate = self.interpreter.ecal_state().predicate_result.clone();
         let span = self.test_entry.span.clone();
         let file_path = self.test_entry.file_path.clone();
         let condition = self.test_entry.pass_condition.clone();
@@ -211,6 +367,13 @@ impl TestExecutor {
             condition,
             logs,
             gas_used,
+            fuzz: None,
+            unmet_expectations,
+            predicate,
+            coverage: None,
+            out_of_gas,
+            timed_out: false,
+            trace: None,
         }))
     }
 
@@ -229,6 +392,9 @@ impl TestExecutor {
         }
         let duration = start.elapsed();
         let (gas_used, logs) = Self::get_gas_and_receipts(self.interpreter.receipts().to_vec())?; // TODO: calculate culumlative
+        let out_of_gas = self.ran_out_of_gas();
+        let unmet_expectations = self.unmet_expectations();
+        let predicate = self.interpreter.ecal_state().predicate_result.clone();
         let span = self.test_entry.span.clone();
         let file_path = self.test_entry.file_path.clone();
         let condition = self.test_entry.pass_condition.clone();
@@ -242,32 +408,104 @@ impl TestExecutor {
             condition,
             logs,
             gas_used,
+            fuzz: None,
+            unmet_expectations,
+            predicate,
+            coverage: None,
+            out_of_gas,
+            timed_out: false,
+            trace: None,
         }))
     }
 
+    /// Execute the test.
+    ///
+    /// If the cheatcodes executed by the test request another environment, the test is executed
+    /// again in that environment.
+    ///
+    /// If a timeout is set, the test is stopped once it runs for longer than the timeout.
     pub fn execute(&mut self) -> anyhow::Result<TestResult> {
-        let start = std::time::Instant::now();
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let mut result = self.execute_in_environment(deadline)?;
+        for _ in 0..MAX_ENVIRONMENT_CHANGES {
+            let requested = self.interpreter.ecal_state().environment.clone();
+            if requested == self.environment || result.timed_out {
+                break;
+            }
+            *self = self.rebuild_in_environment(requested)?;
+            result = self.execute_in_environment(deadline)?;
+        }
+        Ok(result)
+    }
+
+    fn execute_in_environment(&mut self, deadline: Option<Instant>) -> anyhow::Result<TestResult> {
+        let start = Instant::now();
+
+        // Recording the executed instructions, tracing, checking the deadline and executing the
+        // storage cheatcodes require executing the instructions one at a time.
+        let mut coverage = self.coverage.then(InstructionHits::default);
+        let mut trace = self
+            .trace
+            .clone()
+            .map(|context| TraceRecorder::new(context, &self.name));
+        let storage_cheatcodes = ecal::uses_cheatcodes(&self.bytecode);
+        let stepping =
+            coverage.is_some() || trace.is_some() || deadline.is_some() || storage_cheatcodes;
+        let mut timed_out = false;
+        let mut panic_reason = None;
 
         let mut state = Ok(self.single_step_until_test());
 
         // Run test until its end
         loop {
             match state {
-                Err(_) => {
+                Err(error) => {
+                    // The VM only records a panic in the receipts at the end of the transaction,
+                    // which is never reached when resuming or stepping through it.
+                    panic_reason = InterpreterError::panic_reason(&error);
                     state = Ok(ProgramState::Revert(0));
                     break;
                 }
                 Ok(
                     ProgramState::Return(_) | ProgramState::ReturnData(_) | ProgramState::Revert(_),
                 ) => break,
-                Ok(ProgramState::RunProgram(_) | ProgramState::VerifyPredicate(_)) => {
+                Ok(ProgramState::RunProgram(_) | ProgramState::VerifyPredicate(_)) if !stepping => {
                     state = self.interpreter.resume();
                 }
+                Ok(ProgramState::RunProgram(_) | ProgramState::VerifyPredicate(_)) => {
+                    if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                        timed_out = true;
+                        break;
+                    }
+                    if storage_cheatcodes {
+                        ecal::execute_storage_cheatcode(&mut self.interpreter);
+                    }
+                    let (contract_id, pc) = self.location();
+                    if let Some(coverage) = &mut coverage {
+                        coverage.record(contract_id, pc);
+                    }
+                    if let Some(trace) = &mut trace {
+                        trace.step(&self.interpreter, contract_id, pc);
+                    }
+                    state = self.execute_instruction();
+                }
             }
         }
 
         let duration = start.elapsed();
-        let (gas_used, logs) = Self::get_gas_and_receipts(self.interpreter.receipts().to_vec())?;
+        let (gas_used, logs) = match timed_out {
+            // A test stopped before its end has no script result, its gas used is read from the
+            // remaining gas.
+            true => (
+                self.script_gas_limit - self.interpreter.registers()[RegId::GGAS],
+                Self::retain_logs(self.interpreter.receipts().to_vec()),
+            ),
+            false => Self::get_gas_and_receipts(self.interpreter.receipts().to_vec())?,
+        };
+        let out_of_gas = panic_reason == Some(PanicReason::OutOfGas) || self.ran_out_of_gas();
+        let trace = trace.map(|trace| trace.finish(&self.interpreter));
+        let unmet_expectations = self.unmet_expectations();
+        let predicate = self.interpreter.ecal_state().predicate_result.clone();
         let span = self.test_entry.span.clone();
         let file_path = self.test_entry.file_path.clone();
         let condition = self.test_entry.pass_condition.clone();
@@ -281,9 +519,69 @@ impl TestExecutor {
             condition,
             logs,
             gas_used,
+            fuzz: None,
+            unmet_expectations,
+            predicate,
+            coverage,
+            out_of_gas,
+            timed_out,
+            trace,
+        })
+    }
+
+    /// Executes the next instruction of the test.
+    ///
+    /// The VM never stops within a contract call when single stepping, so the instructions are
+    /// executed one at a time instead, ending the execution the way the VM does.
+    fn execute_instruction(
+        &mut self,
+    ) -> Result<ProgramState, InterpreterError<<MemoryStorage as InterpreterStorage>::DataError>>
+    {
+        let in_call = self.interpreter.registers()[RegId::FP] != 0;
+        self.interpreter
+            .execute()
+            .map(|execute_state| match execute_state {
+                // Only reverts end the execution from a call context.
+                ExecuteState::Revert(value) => ProgramState::Revert(value),
+                ExecuteState::Return(value) if !in_call => ProgramState::Return(value),
+                ExecuteState::ReturnData(digest) if !in_call => ProgramState::ReturnData(digest),
+                _ => ProgramState::RunProgram(DebugEval::Continue),
+            })
+    }
+
+    /// The contract executing the next instruction, or the zero contract id for the test itself,
+    /// and the offset of the instruction within its bytecode.
+    fn location(&self) -> (tx::ContractId, u64) {
+        let registers = self.interpreter.registers();
+        // Within a contract call, the frame pointer points to the call frame, which starts with
+        // the id of the called contract.
+        let contract_id = match registers[RegId::FP] {
+            0 => tx::ContractId::zeroed(),
+            fp => self
+                .interpreter
+                .memory()
+                .read_bytes(fp)
+                .map(tx::ContractId::new)
+                .unwrap_or_default(),
+        };
+        let pc = registers[RegId::PC].saturating_sub(registers[RegId::IS]);
+        (contract_id, pc)
+    }
+
+    /// Whether the test panicked because it ran out of gas.
+    fn ran_out_of_gas(&self) -> bool {
+        self.interpreter.receipts().iter().any(|receipt| {
+            matches!(receipt, Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::OutOfGas)
         })
     }
 
+    /// Describes the expectations set through cheatcodes which the test did not meet.
+    fn unmet_expectations(&self) -> Vec<String> {
+        self.interpreter
+            .ecal_state()
+            .unmet_expectations(self.interpreter.receipts())
+    }
+
     fn get_gas_and_receipts(receipts: Vec<Receipt>) -> anyhow::Result<(u64, Vec<Receipt>)> {
         let gas_used = *receipts
             .iter()
@@ -293,15 +591,18 @@ impl TestExecutor {
             })
             .ok_or_else(|| anyhow::anyhow!("missing used gas information from test execution"))?;
 
-        // Only retain `Log` and `LogData` receipts.
-        let logs = receipts
+        Ok((gas_used, Self::retain_logs(receipts)))
+    }
+
+    /// Only retain `Log` and `LogData` receipts.
+    fn retain_logs(receipts: Vec<Receipt>) -> Vec<Receipt> {
+        receipts
             .into_iter()
             .filter(|receipt| {
                 matches!(receipt, tx::Receipt::Log { .. })
                     || matches!(receipt, tx::Receipt::LogData { .. })
             })
-            .collect();
-        Ok((gas_used, logs))
+            .collect()
     }
 }
 
@@ -0,0 +1,544 @@
+//! Support for parameterized tests, i.e. tests declared with `#[test(fuzz)]`.
+//!
+//! The arguments of a fuzz test are generated from the ABI types of its parameters, encoded with
+//! the same ABI encoder used for calling scripts, and provided to the test as its script data. If
+//! a run fails, its input is shrunk to a minimal input which still makes the test fail.
+
+use crate::coverage::InstructionHits;
+use crate::TestResult;
+use fuel_abi_types::abi::unified_program::UnifiedProgramABI;
+use fuels_core::codec::{ABIDecoder, ABIEncoder, EncoderConfig};
+use fuels_core::types::param_types::ParamType;
+use fuels_core::types::{StaticStringToken, Token, U256};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashMap;
+
+/// The number of runs of a fuzz test, if neither the test nor the options specify it.
+pub const DEFAULT_FUZZ_RUNS: u64 = 256;
+/// The maximum number of inputs tried while shrinking a failing input.
+pub const DEFAULT_MAX_SHRINK_ITERS: u64 = 1024;
+/// The maximum length of generated dynamically sized values, e.g. vectors and strings.
+const MAX_DYNAMIC_LEN: usize = 8;
+
+/// The set of options controlling the execution of fuzz tests.
+#[derive(Debug, Clone)]
+pub struct FuzzOpts {
+    /// Overrides the number of runs of every fuzz test.
+    pub runs: Option<u64>,
+    /// The seed of the first run. Run `i` uses the seed `seed + i`.
+    ///
+    /// If not set, a random seed is used.
+    pub seed: Option<u64>,
+    /// The maximum number of inputs tried while shrinking a failing input.
+    pub max_shrink_iters: u64,
+}
+
+/// The result of running a fuzz test.
+#[derive(Debug, Clone)]
+pub struct FuzzResult {
+    /// The number of executed runs.
+    pub runs: u64,
+    /// The seed of the first run.
+    pub seed: u64,
+    /// Present if one of the runs failed.
+    pub failure: Option<FuzzFailure>,
+}
+
+/// A failing run of a fuzz test.
+#[derive(Debug, Clone)]
+pub struct FuzzFailure {
+    /// The seed of the failing run.
+    ///
+    /// Running the test with this seed as `--fuzz-seed` reproduces the failure in the first run.
+    pub seed: u64,
+    /// The generated input of the failing run.
+    pub input: Vec<FuzzArg>,
+    /// The minimal input found by shrinking the generated input, which still fails the test.
+    pub minimal_input: Vec<FuzzArg>,
+    /// The number of successful shrinking steps.
+    pub shrink_steps: u64,
+}
+
+/// A single argument of a fuzz test.
+#[derive(Debug, Clone)]
+pub struct FuzzArg {
+    /// The name of the parameter.
+    pub name: String,
+    /// The decoded, human readable value of the argument.
+    pub value: String,
+}
+
+/// The parameters of a fuzz test, in the order of their declaration.
+#[derive(Debug, Clone)]
+pub struct FuzzParams {
+    params: Vec<(String, ParamType)>,
+}
+
+impl Default for FuzzOpts {
+    fn default() -> Self {
+        Self {
+            runs: None,
+            seed: None,
+            max_shrink_iters: DEFAULT_MAX_SHRINK_ITERS,
+        }
+    }
+}
+
+impl FuzzParams {
+    /// Collects the parameters of the single test function described by the given ABI.
+    pub fn from_abi(abi: &fuel_abi_types::abi::program::ProgramABI) -> anyhow::Result<Self> {
+        let abi = UnifiedProgramABI::from_counterpart(abi)?;
+        let type_lookup = abi
+            .types
+            .iter()
+            .map(|decl| (decl.type_id, decl.clone()))
+            .collect::<HashMap<_, _>>();
+        let function = abi
+            .functions
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("fuzz test ABI is missing the test function"))?;
+        let params = function
+            .inputs
+            .iter()
+            .map(|input| {
+                let param_type = ParamType::try_from_type_application(input, &type_lookup)?;
+                Ok((input.name.clone(), param_type))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Self { params })
+    }
+
+    /// Generates a random input for the parameters.
+    pub fn generate(&self, rng: &mut StdRng) -> anyhow::Result<Vec<Token>> {
+        self.params
+            .iter()
+            .map(|(_, param_type)| generate_token(param_type, rng))
+            .collect()
+    }
+
+    /// Encodes the input as the script data expected by the generated test entry.
+    pub fn encode(&self, input: &[Token]) -> anyhow::Result<Vec<u8>> {
+        let abi_encoder = ABIEncoder::new(EncoderConfig::default());
+        Ok(abi_encoder.encode(input)?)
+    }
+
+    /// Returns the human readable form of the input.
+    pub fn describe(&self, input: &[Token]) -> anyhow::Result<Vec<FuzzArg>> {
+        let abi_decoder = ABIDecoder::default();
+        self.params
+            .iter()
+            .zip(input)
+            .map(|((name, param_type), token)| {
+                let bytes = self.encode(std::slice::from_ref(token))?;
+                let value = abi_decoder.decode_as_debug_str(param_type, bytes.as_slice())?;
+                Ok(FuzzArg {
+                    name: name.clone(),
+                    value,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the inputs which are simpler than the given one by changing a single argument.
+    fn shrink(&self, input: &[Token]) -> Vec<Vec<Token>> {
+        input
+            .iter()
+            .enumerate()
+            .flat_map(|(ix, token)| {
+                shrink_token(token).into_iter().map(move |shrunk| {
+                    let mut shrunk_input = input.to_vec();
+                    shrunk_input[ix] = shrunk;
+                    shrunk_input
+                })
+            })
+            .collect()
+    }
+}
+
+/// Runs a fuzz test, executing each run with `execute`, which receives the encoded arguments.
+///
+/// Returns the result of the minimal failing run if one of the runs failed, otherwise the result
+/// of the last run. In both cases the duration is the total duration of all the executions, and
+/// the coverage is the one of all the executions.
+pub fn run_fuzz_test(
+    params: &FuzzParams,
+    runs: u64,
+    opts: &FuzzOpts,
+    mut execute: impl FnMut(Vec<u8>) -> anyhow::Result<TestResult>,
+) -> anyhow::Result<TestResult> {
+    let seed = opts.seed.unwrap_or_else(rand::random);
+    let mut duration = std::time::Duration::ZERO;
+    let mut coverage: Option<InstructionHits> = None;
+    let mut merge_coverage = |result: &TestResult| {
+        if let Some(hits) = &result.coverage {
+            coverage.get_or_insert_with(Default::default).merge(hits);
+        }
+    };
+    let mut last_result = None;
+    for run in 0..runs.max(1) {
+        let run_seed = seed.wrapping_add(run);
+        let rng = &mut StdRng::seed_from_u64(run_seed);
+        let input = params.generate(rng)?;
+        let result = execute(params.encode(&input)?)?;
+        duration += result.duration;
+        merge_coverage(&result);
+        if result.passed() {
+            last_result = Some(result);
+            continue;
+        }
+
+        // Shrink the failing input, as long as a simpler input still fails the test.
+        let mut minimal_input = input.clone();
+        let mut minimal_result = result;
+        let mut shrink_steps = 0;
+        let mut shrink_iters = 0;
+        'shrinking: loop {
+            for candidate in params.shrink(&minimal_input) {
+                if shrink_iters == opts.max_shrink_iters {
+                    break 'shrinking;
+                }
+                shrink_iters += 1;
+                let result = execute(params.encode(&candidate)?)?;
+                duration += result.duration;
+                merge_coverage(&result);
+                if !result.passed() {
+                    minimal_input = candidate;
+                    minimal_result = result;
+                    shrink_steps += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        let failure = FuzzFailure {
+            seed: run_seed,
+            input: params.describe(&input)?,
+            minimal_input: params.describe(&minimal_input)?,
+            shrink_steps,
+        };
+        return Ok(TestResult {
+            duration,
+            fuzz: Some(FuzzResult {
+                runs: run + 1,
+                seed,
+                failure: Some(failure),
+            }),
+            coverage,
+            ..minimal_result
+        });
+    }
+
+    let last_result = last_result.expect("fuzz tests execute at least one run");
+    Ok(TestResult {
+        duration,
+        fuzz: Some(FuzzResult {
+            runs: runs.max(1),
+            seed,
+            failure: None,
+        }),
+        coverage,
+        ..last_result
+    })
+}
+
+/// Generates a random value of the given type.
+///
+/// Integers are biased towards edge cases, as those are more likely to reveal bugs.
+fn generate_token(param_type: &ParamType, rng: &mut StdRng) -> anyhow::Result<Token> {
+    /// Picks one of the edge cases with a probability of one in four.
+    fn edge_case<T: Copy>(rng: &mut StdRng, edge_cases: &[T]) -> Option<T> {
+        rng.gen_ratio(1, 4)
+            .then(|| edge_cases[rng.gen_range(0..edge_cases.len())])
+    }
+
+    let token = match param_type {
+        ParamType::Unit => Token::Unit,
+        ParamType::Bool => Token::Bool(rng.gen()),
+        ParamType::U8 => Token::U8(edge_case(rng, &[0, 1, u8::MAX]).unwrap_or_else(|| rng.gen())),
+        ParamType::U16 => {
+            Token::U16(edge_case(rng, &[0, 1, u16::MAX]).unwrap_or_else(|| rng.gen()))
+        }
+        ParamType::U32 => {
+            Token::U32(edge_case(rng, &[0, 1, u32::MAX]).unwrap_or_else(|| rng.gen()))
+        }
+        ParamType::U64 => {
+            Token::U64(edge_case(rng, &[0, 1, u64::MAX]).unwrap_or_else(|| rng.gen()))
+        }
+        ParamType::U128 => {
+            Token::U128(edge_case(rng, &[0, 1, u128::MAX]).unwrap_or_else(|| rng.gen()))
+        }
+        ParamType::U256 => {
+            let bytes = edge_case(rng, &[[0u8; 32], [u8::MAX; 32]]).unwrap_or_else(|| rng.gen());
+            Token::U256(U256::from(bytes))
+        }
+        ParamType::B256 => Token::B256(rng.gen()),
+        ParamType::Bytes => Token::Bytes(generate_bytes(rng)),
+        ParamType::RawSlice => Token::RawSlice(generate_bytes(rng)),
+        ParamType::String => Token::String(generate_string(rng, None)),
+        ParamType::StringSlice => {
+            Token::StringSlice(StaticStringToken::new(generate_string(rng, None), None))
+        }
+        ParamType::StringArray(len) => Token::StringArray(StaticStringToken::new(
+            generate_string(rng, Some(*len)),
+            Some(*len),
+        )),
+        ParamType::Tuple(param_types) => Token::Tuple(
+            param_types
+                .iter()
+                .map(|param_type| generate_token(param_type, rng))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        ParamType::Array(param_type, len) => Token::Array(
+            (0..*len)
+                .map(|_| generate_token(param_type, rng))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        ParamType::Vector(param_type) => Token::Vector(
+            (0..rng.gen_range(0..=MAX_DYNAMIC_LEN))
+                .map(|_| generate_token(param_type, rng))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        ParamType::Struct { fields, .. } => Token::Struct(
+            fields
+                .iter()
+                .map(|(_, param_type)| generate_token(param_type, rng))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        ParamType::Enum { enum_variants, .. } => {
+            let variants = enum_variants.variants();
+            let discriminant = rng.gen_range(0..variants.len());
+            let (_, variant_type) = &variants[discriminant];
+            let token = generate_token(variant_type, rng)?;
+            Token::Enum(Box::new((
+                discriminant as u64,
+                token,
+                enum_variants.clone(),
+            )))
+        }
+    };
+    Ok(token)
+}
+
+fn generate_bytes(rng: &mut StdRng) -> Vec<u8> {
+    (0..rng.gen_range(0..=MAX_DYNAMIC_LEN))
+        .map(|_| rng.gen::<u8>())
+        .collect()
+}
+
+/// Generates a string of printable ASCII characters, with the given length if any.
+fn generate_string(rng: &mut StdRng, len: Option<usize>) -> String {
+    let len = len.unwrap_or_else(|| rng.gen_range(0..=MAX_DYNAMIC_LEN));
+    (0..len).map(|_| rng.gen_range(' '..='~')).collect()
+}
+
+/// Returns the values which are simpler than the given one, starting with the simplest.
+fn shrink_token(token: &Token) -> Vec<Token> {
+    /// Shrinks an unsigned integer towards zero.
+    ///
+    /// Besides zero, the candidates get closer to the value by halving the distance to it, so
+    /// that shrinking finds the boundary of a failing range in a logarithmic number of steps.
+    fn shrink_uint<T>(value: T) -> Vec<T>
+    where
+        T: Copy + PartialEq + From<u8> + std::ops::Div<Output = T> + std::ops::Sub<Output = T>,
+    {
+        let zero = T::from(0);
+        let two = T::from(2);
+        if value == zero {
+            return vec![];
+        }
+        let mut shrunk = vec![zero];
+        let mut distance = value / two;
+        while distance != zero {
+            shrunk.push(value - distance);
+            distance = distance / two;
+        }
+        shrunk
+    }
+
+    /// Shrinks a sequence by removing elements, then by shrinking single elements.
+    fn shrink_seq(tokens: &[Token]) -> Vec<Vec<Token>> {
+        let mut shrunk = vec![];
+        if !tokens.is_empty() {
+            shrunk.push(vec![]);
+            shrunk.push(tokens[..tokens.len() / 2].to_vec());
+            shrunk.push(tokens[..tokens.len() - 1].to_vec());
+        }
+        shrunk.dedup();
+        shrunk.extend(shrink_elements(tokens));
+        shrunk
+    }
+
+    /// Shrinks a fixed size sequence by shrinking single elements.
+    fn shrink_elements(tokens: &[Token]) -> Vec<Vec<Token>> {
+        tokens
+            .iter()
+            .enumerate()
+            .flat_map(|(ix, token)| {
+                shrink_token(token).into_iter().map(move |shrunk| {
+                    let mut shrunk_tokens = tokens.to_vec();
+                    shrunk_tokens[ix] = shrunk;
+                    shrunk_tokens
+                })
+            })
+            .collect()
+    }
+
+    fn shrink_bytes(bytes: &[u8]) -> Vec<Vec<u8>> {
+        let tokens = bytes.iter().map(|b| Token::U8(*b)).collect::<Vec<_>>();
+        shrink_seq(&tokens)
+            .into_iter()
+            .map(|tokens| {
+                tokens
+                    .into_iter()
+                    .map(|token| match token {
+                        Token::U8(b) => b,
+                        _ => unreachable!("bytes are shrunk as `u8` tokens"),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Shrinks a string by removing characters, then by replacing characters with 'a'.
+    fn shrink_string(s: &str, fixed_len: bool) -> Vec<String> {
+        let mut shrunk = vec![];
+        let chars = s.chars().collect::<Vec<_>>();
+        if !fixed_len && !chars.is_empty() {
+            shrunk.push(String::new());
+            shrunk.push(chars[..chars.len() - 1].iter().collect());
+        }
+        if let Some(ix) = chars.iter().position(|c| *c != 'a') {
+            let mut simpler = chars.clone();
+            simpler[ix] = 'a';
+            shrunk.push(simpler.into_iter().collect());
+        }
+        shrunk
+    }
+
+    match token {
+        Token::Unit => vec![],
+        Token::Bool(value) => match value {
+            true => vec![Token::Bool(false)],
+            false => vec![],
+        },
+        Token::U8(value) => shrink_uint(*value).into_iter().map(Token::U8).collect(),
+        Token::U16(value) => shrink_uint(*value).into_iter().map(Token::U16).collect(),
+        Token::U32(value) => shrink_uint(*value).into_iter().map(Token::U32).collect(),
+        Token::U64(value) => shrink_uint(*value).into_iter().map(Token::U64).collect(),
+        Token::U128(value) => shrink_uint(*value).into_iter().map(Token::U128).collect(),
+        Token::U256(value) => {
+            if value.is_zero() {
+                return vec![];
+            }
+            let two = U256::from(2u8);
+            let mut shrunk = vec![Token::U256(U256::zero())];
+            let mut distance = *value / two;
+            while !distance.is_zero() {
+                shrunk.push(Token::U256(*value - distance));
+                distance /= two;
+            }
+            shrunk
+        }
+        Token::B256(bytes) => {
+            if bytes.iter().all(|b| *b == 0) {
+                vec![]
+            } else {
+                vec![Token::B256([0u8; 32])]
+            }
+        }
+        Token::Bytes(bytes) => shrink_bytes(bytes).into_iter().map(Token::Bytes).collect(),
+        Token::RawSlice(bytes) => shrink_bytes(bytes)
+            .into_iter()
+            .map(Token::RawSlice)
+            .collect(),
+        Token::String(s) => shrink_string(s, false)
+            .into_iter()
+            .map(Token::String)
+            .collect(),
+        Token::StringSlice(s) => match s.get_encodable_str() {
+            Ok(s) => shrink_string(s, false)
+                .into_iter()
+                .map(|s| Token::StringSlice(StaticStringToken::new(s, None)))
+                .collect(),
+            Err(_) => vec![],
+        },
+        Token::StringArray(s) => match s.get_encodable_str() {
+            Ok(s) => {
+                let len = s.len();
+                shrink_string(s, true)
+                    .into_iter()
+                    .map(|s| Token::StringArray(StaticStringToken::new(s, Some(len))))
+                    .collect()
+            }
+            Err(_) => vec![],
+        },
+        Token::Tuple(tokens) => shrink_elements(tokens)
+            .into_iter()
+            .map(Token::Tuple)
+            .collect(),
+        Token::Array(tokens) => shrink_elements(tokens)
+            .into_iter()
+            .map(Token::Array)
+            .collect(),
+        Token::Vector(tokens) => shrink_seq(tokens).into_iter().map(Token::Vector).collect(),
+        Token::Struct(tokens) => shrink_elements(tokens)
+            .into_iter()
+            .map(Token::Struct)
+            .collect(),
+        Token::Enum(selector) => {
+            let (discriminant, token, variants) = &**selector;
+            shrink_token(token)
+                .into_iter()
+                .map(|shrunk| Token::Enum(Box::new((*discriminant, shrunk, variants.clone()))))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrink_uint_towards_zero() {
+        assert_eq!(
+            shrink_token(&Token::U64(10)),
+            vec![Token::U64(0), Token::U64(5), Token::U64(8), Token::U64(9)]
+        );
+        assert_eq!(shrink_token(&Token::U8(1)), vec![Token::U8(0)]);
+        assert!(shrink_token(&Token::U32(0)).is_empty());
+    }
+
+    #[test]
+    fn shrink_vector_by_removing_elements_first() {
+        let vector = Token::Vector(vec![Token::U8(3), Token::U8(0)]);
+        let shrunk = shrink_token(&vector);
+        assert_eq!(shrunk[0], Token::Vector(vec![]));
+        assert_eq!(shrunk[1], Token::Vector(vec![Token::U8(3)]));
+        assert!(shrunk.contains(&Token::Vector(vec![Token::U8(0), Token::U8(0)])));
+    }
+
+    #[test]
+    fn generation_is_deterministic_for_a_seed() {
+        let params = FuzzParams {
+            params: vec![
+                ("a".into(), ParamType::U64),
+                (
+                    "b".into(),
+                    ParamType::Tuple(vec![
+                        ParamType::Bool,
+                        ParamType::Vector(Box::new(ParamType::U8)),
+                    ]),
+                ),
+                ("c".into(), ParamType::StringArray(4)),
+            ],
+        };
+        let generate = |seed| params.generate(&mut StdRng::seed_from_u64(seed)).unwrap();
+        assert_eq!(generate(42), generate(42));
+        match &generate(7)[2] {
+            Token::StringArray(s) => assert_eq!(s.get_encodable_str().unwrap().len(), 4),
+            token => panic!("unexpected token {token:?}"),
+        }
+    }
+}
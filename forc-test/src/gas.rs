@@ -0,0 +1,302 @@
+//! Gas snapshots of the tests of a package, used to detect gas regressions.
+//!
+//! A snapshot is stored in the `.gas-snapshot` file in the package's directory. Each line holds
+//! the path of a test within its package and the gas it used, e.g.
+//! `tokens::test_transfer (gas: 1234)`, ordered by test path. Fuzz tests are not part of the
+//! snapshot, as their gas usage depends on the generated inputs.
+
+use crate::{TestResult, TestedPackage};
+use forc_pkg::manifest::GenericManifestFile;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The name of the file holding the gas snapshot of a package.
+pub const GAS_SNAPSHOT_FILE_NAME: &str = ".gas-snapshot";
+
+/// The gas used by each test of a package, by test path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GasSnapshot {
+    gas_used: BTreeMap<String, u64>,
+}
+
+/// The maximum allowed relative increase of the gas used by a test, in percent.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GasTolerance(pub f64);
+
+/// The change of the gas used by a single test between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasChange {
+    /// The path of the test within its package.
+    pub name: String,
+    /// The gas used in the previous snapshot, if the test was part of it.
+    pub old: Option<u64>,
+    /// The gas used in the new snapshot, if the test is part of it.
+    pub new: Option<u64>,
+}
+
+/// The changes between two gas snapshots.
+#[derive(Debug, Clone, Default)]
+pub struct GasDiff {
+    /// The tests whose gas changed, or which were added, ordered by path.
+    pub changes: Vec<GasChange>,
+}
+
+impl GasSnapshot {
+    /// Collects the gas used by the non-fuzz tests of the tested package.
+    pub fn from_tested(tested: &TestedPackage) -> Self {
+        let gas_used = tested
+            .tests
+            .iter()
+            .filter(|test| test.fuzz.is_none())
+            .map(|test| (test_path(test, tested), test.gas_used))
+            .collect();
+        Self { gas_used }
+    }
+
+    /// Returns `old` updated with the gas used by the tests of this snapshot.
+    ///
+    /// The entries of the tests which are not part of this snapshot, e.g. because they were
+    /// filtered out, are kept as they are.
+    pub fn merge_into(&self, old: &GasSnapshot) -> GasSnapshot {
+        let mut gas_used = old.gas_used.clone();
+        gas_used.extend(self.gas_used.iter().map(|(name, gas)| (name.clone(), *gas)));
+        GasSnapshot { gas_used }
+    }
+
+    /// The path of the snapshot file of the tested package.
+    pub fn path(tested: &TestedPackage) -> PathBuf {
+        tested
+            .built
+            .descriptor
+            .manifest_file
+            .dir()
+            .join(GAS_SNAPSHOT_FILE_NAME)
+    }
+
+    /// Reads the snapshot at the given path, if there is one.
+    pub fn read(path: &Path) -> anyhow::Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(path)?;
+        let snapshot = contents
+            .parse()
+            .map_err(|err| anyhow::anyhow!("{}: {err}", path.display()))?;
+        Ok(Some(snapshot))
+    }
+
+    /// Writes the snapshot to the given path.
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    /// Returns the gas used by the test with the given path.
+    pub fn gas_used(&self, name: &str) -> Option<u64> {
+        self.gas_used.get(name).copied()
+    }
+
+    /// Returns the changes from `old` to this snapshot, for the tests of this snapshot.
+    ///
+    /// The tests which are only part of `old` are not considered, as they may not have been run.
+    pub fn diff(&self, old: &GasSnapshot) -> GasDiff {
+        let changes = self
+            .gas_used
+            .keys()
+            .map(|name| GasChange {
+                name: name.clone(),
+                old: old.gas_used(name),
+                new: self.gas_used(name),
+            })
+            .filter(|change| change.old != change.new)
+            .collect();
+        GasDiff { changes }
+    }
+}
+
+impl GasChange {
+    /// The relative change of the gas used, in percent.
+    ///
+    /// Returns `None` if the test was added, or did not use any gas before.
+    pub fn percent(&self) -> Option<f64> {
+        match (self.old, self.new) {
+            (Some(old), Some(new)) if old != 0 => {
+                Some((new as f64 - old as f64) / old as f64 * 100.0)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the gas used increased beyond the tolerance.
+    ///
+    /// Added tests are not considered regressions.
+    pub fn exceeds(&self, tolerance: GasTolerance) -> bool {
+        match (self.old, self.new) {
+            (Some(old), Some(new)) if new > old => match self.percent() {
+                Some(percent) => percent > tolerance.0,
+                None => true,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl GasDiff {
+    /// The changes in which the gas used increased beyond the tolerance.
+    pub fn regressions(&self, tolerance: GasTolerance) -> impl Iterator<Item = &GasChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.exceeds(tolerance))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// The path of a test within its package, i.e. the path of the module declaring it followed by
+/// its name, e.g. `tokens::test_transfer`. Tests of the root module are identified by name alone.
+pub fn test_path(test: &TestResult, tested: &TestedPackage) -> String {
+    let entry_path = tested.built.descriptor.manifest_file.entry_path();
+    let module_path = entry_path
+        .parent()
+        .and_then(|src_dir| test.file_path.strip_prefix(src_dir).ok())
+        .filter(|relative_path| Some(relative_path.as_os_str()) != entry_path.file_name())
+        .map(|relative_path| {
+            relative_path
+                .with_extension("")
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+                .join("::")
+        });
+    match module_path {
+        Some(module_path) => format!("{module_path}::{}", test.name),
+        None => test.name.clone(),
+    }
+}
+
+impl fmt::Display for GasSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, gas_used) in &self.gas_used {
+            writeln!(f, "{name} (gas: {gas_used})")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GasSnapshot {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let gas_used = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(ix, line)| {
+                let invalid = || format!("invalid gas snapshot entry at line {}: {line}", ix + 1);
+                let (name, gas_used) = line.trim().split_once(" (gas: ").ok_or_else(invalid)?;
+                let gas_used = gas_used
+                    .strip_suffix(')')
+                    .and_then(|gas_used| gas_used.parse::<u64>().ok())
+                    .ok_or_else(invalid)?;
+                Ok((name.to_string(), gas_used))
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Self { gas_used })
+    }
+}
+
+impl FromStr for GasTolerance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let percent = s
+            .trim_end_matches('%')
+            .parse::<f64>()
+            .map_err(|_| format!("invalid gas tolerance `{s}`, expected a percentage"))?;
+        if !percent.is_finite() || percent < 0.0 {
+            return Err(format!(
+                "invalid gas tolerance `{s}`, expected a non-negative percentage"
+            ));
+        }
+        Ok(Self(percent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(entries: &[(&str, u64)]) -> GasSnapshot {
+        GasSnapshot {
+            gas_used: entries
+                .iter()
+                .map(|(name, gas_used)| (name.to_string(), *gas_used))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let snapshot = snapshot(&[("test_b", 20), ("test_a", 1000)]);
+        let contents = snapshot.to_string();
+        assert_eq!(contents, "test_a (gas: 1000)\ntest_b (gas: 20)\n");
+        assert_eq!(contents.parse::<GasSnapshot>().unwrap(), snapshot);
+        assert!("test_a (gas: lots)".parse::<GasSnapshot>().is_err());
+    }
+
+    #[test]
+    fn diff_and_regressions() {
+        let old = snapshot(&[
+            ("same", 10),
+            ("cheaper", 100),
+            ("pricier", 100),
+            ("gone", 1),
+        ]);
+        let new = snapshot(&[("same", 10), ("cheaper", 90), ("pricier", 104), ("new", 1)]);
+        let diff = new.diff(&old);
+        let names = diff
+            .changes
+            .iter()
+            .map(|change| change.name.as_str())
+            .collect::<Vec<_>>();
+        // `gone` may just not have been run, so it is not reported.
+        assert_eq!(names, vec!["cheaper", "new", "pricier"]);
+
+        let regressions = |tolerance| {
+            diff.regressions(GasTolerance(tolerance))
+                .map(|change| change.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(regressions(0.0), vec!["pricier".to_string()]);
+        assert!(regressions(5.0).is_empty());
+    }
+
+    #[test]
+    fn merge_keeps_tests_not_run() {
+        let old = snapshot(&[("a::test", 10), ("b::test", 20), ("test", 30)]);
+        let new = snapshot(&[("b::test", 25), ("c::test", 5)]);
+        assert_eq!(
+            new.merge_into(&old),
+            snapshot(&[
+                ("a::test", 10),
+                ("b::test", 25),
+                ("c::test", 5),
+                ("test", 30)
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_tolerance() {
+        assert_eq!("2.5".parse::<GasTolerance>().unwrap(), GasTolerance(2.5));
+        assert_eq!("10%".parse::<GasTolerance>().unwrap(), GasTolerance(10.0));
+        assert!("-1".parse::<GasTolerance>().is_err());
+        assert!("a lot".parse::<GasTolerance>().is_err());
+    }
+}
@@ -1,11 +1,21 @@
+pub mod coverage;
+pub mod doc_test;
+pub mod ecal;
 pub mod execute;
+pub mod fuzz;
+pub mod gas;
+pub mod predicate;
+pub mod report;
+pub mod scenario;
 pub mod setup;
+pub mod trace;
 
 use crate::execute::TestExecutor;
+use crate::fuzz::{FuzzOpts, FuzzParams};
 use crate::setup::{
     ContractDeploymentSetup, ContractTestSetup, DeploymentSetup, ScriptTestSetup, TestSetup,
 };
-use forc_pkg::{self as pkg, BuildOpts};
+use forc_pkg::{self as pkg, manifest::GenericManifestFile, BuildOpts};
 use fuel_abi_types::error_codes::ErrorSignal;
 use fuel_tx as tx;
 use fuel_vm::checked_transaction::builder::TransactionBuilderExt;
@@ -16,7 +26,7 @@ use pkg::TestPassCondition;
 use pkg::{Built, BuiltPackage};
 use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
-use std::{collections::HashMap, fs, path::PathBuf, sync::Arc};
+use std::{collections::HashMap, fs, path::PathBuf, str::FromStr, sync::Arc, time::Duration};
 use sway_core::asm_generation::ProgramABI;
 use sway_core::BuildTarget;
 use sway_types::Span;
@@ -38,6 +48,11 @@ pub struct TestedPackage {
     pub built: Box<pkg::BuiltPackage>,
     /// The resulting `ProgramState` after executing the test.
     pub tests: Vec<TestResult>,
+    /// The coverage of the package and of its contract dependencies by the tests, if coverage is
+    /// enabled.
+    pub coverage: Option<coverage::Coverage>,
+    /// The results of the test scenarios declared in the manifest of the package.
+    pub scenarios: Vec<scenario::ScenarioResult>,
 }
 
 #[derive(Debug)]
@@ -58,6 +73,18 @@ pub struct TestFilter<'a> {
     pub exact_match: bool,
 }
 
+/// A subset of the tests, used to split the tests across multiple machines.
+///
+/// Each test belongs to exactly one of the `count` shards, determined by the hash of the names of
+/// its package and of the test, so the shard of a test does not depend on the other tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestShard {
+    /// The index of the shard, from `1` to `count`.
+    pub index: u64,
+    /// The number of shards.
+    pub count: u64,
+}
+
 /// The result of executing a single test within a single package.
 #[derive(Debug, Clone)]
 pub struct TestResult {
@@ -77,6 +104,21 @@ pub struct TestResult {
     pub logs: Vec<fuel_tx::Receipt>,
     /// Gas used while executing this test.
     pub gas_used: u64,
+    /// The result of the runs of a parameterized test, i.e. `#[test(fuzz)]`.
+    pub fuzz: Option<fuzz::FuzzResult>,
+    /// The expectations set through cheatcodes, e.g. `std::test::expect_log`, which were not met.
+    pub unmet_expectations: Vec<String>,
+    /// The result of verifying the predicate under test against the transaction declared by the
+    /// test, if any.
+    pub predicate: Option<predicate::PredicateResult>,
+    /// The instructions executed by the test, if coverage is enabled.
+    pub coverage: Option<coverage::InstructionHits>,
+    /// Whether the test panicked because it ran out of gas.
+    pub out_of_gas: bool,
+    /// Whether the test was stopped because it ran for longer than the timeout.
+    pub timed_out: bool,
+    /// The calls made by the test, if tracing is enabled.
+    pub trace: Option<trace::CallTrace>,
 }
 
 const TEST_METADATA_SEED: u64 = 0x7E57u64;
@@ -161,6 +203,20 @@ pub struct TestOpts {
     pub no_experimental: Vec<sway_features::Feature>,
 }
 
+/// The set of options provided for controlling the execution of the tests.
+#[derive(Default, Clone)]
+pub struct TestRunOpts {
+    pub fuzz: FuzzOpts,
+    /// Record the instructions executed by the tests, and map them to source lines.
+    pub coverage: bool,
+    /// The maximum time each test may run for. Fuzz tests are limited per run.
+    pub timeout: Option<Duration>,
+    /// Only run the tests of the given shard.
+    pub shard: Option<TestShard>,
+    /// Record the calls, logs and storage accesses made by the tests.
+    pub trace: bool,
+}
+
 /// The set of options provided for controlling logs printed for each test.
 #[derive(Default, Clone)]
 pub struct TestPrintOpts {
@@ -176,6 +232,7 @@ pub struct DecodedLog {
 impl TestedPackage {
     pub fn tests_passed(&self) -> bool {
         self.tests.iter().all(|test| test.passed())
+            && self.scenarios.iter().all(|scenario| scenario.passed())
     }
 }
 
@@ -346,7 +403,9 @@ impl<'a> PackageTests {
             .cloned()
             .unwrap_or_default();
         match built_without_tests_bytecode {
-            Some(contract_without_tests) => {
+            Some(contract_without_tests)
+                if built_pkg.tree_type == sway_core::language::parsed::TreeType::Contract =>
+            {
                 let contract_to_test = ContractToTest {
                     pkg: built_pkg,
                     without_tests_bytecode: contract_without_tests,
@@ -354,7 +413,7 @@ impl<'a> PackageTests {
                 };
                 PackageTests::Contract(PackageWithDeploymentToTest::Contract(contract_to_test))
             }
-            None => match built_pkg.tree_type {
+            _ => match built_pkg.tree_type {
                 sway_core::language::parsed::TreeType::Predicate => {
                     PackageTests::Predicate(built_pkg)
                 }
@@ -376,8 +435,21 @@ impl<'a> PackageTests {
         &self,
         test_runners: &rayon::ThreadPool,
         test_filter: Option<&TestFilter>,
+        run_opts: &TestRunOpts,
     ) -> anyhow::Result<TestedPackage> {
         let pkg_with_tests = self.built_pkg_with_tests();
+        // Predicates are verified with their bytecode built without tests.
+        let predicate_bytecode = match self {
+            PackageTests::Predicate(built_pkg) => built_pkg
+                .bytecode_without_tests
+                .as_ref()
+                .map(|bytecode| Arc::new(bytecode.bytes.clone())),
+            _ => None,
+        };
+        let trace_context = match run_opts.trace {
+            true => Some(Arc::new(self.trace_context()?)),
+            false => None,
+        };
         let tests = test_runners.install(|| {
             pkg_with_tests
                 .bytecode
@@ -393,6 +465,11 @@ impl<'a> PackageTests {
                                 return None;
                             }
                         }
+                        if let Some(shard) = &run_opts.shard {
+                            if !shard.contains(&pkg_with_tests.descriptor.name, &name) {
+                                return None;
+                            }
+                        }
                         return Some((entry, test_entry));
                     }
                     None
@@ -403,24 +480,214 @@ impl<'a> PackageTests {
                         .expect("test instruction offset out of range");
                     let name = entry.finalized.fn_name.clone();
                     let test_setup = self.setup()?;
-                    TestExecutor::build(
-                        &pkg_with_tests.bytecode.bytes,
-                        offset,
-                        test_setup,
-                        test_entry,
-                        name,
-                    )?
-                    .execute()
+                    match &test_entry.fuzz {
+                        Some(fuzz_entry) => {
+                            let params = FuzzParams::from_abi(&fuzz_entry.abi)?;
+                            let runs = run_opts
+                                .fuzz
+                                .runs
+                                .or(fuzz_entry.runs)
+                                .unwrap_or(fuzz::DEFAULT_FUZZ_RUNS);
+                            fuzz::run_fuzz_test(&params, runs, &run_opts.fuzz, |script_data| {
+                                let mut executor = TestExecutor::build_with_script_data(
+                                    &pkg_with_tests.bytecode.bytes,
+                                    offset,
+                                    test_setup.clone(),
+                                    test_entry,
+                                    name.clone(),
+                                    script_data,
+                                )?;
+                                executor.coverage = run_opts.coverage;
+                                executor.timeout = run_opts.timeout;
+                                executor.trace = trace_context.clone();
+                                executor.predicate_bytecode = predicate_bytecode.clone();
+                                executor.execute()
+                            })
+                        }
+                        None => {
+                            let mut executor = TestExecutor::build(
+                                &pkg_with_tests.bytecode.bytes,
+                                offset,
+                                test_setup,
+                                test_entry,
+                                name,
+                            )?;
+                            executor.coverage = run_opts.coverage;
+                            executor.timeout = run_opts.timeout;
+                            executor.trace = trace_context.clone();
+                            executor.predicate_bytecode = predicate_bytecode.clone();
+                            executor.execute()
+                        }
+                    }
                 })
-                .collect::<anyhow::Result<_>>()
+                .collect::<anyhow::Result<Vec<_>>>()
         })?;
 
+        let coverage = match run_opts.coverage {
+            true => Some(self.coverage(&tests)?),
+            false => None,
+        };
+        let scenarios = self.run_scenarios(test_filter, run_opts.shard)?;
+
         Ok(TestedPackage {
             built: Box::new(pkg_with_tests.clone()),
             tests,
+            coverage,
+            scenarios,
         })
     }
 
+    /// Run the test scenarios declared in the manifest of the package, after applying the provided
+    /// filter and shard to their names.
+    fn run_scenarios(
+        &self,
+        test_filter: Option<&TestFilter>,
+        shard: Option<TestShard>,
+    ) -> anyhow::Result<Vec<scenario::ScenarioResult>> {
+        let descriptor = &self.built_pkg_with_tests().descriptor;
+        let manifest_file = &descriptor.manifest_file;
+        let Some(test) = manifest_file.test() else {
+            return Ok(vec![]);
+        };
+
+        // Scenarios can deploy the tested contract and its contract dependencies.
+        let mut packages = vec![];
+        if let PackageTests::Contract(PackageWithDeploymentToTest::Contract(contract_to_test)) =
+            self
+        {
+            let built_pkg = &contract_to_test.pkg;
+            packages.push(scenario::ScenarioPackage {
+                name: &built_pkg.descriptor.name,
+                bytecode: &contract_to_test.without_tests_bytecode.bytes,
+                storage_slots: &built_pkg.storage_slots,
+                program_abi: built_pkg
+                    .program_abi_without_tests
+                    .as_ref()
+                    .unwrap_or(&built_pkg.program_abi),
+            });
+        }
+        if let PackageTests::Contract(pkg) | PackageTests::Script(pkg) = self {
+            packages.extend(pkg.contract_dependencies().map(|built_pkg| {
+                scenario::ScenarioPackage {
+                    name: &built_pkg.descriptor.name,
+                    bytecode: &built_pkg.bytecode.bytes,
+                    storage_slots: &built_pkg.storage_slots,
+                    program_abi: &built_pkg.program_abi,
+                }
+            }));
+        }
+
+        test.scenarios
+            .iter()
+            .map(|path| manifest_file.dir().join(path))
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_string_lossy().to_string();
+                match (test_filter, shard) {
+                    (Some(filter), _) if !filter.filter(&name) => None,
+                    (_, Some(shard)) if !shard.contains(&descriptor.name, &name) => None,
+                    _ => Some((name, path)),
+                }
+            })
+            .map(|(name, path)| scenario::Scenario::from_file(&path)?.run(name, path, &packages))
+            .collect()
+    }
+
+    /// Maps the instructions executed by the tests to the source lines of the package and of its
+    /// contract dependencies.
+    fn coverage(&self, tests: &[TestResult]) -> anyhow::Result<coverage::Coverage> {
+        let mut hits = coverage::InstructionHits::default();
+        for test_hits in tests.iter().filter_map(|test| test.coverage.as_ref()) {
+            hits.merge(test_hits);
+        }
+
+        let pkg_with_tests = self.built_pkg_with_tests();
+        let mut programs = vec![coverage::CoveredProgram {
+            contract_id: tx::ContractId::zeroed(),
+            bytecode: &pkg_with_tests.bytecode.bytes,
+            source_map: &pkg_with_tests.source_map,
+        }];
+        let mut source_dirs = vec![pkg_with_tests.descriptor.manifest_file.dir()];
+        let test_setup = self.setup()?;
+        if let PackageTests::Contract(pkg) | PackageTests::Script(pkg) = self {
+            for (contract_id, built_pkg) in test_setup
+                .contract_dependency_ids()
+                .zip(pkg.contract_dependencies())
+            {
+                programs.push(coverage::CoveredProgram {
+                    contract_id: *contract_id,
+                    bytecode: &built_pkg.bytecode.bytes,
+                    source_map: &built_pkg.source_map,
+                });
+                source_dirs.push(built_pkg.descriptor.manifest_file.dir());
+            }
+        }
+        if let (Some(root_contract_id), PackageTests::Contract(pkg)) =
+            (test_setup.root_contract_id(), self)
+        {
+            let built_pkg = pkg.pkg();
+            if let (Some(bytecode), Some(source_map)) = (
+                &built_pkg.bytecode_without_tests,
+                &built_pkg.source_map_without_tests,
+            ) {
+                programs.push(coverage::CoveredProgram {
+                    contract_id: root_contract_id,
+                    bytecode: &bytecode.bytes,
+                    source_map,
+                });
+            }
+        }
+
+        // Only the sources of the tested packages are covered, not the ones of their library
+        // dependencies.
+        Ok(coverage::Coverage::from_hits(&programs, &hits, |path| {
+            source_dirs.iter().any(|dir| path.starts_with(dir))
+        }))
+    }
+
+    /// Collects the programs the tests may execute, i.e. the tests themselves, the tested contract
+    /// and the contract dependencies, to decode their calls and locate them in their sources.
+    fn trace_context(&self) -> anyhow::Result<trace::TraceContext> {
+        let pkg_with_tests = self.built_pkg_with_tests();
+        let mut programs = vec![trace::TracedProgram {
+            contract_id: tx::ContractId::zeroed(),
+            name: pkg_with_tests.descriptor.name.clone(),
+            program_abi: pkg_with_tests.program_abi.clone(),
+            source_map: pkg_with_tests.source_map.clone(),
+        }];
+        let test_setup = self.setup()?;
+        if let PackageTests::Contract(pkg) | PackageTests::Script(pkg) = self {
+            for (contract_id, built_pkg) in test_setup
+                .contract_dependency_ids()
+                .zip(pkg.contract_dependencies())
+            {
+                programs.push(trace::TracedProgram {
+                    contract_id: *contract_id,
+                    name: built_pkg.descriptor.name.clone(),
+                    program_abi: built_pkg.program_abi.clone(),
+                    source_map: built_pkg.source_map.clone(),
+                });
+            }
+        }
+        if let (Some(root_contract_id), PackageTests::Contract(pkg)) =
+            (test_setup.root_contract_id(), self)
+        {
+            let built_pkg = pkg.pkg();
+            programs.push(trace::TracedProgram {
+                contract_id: root_contract_id,
+                name: built_pkg.descriptor.name.clone(),
+                program_abi: built_pkg
+                    .program_abi_without_tests
+                    .clone()
+                    .unwrap_or_else(|| built_pkg.program_abi.clone()),
+                source_map: built_pkg
+                    .source_map_without_tests
+                    .clone()
+                    .unwrap_or_else(|| built_pkg.source_map.clone()),
+            });
+        }
+        Ok(trace::TraceContext::new(programs))
+    }
+
     /// Setup the storage for a test and returns a contract id for testing contracts.
     ///
     /// For testing contracts, storage returned from this function contains the deployed contract.
@@ -492,6 +759,10 @@ impl TestOpts {
 impl TestResult {
     /// Whether or not the test passed.
     pub fn passed(&self) -> bool {
+        // Running out of gas or time is a failure, even for tests which should revert.
+        if self.out_of_gas || self.timed_out || !self.unmet_expectations.is_empty() {
+            return false;
+        }
         match &self.condition {
             TestPassCondition::ShouldRevert(revert_code) => match revert_code {
                 Some(revert_code) => self.state == vm::state::ProgramState::Revert(*revert_code),
@@ -504,7 +775,12 @@ impl TestResult {
     }
 
     /// Return the revert code for this [TestResult] if the test is reverted.
+    ///
+    /// Tests which ran out of gas have no revert code.
     pub fn revert_code(&self) -> Option<u64> {
+        if self.out_of_gas {
+            return None;
+        }
         match self.state {
             vm::state::ProgramState::Revert(revert_code) => Some(revert_code),
             _ => None,
@@ -536,6 +812,7 @@ impl TestResult {
 
 /// Used to control test runner count for forc-test. Number of runners to use can be specified using
 /// `Manual` or can be left forc-test to decide by using `Auto`.
+#[derive(Clone, Copy, Debug)]
 pub enum TestRunnerCount {
     Manual(usize),
     Auto,
@@ -547,6 +824,37 @@ pub struct TestCount {
     pub ignored: usize,
 }
 
+impl TestShard {
+    /// Whether the test with the given name, in the package with the given name, belongs to this
+    /// shard.
+    pub fn contains(&self, pkg_name: &str, test_name: &str) -> bool {
+        // FNV-1a, as the shard of a test must be the same on every machine.
+        let hash = format!("{pkg_name}::{test_name}")
+            .bytes()
+            .fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+        hash % self.count == self.index - 1
+    }
+}
+
+impl FromStr for TestShard {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid shard `{s}`, expected `<index>/<count>`, e.g. `1/4`");
+        let (index, count) = s.split_once('/').ok_or_else(invalid)?;
+        let index = index.trim().parse::<u64>().map_err(|_| invalid())?;
+        let count = count.trim().parse::<u64>().map_err(|_| invalid())?;
+        if index == 0 || index > count {
+            return Err(format!(
+                "invalid shard `{s}`, the index must be between 1 and the number of shards"
+            ));
+        }
+        Ok(Self { index, count })
+    }
+}
+
 impl<'a> TestFilter<'a> {
     fn filter(&self, fn_name: &str) -> bool {
         if self.exact_match {
@@ -559,29 +867,32 @@ impl<'a> TestFilter<'a> {
 
 impl BuiltTests {
     /// The total number of tests.
-    pub fn test_count(&self, test_filter: Option<&TestFilter>) -> TestCount {
+    pub fn test_count(
+        &self,
+        test_filter: Option<&TestFilter>,
+        shard: Option<TestShard>,
+    ) -> TestCount {
         let pkgs: Vec<&PackageTests> = match self {
             BuiltTests::Package(pkg) => vec![pkg],
             BuiltTests::Workspace(workspace) => workspace.iter().collect(),
         };
         pkgs.iter()
             .flat_map(|pkg| {
-                pkg.built_pkg_with_tests()
+                let built_pkg = pkg.built_pkg_with_tests();
+                built_pkg
                     .bytecode
                     .entries
                     .iter()
                     .filter_map(|entry| entry.kind.test().map(|test| (entry, test)))
+                    .map(|(entry, test)| (&built_pkg.descriptor.name, entry, test))
             })
-            .fold(TestCount::default(), |acc, (pkg_entry, _)| {
-                let num_ignored = match &test_filter {
-                    Some(filter) => {
-                        if filter.filter(&pkg_entry.finalized.fn_name) {
-                            acc.ignored
-                        } else {
-                            acc.ignored + 1
-                        }
-                    }
-                    None => acc.ignored,
+            .fold(TestCount::default(), |acc, (pkg_name, pkg_entry, _)| {
+                let name = &pkg_entry.finalized.fn_name;
+                let filtered = test_filter.is_some_and(|filter| !filter.filter(name))
+                    || shard.is_some_and(|shard| !shard.contains(pkg_name, name));
+                let num_ignored = match filtered {
+                    true => acc.ignored + 1,
+                    false => acc.ignored,
                 };
                 TestCount {
                     total: acc.total + 1,
@@ -595,6 +906,16 @@ impl BuiltTests {
         self,
         test_runner_count: TestRunnerCount,
         test_filter: Option<TestFilter>,
+    ) -> anyhow::Result<Tested> {
+        self.run_with_opts(test_runner_count, test_filter, TestRunOpts::default())
+    }
+
+    /// Run all built tests with the given options, return the result.
+    pub fn run_with_opts(
+        self,
+        test_runner_count: TestRunnerCount,
+        test_filter: Option<TestFilter>,
+        run_opts: TestRunOpts,
     ) -> anyhow::Result<Tested> {
         let test_runners = match test_runner_count {
             TestRunnerCount::Manual(runner_count) => rayon::ThreadPoolBuilder::new()
@@ -602,7 +923,7 @@ impl BuiltTests {
                 .build(),
             TestRunnerCount::Auto => rayon::ThreadPoolBuilder::new().build(),
         }?;
-        run_tests(self, &test_runners, test_filter)
+        run_tests(self, &test_runners, test_filter, &run_opts)
     }
 }
 
@@ -638,15 +959,28 @@ fn deployment_transaction(
     built_pkg: &pkg::BuiltPackage,
     without_tests_bytecode: &pkg::BuiltPackageBytecode,
     params: &tx::ConsensusParameters,
+) -> ContractDeploymentSetup {
+    deployment_transaction_with_salt(
+        &without_tests_bytecode.bytes,
+        built_pkg.storage_slots.clone(),
+        tx::Salt::zeroed(),
+        params,
+    )
+}
+
+/// Creates the deployment transaction of a contract with the given bytecode, storage slots and
+/// salt, and returns it along with the id of the contract.
+pub(crate) fn deployment_transaction_with_salt(
+    bytecode: &[u8],
+    mut storage_slots: Vec<tx::StorageSlot>,
+    salt: tx::Salt,
+    params: &tx::ConsensusParameters,
 ) -> ContractDeploymentSetup {
     // Obtain the contract id for deployment.
-    let mut storage_slots = built_pkg.storage_slots.clone();
     storage_slots.sort();
-    let bytecode = &without_tests_bytecode.bytes;
-    let contract = tx::Contract::from(bytecode.clone());
+    let contract = tx::Contract::from(bytecode.to_vec());
     let root = contract.root();
     let state_root = tx::Contract::initial_state_root(storage_slots.iter());
-    let salt = tx::Salt::zeroed();
     let contract_id = contract.id(&salt, &root, &state_root);
 
     // Create the deployment transaction.
@@ -664,7 +998,7 @@ fn deployment_transaction(
     let tx_pointer = rng.gen();
     let block_height = (u32::MAX >> 1).into();
 
-    let tx = tx::TransactionBuilder::create(bytecode.as_slice().into(), salt, storage_slots)
+    let tx = tx::TransactionBuilder::create(bytecode.into(), salt, storage_slots)
         .with_params(params.clone())
         .add_unsigned_coin_input(secret_key, utxo_id, amount, asset_id, tx_pointer)
         .add_output(tx::Output::contract_created(contract_id, state_root))
@@ -718,16 +1052,17 @@ fn run_tests(
     built: BuiltTests,
     test_runners: &rayon::ThreadPool,
     test_filter: Option<TestFilter>,
+    run_opts: &TestRunOpts,
 ) -> anyhow::Result<Tested> {
     match built {
         BuiltTests::Package(pkg) => {
-            let tested_pkg = pkg.run_tests(test_runners, test_filter.as_ref())?;
+            let tested_pkg = pkg.run_tests(test_runners, test_filter.as_ref(), run_opts)?;
             Ok(Tested::Package(Box::new(tested_pkg)))
         }
         BuiltTests::Workspace(workspace) => {
             let tested_pkgs = workspace
                 .into_iter()
-                .map(|pkg| pkg.run_tests(test_runners, test_filter.as_ref()))
+                .map(|pkg| pkg.run_tests(test_runners, test_filter.as_ref(), run_opts))
                 .collect::<anyhow::Result<Vec<TestedPackage>>>()?;
             Ok(Tested::Workspace(tested_pkgs))
         }
@@ -738,7 +1073,9 @@ fn run_tests(
 mod tests {
     use std::path::PathBuf;
 
-    use crate::{build, BuiltTests, TestFilter, TestOpts, TestResult};
+    use forc_pkg::manifest::{GenericManifestFile, PackageManifestFile};
+
+    use crate::{build, BuiltTests, TestFilter, TestOpts, TestResult, TestRunOpts, TestShard};
 
     /// Name of the folder containing required data for tests to run, such as an example forc
     /// project.
@@ -751,6 +1088,15 @@ mod tests {
     const TEST_PREDICATE_PACKAGE_NAME: &str = "test_predicate";
     /// Name of the script package in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
     const TEST_SCRIPT_PACKAGE_NAME: &str = "test_script";
+    /// Name of the library package with fuzz tests in the "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME".
+    const TEST_FUZZ_PACKAGE_NAME: &str = "test_fuzz";
+    const TEST_CHEATCODES_PACKAGE_NAME: &str = "test_cheatcodes";
+    const TEST_COVERAGE_PACKAGE_NAME: &str = "test_coverage";
+    const TEST_PREDICATE_VERIFICATION_PACKAGE_NAME: &str = "test_predicate_verification";
+    const TEST_SCENARIOS_PACKAGE_NAME: &str = "test_scenarios";
+    const TEST_LIMITS_PACKAGE_NAME: &str = "test_limits";
+    const TEST_TRACE_PACKAGE_NAME: &str = "test_trace";
+    const TEST_DOC_TESTS_PACKAGE_NAME: &str = "test_doc_tests";
 
     /// Build the tests in the test package with the given name located at
     /// "CARGO_MANIFEST_DIR/TEST_DATA_FOLDER_NAME/TEST_LIBRARY_PACKAGE_NAME".
@@ -975,4 +1321,274 @@ mod tests {
         assert_eq!(tested_predicate_test_count, 2);
         assert_eq!(tested_script_test_count, 2);
     }
+
+    #[test]
+    fn test_fuzz() {
+        let test_results = test_package_test_results(TEST_FUZZ_PACKAGE_NAME, None).unwrap();
+        assert_eq!(test_results.len(), 2);
+
+        let find_test = |name: &str| test_results.iter().find(|test| test.name == name).unwrap();
+
+        let passing = find_test("test_fuzz_addition_commutes");
+        let passing_fuzz = passing.fuzz.as_ref().unwrap();
+        assert!(passing.passed());
+        assert_eq!(passing_fuzz.runs, crate::fuzz::DEFAULT_FUZZ_RUNS);
+        assert!(passing_fuzz.failure.is_none());
+
+        let failing = find_test("test_fuzz_small_values");
+        let failure = failing.fuzz.as_ref().unwrap().failure.as_ref().unwrap();
+        assert!(!failing.passed());
+        let minimal_input = failure
+            .minimal_input
+            .iter()
+            .map(|arg| (arg.name.as_str(), arg.value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(minimal_input, vec![("a", "1000"), ("b", "true")]);
+    }
+
+    #[test]
+    fn test_cheatcodes() {
+        let test_results = test_package_test_results(TEST_CHEATCODES_PACKAGE_NAME, None).unwrap();
+        assert_eq!(test_results.len(), 5);
+
+        for test in &test_results {
+            match test.name.as_str() {
+                "test_expect_log_unmet" => {
+                    assert!(!test.passed());
+                    assert_eq!(test.unmet_expectations.len(), 1);
+                }
+                _ => assert!(test.passed(), "{} failed", test.name),
+            }
+        }
+    }
+
+    #[test]
+    fn test_predicate_verification() {
+        let test_results =
+            test_package_test_results(TEST_PREDICATE_VERIFICATION_PACKAGE_NAME, None).unwrap();
+        assert_eq!(test_results.len(), 3);
+
+        for test in &test_results {
+            assert!(test.passed(), "{} failed", test.name);
+            let predicate = test.predicate.as_ref().unwrap();
+            match test.name.as_str() {
+                "test_correct_password" => {
+                    assert!(predicate.verified);
+                    assert_ne!(predicate.gas_used, 0);
+                }
+                _ => {
+                    assert!(!predicate.verified);
+                    assert!(predicate.error.is_some());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_coverage() {
+        let built_tests = test_package_built_tests(TEST_COVERAGE_PACKAGE_NAME).unwrap();
+        let run_opts = TestRunOpts {
+            coverage: true,
+            ..Default::default()
+        };
+        let tested = built_tests
+            .run_with_opts(crate::TestRunnerCount::Auto, None, run_opts)
+            .unwrap();
+        let crate::Tested::Package(tested_pkg) = tested else {
+            unreachable!("test_coverage is a package, not a workspace.")
+        };
+        assert!(tested_pkg.tests_passed());
+
+        // The contract is only called with a small value, so the branch doubling the value is not
+        // covered.
+        let coverage = tested_pkg.coverage.unwrap();
+        let (path, file) = coverage
+            .files
+            .iter()
+            .find(|(path, _)| path.ends_with("src/main.sw"))
+            .unwrap();
+        assert!(path.starts_with(tested_pkg.built.descriptor.manifest_file.dir()));
+        assert_ne!(file.lines.get(&9).copied().unwrap_or_default(), 0);
+        assert_eq!(file.lines.get(&10), Some(&0));
+        assert!(file
+            .branches
+            .values()
+            .flatten()
+            .any(|branch| branch.executed != 0));
+    }
+
+    #[test]
+    fn test_doc_tests() {
+        let pkg_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join(TEST_DATA_FOLDER_NAME)
+            .join(TEST_DOC_TESTS_PACKAGE_NAME);
+        let pkg_manifest = PackageManifestFile::from_dir(&pkg_dir).unwrap();
+        let doc_tests = crate::doc_test::collect(&pkg_manifest).unwrap();
+        // The doc test packages cannot be nested within the documented package.
+        let doc_tests_dir = std::env::temp_dir()
+            .join(crate::doc_test::DOC_TESTS_DIR_NAME)
+            .join(TEST_DOC_TESTS_PACKAGE_NAME);
+        let results = crate::doc_test::run(
+            &pkg_manifest,
+            doc_tests,
+            &doc_tests_dir,
+            TestOpts::default(),
+            crate::TestRunnerCount::Auto,
+        )
+        .unwrap();
+
+        // The example of `sub` asserts the wrong difference, so it reverts.
+        assert_eq!(
+            results
+                .iter()
+                .map(|result| (
+                    result.doc_test.location(pkg_manifest.dir()),
+                    result.passed()
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                ("src/lib.sw:5".to_string(), true),
+                ("src/lib.sw:16".to_string(), false)
+            ]
+        );
+        assert!(matches!(
+            results[1].outcome,
+            crate::doc_test::DocTestOutcome::Failed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_scenarios() {
+        let built_tests = test_package_built_tests(TEST_SCENARIOS_PACKAGE_NAME).unwrap();
+        let tested = built_tests.run(crate::TestRunnerCount::Auto, None).unwrap();
+        let crate::Tested::Package(tested_pkg) = tested else {
+            unreachable!("test_scenarios is a package, not a workspace.")
+        };
+        assert!(tested_pkg.tests_passed());
+
+        let scenario = &tested_pkg.scenarios[0];
+        assert_eq!(scenario.name, "counters");
+        assert_eq!(scenario.calls.len(), 6);
+        assert!(scenario.calls.iter().all(|call| call.passed));
+        assert_eq!(scenario.calls[1].value.as_deref(), Some("7"));
+        assert_eq!(scenario.calls[5].revert_code, Some(42));
+    }
+
+    #[test]
+    fn test_gas_limits_and_timeouts() {
+        let built_tests = test_package_built_tests(TEST_LIMITS_PACKAGE_NAME).unwrap();
+        let run_opts = TestRunOpts {
+            timeout: Some(std::time::Duration::from_millis(500)),
+            ..Default::default()
+        };
+        let tested = built_tests
+            .run_with_opts(crate::TestRunnerCount::Auto, None, run_opts)
+            .unwrap();
+        let crate::Tested::Package(tested_pkg) = tested else {
+            unreachable!("test_limits is a package, not a workspace.")
+        };
+        let test = |name: &str| {
+            tested_pkg
+                .tests
+                .iter()
+                .find(|test| test.name == name)
+                .unwrap()
+        };
+
+        let within_gas_limit = test("test_within_gas_limit");
+        assert!(within_gas_limit.passed());
+        assert!(within_gas_limit.gas_used <= 100000);
+
+        let out_of_gas = test("test_out_of_gas");
+        assert!(out_of_gas.out_of_gas);
+        assert!(!out_of_gas.passed());
+        assert_eq!(out_of_gas.revert_code(), None);
+
+        let never_ends = test("test_never_ends");
+        assert!(never_ends.timed_out);
+        assert!(!never_ends.out_of_gas);
+        assert!(!never_ends.passed());
+    }
+
+    #[test]
+    fn test_shards() {
+        assert!("0/2".parse::<TestShard>().is_err());
+        assert!("3/2".parse::<TestShard>().is_err());
+        assert!("1-2".parse::<TestShard>().is_err());
+        let shards = (1..=3)
+            .map(|index| format!("{index}/3").parse::<TestShard>().unwrap())
+            .collect::<Vec<_>>();
+
+        // Each test belongs to exactly one shard.
+        for test_name in ["test_a", "test_b", "test_c", "test_d", "test_e"] {
+            let num_shards = shards
+                .iter()
+                .filter(|shard| shard.contains("pkg", test_name))
+                .count();
+            assert_eq!(num_shards, 1);
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let built_tests = test_package_built_tests(TEST_TRACE_PACKAGE_NAME).unwrap();
+        let run_opts = TestRunOpts {
+            trace: true,
+            ..Default::default()
+        };
+        let tested = built_tests
+            .run_with_opts(crate::TestRunnerCount::Auto, None, run_opts)
+            .unwrap();
+        let crate::Tested::Package(tested_pkg) = tested else {
+            unreachable!("test_trace is a package, not a workspace.")
+        };
+        assert!(tested_pkg.tests_passed());
+        let call = |name: &str| {
+            let test = tested_pkg
+                .tests
+                .iter()
+                .find(|test| test.name == name)
+                .unwrap();
+            let root = &test.trace.as_ref().unwrap().root;
+            assert_eq!(root.name, name);
+            root.entries
+                .iter()
+                .find_map(|entry| match entry {
+                    crate::trace::TraceEntry::Call(frame) => Some(frame.clone()),
+                    _ => None,
+                })
+                .unwrap()
+        };
+
+        let increment = call("test_increment");
+        assert_eq!(increment.name, "test_trace.increment");
+        assert_eq!(increment.args.as_deref(), Some("amount: 5"));
+        assert!(increment.location.is_some());
+        let kinds = increment
+            .entries
+            .iter()
+            .map(|entry| match entry {
+                crate::trace::TraceEntry::Call(_) => "call",
+                crate::trace::TraceEntry::Log { .. } => "log",
+                crate::trace::TraceEntry::StorageRead { .. } => "read",
+                crate::trace::TraceEntry::StorageWrite { .. } => "write",
+            })
+            .collect::<Vec<_>>();
+        // Writing a word of a storage slot reads the slot first, to keep the rest of it.
+        assert_eq!(kinds.first(), Some(&"read"));
+        assert!(kinds.contains(&"write"));
+        assert_eq!(kinds.last(), Some(&"log"));
+        assert_eq!(
+            increment.outcome,
+            Some(crate::trace::TraceOutcome::Return(Some("5".to_string())))
+        );
+
+        let fail = call("test_fail");
+        assert_eq!(fail.name, "test_trace.fail");
+        assert_eq!(fail.args.as_deref(), Some("code: 42"));
+        assert!(matches!(
+            fail.outcome,
+            Some(crate::trace::TraceOutcome::Revert { code: 42, .. })
+        ));
+    }
 }
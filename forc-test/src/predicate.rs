@@ -0,0 +1,179 @@
+//! Verification of predicates against transactions declared by their tests.
+//!
+//! A test of a predicate package declares the transaction spending the predicate through the
+//! cheatcodes of the `std::test` library, e.g. `std::test::predicate_coin`. The predicate is then
+//! verified against that transaction, as done by a node estimating the gas used by predicates, and
+//! the test reads the result through `std::test::verify_predicate`.
+
+use crate::maxed_consensus_params;
+use fuel_tx::{
+    self as tx, field::Inputs, Address, AssetId, ConsensusParameters, Finalizable, Input, Output,
+};
+use fuel_vm::{
+    checked_transaction::{CheckPredicateParams, CheckPredicates, EstimatePredicates, IntoChecked},
+    fuel_asm::{op, RegId},
+    interpreter::MemoryInstance,
+    storage::MemoryStorage,
+};
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+
+/// The height of the block including the transaction verifying a predicate.
+const PREDICATE_BLOCK_HEIGHT: u32 = u32::MAX >> 1;
+
+/// The seed of the ids of the inputs of the transaction verifying a predicate.
+const PREDICATE_TRANSACTION_SEED: u64 = 0x9ED1;
+
+/// The parts of the transaction spending a predicate which are declared by its test.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PredicateTransaction {
+    /// The coins owned by the predicate, as asset id and amount.
+    pub coins: Vec<(AssetId, u64)>,
+    /// The messages sent to the predicate, as amount and data.
+    pub messages: Vec<(u64, Vec<u8>)>,
+    /// The predicate data of all the inputs of the predicate.
+    pub predicate_data: Vec<u8>,
+    /// The witnesses of the transaction.
+    pub witnesses: Vec<Vec<u8>>,
+    /// The coin outputs, as recipient, asset id and amount.
+    pub outputs: Vec<(Address, AssetId, u64)>,
+    /// The change outputs, as recipient and asset id.
+    pub change_outputs: Vec<(Address, AssetId)>,
+}
+
+/// The result of verifying a predicate against the transaction declared by its test.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PredicateResult {
+    /// Whether the predicate returned `true` for all of its inputs.
+    pub verified: bool,
+    /// The gas used by the predicate for all of its inputs, as estimated for the transaction.
+    /// Zero if the predicate was not verified.
+    pub gas_used: u64,
+    /// Why the predicate was not verified, if it was not.
+    pub error: Option<String>,
+}
+
+impl PredicateTransaction {
+    /// Verifies the predicate with the given bytecode against this transaction.
+    ///
+    /// Coins minted to addresses with `std::test::mint` are added as signed coin inputs.
+    pub fn verify(
+        &self,
+        predicate: &[u8],
+        coins: &[(Address, AssetId, u64)],
+        storage: &MemoryStorage,
+    ) -> PredicateResult {
+        let params = maxed_consensus_params();
+        let predicate_params = CheckPredicateParams::from(&params);
+        let mut tx = self.transaction(predicate, coins, &params);
+        // Estimating the predicates only sets the gas they use, whatever they return, so the
+        // estimated transaction is then checked as done by a node receiving it.
+        let verified = tx
+            .estimate_predicates(&predicate_params, MemoryInstance::new(), storage)
+            .map_err(|err| format!("{err:?}"))
+            .and_then(|()| {
+                tx.clone()
+                    .into_checked_basic(PREDICATE_BLOCK_HEIGHT.into(), &params)
+                    .and_then(|checked| {
+                        checked.check_predicates(&predicate_params, MemoryInstance::new(), storage)
+                    })
+                    .map_err(|err| format!("{err:?}"))
+            });
+        match verified {
+            Ok(_) => PredicateResult {
+                verified: true,
+                gas_used: tx
+                    .inputs()
+                    .iter()
+                    .filter_map(Input::predicate_gas_used)
+                    .sum(),
+                error: None,
+            },
+            Err(error) => PredicateResult {
+                verified: false,
+                gas_used: 0,
+                error: Some(error),
+            },
+        }
+    }
+
+    /// Builds the script transaction spending the inputs of the predicate.
+    fn transaction(
+        &self,
+        predicate: &[u8],
+        coins: &[(Address, AssetId, u64)],
+        params: &ConsensusParameters,
+    ) -> tx::Script {
+        let rng = &mut rand::rngs::StdRng::seed_from_u64(PREDICATE_TRANSACTION_SEED);
+        let owner = Input::predicate_owner(predicate);
+        let script = op::ret(RegId::ONE).to_bytes().to_vec();
+
+        let mut tx_builder = tx::TransactionBuilder::script(script, vec![]);
+        tx_builder
+            .with_params(params.clone())
+            .script_gas_limit(params.tx_params().max_gas_per_tx() / 2);
+        for (asset_id, amount) in &self.coins {
+            tx_builder.add_input(Input::coin_predicate(
+                rng.gen(),
+                owner,
+                *amount,
+                *asset_id,
+                rng.gen(),
+                0,
+                predicate.to_vec(),
+                self.predicate_data.clone(),
+            ));
+        }
+        for (amount, data) in &self.messages {
+            let input = match data.is_empty() {
+                true => Input::message_coin_predicate(
+                    rng.gen(),
+                    owner,
+                    *amount,
+                    rng.gen(),
+                    0,
+                    predicate.to_vec(),
+                    self.predicate_data.clone(),
+                ),
+                false => Input::message_data_predicate(
+                    rng.gen(),
+                    owner,
+                    *amount,
+                    rng.gen(),
+                    0,
+                    data.clone(),
+                    predicate.to_vec(),
+                    self.predicate_data.clone(),
+                ),
+            };
+            tx_builder.add_input(input);
+        }
+
+        // Signed coins refer to the first witness, which is added if the test declares none.
+        // Signatures are not checked while estimating predicates.
+        for (owner, asset_id, amount) in coins {
+            tx_builder.add_input(Input::coin_signed(
+                rng.gen(),
+                *owner,
+                *amount,
+                *asset_id,
+                rng.gen(),
+                0,
+            ));
+        }
+        if !coins.is_empty() && self.witnesses.is_empty() {
+            tx_builder.add_witness(tx::Witness::default());
+        }
+        for witness in &self.witnesses {
+            tx_builder.add_witness(witness.clone().into());
+        }
+
+        for (to, asset_id, amount) in &self.outputs {
+            tx_builder.add_output(Output::coin(*to, *amount, *asset_id));
+        }
+        for (to, asset_id) in &self.change_outputs {
+            tx_builder.add_output(Output::change(*to, 0, *asset_id));
+        }
+        tx_builder.finalize()
+    }
+}
@@ -0,0 +1,337 @@
+//! Machine readable reports of test results, for consumption by CI dashboards.
+//!
+//! A [TestReport] can be rendered either as JSON or as a JUnit XML document.
+
+use crate::{decode_log_data, predicate::PredicateResult, TestResult, Tested, TestedPackage};
+use fuel_tx::Receipt;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// The results of all tested packages of a `forc test` invocation.
+#[derive(Debug, Clone, Serialize)]
+pub struct TestReport {
+    /// The results of each tested package, in the order the packages were tested.
+    pub packages: Vec<PackageReport>,
+}
+
+/// The results of the tests of a single package.
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageReport {
+    /// The name of the package.
+    pub name: String,
+    /// The number of passed tests.
+    pub passed: usize,
+    /// The number of failed tests.
+    pub failed: usize,
+    /// The sum of the durations of the tests, in seconds.
+    pub duration_secs: f64,
+    /// The results of each test.
+    pub tests: Vec<TestReportEntry>,
+}
+
+/// The result of a single test.
+#[derive(Debug, Clone, Serialize)]
+pub struct TestReportEntry {
+    /// The name of the test function.
+    pub name: String,
+    /// The file that contains the test function.
+    pub file_path: String,
+    /// The line number of the test declaration.
+    pub line_number: Option<usize>,
+    /// Whether or not the test passed.
+    pub passed: bool,
+    /// The time taken for the test to execute, in seconds.
+    pub duration_secs: f64,
+    /// Gas used while executing the test.
+    pub gas_used: u64,
+    /// The revert code, if the test reverted.
+    pub revert_code: Option<u64>,
+    /// The known error signal of the revert code, if any.
+    pub error_signal: Option<String>,
+    /// Whether the test ran out of gas.
+    pub out_of_gas: bool,
+    /// Whether the test was stopped because it ran for longer than the timeout.
+    pub timed_out: bool,
+    /// The expectations set through cheatcodes which were not met.
+    pub unmet_expectations: Vec<String>,
+    /// The result of verifying the predicate under test, if the test declared a transaction
+    /// spending it.
+    pub predicate: Option<PredicateResult>,
+    /// The logs emitted by the test.
+    pub logs: Vec<LogReport>,
+}
+
+/// A log emitted by a test.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogReport {
+    /// The log id, i.e. the `rb` register of the receipt.
+    pub log_id: u64,
+    /// The decoded value of the log, if it could be decoded using the package's ABI.
+    pub value: Option<String>,
+    /// The raw data of the log, hex encoded.
+    pub data: Option<String>,
+}
+
+impl TestReport {
+    /// Collects the results of the tested package or of all tested workspace members.
+    pub fn from_tested(tested: &Tested) -> Self {
+        let packages = match tested {
+            Tested::Package(pkg) => vec![PackageReport::from_tested(pkg)],
+            Tested::Workspace(pkgs) => pkgs.iter().map(PackageReport::from_tested).collect(),
+        };
+        Self { packages }
+    }
+
+    /// Renders the report as pretty-printed JSON.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Renders the report as a JUnit XML document, with a `testsuite` for each package.
+    pub fn to_junit(&self) -> String {
+        let tests: usize = self.packages.iter().map(|pkg| pkg.tests.len()).sum();
+        let failures: usize = self.packages.iter().map(|pkg| pkg.failed).sum();
+        let duration_secs: f64 = self.packages.iter().map(|pkg| pkg.duration_secs).sum();
+
+        let mut xml = String::new();
+        let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            xml,
+            r#"<testsuites name="forc test" tests="{tests}" failures="{failures}" time="{duration_secs}">"#
+        );
+        for pkg in &self.packages {
+            let _ = writeln!(
+                xml,
+                r#"  <testsuite name="{}" tests="{}" failures="{}" time="{}">"#,
+                escape_xml(&pkg.name),
+                pkg.tests.len(),
+                pkg.failed,
+                pkg.duration_secs
+            );
+            for test in &pkg.tests {
+                test.write_junit_test_case(&mut xml, &pkg.name);
+            }
+            let _ = writeln!(xml, "  </testsuite>");
+        }
+        let _ = writeln!(xml, "</testsuites>");
+        xml
+    }
+}
+
+impl PackageReport {
+    fn from_tested(tested: &TestedPackage) -> Self {
+        let tests = tested
+            .tests
+            .iter()
+            .map(|test| TestReportEntry::from_test_result(test, tested))
+            .collect::<Vec<_>>();
+        let passed = tests.iter().filter(|test| test.passed).count();
+        Self {
+            name: tested.built.descriptor.name.clone(),
+            passed,
+            failed: tests.len() - passed,
+            duration_secs: tests.iter().map(|test| test.duration_secs).sum(),
+            tests,
+        }
+    }
+}
+
+impl TestReportEntry {
+    fn from_test_result(test: &TestResult, tested: &TestedPackage) -> Self {
+        let logs = test
+            .logs
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::Log { ra, rb, .. } => Some(LogReport {
+                    log_id: *rb,
+                    value: Some(ra.to_string()),
+                    data: None,
+                }),
+                Receipt::LogData { rb, data, .. } => Some(LogReport {
+                    log_id: *rb,
+                    value: data.as_ref().and_then(|data| {
+                        decode_log_data(&rb.to_string(), data, &tested.built.program_abi)
+                            .ok()
+                            .map(|decoded| decoded.value)
+                    }),
+                    data: data.as_ref().map(hex::encode),
+                }),
+                _ => None,
+            })
+            .collect();
+        Self {
+            name: test.name.clone(),
+            file_path: test.file_path.display().to_string(),
+            line_number: test.details().ok().map(|details| details.line_number),
+            passed: test.passed(),
+            duration_secs: test.duration.as_secs_f64(),
+            gas_used: test.gas_used,
+            revert_code: test.revert_code(),
+            error_signal: test
+                .error_signal()
+                .ok()
+                .map(|error_signal| error_signal.to_string()),
+            out_of_gas: test.out_of_gas,
+            timed_out: test.timed_out,
+            unmet_expectations: test.unmet_expectations.clone(),
+            predicate: test.predicate.clone(),
+            logs,
+        }
+    }
+
+    fn write_junit_test_case(&self, xml: &mut String, pkg_name: &str) {
+        let line = self
+            .line_number
+            .map(|line| format!(r#" line="{line}""#))
+            .unwrap_or_default();
+        let _ = writeln!(
+            xml,
+            r#"    <testcase name="{}" classname="{}" file="{}"{line} time="{}">"#,
+            escape_xml(&self.name),
+            escape_xml(pkg_name),
+            escape_xml(&self.file_path),
+            self.duration_secs
+        );
+        let _ = writeln!(xml, "      <properties>");
+        let _ = writeln!(
+            xml,
+            r#"        <property name="gas_used" value="{}"/>"#,
+            self.gas_used
+        );
+        if let Some(predicate) = &self.predicate {
+            let _ = writeln!(
+                xml,
+                r#"        <property name="predicate_verified" value="{}"/>"#,
+                predicate.verified
+            );
+            let _ = writeln!(
+                xml,
+                r#"        <property name="predicate_gas_used" value="{}"/>"#,
+                predicate.gas_used
+            );
+        }
+        let _ = writeln!(xml, "      </properties>");
+        if !self.passed {
+            let message = match (self.revert_code, &self.error_signal) {
+                _ if self.out_of_gas => "out of gas".to_string(),
+                _ if self.timed_out => "timed out".to_string(),
+                (Some(revert_code), Some(error_signal)) => {
+                    format!("revert code: {revert_code:x} -- {error_signal}")
+                }
+                (Some(revert_code), None) => format!("revert code: {revert_code:x}"),
+                (None, _) if !self.unmet_expectations.is_empty() => {
+                    self.unmet_expectations.join("; ")
+                }
+                (None, _) => "test did not revert as expected".to_string(),
+            };
+            let _ = writeln!(
+                xml,
+                r#"      <failure message="{}" type="{}"/>"#,
+                escape_xml(&message),
+                match (self.revert_code, self.unmet_expectations.is_empty()) {
+                    _ if self.out_of_gas => "out_of_gas",
+                    _ if self.timed_out => "timeout",
+                    (Some(_), _) => "revert",
+                    (None, false) => "unmet_expectation",
+                    (None, true) => "no_revert",
+                }
+            );
+        }
+        if !self.logs.is_empty() {
+            let logs = self
+                .logs
+                .iter()
+                .map(|log| match (&log.value, &log.data) {
+                    (Some(value), _) => format!("log {}: {value}", log.log_id),
+                    (None, Some(data)) => format!("log {}: 0x{data}", log.log_id),
+                    (None, None) => format!("log {}", log.log_id),
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let _ = writeln!(xml, "      <system-out>{}</system-out>", escape_xml(&logs));
+        }
+        let _ = writeln!(xml, "    </testcase>");
+    }
+}
+
+/// Escapes the characters that are not allowed in XML attributes and text.
+pub(crate) fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, passed: bool, revert_code: Option<u64>) -> TestReportEntry {
+        TestReportEntry {
+            name: name.to_string(),
+            file_path: "src/main.sw".to_string(),
+            line_number: Some(7),
+            passed,
+            duration_secs: 0.5,
+            gas_used: 42,
+            revert_code,
+            error_signal: None,
+            out_of_gas: false,
+            timed_out: false,
+            unmet_expectations: vec![],
+            predicate: None,
+            logs: vec![LogReport {
+                log_id: 0,
+                value: Some("<ok>".to_string()),
+                data: Some("2a".to_string()),
+            }],
+        }
+    }
+
+    fn report() -> TestReport {
+        TestReport {
+            packages: vec![PackageReport {
+                name: "pkg_a".to_string(),
+                passed: 1,
+                failed: 1,
+                duration_secs: 1.0,
+                tests: vec![
+                    entry("test_ok", true, None),
+                    entry("test_bad", false, Some(42)),
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn junit_report() {
+        let xml = report().to_junit();
+        assert!(xml.contains(r#"<testsuites name="forc test" tests="2" failures="1" time="1">"#));
+        assert!(xml.contains(r#"<testsuite name="pkg_a" tests="2" failures="1" time="1">"#));
+        assert!(xml.contains(
+            r#"<testcase name="test_ok" classname="pkg_a" file="src/main.sw" line="7" time="0.5">"#
+        ));
+        assert!(xml.contains(r#"<failure message="revert code: 2a" type="revert"/>"#));
+        assert!(xml.contains("<system-out>log 0: &lt;ok&gt;</system-out>"));
+        assert_eq!(xml.matches("<failure").count(), 1);
+    }
+
+    #[test]
+    fn json_report() {
+        let json: serde_json::Value = serde_json::from_str(&report().to_json().unwrap()).unwrap();
+        let tests = &json["packages"][0]["tests"];
+        assert_eq!(json["packages"][0]["name"], "pkg_a");
+        assert_eq!(tests[1]["name"], "test_bad");
+        assert_eq!(tests[1]["revert_code"], 42);
+        assert_eq!(tests[1]["gas_used"], 42);
+        assert_eq!(tests[0]["logs"][0]["value"], "<ok>");
+    }
+}
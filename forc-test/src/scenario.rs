@@ -0,0 +1,830 @@
+//! Declarative multi-contract test scenarios.
+//!
+//! A scenario is a TOML file listed under `[test] scenarios` in the `Forc.toml` of a package. It
+//! describes the contracts to deploy, each one an instance of the tested contract or of one of its
+//! contract dependencies, and a sequence of calls to them with their expected results:
+//!
+//! ```toml
+//! [[contract]]
+//! name = "token"
+//! package = "my_token"
+//! salt = "0x0000000000000000000000000000000000000000000000000000000000000001"
+//! configurables = { DECIMALS = 9 }
+//! storage = [{ key = "0x...", value = "0x..." }]
+//! balances = [{ amount = 100 }]
+//!
+//! [[call]]
+//! contract = "token"
+//! function = "mint"
+//! args = ["@token", 100]
+//! expect = 100
+//!
+//! [[call]]
+//! contract = "token"
+//! function = "burn"
+//! args = [1000]
+//! expect-revert = true
+//! ```
+//!
+//! Values are written as TOML values of the ABI types of the arguments: integers and booleans as
+//! such, `b256` values and byte sequences as hex strings, structs as tables, enums as the name of a
+//! unit variant or as a table with a single variant, and tuples, arrays and vectors as arrays.
+//! `"@name"` is the id of the deployed contract `name`, and can be used wherever a `b256`,
+//! `ContractId` or `Address` is expected.
+//!
+//! Scenarios are executed by the in-memory VM of `forc-test`. The calls are executed in order, each
+//! in its own transaction, and the state of the contracts persists between them.
+
+use crate::{deployment_transaction_with_salt, maxed_consensus_params, TEST_METADATA_SEED};
+use anyhow::{anyhow, bail, Context};
+use fuel_abi_types::abi::unified_program::{UnifiedProgramABI, UnifiedTypeDeclaration};
+use fuel_tx::{
+    self as tx, output::contract::Contract, AssetId, Bytes32, Chargeable, ContractId, Finalizable,
+    Receipt, StorageSlot,
+};
+use fuel_vm::{
+    checked_transaction::builder::TransactionBuilderExt,
+    fuel_asm::{op, GTFArgs, Instruction, RegId},
+    interpreter::{InterpreterParams, MemoryInstance, NotSupportedEcal},
+    prelude::{Interpreter, SecretKey},
+    state::ProgramState,
+    storage::{ContractsAssetsStorage, MemoryStorage},
+};
+use fuels_core::{
+    codec::{ABIDecoder, ABIEncoder, EncoderConfig},
+    types::{param_types::ParamType, StaticStringToken, Token, U256},
+};
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use sway_core::asm_generation::ProgramABI;
+
+/// The offset of the encoded function selector in the script data of a call.
+const CALL_SELECTOR_OFFSET: usize = 80;
+
+/// A test scenario, as read from its TOML file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Scenario {
+    /// The contracts to deploy, in the order of their deployment.
+    #[serde(default, rename = "contract")]
+    pub contracts: Vec<ScenarioContract>,
+    /// The calls to execute, in order.
+    #[serde(default, rename = "call")]
+    pub calls: Vec<ScenarioCall>,
+}
+
+/// A contract deployed by a scenario.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ScenarioContract {
+    /// The name of the deployed instance, referred to by calls and by `"@name"` values.
+    pub name: String,
+    /// The contract package to deploy. Defaults to the name of the instance.
+    pub package: Option<String>,
+    /// The salt of the deployment, as a hex string. Defaults to zero.
+    pub salt: Option<String>,
+    /// The values of the configurables of the contract, by name.
+    #[serde(default)]
+    pub configurables: BTreeMap<String, toml::Value>,
+    /// Storage slots overriding the initial storage of the contract.
+    #[serde(default)]
+    pub storage: Vec<ScenarioStorageSlot>,
+    /// The initial balances of the contract.
+    #[serde(default)]
+    pub balances: Vec<ScenarioBalance>,
+}
+
+/// A storage slot of a contract deployed by a scenario.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ScenarioStorageSlot {
+    /// The key of the slot, as a hex string.
+    pub key: String,
+    /// The value of the slot, as a hex string.
+    pub value: String,
+}
+
+/// A balance of a contract deployed by a scenario.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ScenarioBalance {
+    /// The asset id, as a hex string. Defaults to the base asset.
+    pub asset_id: Option<String>,
+    /// The amount of the asset.
+    pub amount: u64,
+}
+
+/// A call executed by a scenario.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ScenarioCall {
+    /// The name of the called contract instance.
+    pub contract: String,
+    /// The name of the called ABI function.
+    pub function: String,
+    /// The arguments of the call.
+    #[serde(default)]
+    pub args: Vec<toml::Value>,
+    /// The amount of coins forwarded to the contract.
+    #[serde(default)]
+    pub amount: u64,
+    /// The asset of the forwarded coins, as a hex string. Defaults to the base asset.
+    pub asset_id: Option<String>,
+    /// The value the call is expected to return.
+    pub expect: Option<toml::Value>,
+    /// Whether the call is expected to revert, or the revert code it is expected to revert with.
+    pub expect_revert: Option<ExpectRevert>,
+}
+
+/// The expected revert of a call.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(untagged)]
+pub enum ExpectRevert {
+    /// Whether the call is expected to revert, with any revert code.
+    Any(bool),
+    /// The revert code the call is expected to revert with.
+    Code(u64),
+}
+
+/// A contract package which can be deployed by scenarios.
+#[derive(Debug, Clone, Copy)]
+pub struct ScenarioPackage<'a> {
+    /// The name of the package.
+    pub name: &'a str,
+    /// The bytecode of the contract, without tests.
+    pub bytecode: &'a [u8],
+    /// The initial storage slots of the contract.
+    pub storage_slots: &'a [StorageSlot],
+    /// The ABI of the bytecode.
+    pub program_abi: &'a ProgramABI,
+}
+
+/// The result of executing a scenario.
+#[derive(Debug, Clone)]
+pub struct ScenarioResult {
+    /// The name of the scenario, i.e. the name of its file without extension.
+    pub name: String,
+    /// The path of the scenario file.
+    pub path: PathBuf,
+    /// The time taken to deploy the contracts and execute the calls.
+    pub duration: Duration,
+    /// The results of the calls, in the order of their execution.
+    pub calls: Vec<ScenarioCallResult>,
+}
+
+/// The result of a call executed by a scenario.
+#[derive(Debug, Clone)]
+pub struct ScenarioCallResult {
+    /// The called contract instance and function, e.g. `token.mint`.
+    pub name: String,
+    /// Whether the call behaved as expected.
+    pub passed: bool,
+    /// Gas used by the transaction executing the call.
+    pub gas_used: u64,
+    /// The revert code, if the call reverted.
+    pub revert_code: Option<u64>,
+    /// The decoded value returned by the call, if it returned.
+    pub value: Option<String>,
+    /// Why the call did not behave as expected.
+    pub failure: Option<String>,
+}
+
+/// A contract deployed by a scenario.
+struct DeployedContract {
+    contract_id: ContractId,
+    abi: UnifiedProgramABI,
+    type_lookup: HashMap<usize, UnifiedTypeDeclaration>,
+}
+
+impl Scenario {
+    /// Reads the scenario at the given path.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read test scenario {}", path.display()))?;
+        toml::from_str(&contents)
+            .map_err(|err| anyhow!("failed to parse test scenario {}: {err}", path.display()))
+    }
+
+    /// Deploys the contracts of the scenario and executes its calls.
+    ///
+    /// Returns an error if the scenario is invalid, e.g. if it refers to an unknown package or
+    /// function, and the results of the calls otherwise.
+    pub fn run(
+        &self,
+        name: String,
+        path: PathBuf,
+        packages: &[ScenarioPackage],
+    ) -> anyhow::Result<ScenarioResult> {
+        let start = Instant::now();
+        let (contracts, storage) = self.deploy(packages)?;
+        let contract_ids = contracts
+            .iter()
+            .map(|(name, contract)| (name.clone(), contract.contract_id))
+            .collect::<BTreeMap<_, _>>();
+
+        let params = maxed_consensus_params();
+        let mut interpreter: Interpreter<_, _, tx::Script, NotSupportedEcal> =
+            Interpreter::with_storage(
+                MemoryInstance::new(),
+                storage,
+                InterpreterParams::new(0, params.clone()),
+            );
+        let calls = self
+            .calls
+            .iter()
+            .enumerate()
+            .map(|(ix, call)| {
+                let contract = contracts.get(&call.contract).ok_or_else(|| {
+                    anyhow!(
+                        "call {} refers to unknown contract `{}`",
+                        ix + 1,
+                        call.contract
+                    )
+                })?;
+                call.execute(contract, &contract_ids, &mut interpreter)
+                    .with_context(|| format!("invalid call {} of scenario `{name}`", ix + 1))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(ScenarioResult {
+            name,
+            path,
+            duration: start.elapsed(),
+            calls,
+        })
+    }
+
+    /// Deploys the contracts of the scenario and sets their balances.
+    fn deploy(
+        &self,
+        packages: &[ScenarioPackage],
+    ) -> anyhow::Result<(BTreeMap<String, DeployedContract>, MemoryStorage)> {
+        let params = maxed_consensus_params();
+        let mut interpreter: Interpreter<_, _, tx::Create, NotSupportedEcal> =
+            Interpreter::with_storage(
+                MemoryInstance::new(),
+                MemoryStorage::default(),
+                InterpreterParams::new(0, params.clone()),
+            );
+
+        let mut contracts = BTreeMap::new();
+        let mut contract_ids = BTreeMap::new();
+        for contract in &self.contracts {
+            let package_name = contract.package.as_deref().unwrap_or(&contract.name);
+            let package = packages
+                .iter()
+                .find(|package| package.name == package_name)
+                .ok_or_else(|| {
+                    let names = packages
+                        .iter()
+                        .map(|package| package.name)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    anyhow!(
+                        "contract `{}` refers to unknown contract package `{package_name}`, \
+                         expected one of: {names}",
+                        contract.name
+                    )
+                })?;
+            let abi = match package.program_abi {
+                ProgramABI::Fuel(abi) => UnifiedProgramABI::from_counterpart(abi)?,
+                _ => bail!("only fuelvm is supported for test scenarios"),
+            };
+            let type_lookup = abi
+                .types
+                .iter()
+                .map(|decl| (decl.type_id, decl.clone()))
+                .collect::<HashMap<_, _>>();
+
+            let mut bytecode = package.bytecode.to_vec();
+            for (name, value) in &contract.configurables {
+                let configurable = abi
+                    .configurables
+                    .iter()
+                    .flatten()
+                    .find(|configurable| &configurable.name == name)
+                    .ok_or_else(|| {
+                        anyhow!("contract `{}` has no configurable `{name}`", contract.name)
+                    })?;
+                let param_type =
+                    ParamType::try_from_type_application(&configurable.application, &type_lookup)?;
+                let token = toml_to_token(value, &param_type, &contract_ids)
+                    .with_context(|| format!("invalid value of configurable `{name}`"))?;
+                let encoded = ABIEncoder::new(EncoderConfig::default()).encode(&[token])?;
+                let offset = configurable.offset as usize;
+                bytecode
+                    .get_mut(offset..offset + encoded.len())
+                    .ok_or_else(|| anyhow!("configurable `{name}` is out of the bytecode"))?
+                    .copy_from_slice(&encoded);
+            }
+
+            let mut storage_slots = package.storage_slots.to_vec();
+            for slot in &contract.storage {
+                let key = Bytes32::new(parse_b256(&slot.key)?);
+                let value = Bytes32::new(parse_b256(&slot.value)?);
+                storage_slots.retain(|existing| existing.key() != &key);
+                storage_slots.push(StorageSlot::new(key, value));
+            }
+
+            let salt = match &contract.salt {
+                Some(salt) => tx::Salt::new(parse_b256(salt)?),
+                None => tx::Salt::zeroed(),
+            };
+            let (contract_id, tx) =
+                deployment_transaction_with_salt(&bytecode, storage_slots, salt, &params);
+            if contract_ids.values().any(|id| *id == contract_id) {
+                bail!(
+                    "contract `{}` has the same id as a previously deployed contract, \
+                     use a different salt",
+                    contract.name
+                );
+            }
+            let tx = tx
+                .into_ready(0, params.gas_costs(), params.fee_params())
+                .map_err(|err| anyhow!("{err:?}"))?;
+            interpreter.transact(tx).map_err(anyhow::Error::msg)?;
+
+            contract_ids.insert(contract.name.clone(), contract_id);
+            let deployed = DeployedContract {
+                contract_id,
+                abi,
+                type_lookup,
+            };
+            if contracts.insert(contract.name.clone(), deployed).is_some() {
+                bail!("contract `{}` is declared more than once", contract.name);
+            }
+        }
+
+        let mut storage = interpreter.as_ref().clone();
+        for contract in &self.contracts {
+            let contract_id = contract_ids[&contract.name];
+            for balance in &contract.balances {
+                let asset_id = parse_asset_id(balance.asset_id.as_deref())?;
+                storage
+                    .contract_asset_id_balance_insert(&contract_id, &asset_id, balance.amount)
+                    .map_err(|err| anyhow!("{err:?}"))?;
+            }
+        }
+        Ok((contracts, storage))
+    }
+}
+
+impl ScenarioCall {
+    /// Executes the call in its own transaction, including all the deployed contracts.
+    fn execute(
+        &self,
+        contract: &DeployedContract,
+        contract_ids: &BTreeMap<String, ContractId>,
+        interpreter: &mut Interpreter<MemoryInstance, MemoryStorage, tx::Script, NotSupportedEcal>,
+    ) -> anyhow::Result<ScenarioCallResult> {
+        let function = contract
+            .abi
+            .functions
+            .iter()
+            .find(|function| function.name == self.function)
+            .ok_or_else(|| {
+                anyhow!(
+                    "contract `{}` has no function `{}`",
+                    self.contract,
+                    self.function
+                )
+            })?;
+        if function.inputs.len() != self.args.len() {
+            bail!(
+                "function `{}` expects {} arguments, {} were provided",
+                self.function,
+                function.inputs.len(),
+                self.args.len()
+            );
+        }
+        let args = function
+            .inputs
+            .iter()
+            .zip(&self.args)
+            .map(|(input, value)| {
+                let param_type =
+                    ParamType::try_from_type_application(input, &contract.type_lookup)?;
+                toml_to_token(value, &param_type, contract_ids)
+                    .with_context(|| format!("invalid value of argument `{}`", input.name))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let output_type =
+            ParamType::try_from_type_application(&function.output, &contract.type_lookup)?;
+        let encoder = ABIEncoder::new(EncoderConfig::default());
+        let expected = self
+            .expect
+            .as_ref()
+            .map(|value| {
+                let token = toml_to_token(value, &output_type, contract_ids)
+                    .context("invalid expected value")?;
+                Ok::<_, anyhow::Error>(encoder.encode(&[token])?)
+            })
+            .transpose()?;
+
+        let asset_id = parse_asset_id(self.asset_id.as_deref())?;
+        let script_data = call_script_data(
+            contract.contract_id,
+            asset_id,
+            self.amount,
+            &self.function,
+            &encoder.encode(&args)?,
+        );
+        let tx = call_transaction(script_data, asset_id, self.amount, contract_ids)?;
+
+        let (state, receipts) = match interpreter.transact(tx) {
+            Ok(transition) => (*transition.state(), transition.receipts().to_vec()),
+            // If the VM fails, we interpret it as a revert.
+            Err(_) => (ProgramState::Revert(0), vec![]),
+        };
+        let gas_used = receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                _ => None,
+            })
+            .unwrap_or_default();
+        let revert_code = match state {
+            ProgramState::Revert(revert_code) => Some(revert_code),
+            _ => None,
+        };
+        let returned = receipts.iter().rev().find_map(|receipt| match receipt {
+            Receipt::ReturnData {
+                id,
+                data: Some(data),
+                ..
+            } if *id == contract.contract_id => Some(data.clone()),
+            _ => None,
+        });
+        let decoder = ABIDecoder::default();
+        let describe = |data: &[u8]| {
+            decoder
+                .decode_as_debug_str(&output_type, data)
+                .unwrap_or_else(|_| format!("0x{}", hex::encode(data)))
+        };
+        let value = match revert_code {
+            Some(_) => None,
+            None => returned.as_deref().map(describe),
+        };
+
+        let failure = match (self.expect_revert, revert_code) {
+            (Some(ExpectRevert::Any(true)), None) | (Some(ExpectRevert::Code(_)), None) => {
+                Some("expected the call to revert".to_string())
+            }
+            (Some(ExpectRevert::Code(expected)), Some(revert_code)) if expected != revert_code => {
+                Some(format!(
+                    "expected revert code {expected:x}, reverted with {revert_code:x}"
+                ))
+            }
+            (Some(ExpectRevert::Code(_)), Some(_)) | (Some(ExpectRevert::Any(true)), Some(_)) => {
+                None
+            }
+            (_, Some(revert_code)) => Some(format!("reverted with code {revert_code:x}")),
+            (_, None) => match (&expected, &returned) {
+                (Some(expected), Some(returned)) if expected != returned => Some(format!(
+                    "expected {}, returned {}",
+                    describe(expected),
+                    describe(returned)
+                )),
+                (Some(expected), None) => {
+                    Some(format!("expected {}, returned nothing", describe(expected)))
+                }
+                _ => None,
+            },
+        };
+
+        Ok(ScenarioCallResult {
+            name: format!("{}.{}", self.contract, self.function),
+            passed: failure.is_none(),
+            gas_used,
+            revert_code,
+            value,
+            failure,
+        })
+    }
+}
+
+impl ScenarioResult {
+    /// Whether all the calls of the scenario behaved as expected.
+    pub fn passed(&self) -> bool {
+        self.calls.iter().all(|call| call.passed)
+    }
+}
+
+/// Returns the script calling a contract with the call described by the script data.
+///
+/// The script data holds the id of the contract, the id and amount of the forwarded asset, the
+/// offset of the encoded arguments, then the encoded function selector and arguments.
+fn call_script() -> Vec<u8> {
+    let [data, frame, selector, args, asset_id, amount]: [u8; 6] =
+        [0x10, 0x11, 0x12, 0x13, 0x14, 0x15];
+    [
+        op::gtf_args(data, RegId::ZERO, GTFArgs::ScriptData),
+        // The call frame must be in memory owned by the script.
+        op::move_(frame, RegId::SP),
+        op::cfei(48),
+        op::mcpi(frame, data, 32),
+        op::addi(selector, data, CALL_SELECTOR_OFFSET as u16),
+        op::sw(frame, selector, 4),
+        op::lw(args, data, 9),
+        op::add(args, data, args),
+        op::sw(frame, args, 5),
+        op::addi(asset_id, data, 32),
+        op::lw(amount, data, 8),
+        op::call(frame, amount, asset_id, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .flat_map(Instruction::to_bytes)
+    .collect()
+}
+
+/// Returns the script data of a call, as expected by [call_script].
+fn call_script_data(
+    contract_id: ContractId,
+    asset_id: AssetId,
+    amount: u64,
+    function: &str,
+    args: &[u8],
+) -> Vec<u8> {
+    // Functions are selected by their encoded name.
+    let mut selector = (function.len() as u64).to_be_bytes().to_vec();
+    selector.extend_from_slice(function.as_bytes());
+    let args_offset = (CALL_SELECTOR_OFFSET + selector.len()) as u64;
+    [
+        contract_id.as_ref(),
+        asset_id.as_ref(),
+        &amount.to_be_bytes(),
+        &args_offset.to_be_bytes(),
+        &selector,
+        args,
+    ]
+    .concat()
+}
+
+/// Creates the transaction executing a call, with all the deployed contracts as inputs.
+fn call_transaction(
+    script_data: Vec<u8>,
+    asset_id: AssetId,
+    amount: u64,
+    contract_ids: &BTreeMap<String, ContractId>,
+) -> anyhow::Result<fuel_vm::checked_transaction::Ready<tx::Script>> {
+    let rng = &mut rand::rngs::StdRng::seed_from_u64(TEST_METADATA_SEED);
+    let secret_key = SecretKey::random(rng);
+    let block_height = (u32::MAX >> 1).into();
+    let params = maxed_consensus_params();
+
+    let mut tx_builder = tx::TransactionBuilder::script(call_script(), script_data);
+    tx_builder.with_params(params.clone()).maturity(1.into());
+    tx_builder.add_unsigned_coin_input(secret_key, rng.gen(), 1, AssetId::BASE, rng.gen());
+    // The forwarded coins are provided by an additional coin input.
+    let mut output_index = 1;
+    if amount != 0 {
+        tx_builder.add_unsigned_coin_input(secret_key, rng.gen(), amount, asset_id, rng.gen());
+        output_index += 1;
+    }
+    for contract_id in contract_ids.values() {
+        tx_builder
+            .add_input(tx::Input::contract(
+                tx::UtxoId::new(tx::Bytes32::zeroed(), 0),
+                tx::Bytes32::zeroed(),
+                tx::Bytes32::zeroed(),
+                tx::TxPointer::new(0u32.into(), 0),
+                *contract_id,
+            ))
+            .add_output(tx::Output::Contract(Contract {
+                input_index: output_index,
+                balance_root: tx::Bytes32::zeroed(),
+                state_root: tx::Bytes32::zeroed(),
+            }));
+        output_index += 1;
+    }
+
+    // Get `max_gas` used by everything except the script execution. Add `1` because of rounding.
+    let tmp_tx = tx_builder.clone().finalize();
+    let max_gas = tmp_tx.max_gas(params.gas_costs(), params.fee_params()) + 1;
+    tx_builder.script_gas_limit(params.tx_params().max_gas_per_tx() - max_gas);
+
+    tx_builder
+        .finalize_checked(block_height)
+        .into_ready(0, params.gas_costs(), params.fee_params())
+        .map_err(|err| anyhow!("{err:?}"))
+}
+
+/// Converts a TOML value of a scenario to a token of the given ABI type.
+fn toml_to_token(
+    value: &toml::Value,
+    param_type: &ParamType,
+    contract_ids: &BTreeMap<String, ContractId>,
+) -> anyhow::Result<Token> {
+    let unexpected = || anyhow!("expected a value of type {param_type:?}, found `{value}`");
+    let uint = || -> anyhow::Result<u128> {
+        match value {
+            toml::Value::Integer(int) => u128::try_from(*int).map_err(|_| unexpected()),
+            toml::Value::String(s) => match s.strip_prefix("0x") {
+                Some(hex) => u128::from_str_radix(hex, 16).map_err(|_| unexpected()),
+                None => s.parse().map_err(|_| unexpected()),
+            },
+            _ => Err(unexpected()),
+        }
+    };
+    let string = || value.as_str().ok_or_else(unexpected);
+    let array = || value.as_array().ok_or_else(unexpected);
+    let tokens = |values: &[toml::Value], param_types: &mut dyn Iterator<Item = &ParamType>| {
+        values
+            .iter()
+            .zip(param_types)
+            .map(|(value, param_type)| toml_to_token(value, param_type, contract_ids))
+            .collect::<anyhow::Result<Vec<_>>>()
+    };
+
+    let token = match param_type {
+        ParamType::Unit => Token::Unit,
+        ParamType::Bool => Token::Bool(value.as_bool().ok_or_else(unexpected)?),
+        ParamType::U8 => Token::U8(uint()?.try_into().map_err(|_| unexpected())?),
+        ParamType::U16 => Token::U16(uint()?.try_into().map_err(|_| unexpected())?),
+        ParamType::U32 => Token::U32(uint()?.try_into().map_err(|_| unexpected())?),
+        ParamType::U64 => Token::U64(uint()?.try_into().map_err(|_| unexpected())?),
+        ParamType::U128 => Token::U128(uint()?),
+        ParamType::U256 => Token::U256(match value {
+            toml::Value::String(s) => match s.strip_prefix("0x") {
+                Some(hex) => U256::from_str_radix(hex, 16).map_err(|_| unexpected())?,
+                None => U256::from_dec_str(s).map_err(|_| unexpected())?,
+            },
+            _ => U256::from(uint()?),
+        }),
+        ParamType::B256 => Token::B256(match string()?.strip_prefix('@') {
+            Some(name) => **contract_ids
+                .get(name)
+                .ok_or_else(|| anyhow!("unknown contract `{name}`"))?,
+            None => parse_b256(string()?)?,
+        }),
+        ParamType::Bytes => Token::Bytes(parse_hex(string()?)?),
+        ParamType::RawSlice => Token::RawSlice(parse_hex(string()?)?),
+        ParamType::String => Token::String(string()?.to_string()),
+        ParamType::StringSlice => {
+            Token::StringSlice(StaticStringToken::new(string()?.to_string(), None))
+        }
+        ParamType::StringArray(len) => {
+            let s = string()?;
+            if s.len() != *len {
+                bail!("expected a string of length {len}, found `{s}`");
+            }
+            Token::StringArray(StaticStringToken::new(s.to_string(), Some(*len)))
+        }
+        ParamType::Tuple(param_types) => {
+            let values = array()?;
+            if values.len() != param_types.len() {
+                return Err(unexpected());
+            }
+            Token::Tuple(tokens(values, &mut param_types.iter())?)
+        }
+        ParamType::Array(param_type, len) => {
+            let values = array()?;
+            if values.len() != *len {
+                return Err(unexpected());
+            }
+            Token::Array(tokens(values, &mut std::iter::repeat(&**param_type))?)
+        }
+        ParamType::Vector(param_type) => {
+            Token::Vector(tokens(array()?, &mut std::iter::repeat(&**param_type))?)
+        }
+        // Wrappers of a single `b256`, e.g. `ContractId` and `Address`, can be written as strings.
+        ParamType::Struct { fields, .. }
+            if value.is_str() && matches!(fields.as_slice(), [(_, ParamType::B256)]) =>
+        {
+            Token::Struct(vec![toml_to_token(value, &fields[0].1, contract_ids)?])
+        }
+        ParamType::Struct { fields, .. } => {
+            let table = value.as_table().ok_or_else(unexpected)?;
+            if let Some(name) = table
+                .keys()
+                .find(|name| !fields.iter().any(|(field, _)| field == *name))
+            {
+                bail!("unknown field `{name}`");
+            }
+            Token::Struct(
+                fields
+                    .iter()
+                    .map(|(name, param_type)| {
+                        let value = table
+                            .get(name)
+                            .ok_or_else(|| anyhow!("missing field `{name}`"))?;
+                        toml_to_token(value, param_type, contract_ids)
+                    })
+                    .collect::<anyhow::Result<_>>()?,
+            )
+        }
+        ParamType::Enum { enum_variants, .. } => {
+            let (name, variant_value) = match value {
+                toml::Value::String(name) => (name.as_str(), None),
+                toml::Value::Table(table) if table.len() == 1 => {
+                    let (name, value) = table.iter().next().expect("table has one entry");
+                    (name.as_str(), Some(value))
+                }
+                _ => return Err(unexpected()),
+            };
+            let variants = enum_variants.variants();
+            let discriminant = variants
+                .iter()
+                .position(|(variant, _)| variant == name)
+                .ok_or_else(|| anyhow!("unknown variant `{name}`"))?;
+            let variant_type = &variants[discriminant].1;
+            let token = match (variant_value, variant_type) {
+                (None, ParamType::Unit) => Token::Unit,
+                (None, _) => bail!("variant `{name}` expects a value"),
+                (Some(value), variant_type) => toml_to_token(value, variant_type, contract_ids)?,
+            };
+            Token::Enum(Box::new((
+                discriminant as u64,
+                token,
+                enum_variants.clone(),
+            )))
+        }
+    };
+    Ok(token)
+}
+
+/// Parses a hex string, with or without a `0x` prefix.
+fn parse_hex(s: &str) -> anyhow::Result<Vec<u8>> {
+    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|_| anyhow!("invalid hex `{s}`"))
+}
+
+/// Parses a hex string of 32 bytes, with or without a `0x` prefix.
+fn parse_b256(s: &str) -> anyhow::Result<[u8; 32]> {
+    parse_hex(s)?
+        .try_into()
+        .map_err(|_| anyhow!("expected 32 bytes, found `{s}`"))
+}
+
+/// Parses an asset id, defaulting to the base asset.
+fn parse_asset_id(asset_id: Option<&str>) -> anyhow::Result<AssetId> {
+    match asset_id {
+        Some(asset_id) => Ok(AssetId::new(parse_b256(asset_id)?)),
+        None => Ok(AssetId::BASE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_scenario() {
+        let scenario: Scenario = toml::from_str(
+            r#"
+            [[contract]]
+            name = "token"
+            configurables = { DECIMALS = 9 }
+            balances = [{ amount = 100 }]
+
+            [[call]]
+            contract = "token"
+            function = "burn"
+            args = [1000]
+            expect-revert = 42
+            "#,
+        )
+        .unwrap();
+        assert_eq!(scenario.contracts[0].configurables.len(), 1);
+        assert_eq!(scenario.contracts[0].balances[0].amount, 100);
+        assert!(matches!(
+            scenario.calls[0].expect_revert,
+            Some(ExpectRevert::Code(42))
+        ));
+        assert!(toml::from_str::<Scenario>(
+            "[[call]]\ncontract = \"token\"\nfunction = \"f\"\nexpected = 1"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn toml_values_to_tokens() {
+        let contract_ids = [("token".to_string(), ContractId::new([1; 32]))]
+            .into_iter()
+            .collect();
+        let token = |value: &str, param_type: &ParamType| {
+            let value: toml::Value = toml::from_str(&format!("value = {value}")).unwrap();
+            toml_to_token(&value["value"], param_type, &contract_ids)
+        };
+        assert_eq!(token("42", &ParamType::U8).unwrap(), Token::U8(42));
+        assert_eq!(token("\"0x2a\"", &ParamType::U64).unwrap(), Token::U64(42));
+        assert!(token("256", &ParamType::U8).is_err());
+        assert_eq!(
+            token("\"@token\"", &ParamType::B256).unwrap(),
+            Token::B256([1; 32])
+        );
+        assert_eq!(
+            token(
+                "[true, 7]",
+                &ParamType::Tuple(vec![ParamType::Bool, ParamType::U32])
+            )
+            .unwrap(),
+            Token::Tuple(vec![Token::Bool(true), Token::U32(7)])
+        );
+    }
+}
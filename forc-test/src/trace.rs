@@ -0,0 +1,712 @@
+//! Execution traces of tests.
+//!
+//! With tracing enabled, the executor single-steps through the test and records the contract
+//! calls, returns, reverts and logs from the receipts emitted by the interpreter, as well as the
+//! storage reads and writes from the executed instructions. The records are assembled into a
+//! [CallTrace], a tree of the calls made by the test, with their arguments and returned values
+//! decoded through the ABIs of the called contracts and their locations mapped through the source
+//! maps of the executed programs.
+
+use crate::{decode_log_data, ecal::CheatcodeEcal};
+use fuel_abi_types::abi::unified_program::{
+    UnifiedABIFunction, UnifiedProgramABI, UnifiedTypeDeclaration,
+};
+use fuel_tx::{self as tx, Bytes32, ContractId, Receipt};
+use fuel_vm::{
+    fuel_asm::{Instruction, Opcode, RegId},
+    interpreter::{Interpreter, MemoryInstance},
+    storage::MemoryStorage,
+};
+use fuels_core::{
+    codec::{ABIDecoder, ABIEncoder, EncoderConfig},
+    types::param_types::ParamType,
+};
+use std::{collections::HashMap, fmt, path::PathBuf};
+use sway_core::{asm_generation::ProgramABI, source_map::SourceMap};
+
+/// The maximum number of bytes of encoded call arguments read from memory.
+const MAX_ARGS_LEN: u64 = 4096;
+
+/// A program executed by tests, together with what is needed to decode its calls and map its
+/// instructions to source locations.
+#[derive(Debug, Clone)]
+pub struct TracedProgram {
+    /// The id of the contract, or the zeroed contract id for the test itself.
+    pub contract_id: ContractId,
+    /// The name of the package of the program.
+    pub name: String,
+    /// The ABI of the program.
+    pub program_abi: ProgramABI,
+    /// The source map of the program.
+    pub source_map: SourceMap,
+}
+
+/// The programs which can be executed by the tests of a package.
+#[derive(Debug, Clone, Default)]
+pub struct TraceContext {
+    programs: HashMap<ContractId, TracedProgram>,
+    abis: HashMap<ContractId, (UnifiedProgramABI, HashMap<usize, UnifiedTypeDeclaration>)>,
+}
+
+/// The calls made by a test, as a tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallTrace {
+    /// The frame of the test itself.
+    pub root: TraceFrame,
+}
+
+/// A call, and what happened during it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceFrame {
+    /// The called contract, or the zeroed contract id for the test itself.
+    pub contract_id: ContractId,
+    /// The name of the call, e.g. `token.transfer`, or the name of the test.
+    pub name: String,
+    /// The called ABI function, if it is known.
+    pub function: Option<String>,
+    /// The decoded arguments of the call.
+    pub args: Option<String>,
+    /// The location of the call in the source of the caller.
+    pub location: Option<SourceLocation>,
+    /// What happened during the call, in order.
+    pub entries: Vec<TraceEntry>,
+    /// How the call ended, if it did.
+    pub outcome: Option<TraceOutcome>,
+}
+
+/// Something that happened during a call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEntry {
+    /// A nested call.
+    Call(TraceFrame),
+    /// A logged value.
+    Log {
+        log_id: u64,
+        value: String,
+        location: Option<SourceLocation>,
+    },
+    /// A read of storage slots.
+    StorageRead {
+        key: Bytes32,
+        value: Option<String>,
+        location: Option<SourceLocation>,
+    },
+    /// A write of storage slots.
+    StorageWrite {
+        key: Bytes32,
+        value: String,
+        location: Option<SourceLocation>,
+    },
+}
+
+/// How a call ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceOutcome {
+    /// The call returned, with the decoded returned value if any.
+    Return(Option<String>),
+    /// The call reverted with the given code.
+    Revert {
+        code: u64,
+        location: Option<SourceLocation>,
+    },
+    /// The call panicked for the given reason.
+    Panic {
+        reason: String,
+        location: Option<SourceLocation>,
+    },
+}
+
+/// A location in a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub path: PathBuf,
+    pub line: usize,
+    pub col: usize,
+}
+
+/// Records the trace of a test while the executor single-steps through it.
+#[derive(Debug, Clone)]
+pub(crate) struct TraceRecorder {
+    context: std::sync::Arc<TraceContext>,
+    /// The open frames, starting with the frame of the test.
+    stack: Vec<TraceFrame>,
+    /// The number of receipts already recorded.
+    num_receipts: usize,
+    /// The call about to be executed, completed with the called contract by its receipt.
+    pending_call: Option<PendingCall>,
+    /// The storage read about to be executed, completed with the read value at the next step.
+    pending_read: Option<PendingRead>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct PendingCall {
+    function: Option<String>,
+    /// The encoded arguments of the called function.
+    args: Vec<u8>,
+    /// The location of the call, as the receipt only locates the start of the called contract.
+    location: Option<SourceLocation>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingRead {
+    key: Bytes32,
+    location: Option<SourceLocation>,
+    /// The register receiving the read word, or the pointer and length of the read slots.
+    destination: ReadDestination,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ReadDestination {
+    Register(RegId),
+    Memory { ptr: u64, len: u64 },
+}
+
+type TestInterpreter = Interpreter<MemoryInstance, MemoryStorage, tx::Script, CheatcodeEcal>;
+
+impl TraceContext {
+    pub fn new(programs: impl IntoIterator<Item = TracedProgram>) -> Self {
+        let mut context = Self::default();
+        for program in programs {
+            if let ProgramABI::Fuel(abi) = &program.program_abi {
+                if let Ok(abi) = UnifiedProgramABI::from_counterpart(abi) {
+                    let type_lookup = abi
+                        .types
+                        .iter()
+                        .map(|decl| (decl.type_id, decl.clone()))
+                        .collect();
+                    context.abis.insert(program.contract_id, (abi, type_lookup));
+                }
+            }
+            context.programs.insert(program.contract_id, program);
+        }
+        context
+    }
+
+    /// The source location of the instruction at `pc` of the given contract.
+    fn location(&self, contract_id: ContractId, pc: u64) -> Option<SourceLocation> {
+        let program = self.programs.get(&contract_id)?;
+        let (path, range) = program
+            .source_map
+            .addr_to_span(pc as usize / Instruction::SIZE)?;
+        Some(SourceLocation {
+            path,
+            line: range.start.line,
+            col: range.start.col,
+        })
+    }
+
+    /// The ABI function of the given contract, with the types it refers to.
+    fn function(
+        &self,
+        contract_id: ContractId,
+        name: &str,
+    ) -> Option<(&UnifiedABIFunction, &HashMap<usize, UnifiedTypeDeclaration>)> {
+        let (abi, type_lookup) = self.abis.get(&contract_id)?;
+        let function = abi
+            .functions
+            .iter()
+            .find(|function| function.name == name)?;
+        Some((function, type_lookup))
+    }
+
+    /// Decodes the encoded arguments of a call to the given function.
+    fn decode_args(&self, contract_id: ContractId, function: &str, args: &[u8]) -> Option<String> {
+        let (function, type_lookup) = self.function(contract_id, function)?;
+        let param_types = function
+            .inputs
+            .iter()
+            .map(|input| ParamType::try_from_type_application(input, type_lookup))
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        let abi_decoder = ABIDecoder::default();
+        let tokens = abi_decoder.decode_multiple(&param_types, args).ok()?;
+        // Each argument is encoded on its own again to be decoded in its human readable form.
+        let abi_encoder = ABIEncoder::new(EncoderConfig::default());
+        let values = param_types
+            .iter()
+            .zip(tokens)
+            .map(|(param_type, token)| {
+                let bytes = abi_encoder.encode(&[token]).ok()?;
+                abi_decoder.decode_as_debug_str(param_type, &bytes).ok()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(
+            function
+                .inputs
+                .iter()
+                .zip(values)
+                .map(|(input, value)| format!("{}: {value}", input.name))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+
+    /// Decodes the value returned by a call to the given function.
+    fn decode_return(&self, contract_id: ContractId, function: &str, data: &[u8]) -> String {
+        self.function(contract_id, function)
+            .and_then(|(function, type_lookup)| {
+                let param_type =
+                    ParamType::try_from_type_application(&function.output, type_lookup).ok()?;
+                ABIDecoder::default()
+                    .decode_as_debug_str(&param_type, data)
+                    .ok()
+            })
+            .unwrap_or_else(|| format!("0x{}", hex::encode(data)))
+    }
+
+    /// Decodes a value logged by the given contract.
+    fn decode_log(&self, contract_id: ContractId, log_id: u64, data: &[u8]) -> String {
+        self.programs
+            .get(&contract_id)
+            .and_then(|program| {
+                decode_log_data(&log_id.to_string(), data, &program.program_abi).ok()
+            })
+            .map(|decoded| decoded.value)
+            .unwrap_or_else(|| format!("0x{}", hex::encode(data)))
+    }
+
+    /// The name of the package of the given contract, or its id if it is unknown.
+    fn contract_name(&self, contract_id: ContractId) -> String {
+        match self.programs.get(&contract_id) {
+            Some(program) => program.name.clone(),
+            None => format!("0x{}", hex::encode(contract_id)),
+        }
+    }
+}
+
+impl TraceRecorder {
+    pub(crate) fn new(context: std::sync::Arc<TraceContext>, test_name: &str) -> Self {
+        Self {
+            context,
+            stack: vec![TraceFrame {
+                contract_id: ContractId::zeroed(),
+                name: test_name.to_string(),
+                function: None,
+                args: None,
+                location: None,
+                entries: vec![],
+                outcome: None,
+            }],
+            num_receipts: 0,
+            pending_call: None,
+            pending_read: None,
+        }
+    }
+
+    /// Records what happened since the previous step, and inspects the instruction at `pc` of the
+    /// given contract, which is about to be executed.
+    pub(crate) fn step(&mut self, vm: &TestInterpreter, contract_id: ContractId, pc: u64) {
+        self.record_receipts(vm);
+        if let Some(read) = self.pending_read.take() {
+            let value = match read.destination {
+                ReadDestination::Register(reg) => Some(vm.registers()[reg].to_string()),
+                ReadDestination::Memory { ptr, len } => {
+                    read_memory(vm, ptr, len).map(|value| format!("0x{}", hex::encode(value)))
+                }
+            };
+            self.push(TraceEntry::StorageRead {
+                key: read.key,
+                value,
+                location: read.location,
+            });
+        }
+
+        let Some(instruction) = read_memory(vm, vm.registers()[RegId::PC], 4)
+            .and_then(|bytes| <[u8; 4]>::try_from(bytes).ok())
+            .and_then(|bytes| Instruction::try_from(bytes).ok())
+        else {
+            return;
+        };
+        let reg = |ix: usize| instruction.reg_ids()[ix].map(|reg| vm.registers()[reg]);
+        let key = |ptr: Option<u64>| {
+            ptr.and_then(|ptr| read_memory(vm, ptr, 32))
+                .and_then(|key| <[u8; 32]>::try_from(key).ok())
+                .map(Bytes32::new)
+        };
+        let location = || self.context.location(contract_id, pc);
+        match instruction.opcode() {
+            Opcode::CALL => {
+                // The call frame holds the contract id, then the pointers to the encoded function
+                // selector and arguments.
+                let Some(frame) = reg(0) else { return };
+                let word = |offset: u64| {
+                    read_memory(vm, frame + offset, 8)
+                        .and_then(|word| <[u8; 8]>::try_from(word).ok())
+                        .map(u64::from_be_bytes)
+                };
+                let function = word(32).and_then(|selector| {
+                    let len = read_memory(vm, selector, 8)
+                        .and_then(|len| <[u8; 8]>::try_from(len).ok())
+                        .map(u64::from_be_bytes)?;
+                    let name = read_memory(vm, selector + 8, len)?;
+                    String::from_utf8(name).ok()
+                });
+                let args = word(40)
+                    .and_then(|args| read_memory(vm, args, args_len(vm, args)))
+                    .unwrap_or_default();
+                self.pending_call = Some(PendingCall {
+                    function,
+                    args,
+                    location: location(),
+                });
+            }
+            Opcode::SRW => {
+                if let (Some(key), Some(reg)) = (key(reg(2)), instruction.reg_ids()[0]) {
+                    self.pending_read = Some(PendingRead {
+                        key,
+                        location: location(),
+                        destination: ReadDestination::Register(reg),
+                    });
+                }
+            }
+            Opcode::SRWQ => {
+                if let (Some(key), Some(ptr), Some(slots)) = (key(reg(2)), reg(0), reg(3)) {
+                    self.pending_read = Some(PendingRead {
+                        key,
+                        location: location(),
+                        destination: ReadDestination::Memory {
+                            ptr,
+                            len: slots.saturating_mul(32),
+                        },
+                    });
+                }
+            }
+            Opcode::SWW => {
+                if let (Some(key), Some(value)) = (key(reg(0)), reg(2)) {
+                    let location = location();
+                    self.push(TraceEntry::StorageWrite {
+                        key,
+                        value: value.to_string(),
+                        location,
+                    });
+                }
+            }
+            Opcode::SWWQ => {
+                if let (Some(key), Some(ptr), Some(slots)) = (key(reg(0)), reg(2), reg(3)) {
+                    let value = read_memory(vm, ptr, slots.saturating_mul(32))
+                        .map(|value| format!("0x{}", hex::encode(value)))
+                        .unwrap_or_default();
+                    let location = location();
+                    self.push(TraceEntry::StorageWrite {
+                        key,
+                        value,
+                        location,
+                    });
+                }
+            }
+            Opcode::SCWQ => {
+                if let (Some(key), Some(slots)) = (key(reg(0)), reg(2)) {
+                    let location = location();
+                    self.push(TraceEntry::StorageWrite {
+                        key,
+                        value: format!("cleared {slots} slots"),
+                        location,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Records the remaining receipts and closes the frames left open by a revert or a panic.
+    pub(crate) fn finish(mut self, vm: &TestInterpreter) -> CallTrace {
+        self.record_receipts(vm);
+        while self.stack.len() > 1 {
+            self.pop(None);
+        }
+        CallTrace {
+            root: self
+                .stack
+                .pop()
+                .expect("the frame of the test is never popped"),
+        }
+    }
+
+    fn record_receipts(&mut self, vm: &TestInterpreter) {
+        let receipts = vm.receipts();
+        for receipt in &receipts[self.num_receipts.min(receipts.len())..] {
+            self.record_receipt(receipt);
+        }
+        self.num_receipts = receipts.len();
+    }
+
+    fn record_receipt(&mut self, receipt: &Receipt) {
+        let context = self.context.clone();
+        match receipt {
+            Receipt::Call { to, .. } => {
+                let PendingCall {
+                    function,
+                    args,
+                    location,
+                } = self.pending_call.take().unwrap_or_default();
+                let contract_name = context.contract_name(*to);
+                let (name, args) = match &function {
+                    Some(function) => (
+                        format!("{contract_name}.{function}"),
+                        Some(
+                            context
+                                .decode_args(*to, function, &args)
+                                .unwrap_or_else(|| format!("0x{}", hex::encode(&args))),
+                        ),
+                    ),
+                    None => (contract_name, None),
+                };
+                self.stack.push(TraceFrame {
+                    contract_id: *to,
+                    name,
+                    function,
+                    args,
+                    location,
+                    entries: vec![],
+                    outcome: None,
+                });
+            }
+            Receipt::Return { id, .. } if self.returns_from(*id) => {
+                self.pop(Some(TraceOutcome::Return(None)));
+            }
+            Receipt::ReturnData {
+                id,
+                data: Some(data),
+                ..
+            } if self.returns_from(*id) => {
+                let value = match &self.stack[self.stack.len() - 1].function {
+                    Some(function) => context.decode_return(*id, function, data),
+                    None => format!("0x{}", hex::encode(data)),
+                };
+                self.pop(Some(TraceOutcome::Return(Some(value))));
+            }
+            Receipt::Revert { id, ra, pc, is, .. } => {
+                self.set_outcome(TraceOutcome::Revert {
+                    code: *ra,
+                    location: context.location(*id, pc - is),
+                });
+            }
+            Receipt::Panic {
+                id, reason, pc, is, ..
+            } => {
+                self.set_outcome(TraceOutcome::Panic {
+                    reason: format!("{:?}", reason.reason()),
+                    location: context.location(*id, pc - is),
+                });
+            }
+            Receipt::Log {
+                id, ra, rb, pc, is, ..
+            } => self.push(TraceEntry::Log {
+                log_id: *rb,
+                value: ra.to_string(),
+                location: context.location(*id, pc - is),
+            }),
+            Receipt::LogData {
+                id,
+                rb,
+                pc,
+                is,
+                data: Some(data),
+                ..
+            } => self.push(TraceEntry::Log {
+                log_id: *rb,
+                value: context.decode_log(*id, *rb, data),
+                location: context.location(*id, pc - is),
+            }),
+            _ => {}
+        }
+    }
+
+    /// Whether a return of the given contract returns from the innermost call, rather than from
+    /// the test itself.
+    fn returns_from(&self, contract_id: ContractId) -> bool {
+        self.stack.len() > 1 && self.stack[self.stack.len() - 1].contract_id == contract_id
+    }
+
+    /// Adds an entry to the innermost open frame.
+    fn push(&mut self, entry: TraceEntry) {
+        self.stack
+            .last_mut()
+            .expect("the frame of the test is never popped")
+            .entries
+            .push(entry);
+    }
+
+    /// Closes the innermost call, and adds it to its caller.
+    fn pop(&mut self, outcome: Option<TraceOutcome>) {
+        let mut frame = self
+            .stack
+            .pop()
+            .expect("the frame of the test is never popped");
+        if outcome.is_some() {
+            frame.outcome = outcome;
+        }
+        self.push(TraceEntry::Call(frame));
+    }
+
+    /// Sets how the innermost open frame ended.
+    fn set_outcome(&mut self, outcome: TraceOutcome) {
+        self.stack
+            .last_mut()
+            .expect("the frame of the test is never popped")
+            .outcome = Some(outcome);
+    }
+}
+
+/// Reads `len` bytes of the memory of the VM at `ptr`, if they are readable.
+fn read_memory(vm: &TestInterpreter, ptr: u64, len: u64) -> Option<Vec<u8>> {
+    vm.memory().read(ptr, len).ok().map(<[u8]>::to_vec)
+}
+
+/// The number of bytes to read for the encoded arguments at `ptr`.
+///
+/// The length of the encoded arguments is not part of the call, so the arguments are read up to
+/// the end of the stack or of the heap they are in, which the decoder does not read past.
+fn args_len(vm: &TestInterpreter, ptr: u64) -> u64 {
+    let end = match ptr < vm.registers()[RegId::SP] {
+        true => vm.registers()[RegId::SP],
+        false => fuel_vm::consts::MEM_SIZE as u64,
+    };
+    end.saturating_sub(ptr).min(MAX_ARGS_LEN)
+}
+
+impl fmt::Display for CallTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.root.fmt_indented(f, 0)
+    }
+}
+
+impl TraceFrame {
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        let pad = "  ".repeat(indent);
+        write!(f, "{pad}{}", self.name)?;
+        if let Some(args) = &self.args {
+            write!(f, "({args})")?;
+        }
+        writeln!(f, "{}", fmt_location(&self.location))?;
+        for entry in &self.entries {
+            let pad = "  ".repeat(indent + 1);
+            match entry {
+                TraceEntry::Call(frame) => frame.fmt_indented(f, indent + 1)?,
+                TraceEntry::Log {
+                    log_id,
+                    value,
+                    location,
+                } => writeln!(f, "{pad}log {log_id}: {value}{}", fmt_location(location))?,
+                TraceEntry::StorageRead {
+                    key,
+                    value,
+                    location,
+                } => writeln!(
+                    f,
+                    "{pad}storage read 0x{}{}{}",
+                    hex::encode(key),
+                    value
+                        .as_ref()
+                        .map(|value| format!(" = {value}"))
+                        .unwrap_or_default(),
+                    fmt_location(location)
+                )?,
+                TraceEntry::StorageWrite {
+                    key,
+                    value,
+                    location,
+                } => writeln!(
+                    f,
+                    "{pad}storage write 0x{} = {value}{}",
+                    hex::encode(key),
+                    fmt_location(location)
+                )?,
+            }
+        }
+        let pad = "  ".repeat(indent + 1);
+        match &self.outcome {
+            Some(TraceOutcome::Return(Some(value))) => writeln!(f, "{pad}returned {value}"),
+            Some(TraceOutcome::Return(None)) => writeln!(f, "{pad}returned"),
+            Some(TraceOutcome::Revert { code, location }) => {
+                writeln!(f, "{pad}reverted with {code:x}{}", fmt_location(location))
+            }
+            Some(TraceOutcome::Panic { reason, location }) => {
+                writeln!(f, "{pad}panicked with {reason}{}", fmt_location(location))
+            }
+            // The call was unwound by a revert or a panic in a nested call.
+            None if indent != 0 => writeln!(f, "{pad}unwound"),
+            None => Ok(()),
+        }
+    }
+}
+
+fn fmt_location(location: &Option<SourceLocation>) -> String {
+    match location {
+        Some(location) => format!(
+            " at {}:{}:{}",
+            location.path.display(),
+            location.line,
+            location.col
+        ),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(name: &str, entries: Vec<TraceEntry>, outcome: Option<TraceOutcome>) -> TraceFrame {
+        TraceFrame {
+            contract_id: ContractId::zeroed(),
+            name: name.to_string(),
+            function: None,
+            args: None,
+            location: None,
+            entries,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn render_call_tree() {
+        let location = Some(SourceLocation {
+            path: PathBuf::from("src/main.sw"),
+            line: 12,
+            col: 9,
+        });
+        let call = TraceFrame {
+            args: Some("amount: 5".to_string()),
+            location: location.clone(),
+            ..frame(
+                "token.mint",
+                vec![
+                    TraceEntry::StorageWrite {
+                        key: Bytes32::zeroed(),
+                        value: "5".to_string(),
+                        location: None,
+                    },
+                    TraceEntry::Log {
+                        log_id: 0,
+                        value: "5".to_string(),
+                        location: None,
+                    },
+                ],
+                Some(TraceOutcome::Return(Some("5".to_string()))),
+            )
+        };
+        let trace = CallTrace {
+            root: frame(
+                "test_mint",
+                vec![TraceEntry::Call(call)],
+                Some(TraceOutcome::Revert {
+                    code: 42,
+                    location: location.clone(),
+                }),
+            ),
+        };
+        let zero_key = "0".repeat(64);
+        assert_eq!(
+            trace.to_string(),
+            format!(
+                "test_mint\n  \
+                   token.mint(amount: 5) at src/main.sw:12:9\n    \
+                     storage write 0x{zero_key} = 5\n    \
+                     log 0: 5\n    \
+                     returned 5\n  \
+                   reverted with 2a at src/main.sw:12:9\n"
+            )
+        );
+    }
+}
@@ -0,0 +1,43 @@
+library;
+
+use std::{
+    auth::caller_address,
+    block::{height, timestamp},
+    test::{expect_log, mint, set_block_height, set_caller, set_timestamp},
+};
+
+fn owner() -> Address {
+    Address::from(0x0000000000000000000000000000000000000000000000000000000000000001)
+}
+
+#[test]
+fn test_set_block_height() {
+    set_block_height(1000);
+    assert(height() == 1000);
+}
+
+#[test]
+fn test_set_timestamp() {
+    let deadline = 0x4000_0000_3b9a_ca25;
+    set_timestamp(deadline);
+    assert(timestamp() == deadline);
+}
+
+#[test]
+fn test_set_caller() {
+    set_caller(owner());
+    mint(Identity::Address(owner()), AssetId::base(), 100);
+    assert(caller_address().unwrap() == owner());
+}
+
+#[test]
+fn test_expect_log() {
+    expect_log(42u64);
+    log(42u64);
+}
+
+#[test]
+fn test_expect_log_unmet() {
+    expect_log(42u64);
+    log(43u64);
+}
@@ -0,0 +1,21 @@
+contract;
+
+abi Classifier {
+    fn classify(value: u64) -> u64;
+}
+
+impl Classifier for Contract {
+    fn classify(value: u64) -> u64 {
+        if value > 10 {
+            value * 2
+        } else {
+            value + 1
+        }
+    }
+}
+
+#[test]
+fn test_classify_small() {
+    let classifier = abi(Classifier, CONTRACT_ID);
+    assert(classifier.classify(1) == 2);
+}
@@ -0,0 +1,24 @@
+library;
+
+/// Adds two numbers.
+///
+/// ```sway
+/// use test_doc_tests::add;
+///
+/// assert(add(1, 2) == 3);
+/// ```
+pub fn add(a: u64, b: u64) -> u64 {
+    a + b
+}
+
+/// Subtracts `b` from `a`.
+///
+/// ```sway
+/// use test_doc_tests::sub;
+///
+/// // The example is wrong, the difference is one.
+/// assert(sub(2, 1) == 3);
+/// ```
+pub fn sub(a: u64, b: u64) -> u64 {
+    a - b
+}
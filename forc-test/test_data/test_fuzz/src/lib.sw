@@ -0,0 +1,13 @@
+library;
+
+#[test(fuzz)]
+fn test_fuzz_addition_commutes(a: u32, b: u32) {
+  let a = a.as_u64();
+  let b = b.as_u64();
+  assert(a + b == b + a)
+}
+
+#[test(fuzz = "64")]
+fn test_fuzz_small_values(a: u64, b: bool) {
+  assert(a < 1000 || b == false)
+}
@@ -0,0 +1,27 @@
+library;
+
+fn count_to(n: u64) -> u64 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+#[test(gas_limit = "100000")]
+fn test_within_gas_limit() {
+    assert(count_to(10) == 10);
+}
+
+#[test(gas_limit = "10000")]
+fn test_out_of_gas() {
+    assert(count_to(100000) == 100000);
+}
+
+#[test]
+fn test_never_ends() {
+    let mut i = 0;
+    while true {
+        i += 1;
+    }
+}
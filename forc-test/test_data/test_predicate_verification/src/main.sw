@@ -0,0 +1,29 @@
+predicate;
+
+use std::{inputs::input_count, test::{predicate_coin, set_predicate_data, verify_predicate}};
+
+fn main(password: u64) -> bool {
+    password == 42 && input_count() == 1
+}
+
+#[test]
+fn test_correct_password() {
+    predicate_coin(AssetId::base(), 100);
+    set_predicate_data(42);
+    assert(verify_predicate());
+}
+
+#[test]
+fn test_wrong_password() {
+    predicate_coin(AssetId::base(), 100);
+    set_predicate_data(7);
+    assert(!verify_predicate());
+}
+
+#[test]
+fn test_too_many_inputs() {
+    predicate_coin(AssetId::base(), 100);
+    predicate_coin(AssetId::base(), 50);
+    set_predicate_data(42);
+    assert(!verify_predicate());
+}
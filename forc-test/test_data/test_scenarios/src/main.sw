@@ -0,0 +1,47 @@
+contract;
+
+use std::context::this_balance;
+
+configurable {
+    INITIAL: u64 = 0,
+}
+
+storage {
+    counter: u64 = 0,
+}
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64;
+    #[storage(read)]
+    fn get() -> u64;
+    fn initial() -> u64;
+    fn balance() -> u64;
+    fn fail(code: u64);
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64 {
+        let value = storage.counter.read() + amount;
+        storage.counter.write(value);
+        value
+    }
+
+    #[storage(read)]
+    fn get() -> u64 {
+        storage.counter.read()
+    }
+
+    fn initial() -> u64 {
+        INITIAL
+    }
+
+    fn balance() -> u64 {
+        this_balance(AssetId::base())
+    }
+
+    fn fail(code: u64) {
+        revert(code);
+    }
+}
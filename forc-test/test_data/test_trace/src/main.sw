@@ -0,0 +1,37 @@
+contract;
+
+storage {
+    counter: u64 = 0,
+}
+
+abi Counter {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64;
+    fn fail(code: u64);
+}
+
+impl Counter for Contract {
+    #[storage(read, write)]
+    fn increment(amount: u64) -> u64 {
+        let value = storage.counter.read() + amount;
+        storage.counter.write(value);
+        log(value);
+        value
+    }
+
+    fn fail(code: u64) {
+        revert(code);
+    }
+}
+
+#[test]
+fn test_increment() {
+    let counter = abi(Counter, CONTRACT_ID);
+    assert(counter.increment(5) == 5);
+}
+
+#[test(should_revert)]
+fn test_fail() {
+    let counter = abi(Counter, CONTRACT_ID);
+    counter.fail(42);
+}
@@ -69,6 +69,26 @@ pub(crate) fn exec(command: Command) -> ForcResult<()> {
         let renderer = create_diagnostics_renderer();
         info!("{}", renderer.render(snippet));
 
+        // Show the function containing the opcode, and the calls it was inlined from.
+        if let Some(function) = sm.addr_to_function(command.opcode_index) {
+            for inlined in function.inlined.iter().filter(|inlined| {
+                inlined
+                    .ranges
+                    .iter()
+                    .any(|range| range.contains(&command.opcode_index))
+            }) {
+                let (path, range) = inlined.call.to_span(&sm.paths, &sm.dependency_paths);
+                info!(
+                    "  in {} (inlined at {}:{}:{})",
+                    inlined.name,
+                    path.display(),
+                    range.start.line,
+                    range.start.col
+                );
+            }
+            info!("  in {}", function.name);
+        }
+
         Ok(())
     } else {
         Err("Address did not map to any source code location".into())
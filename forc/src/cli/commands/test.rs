@@ -2,10 +2,25 @@ use crate::cli;
 use ansiterm::Colour;
 use clap::Parser;
 use forc_pkg as pkg;
-use forc_test::{decode_log_data, TestFilter, TestRunnerCount, TestedPackage};
+use forc_pkg::manifest::{GenericManifestFile, ManifestFile};
+use forc_test::{
+    coverage::HTML_INDEX_FILE_NAME,
+    decode_log_data,
+    doc_test::{self, DocTestMode, DocTestOutcome, DocTestResult, DOC_TESTS_DIR_NAME},
+    fuzz::FuzzOpts,
+    gas::{GasDiff, GasSnapshot, GasTolerance},
+    report::TestReport,
+    TestFilter, TestRunOpts, TestRunnerCount, TestShard, TestedPackage,
+};
 use forc_tracing::println_action_green;
-use forc_util::{tx_utils::format_log_receipts, ForcError, ForcResult};
-use sway_core::fuel_prelude::fuel_tx::Receipt;
+use forc_util::{
+    default_output_directory, tx_utils::format_log_receipts, user_forc_directory, ForcError,
+    ForcResult,
+};
+use std::path::{Path, PathBuf};
+use sway_core::{fuel_prelude::fuel_tx::Receipt, language::parsed::TreeType};
+use term_table::row::Row;
+use term_table::table_cell::{Alignment, TableCell};
 use tracing::info;
 
 forc_util::cli_examples! {
@@ -14,6 +29,13 @@ forc_util::cli_examples! {
         [ Run test with a filter => "forc test $filter" ]
         [ Run test without any output => "forc test --silent" ]
         [ Run test without creating or update the lock file  => "forc test --locked" ]
+        [ Run test and record the gas used by each test in the gas snapshot => "forc test --gas-snapshot" ]
+        [ Run test and fail if the gas used by a test increased by more than five percent => "forc test --check-gas --gas-tolerance 5" ]
+        [ Run test and write a JUnit report of the results => "forc test --format junit --report-file report.xml" ]
+        [ Run test and write lcov and HTML coverage reports for each tested package => "forc test --coverage" ]
+        [ Run test and print the contract calls and storage accesses made by the failing tests => "forc test --trace" ]
+        [ Run the second of four shards of the tests and stop tests running for over ten seconds => "forc test --shard 2/4 --test-timeout 10" ]
+        [ Run the code examples in the doc comments of the project => "forc test --doc" ]
     }
 }
 
@@ -31,9 +53,17 @@ forc_util::cli_examples! {
 /// Unit tests decorated with the `#[test(script)]` attribute that are declared within `contract`
 /// projects may also call directly into their associated contract's ABI.
 ///
+/// Unit tests decorated with the `#[test(fuzz)]` attribute may declare parameters. They are run
+/// multiple times with generated arguments. If a run fails, its arguments are shrunk to a minimal
+/// failing input, which is reported along with the seed reproducing the failure.
+///
 /// Upon successful compilation, test scripts are executed to their completion. A test is
 /// considered a failure in the case that a revert (`rvrt`) instruction is encountered during
 /// execution. Otherwise, it is considered a success.
+///
+/// With `--doc`, the ```sway code examples in the doc comments of library packages are tested
+/// instead of the unit tests. Each example is compiled as a test depending on its package, and
+/// failures are reported with the location of the example.
 #[derive(Debug, Parser)]
 #[clap(bin_name = "forc test", version, after_help = help())]
 pub struct Command {
@@ -41,6 +71,12 @@ pub struct Command {
     pub build: cli::shared::Build,
     #[clap(flatten)]
     pub test_print: TestPrintOpts,
+    #[clap(flatten)]
+    pub test_fuzz: TestFuzzOpts,
+    #[clap(flatten)]
+    pub test_gas: TestGasOpts,
+    #[clap(flatten)]
+    pub test_report: TestReportOpts,
     /// When specified, only tests containing the given string will be executed.
     pub filter: Option<String>,
     #[clap(long)]
@@ -50,6 +86,29 @@ pub struct Command {
     /// Number of threads to utilize when running the tests. By default, this is the number of
     /// threads available in your system.
     pub test_threads: Option<usize>,
+    /// Maximum time in seconds each test may run for. Tests running for longer are stopped and
+    /// reported as timed out. Each run of a fuzz test is limited separately.
+    #[clap(long, value_name = "SECONDS")]
+    pub test_timeout: Option<u64>,
+    /// Only run the tests of the given shard, e.g. `2/4` for the second of four shards. Tests are
+    /// assigned to shards deterministically, which allows splitting them across CI machines.
+    #[clap(long, value_name = "INDEX/COUNT")]
+    pub shard: Option<TestShard>,
+    /// Record the code executed by the tests, including the code of the contracts they call, and
+    /// write an lcov tracefile and an HTML report of the line and branch coverage to the
+    /// `out/coverage` directory of each tested package. Tests run slower with coverage enabled.
+    #[clap(long)]
+    pub coverage: bool,
+    /// Record the contract calls, returns, logs and storage reads and writes made by the tests,
+    /// and print them for the failing tests as a tree of calls with their decoded arguments,
+    /// returned values and source locations. Tests run slower with tracing enabled.
+    #[clap(long)]
+    pub trace: bool,
+    /// Compile and run the ```sway code examples in the `///` and `//!` doc comments of the
+    /// library packages, instead of the unit tests. The filter is matched against the locations
+    /// of the examples, e.g. `src/hash.sw:42`.
+    #[clap(long)]
+    pub doc: bool,
 
     #[clap(flatten)]
     pub experimental: sway_features::CliFields,
@@ -68,24 +127,107 @@ pub struct TestPrintOpts {
     /// Print the raw logs for tests.
     #[clap(long)]
     pub raw_logs: bool,
+    /// Print the traces of all tests, including the passing ones. Implies `--trace`.
+    #[clap(long = "trace-all")]
+    pub print_all_traces: bool,
+}
+
+/// The set of options provided for controlling the runs of fuzz tests.
+#[derive(Parser, Debug, Clone)]
+#[clap(after_help = help())]
+pub struct TestFuzzOpts {
+    /// Number of runs of each fuzz test. Overrides the number of runs specified in the tests.
+    #[clap(long)]
+    pub fuzz_runs: Option<u64>,
+    /// Seed of the inputs generated for fuzz tests. Use the seed reported for a failing fuzz test
+    /// to reproduce the failure.
+    #[clap(long)]
+    pub fuzz_seed: Option<u64>,
+    /// Maximum number of inputs tried while shrinking the input of a failing fuzz test.
+    #[clap(long, default_value_t = forc_test::fuzz::DEFAULT_MAX_SHRINK_ITERS)]
+    pub fuzz_max_shrink_iters: u64,
+}
+
+/// The set of options provided for recording and checking the gas used by tests.
+#[derive(Parser, Debug, Clone)]
+#[clap(after_help = help())]
+pub struct TestGasOpts {
+    /// Write the gas used by each test to the `.gas-snapshot` file of its package.
+    #[clap(long)]
+    pub gas_snapshot: bool,
+    /// Compare the gas used by each test with the `.gas-snapshot` file of its package, and fail if
+    /// the gas used by a test increased beyond the tolerance.
+    #[clap(long)]
+    pub check_gas: bool,
+    /// Maximum allowed increase of the gas used by a test with `--check-gas`, in percent.
+    #[clap(long, default_value = "0")]
+    pub gas_tolerance: GasTolerance,
+}
+
+/// The set of options provided for producing a machine readable report of the test results.
+#[derive(Parser, Debug, Clone)]
+#[clap(after_help = help())]
+pub struct TestReportOpts {
+    /// The format of the test results. The `json` and `junit` reports replace the human readable
+    /// results, unless `--report-file` is provided. Use `--silent` to only print the report.
+    #[clap(long, value_enum, default_value_t = TestReportFormat::Human)]
+    pub format: TestReportFormat,
+    /// Write the report of the test results to the given file, instead of printing it.
+    /// Requires `--format json` or `--format junit`.
+    #[clap(long)]
+    pub report_file: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum TestReportFormat {
+    /// Human readable test results.
+    #[default]
+    Human,
+    /// A JSON report with the results of each test.
+    Json,
+    /// A JUnit XML report with a test suite for each package.
+    Junit,
 }
 
 pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
+    let test_report_opts = cmd.test_report.clone();
+    if test_report_opts.report_file.is_some() && test_report_opts.format == TestReportFormat::Human
+    {
+        return Err("`--report-file` requires `--format json` or `--format junit`.".into());
+    }
+
     let test_runner_count = match cmd.test_threads {
         Some(runner_count) => TestRunnerCount::Manual(runner_count),
         None => TestRunnerCount::Auto,
     };
+    if cmd.doc {
+        return exec_doc_tests(cmd, test_runner_count);
+    }
 
     let test_print_opts = cmd.test_print.clone();
+    let test_gas_opts = cmd.test_gas.clone();
+    let test_run_opts = TestRunOpts {
+        fuzz: FuzzOpts {
+            runs: cmd.test_fuzz.fuzz_runs,
+            seed: cmd.test_fuzz.fuzz_seed,
+            max_shrink_iters: cmd.test_fuzz.fuzz_max_shrink_iters,
+        },
+        coverage: cmd.coverage,
+        timeout: cmd.test_timeout.map(std::time::Duration::from_secs),
+        shard: cmd.shard,
+        trace: cmd.trace || cmd.test_print.print_all_traces,
+    };
     let test_filter_phrase = cmd.filter.clone();
     let test_filter = test_filter_phrase.as_ref().map(|filter_phrase| TestFilter {
         filter_phrase,
         exact_match: cmd.filter_exact,
     });
+    // Without a filter or a shard every test is run, so the tests missing from the run were removed.
+    let all_tests_run = test_filter.is_none() && test_run_opts.shard.is_none();
     let opts = opts_from_cmd(cmd);
     let built_tests = forc_test::build(opts)?;
     let start = std::time::Instant::now();
-    let test_count = built_tests.test_count(test_filter.as_ref());
+    let test_count = built_tests.test_count(test_filter.as_ref(), test_run_opts.shard);
     let num_tests_running = test_count.total - test_count.ignored;
     let num_tests_ignored = test_count.ignored;
     println_action_green(
@@ -98,34 +240,323 @@ pub(crate) fn exec(cmd: Command) -> ForcResult<()> {
             formatted_test_count_string(&num_tests_ignored)
         ),
     );
-    let tested = built_tests.run(test_runner_count, test_filter)?;
+    let tested = built_tests.run_with_opts(test_runner_count, test_filter, test_run_opts)?;
     let duration = start.elapsed();
 
+    // The human readable results are replaced by the report if it is not written to a file.
+    let print_results = test_report_opts.format == TestReportFormat::Human
+        || test_report_opts.report_file.is_some();
+
     // Eventually we'll print this in a fancy manner, but this will do for testing.
-    let all_tests_passed = match tested {
+    let all_tests_passed = match &tested {
         forc_test::Tested::Workspace(pkgs) => {
-            for pkg in &pkgs {
-                let built = &pkg.built.descriptor.name;
-                info!("\ntested -- {built}\n");
-                print_tested_pkg(pkg, &test_print_opts)?;
+            if print_results {
+                for pkg in pkgs {
+                    let built = &pkg.built.descriptor.name;
+                    info!("\ntested -- {built}\n");
+                    print_tested_pkg(pkg, &test_print_opts)?;
+                }
+                info!("");
+                println_action_green("Finished", &format!("in {:?}", duration));
             }
-            info!("");
-            println_action_green("Finished", &format!("in {:?}", duration));
             pkgs.iter().all(|pkg| pkg.tests_passed())
         }
         forc_test::Tested::Package(pkg) => {
-            print_tested_pkg(&pkg, &test_print_opts)?;
+            if print_results {
+                print_tested_pkg(pkg, &test_print_opts)?;
+            }
             pkg.tests_passed()
         }
     };
 
-    if all_tests_passed {
-        Ok(())
-    } else {
+    let report = match test_report_opts.format {
+        TestReportFormat::Human => None,
+        TestReportFormat::Json => Some(TestReport::from_tested(&tested).to_json()?),
+        TestReportFormat::Junit => Some(TestReport::from_tested(&tested).to_junit()),
+    };
+    if let Some(report) = report {
+        match &test_report_opts.report_file {
+            Some(report_file) => {
+                std::fs::write(report_file, report)?;
+                println_action_green("Wrote", &format!("test report {}", report_file.display()));
+            }
+            None => println!("{report}"),
+        }
+    }
+
+    let tested_pkgs: Vec<&TestedPackage> = match &tested {
+        forc_test::Tested::Workspace(pkgs) => pkgs.iter().collect(),
+        forc_test::Tested::Package(pkg) => vec![pkg],
+    };
+    let num_gas_regressions = check_and_write_gas_snapshots(
+        &tested_pkgs,
+        &test_gas_opts,
+        all_tests_passed,
+        all_tests_run,
+    )?;
+    write_coverage_reports(&tested_pkgs)?;
+
+    if !all_tests_passed {
         let forc_error: ForcError = "Some tests failed.".into();
         const FAILING_UNIT_TESTS_EXIT_CODE: u8 = 101;
         Err(forc_error.exit_code(FAILING_UNIT_TESTS_EXIT_CODE))
+    } else if num_gas_regressions != 0 {
+        Err(format!(
+            "The gas used by {num_gas_regressions} {} increased by more than {}%.",
+            formatted_test_count_string(&num_gas_regressions),
+            test_gas_opts.gas_tolerance.0
+        )
+        .as_str()
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs the doc tests of the library packages of the project.
+fn exec_doc_tests(cmd: Command, test_runner_count: TestRunnerCount) -> ForcResult<()> {
+    let this_dir = match &cmd.build.pkg.path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let member_manifests = ManifestFile::from_dir(this_dir)?.member_manifests()?;
+    let filter_phrase = cmd.filter.clone();
+    let exact_match = cmd.filter_exact;
+    let opts = opts_from_cmd(cmd);
+
+    let mut all_doc_tests_passed = true;
+    for pkg_manifest in member_manifests.values() {
+        let pkg_name = pkg_manifest.project_name();
+        if pkg_manifest.program_type()? != TreeType::Library {
+            info!("\nSkipping the doc tests of {pkg_name}, which is not a library.");
+            continue;
+        }
+        let pkg_dir = pkg_manifest.dir();
+        let doc_tests = doc_test::collect(pkg_manifest)?
+            .into_iter()
+            .filter(|doc_test| {
+                let location = doc_test.location(pkg_dir);
+                filter_phrase
+                    .as_ref()
+                    .map_or(true, |phrase| match exact_match {
+                        true => location == *phrase,
+                        false => location.contains(phrase.as_str()),
+                    })
+            })
+            .collect::<Vec<_>>();
+        println_action_green(
+            "Running",
+            &format!(
+                "{} doc {} of {pkg_name}",
+                doc_tests.len(),
+                formatted_test_count_string(&doc_tests.len())
+            ),
+        );
+        // The doc test packages depend on the documented package, so they cannot be nested
+        // within it, and are written to the user's forc directory by default.
+        let doc_tests_dir = opts
+            .pkg
+            .output_directory
+            .as_ref()
+            .map(PathBuf::from)
+            .unwrap_or_else(user_forc_directory)
+            .join(DOC_TESTS_DIR_NAME)
+            .join(pkg_name);
+        let results = doc_test::run(
+            pkg_manifest,
+            doc_tests,
+            &doc_tests_dir,
+            opts.clone(),
+            test_runner_count,
+        )?;
+        all_doc_tests_passed &= print_doc_test_results(&results, pkg_dir);
+    }
+
+    if !all_doc_tests_passed {
+        let forc_error: ForcError = "Some doc tests failed.".into();
+        const FAILING_UNIT_TESTS_EXIT_CODE: u8 = 101;
+        Err(forc_error.exit_code(FAILING_UNIT_TESTS_EXIT_CODE))
+    } else {
+        Ok(())
+    }
+}
+
+/// Prints the results of the doc tests of a package, and returns whether all of them passed.
+fn print_doc_test_results(results: &[DocTestResult], pkg_dir: &Path) -> bool {
+    for result in results {
+        let location = result.doc_test.location(pkg_dir);
+        match &result.outcome {
+            DocTestOutcome::Passed { gas_used } => info!(
+                "      doc test {location} ... {} ({gas_used} gas)",
+                Colour::Green.paint("ok")
+            ),
+            DocTestOutcome::Compiled => info!(
+                "      doc test {location} ... {} (no_run)",
+                Colour::Green.paint("compiled")
+            ),
+            DocTestOutcome::Ignored => info!(
+                "      doc test {location} ... {}",
+                Colour::Yellow.paint("ignored")
+            ),
+            DocTestOutcome::Failed { .. } | DocTestOutcome::CompileFailed(_) => info!(
+                "      doc test {location} ... {}",
+                Colour::Red.paint("FAILED")
+            ),
+        }
     }
+
+    let failed = results.iter().filter(|result| !result.passed()).count();
+    let ignored = results
+        .iter()
+        .filter(|result| matches!(result.outcome, DocTestOutcome::Ignored))
+        .count();
+    if failed != 0 {
+        info!("\n   failures:");
+        for result in results.iter().filter(|result| !result.passed()) {
+            info!("      - doc test {}", result.doc_test.location(pkg_dir));
+            match &result.outcome {
+                DocTestOutcome::Failed {
+                    revert_code: Some(revert_code),
+                } => info!("        revert code: {revert_code:x}"),
+                DocTestOutcome::Failed { revert_code: None }
+                    if result.doc_test.mode == DocTestMode::ShouldRevert =>
+                {
+                    info!("        expected the example to revert")
+                }
+                DocTestOutcome::CompileFailed(error) => {
+                    info!("        failed to compile: {error}")
+                }
+                _ => {}
+            }
+        }
+        info!("\n");
+    }
+
+    let (state, color) = match failed {
+        0 => ("OK", Colour::Green),
+        _ => ("FAILED", Colour::Red),
+    };
+    info!(
+        "\ndoc test result: {}. {} passed; {} failed; {} ignored",
+        color.paint(state),
+        results.len() - failed - ignored,
+        failed,
+        ignored
+    );
+    failed == 0
+}
+
+/// Compares the gas used by the tests with the gas snapshots of their packages if `--check-gas`
+/// is set, and updates the gas snapshots if `--gas-snapshot` is set and all tests passed.
+///
+/// Only the tests that were run are compared and updated. The entries of the other tests are
+/// removed from the snapshots only if `all_tests_run` is set.
+///
+/// Returns the number of tests whose gas used increased beyond the tolerance.
+fn check_and_write_gas_snapshots(
+    tested_pkgs: &[&TestedPackage],
+    test_gas_opts: &TestGasOpts,
+    all_tests_passed: bool,
+    all_tests_run: bool,
+) -> ForcResult<usize> {
+    let mut num_gas_regressions = 0;
+    if test_gas_opts.gas_snapshot && !all_tests_passed {
+        info!("\nSome tests failed, not updating the gas snapshots.");
+    }
+    for tested_pkg in tested_pkgs {
+        let pkg_name = &tested_pkg.built.descriptor.name;
+        let snapshot = GasSnapshot::from_tested(tested_pkg);
+        let snapshot_path = GasSnapshot::path(tested_pkg);
+        let old_snapshot = GasSnapshot::read(&snapshot_path)?;
+        if test_gas_opts.check_gas {
+            match &old_snapshot {
+                Some(old_snapshot) => {
+                    let diff = snapshot.diff(old_snapshot);
+                    if !diff.is_empty() {
+                        info!("\ngas changes -- {pkg_name}\n");
+                        info!("{}", gas_diff_table(&diff, test_gas_opts.gas_tolerance));
+                    }
+                    num_gas_regressions += diff.regressions(test_gas_opts.gas_tolerance).count();
+                }
+                None => info!(
+                    "\nNo gas snapshot found for {pkg_name} at {}, skipping gas check.",
+                    snapshot_path.display()
+                ),
+            }
+        }
+        if test_gas_opts.gas_snapshot && all_tests_passed {
+            let snapshot = match &old_snapshot {
+                Some(old_snapshot) if !all_tests_run => snapshot.merge_into(old_snapshot),
+                _ => snapshot,
+            };
+            snapshot.write(&snapshot_path)?;
+            println_action_green(
+                "Wrote",
+                &format!("gas snapshot {}", snapshot_path.display()),
+            );
+        }
+    }
+    Ok(num_gas_regressions)
+}
+
+/// Writes the coverage reports of the tested packages to their `out/coverage` directories.
+fn write_coverage_reports(tested_pkgs: &[&TestedPackage]) -> ForcResult<()> {
+    for tested_pkg in tested_pkgs {
+        let Some(coverage) = &tested_pkg.coverage else {
+            continue;
+        };
+        let coverage_dir =
+            default_output_directory(tested_pkg.built.descriptor.manifest_file.dir())
+                .join("coverage");
+        coverage.write(&coverage_dir)?;
+        let (lines_hit, lines_found) = coverage.lines_hit();
+        println_action_green(
+            "Wrote",
+            &format!(
+                "coverage report {} ({lines_hit}/{lines_found} lines covered)",
+                coverage_dir.join(HTML_INDEX_FILE_NAME).display()
+            ),
+        );
+    }
+    Ok(())
+}
+
+/// Renders the changes of the gas used by tests as a table.
+fn gas_diff_table(diff: &GasDiff, tolerance: GasTolerance) -> String {
+    let gas_cell = |gas: Option<u64>| {
+        TableCell::builder(gas.map(|gas| gas.to_string()).unwrap_or_else(|| "-".into()))
+            .alignment(Alignment::Right)
+            .build()
+    };
+    let mut table = term_table::Table::new();
+    table.style = term_table::TableStyle::empty();
+    table.add_row(Row::new(vec![
+        TableCell::new("test"),
+        TableCell::new("old gas"),
+        TableCell::new("new gas"),
+        TableCell::new("change"),
+    ]));
+    for change in &diff.changes {
+        let change_str = match (change.old, change.new, change.percent()) {
+            (None, Some(_), _) => "added".to_string(),
+            (Some(_), None, _) => "removed".to_string(),
+            (_, _, Some(percent)) => format!("{percent:+.2}%"),
+            _ => "-".to_string(),
+        };
+        let change_str = match change.exceeds(tolerance) {
+            true => format!("{change_str} (!)"),
+            false => change_str,
+        };
+        table.add_row(Row::new(vec![
+            TableCell::new(&change.name),
+            gas_cell(change.old),
+            gas_cell(change.new),
+            TableCell::builder(change_str)
+                .alignment(Alignment::Right)
+                .build(),
+        ]));
+    }
+    table.render()
 }
 
 fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> ForcResult<()> {
@@ -138,13 +569,45 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
             true => ("ok", Colour::Green),
             false => ("FAILED", Colour::Red),
         };
-        info!(
-            "      test {} ... {} ({:?}, {} gas)",
-            test.name,
-            color.paint(state),
-            test.duration,
-            test.gas_used
-        );
+        match &test.fuzz {
+            Some(fuzz) => info!(
+                "      test {} ... {} ({:?}, {} gas, {} runs)",
+                test.name,
+                color.paint(state),
+                test.duration,
+                test.gas_used,
+                fuzz.runs
+            ),
+            None => info!(
+                "      test {} ... {} ({:?}, {} gas)",
+                test.name,
+                color.paint(state),
+                test.duration,
+                test.gas_used
+            ),
+        }
+
+        if test.out_of_gas {
+            info!("        out of gas ({} gas)", test.gas_used);
+        }
+        if test.timed_out {
+            info!("        timed out ({} gas)", test.gas_used);
+        }
+
+        // Traces are only printed for failing tests, unless all of them are requested.
+        let print_trace = !test_passed || test_print_opts.print_all_traces;
+        if let Some(trace) = test.trace.as_ref().filter(|_| print_trace) {
+            for line in trace.to_string().lines() {
+                info!("        {line}");
+            }
+        }
+
+        if let Some(predicate) = &test.predicate {
+            match &predicate.error {
+                None => info!("        predicate verified ({} gas)", predicate.gas_used),
+                Some(error) => info!("        predicate not verified: {error}"),
+            }
+        }
 
         // If logs are enabled, print them.
         let logs = &test.logs;
@@ -174,7 +637,34 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
             failed_tests.push(test);
         }
     }
-    let (state, color) = match succeeded == pkg.tests.len() {
+    for scenario in &pkg.scenarios {
+        let (state, color) = match scenario.passed() {
+            true => ("ok", Colour::Green),
+            false => ("FAILED", Colour::Red),
+        };
+        info!(
+            "      scenario {} ... {} ({:?})",
+            scenario.name,
+            color.paint(state),
+            scenario.duration
+        );
+        for call in &scenario.calls {
+            let (state, color) = match call.passed {
+                true => ("ok", Colour::Green),
+                false => ("FAILED", Colour::Red),
+            };
+            info!(
+                "        call {} ... {} ({} gas)",
+                call.name,
+                color.paint(state),
+                call.gas_used
+            );
+            if let Some(failure) = &call.failure {
+                info!("          {failure}");
+            }
+        }
+    }
+    let (state, color) = match pkg.tests_passed() {
         true => ("OK", Colour::Green),
         false => ("FAILED", Colour::Red),
     };
@@ -191,6 +681,24 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
                 "      - test {}, {:?}:{} ",
                 failed_test_name, path, line_number
             );
+            if let Some(failure) = failed_test.fuzz.as_ref().and_then(|f| f.failure.as_ref()) {
+                let format_args = |args: &[forc_test::fuzz::FuzzArg]| {
+                    args.iter()
+                        .map(|arg| format!("{}: {}", arg.name, arg.value))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                info!(
+                    "        seed: {} (rerun with `--fuzz-seed {}`)",
+                    failure.seed, failure.seed
+                );
+                info!("        failing input: ({})", format_args(&failure.input));
+                info!(
+                    "        minimal failing input: ({}), after {} shrink steps",
+                    format_args(&failure.minimal_input),
+                    failure.shrink_steps
+                );
+            }
             if let Some(revert_code) = failed_test.revert_code() {
                 // If we have a revert_code, try to get a known error signal
                 let mut failed_info_str = format!("        revert code: {revert_code:x}");
@@ -201,6 +709,9 @@ fn print_tested_pkg(pkg: &TestedPackage, test_print_opts: &TestPrintOpts) -> For
                 }
                 info!("{failed_info_str}");
             }
+            for unmet_expectation in &failed_test.unmet_expectations {
+                info!("        {unmet_expectation}");
+            }
             info!("        Logs: {}", formatted_logs);
         }
         info!("\n");
@@ -94,8 +94,12 @@ pub struct Print {
     ///  - <pass name>: the name of an optimization pass. Prints the IR state after that pass.
     ///  - all:         short for initial, final, and all the optimization passes.
     ///  - modified:    print a requested optimization pass only if it has modified the IR.
-    #[arg(long, verbatim_doc_comment, num_args(1..=18), value_parser = clap::builder::PossibleValuesParser::new(PrintIrCliOpt::cli_options()))]
+    ///  - diff:        print only the changes a requested optimization pass made to the IR.
+    #[arg(long, verbatim_doc_comment, num_args(1..=19), value_parser = clap::builder::PossibleValuesParser::new(PrintIrCliOpt::cli_options()))]
     pub ir: Option<Vec<String>>,
+    /// Print the Sway IR (Intermediate Representation) before the given optimization passes.
+    #[arg(long, num_args(1..=14), value_parser = clap::builder::PossibleValuesParser::new(PassManager::OPTIMIZATION_PASSES))]
+    pub ir_before: Option<Vec<String>>,
     /// Output the time elapsed over each part of the compilation process.
     #[clap(long)]
     pub time_phases: bool,
@@ -118,9 +122,14 @@ impl Print {
     }
 
     pub fn ir(&self) -> PrintIr {
-        self.ir
+        let mut print_ir = self
+            .ir
             .as_ref()
-            .map_or(PrintIr::default(), |opts| PrintIrCliOpt::from(opts).0)
+            .map_or(PrintIr::default(), |opts| PrintIrCliOpt::from(opts).0);
+        if let Some(passes_before) = &self.ir_before {
+            print_ir.passes_before.clone_from(passes_before);
+        }
+        print_ir
     }
 }
 
@@ -219,8 +228,14 @@ impl PrintIrCliOpt {
     const FINAL: &'static str = "final";
     const ALL: &'static str = "all";
     const MODIFIED: &'static str = "modified";
-    pub const CLI_OPTIONS: [&'static str; 4] =
-        [Self::INITIAL, Self::FINAL, Self::ALL, Self::MODIFIED];
+    const DIFF: &'static str = "diff";
+    pub const CLI_OPTIONS: [&'static str; 5] = [
+        Self::INITIAL,
+        Self::FINAL,
+        Self::ALL,
+        Self::MODIFIED,
+        Self::DIFF,
+    ];
 
     pub fn cli_options() -> Vec<&'static str> {
         Self::CLI_OPTIONS
@@ -236,7 +251,10 @@ impl From<&Vec<String>> for PrintIrCliOpt {
         let contains_opt = |opt: &str| value.iter().any(|val| *val == opt);
 
         let print_ir = if contains_opt(Self::ALL) {
-            PrintIr::all(contains_opt(Self::MODIFIED))
+            PrintIr {
+                diff: contains_opt(Self::DIFF),
+                ..PrintIr::all(contains_opt(Self::MODIFIED))
+            }
         } else {
             PrintIr {
                 initial: contains_opt(Self::INITIAL),
@@ -247,6 +265,8 @@ impl From<&Vec<String>> for PrintIrCliOpt {
                     .filter(|val| !Self::CLI_OPTIONS.contains(&val.as_str()))
                     .cloned()
                     .collect(),
+                passes_before: vec![],
+                diff: contains_opt(Self::DIFF),
             }
         };
 
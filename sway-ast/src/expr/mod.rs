@@ -392,6 +392,8 @@ pub enum ExprArrayDescriptor {
 
 #[derive(Clone, Debug, Serialize)]
 pub struct MatchBranch {
+    /// The optional `|` in front of the pattern, e.g. `| A | B => ...`.
+    pub leading_pipe_token: Option<PipeToken>,
     pub pattern: Pattern,
     pub fat_right_arrow_token: FatRightArrowToken,
     pub kind: MatchBranchKind,
@@ -399,7 +401,11 @@ pub struct MatchBranch {
 
 impl Spanned for MatchBranch {
     fn span(&self) -> Span {
-        Span::join(self.pattern.span(), &self.kind.span())
+        let start = match &self.leading_pipe_token {
+            Some(pipe_token) => pipe_token.span(),
+            None => self.pattern.span(),
+        };
+        Span::join(start, &self.kind.span())
     }
 }
 
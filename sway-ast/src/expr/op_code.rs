@@ -339,6 +339,12 @@ define_op_codes!(
     (S256, S256Opcode, "s256", (addr: reg, data: reg, size: reg)),
     /* Other Instructions */
     (Flag, FlagOpcode, "flag", (value: reg)),
+    (
+        Ecal,
+        EcalOpcode,
+        "ecal",
+        (reg_a: reg, reg_b: reg, reg_c: reg, reg_d: reg)
+    ),
     (Gm, GmOpcode, "gm", (ret: reg, op: imm)),
     (
         Gtf,
@@ -206,6 +206,38 @@ pub fn generate_program_abi(
     Ok(program_abi)
 }
 
+/// Generates the ABI of a single test function, describing the types of its parameters.
+///
+/// Used by test runners to generate and encode the arguments of parameterized tests.
+pub fn generate_test_function_abi(
+    handler: &Handler,
+    ctx: &mut AbiContext,
+    engines: &Engines,
+    test_fn: &TyFunctionDecl,
+    encoding_version: program_abi::Version,
+    spec_version: program_abi::Version,
+) -> Result<program_abi::ProgramABI, ErrorEmitted> {
+    let metadata_types: &mut Vec<program_abi::TypeMetadataDeclaration> = &mut vec![];
+    let concrete_types: &mut Vec<program_abi::TypeConcreteDeclaration> = &mut vec![];
+    let function =
+        test_fn.generate_abi_function(handler, ctx, engines, metadata_types, concrete_types)?;
+    let mut program_abi = program_abi::ProgramABI {
+        program_type: ctx.program.kind.tree_type().to_string(),
+        spec_version,
+        encoding_version,
+        metadata_types: metadata_types.to_vec(),
+        concrete_types: concrete_types.to_vec(),
+        functions: vec![function],
+        logged_types: None,
+        messages_types: None,
+        configurables: None,
+    };
+
+    standardize_json_abi_types(&mut program_abi);
+
+    Ok(program_abi)
+}
+
 /// Standardize the JSON ABI data structure by eliminating duplicate types. This is an iterative
 /// process because every time two types are merged, new opportunities for more merging arise.
 fn standardize_json_abi_types(json_abi_program: &mut program_abi::ProgramABI) {
@@ -732,6 +732,7 @@ impl EvmFinalProgram {
             program_kind: ProgramKind::Script,
             entries: vec![],
             abi: Some(ProgramABI::Evm(self.abi)),
+            debug_functions: vec![],
         }
     }
 }
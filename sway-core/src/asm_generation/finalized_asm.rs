@@ -6,7 +6,7 @@ use super::{
 use crate::asm_generation::fuel::data_section::{Datum, Entry, EntryName};
 use crate::asm_lang::allocated_ops::{AllocatedOp, AllocatedOpcode, FuelAsmData};
 use crate::decl_engine::DeclRefFunction;
-use crate::source_map::SourceMap;
+use crate::source_map::{FunctionDebugInfo, InlinedCallDebugInfo, SourceMap};
 use crate::BuildConfig;
 
 use etk_asm::asm::Assembler;
@@ -45,6 +45,20 @@ pub struct FinalizedAsm {
     pub program_kind: ProgramKind,
     pub entries: Vec<FinalizedEntry>,
     pub abi: Option<ProgramABI>,
+    /// The arguments and local variables of the compiled functions, for debuggers.
+    pub(crate) debug_functions: Vec<DebugFunction>,
+}
+
+/// Debug information about a compiled function, along with the spans which are added to the
+/// source map when the bytecode is generated.
+#[derive(Clone, Debug)]
+pub(crate) struct DebugFunction {
+    pub(crate) info: FunctionDebugInfo,
+    /// The span of the function declaration.
+    pub(crate) span: Option<Span>,
+    /// The names and the declaration spans of the functions inlined into this function, with the
+    /// spans of the inlined calls.
+    pub(crate) inlined_calls: Vec<(String, Option<Span>, Span)>,
 }
 
 #[derive(Clone, Debug)]
@@ -78,6 +92,7 @@ impl FinalizedAsm {
         match &self.program_section {
             InstructionSet::Fuel { ops } => Ok(to_bytecode_mut(
                 ops,
+                &self.debug_functions,
                 &mut self.data_section,
                 source_map,
                 source_engine,
@@ -98,6 +113,64 @@ impl FinalizedAsm {
     }
 }
 
+/// Adds the debug information of the functions to the source map, once the opcode indices of
+/// the instructions are known.
+fn add_debug_functions(
+    source_map: &mut SourceMap,
+    source_engine: &SourceEngine,
+    debug_functions: &[DebugFunction],
+    instruction_spans: &BTreeMap<usize, Span>,
+    code_end: usize,
+) {
+    let mut debug_functions = debug_functions.to_vec();
+    debug_functions.sort_by_key(|function| function.info.range.start);
+
+    // Each function ends where the next one starts, and the last one at the end of the code.
+    let mut end = code_end;
+    for function in debug_functions.iter_mut().rev() {
+        function.info.range.end = end;
+        end = function.info.range.start;
+    }
+
+    for DebugFunction {
+        mut info,
+        span,
+        inlined_calls,
+    } in debug_functions
+    {
+        info.decl = span.and_then(|span| source_map.to_source_map_span(source_engine, &span));
+
+        // Inlined instructions keep the span of the call they were inlined from.
+        for (name, decl, call) in inlined_calls {
+            let mut ranges: Vec<std::ops::Range<usize>> = vec![];
+            for (&ix, _) in instruction_spans
+                .range(info.range.clone())
+                .filter(|(_, span)| **span == call)
+            {
+                match ranges.last_mut() {
+                    Some(range) if range.end == ix => range.end = ix + 1,
+                    _ => ranges.push(ix..ix + 1),
+                }
+            }
+            if ranges.is_empty() {
+                continue;
+            }
+            let Some(call) = source_map.to_source_map_span(source_engine, &call) else {
+                continue;
+            };
+            let decl = decl.and_then(|decl| source_map.to_source_map_span(source_engine, &decl));
+            info.inlined.push(InlinedCallDebugInfo {
+                name,
+                decl,
+                call,
+                ranges,
+            });
+        }
+
+        source_map.functions.push(info);
+    }
+}
+
 impl FinalizedEntry {
     /// We assume the entry point is for a test function in the case it is neither an ABI method
     /// (no selector) or it is not "main".
@@ -115,6 +188,7 @@ impl fmt::Display for FinalizedAsm {
 
 fn to_bytecode_mut(
     ops: &[AllocatedOp],
+    debug_functions: &[DebugFunction],
     data_section: &mut DataSection,
     source_map: &mut SourceMap,
     source_engine: &SourceEngine,
@@ -198,6 +272,7 @@ fn to_bytecode_mut(
 
     let mut half_word_ix = 0;
     let mut offset_from_instr_start = 0;
+    let mut instruction_spans = BTreeMap::new();
     for op in ops.iter() {
         let span = op.owning_span.clone();
         let fuel_op = op.to_fuel_asm(
@@ -286,6 +361,7 @@ fn to_bytecode_mut(
 
                     if let Some(span) = &span {
                         source_map.insert(source_engine, half_word_ix, span);
+                        instruction_spans.insert(half_word_ix, span.clone());
                     }
 
                     let bytes = instruction.to_bytes();
@@ -301,6 +377,14 @@ fn to_bytecode_mut(
         }
     }
 
+    add_debug_functions(
+        source_map,
+        source_engine,
+        debug_functions,
+        &instruction_spans,
+        half_word_ix,
+    );
+
     if build_config.print_bytecode {
         println!(".data_section:");
 
@@ -15,10 +15,14 @@ const NUM_FREE_REGISTERS: u8 = 48;
 const NUM_COMPILER_RESERVED_REGISTERS: u8 = 5 + NUM_ARG_REGISTERS;
 
 pub(crate) const DATA_SECTION_REGISTER: u8 = NUM_TOTAL_REGISTERS - 1;
-pub(crate) const RETURN_ADDRESS_REGISTER: u8 = NUM_TOTAL_REGISTERS - 2;
+/// The register holding the return address of function calls, also used by debuggers to follow
+/// returns.
+pub const RETURN_ADDRESS_REGISTER: u8 = NUM_TOTAL_REGISTERS - 2;
 pub(crate) const RETURN_VALUE_REGISTER: u8 = NUM_TOTAL_REGISTERS - 3;
 pub(crate) const SCRATCH_REGISTER: u8 = NUM_TOTAL_REGISTERS - 4;
-pub(crate) const LOCALS_BASE: u8 = NUM_TOTAL_REGISTERS - 5;
+/// The register holding the base address of the stack locals of the current function, also used
+/// by debuggers to locate local variables.
+pub const LOCALS_BASE: u8 = NUM_TOTAL_REGISTERS - 5;
 
 pub(crate) const NUM_ARG_REGISTERS: u8 = 6;
 pub(crate) const ARG_REG0: u8 = NUM_TOTAL_REGISTERS - 6;
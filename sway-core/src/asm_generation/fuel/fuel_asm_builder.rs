@@ -13,7 +13,7 @@ use crate::{
             data_section::{DataId, DataSection, Entry},
             register_sequencer::RegisterSequencer,
         },
-        FinalizedAsm, ProgramKind,
+        DebugFunction, FinalizedAsm, ProgramKind,
     },
     asm_lang::{
         virtual_register::*, Label, Op, VirtualImmediate06, VirtualImmediate12, VirtualImmediate18,
@@ -84,6 +84,10 @@ pub struct FuelAsmBuilder<'ir, 'eng> {
     pub(super) entries: Vec<(Function, Label, Vec<Op>, Option<DeclRefFunction>)>,
     pub(super) non_entries: Vec<Vec<Op>>,
 
+    // The arguments and local variables of the compiled functions, for debuggers, with the start
+    // labels of the functions.
+    pub(super) debug_functions: Vec<(Label, DebugFunction)>,
+
     // In progress VM bytecode ops.
     pub(super) cur_bytecode: Vec<Op>,
 
@@ -203,6 +207,7 @@ impl<'ir, 'eng> AsmBuilder for FuelAsmBuilder<'ir, 'eng> {
             context,
             entries,
             non_entries,
+            debug_functions,
             before_entries: before_entry,
             ..
         } = self;
@@ -243,6 +248,7 @@ impl<'ir, 'eng> AsmBuilder for FuelAsmBuilder<'ir, 'eng> {
             before_entry,
             entries,
             non_entries,
+            debug_functions,
             reg_seqr,
             context.experimental,
         );
@@ -313,6 +319,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             md_mgr: MetadataManager::default(),
             entries: Vec::new(),
             non_entries: Vec::new(),
+            debug_functions: Vec::new(),
             cur_bytecode: Vec::new(),
             before_entries: vec![],
         }
@@ -389,7 +396,9 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
                     cond_value,
                     true_block,
                     false_block,
-                } => self.compile_conditional_branch(cond_value, true_block, false_block),
+                } => {
+                    self.compile_conditional_branch(instr_val, cond_value, true_block, false_block)
+                }
                 InstOp::ContractCall {
                     params,
                     coins,
@@ -1013,6 +1022,7 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
 
     fn compile_conditional_branch(
         &mut self,
+        instr_val: &Value,
         cond_value: &Value,
         true_block: &BranchToWithArgs,
         false_block: &BranchToWithArgs,
@@ -1030,9 +1040,13 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
 
         let cond_reg = self.value_to_register(cond_value)?;
 
+        // The conditional jump is located at the branch condition, so that the branches taken can
+        // be mapped back to the source code.
         let true_label = self.block_to_label(&true_block.block);
-        self.cur_bytecode
-            .push(Op::jump_if_not_zero(cond_reg, true_label));
+        self.cur_bytecode.push(Op {
+            owning_span: self.md_mgr.val_to_span(self.context, *instr_val),
+            ..Op::jump_if_not_zero(cond_reg, true_label)
+        });
 
         let false_label = self.block_to_label(&false_block.block);
         self.cur_bytecode.push(Op::jump_to_label(false_label));
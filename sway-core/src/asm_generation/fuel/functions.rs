@@ -6,7 +6,7 @@ use crate::{
             data_section::Entry,
             fuel_asm_builder::FuelAsmBuilder,
         },
-        ProgramKind,
+        DebugFunction, ProgramKind,
     },
     asm_lang::{
         virtual_register::{self, *},
@@ -15,6 +15,8 @@ use crate::{
     },
     decl_engine::DeclRef,
     fuel_prelude::fuel_asm::GTFArgs,
+    ir_generation::debug::ir_debug_type,
+    source_map::{FunctionDebugInfo, VariableDebugInfo, VariableLocation},
 };
 
 use sway_ir::*;
@@ -306,6 +308,11 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
             self.compile_fn_call_args(function)
         }
 
+        if self.context.generate_debug_info {
+            let debug_function = self.function_debug_info(function, func_is_entry, span.clone());
+            self.debug_functions.push((start_label, debug_function));
+        }
+
         let reta = self.reg_seqr.next(); // XXX only do this if this function makes calls
         if !func_is_entry {
             // Save $reta and $retv
@@ -385,6 +392,77 @@ impl<'ir, 'eng> FuelAsmBuilder<'ir, 'eng> {
         Ok(())
     }
 
+    /// Describes the arguments, the local variables and the inlined calls of `function` for
+    /// debuggers.
+    ///
+    /// Arguments are located in their argument registers until registers are allocated, and are
+    /// then moved to the registers they are saved to.
+    fn function_debug_info(
+        &mut self,
+        function: Function,
+        func_is_entry: bool,
+        span: Option<Span>,
+    ) -> DebugFunction {
+        let mut variables = Vec::new();
+
+        // Entries decode their arguments from the script data instead.
+        if !func_is_entry && function.num_args(self.context) <= NUM_ARG_REGISTERS as usize {
+            for (idx, (arg_name, arg_val)) in function.args_iter(self.context).enumerate() {
+                if let Some(ty) = arg_val.get_type(self.context) {
+                    variables.push(VariableDebugInfo {
+                        name: arg_name.clone(),
+                        ty: ir_debug_type(self.context, ty),
+                        location: VariableLocation::Register {
+                            index: ConstantRegister::ARG_REGS[idx].to_reg_id().to_u8(),
+                        },
+                    });
+                }
+            }
+        }
+
+        // Locals without debug information were introduced by the compiler.
+        for (_name, local_var) in function.locals_iter(self.context) {
+            let md = local_var.get_metadata(self.context);
+            let Some((name, ty)) = self.md_mgr.md_to_debug_var(self.context, md) else {
+                continue;
+            };
+            let location = match self.ptr_map.get(local_var) {
+                Some(Storage::Stack(word_offset)) => VariableLocation::Stack {
+                    offset: word_offset * 8,
+                },
+                Some(Storage::Const(imm)) => VariableLocation::Constant {
+                    value: imm.value as u64,
+                },
+                _ => continue,
+            };
+            variables.push(VariableDebugInfo { name, ty, location });
+        }
+
+        // Calls without a span cannot be told apart from the rest of the function.
+        let inlined_calls = function
+            .get_inlined_calls(self.context)
+            .to_vec()
+            .into_iter()
+            .filter_map(|call| {
+                let call_span = self.md_mgr.md_to_span(self.context, call.call_metadata)?;
+                let decl_span = self.md_mgr.md_to_span(self.context, call.callee_metadata);
+                Some((call.callee, decl_span, call_span))
+            })
+            .collect();
+
+        DebugFunction {
+            info: FunctionDebugInfo {
+                name: function.get_name(self.context).to_owned(),
+                range: 0..0,
+                decl: None,
+                variables,
+                inlined: vec![],
+            },
+            span,
+            inlined_calls,
+        }
+    }
+
     fn compile_fn_call_args(&mut self, function: Function) {
         if function.num_args(self.context) <= compiler_constants::NUM_ARG_REGISTERS as usize {
             // All arguments are passed through registers.
@@ -1,4 +1,4 @@
-pub(crate) mod compiler_constants;
+pub mod compiler_constants;
 pub(crate) mod data_section;
 pub(crate) mod register_allocator;
 
@@ -1,5 +1,6 @@
 use super::{AllocatedProgram, FnName, SelectorOpt};
 use crate::{
+    asm_generation::DebugFunction,
     asm_generation::{
         fuel::{
             abstract_instruction_set::AbstractInstructionSet,
@@ -43,6 +44,7 @@ pub(crate) struct AbstractProgram {
     before_entries: AbstractInstructionSet,
     entries: Vec<AbstractEntry>,
     non_entries: Vec<AbstractInstructionSet>,
+    debug_functions: Vec<(Label, DebugFunction)>,
     reg_seqr: RegisterSequencer,
     experimental: ExperimentalFeatures,
 }
@@ -56,6 +58,7 @@ impl AbstractProgram {
         before_entries: AbstractInstructionSet,
         entries: Vec<AbstractEntry>,
         non_entries: Vec<AbstractInstructionSet>,
+        debug_functions: Vec<(Label, DebugFunction)>,
         reg_seqr: RegisterSequencer,
         experimental: ExperimentalFeatures,
     ) -> Self {
@@ -66,6 +69,7 @@ impl AbstractProgram {
             before_entries,
             entries,
             non_entries,
+            debug_functions,
             reg_seqr,
             experimental,
         }
@@ -146,6 +150,7 @@ impl AbstractProgram {
             prologue,
             functions,
             entries,
+            debug_functions: self.debug_functions,
         })
     }
 
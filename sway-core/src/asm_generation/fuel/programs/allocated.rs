@@ -6,11 +6,16 @@ use crate::{
             allocated_abstract_instruction_set::AllocatedAbstractInstructionSet,
             data_section::DataSection,
         },
-        ProgramKind,
+        DebugFunction, ProgramKind,
+    },
+    asm_lang::{
+        allocated_ops::{AllocatedOpcode, AllocatedRegister},
+        AllocatedAbstractOp, ControlFlowOp, Label,
     },
-    asm_lang::Label,
     decl_engine::DeclRefFunction,
+    source_map::{FunctionDebugInfo, VariableLocation},
 };
+use either::Either;
 
 /// An [AllocatedProgram] represents code which has allocated registers but still has abstract
 /// control flow.
@@ -20,10 +25,22 @@ pub(crate) struct AllocatedProgram {
     pub(crate) prologue: AllocatedAbstractInstructionSet,
     pub(crate) functions: Vec<AllocatedAbstractInstructionSet>,
     pub(crate) entries: Vec<(SelectorOpt, Label, FnName, Option<DeclRefFunction>)>,
+    pub(crate) debug_functions: Vec<(Label, DebugFunction)>,
 }
 
 impl AllocatedProgram {
     pub(crate) fn into_final_program(mut self) -> Result<FinalProgram, crate::CompileError> {
+        // Find the registers the arguments were saved to, before the functions are merged.
+        for (label, debug_function) in self.debug_functions.iter_mut() {
+            if let Some(function) = self
+                .functions
+                .iter()
+                .find(|function| function.ops.iter().any(|op| is_label(op, label)))
+            {
+                resolve_argument_registers(function, &mut debug_function.info);
+            }
+        }
+
         // Concat the prologue and all the functions together.
         let abstract_ops = AllocatedAbstractInstructionSet {
             ops: std::iter::once(self.prologue.ops)
@@ -36,6 +53,18 @@ impl AllocatedProgram {
             abstract_ops.realize_labels(&mut self.data_section)?;
         let ops = realized_ops.allocated_ops();
 
+        // Collect the function offsets. The end of the functions is only known once the bytecode
+        // is generated.
+        let debug_functions = self
+            .debug_functions
+            .into_iter()
+            .filter_map(|(label, mut debug_function)| {
+                let offset = label_offsets.get(&label)?.offs as usize;
+                debug_function.info.range = offset..offset;
+                Some(debug_function)
+            })
+            .collect();
+
         // Collect the entry point offsets.
         let entries = self
             .entries
@@ -54,10 +83,44 @@ impl AllocatedProgram {
             data_section: self.data_section,
             ops,
             entries,
+            debug_functions,
         })
     }
 }
 
+fn is_label(op: &AllocatedAbstractOp, label: &Label) -> bool {
+    matches!(&op.opcode, Either::Right(ControlFlowOp::Label(l)) if l == label)
+}
+
+/// Moves the arguments of the function from the argument registers to the registers they are
+/// saved to at the start of the function. Arguments which are not saved are dropped, as the
+/// argument registers are overwritten by calls.
+fn resolve_argument_registers(
+    function: &AllocatedAbstractInstructionSet,
+    debug_info: &mut FunctionDebugInfo,
+) {
+    debug_info.variables.retain_mut(|variable| {
+        let VariableLocation::Register { index } = &mut variable.location else {
+            return true;
+        };
+        let saved_to = function.ops.iter().find_map(|op| match &op.opcode {
+            Either::Left(AllocatedOpcode::MOVE(to, AllocatedRegister::Constant(from)))
+                if from.to_reg_id().to_u8() == *index =>
+            {
+                Some(to.to_reg_id().to_u8())
+            }
+            _ => None,
+        });
+        match saved_to {
+            Some(saved_to) => {
+                *index = saved_to;
+                true
+            }
+            None => false,
+        }
+    });
+}
+
 impl std::fmt::Display for AllocatedProgram {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, ";; Program kind: {:?}", self.kind)?;
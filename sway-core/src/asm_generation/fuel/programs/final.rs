@@ -1,6 +1,7 @@
 use crate::{
     asm_generation::{
-        fuel::data_section::DataSection, instruction_set::InstructionSet, ProgramKind,
+        fuel::data_section::DataSection, instruction_set::InstructionSet, DebugFunction,
+        ProgramKind,
     },
     asm_lang::allocated_ops::AllocatedOp,
     decl_engine::DeclRefFunction,
@@ -15,6 +16,7 @@ pub(crate) struct FinalProgram {
     pub(crate) data_section: DataSection,
     pub(crate) ops: Vec<AllocatedOp>,
     pub(crate) entries: Vec<(SelectorOpt, ImmOffset, FnName, Option<DeclRefFunction>)>,
+    pub(crate) debug_functions: Vec<DebugFunction>,
 }
 
 impl FinalProgram {
@@ -24,6 +26,7 @@ impl FinalProgram {
             data_section,
             ops,
             entries,
+            debug_functions,
         } = self;
 
         FinalizedAsm {
@@ -40,6 +43,7 @@ impl FinalProgram {
                 })
                 .collect(),
             abi: None,
+            debug_functions,
         }
     }
 }
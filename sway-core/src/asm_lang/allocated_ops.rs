@@ -270,6 +270,12 @@ pub(crate) enum AllocatedOpcode {
 
     /* Other Instructions */
     FLAG(AllocatedRegister),
+    ECAL(
+        AllocatedRegister,
+        AllocatedRegister,
+        AllocatedRegister,
+        AllocatedRegister,
+    ),
     GM(AllocatedRegister, VirtualImmediate18),
     GTF(AllocatedRegister, AllocatedRegister, VirtualImmediate12),
 
@@ -395,6 +401,7 @@ impl AllocatedOpcode {
 
             /* Other Instructions */
             FLAG(_r1) => vec![],
+            ECAL(_r1, _r2, _r3, _r4) => vec![],
             GM(r1, _imm) => vec![r1],
             GTF(r1, _r2, _i) => vec![r1],
 
@@ -524,6 +531,7 @@ impl fmt::Display for AllocatedOpcode {
 
             /* Other Instructions */
             FLAG(a) => write!(fmtr, "flag {a}"),
+            ECAL(a, b, c, d) => write!(fmtr, "ecal {a} {b} {c} {d}"),
             GM(a, b) => write!(fmtr, "gm   {a} {b}"),
             GTF(a, b, c) => write!(fmtr, "gtf  {a} {b} {c}"),
 
@@ -734,6 +742,9 @@ impl AllocatedOp {
 
             /* Other Instructions */
             FLAG(a) => op::FLAG::new(a.to_reg_id()).into(),
+            ECAL(a, b, c, d) => {
+                op::ECAL::new(a.to_reg_id(), b.to_reg_id(), c.to_reg_id(), d.to_reg_id()).into()
+            }
             GM(a, b) => op::GM::new(a.to_reg_id(), b.value.into()).into(),
             GTF(a, b, c) => op::GTF::new(a.to_reg_id(), b.to_reg_id(), c.value.into()).into(),
 
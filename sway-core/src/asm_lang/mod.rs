@@ -665,6 +665,10 @@ impl Op {
                 let r1 = single_reg(handler, args, immediate, whole_op_span)?;
                 VirtualOp::FLAG(r1)
             }
+            "ecal" => {
+                let (r1, r2, r3, r4) = four_regs(handler, args, immediate, whole_op_span)?;
+                VirtualOp::ECAL(r1, r2, r3, r4)
+            }
             "gm" => {
                 let (r1, imm) = single_reg_imm_18(handler, args, immediate, whole_op_span)?;
                 VirtualOp::GM(r1, imm)
@@ -1242,6 +1246,7 @@ impl fmt::Display for VirtualOp {
 
             /* Other Instructions */
             FLAG(a) => write!(fmtr, "flag {a}"),
+            ECAL(a, b, c, d) => write!(fmtr, "ecal {a} {b} {c} {d}"),
             GM(a, b) => write!(fmtr, "gm {a} {b}"),
             GTF(a, b, c) => write!(fmtr, "gtf {a} {b} {c}"),
 
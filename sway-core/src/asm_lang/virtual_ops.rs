@@ -221,6 +221,12 @@ pub(crate) enum VirtualOp {
 
     /* Other Instructions */
     FLAG(VirtualRegister),
+    ECAL(
+        VirtualRegister,
+        VirtualRegister,
+        VirtualRegister,
+        VirtualRegister,
+    ),
     GM(VirtualRegister, VirtualImmediate18),
     GTF(VirtualRegister, VirtualRegister, VirtualImmediate12),
 
@@ -342,6 +348,7 @@ impl VirtualOp {
 
             /* Other Instructions */
             FLAG(r1) => vec![r1],
+            ECAL(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
             GM(r1, _imm) => vec![r1],
             GTF(r1, r2, _i) => vec![r1, r2],
 
@@ -464,6 +471,7 @@ impl VirtualOp {
             | K256(_, _, _)
             | S256(_, _, _)
             | FLAG(_)
+            | ECAL(_, _, _, _)
             // Virtual OPs
             | BLOB(_)
             | DataSectionOffsetPlaceholder
@@ -572,6 +580,7 @@ impl VirtualOp {
             | S256(_, _, _)
             | GM(_, _)
             | GTF(_, _, _)
+            | ECAL(_, _, _, _)
             | BLOB(_)
             | DataSectionOffsetPlaceholder
             | ConfigurablesOffsetPlaceholder
@@ -690,6 +699,7 @@ impl VirtualOp {
 
             /* Other Instructions */
             FLAG(r1) => vec![r1],
+            ECAL(r1, r2, r3, r4) => vec![r1, r2, r3, r4],
             GM(_r1, _imm) => vec![],
             GTF(_r1, r2, _i) => vec![r2],
 
@@ -812,6 +822,7 @@ impl VirtualOp {
 
             /* Other Instructions */
             FLAG(_r1) => vec![],
+            ECAL(_r1, _r2, _r3, _r4) => vec![],
             GM(r1, _imm) => vec![r1],
             GTF(r1, _r2, _i) => vec![r1],
 
@@ -1259,6 +1270,12 @@ impl VirtualOp {
 
             /* Other Instructions */
             FLAG(r1) => Self::FLAG(update_reg(reg_to_reg_map, r1)),
+            ECAL(r1, r2, r3, r4) => Self::ECAL(
+                update_reg(reg_to_reg_map, r1),
+                update_reg(reg_to_reg_map, r2),
+                update_reg(reg_to_reg_map, r3),
+                update_reg(reg_to_reg_map, r4),
+            ),
             GM(r1, i) => Self::GM(update_reg(reg_to_reg_map, r1), i.clone()),
             GTF(r1, r2, i) => Self::GTF(
                 update_reg(reg_to_reg_map, r1),
@@ -1740,6 +1757,12 @@ impl VirtualOp {
 
             /* Other Instructions */
             FLAG(reg) => AllocatedOpcode::FLAG(map_reg(&mapping, reg)),
+            ECAL(reg1, reg2, reg3, reg4) => AllocatedOpcode::ECAL(
+                map_reg(&mapping, reg1),
+                map_reg(&mapping, reg2),
+                map_reg(&mapping, reg3),
+                map_reg(&mapping, reg4),
+            ),
             GM(reg, imm) => AllocatedOpcode::GM(map_reg(&mapping, reg), imm.clone()),
             GTF(reg1, reg2, imm) => AllocatedOpcode::GTF(
                 map_reg(&mapping, reg1),
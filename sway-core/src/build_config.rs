@@ -108,7 +108,14 @@ pub struct PrintIr {
     pub r#final: bool,
     #[serde(rename = "modified")]
     pub modified_only: bool,
+    /// Passes after which the IR is printed.
     pub passes: Vec<String>,
+    /// Passes before which the IR is printed.
+    #[serde(rename = "before", default)]
+    pub passes_before: Vec<String>,
+    /// Print only the changes a pass made to the IR, instead of the whole IR after it.
+    #[serde(default)]
+    pub diff: bool,
 }
 
 impl Default for PrintIr {
@@ -118,6 +125,8 @@ impl Default for PrintIr {
             r#final: false,
             modified_only: true, // Default option is more restrictive.
             passes: vec![],
+            passes_before: vec![],
+            diff: false,
         }
     }
 }
@@ -132,6 +141,8 @@ impl PrintIr {
                 .iter()
                 .map(|pass| pass.to_string())
                 .collect_vec(),
+            passes_before: vec![],
+            diff: false,
         }
     }
 
@@ -157,6 +168,12 @@ impl std::ops::BitOrAssign for PrintIr {
                 self.passes.push(pass);
             }
         }
+        for pass in rhs.passes_before {
+            if !self.passes_before.contains(&pass) {
+                self.passes_before.push(pass);
+            }
+        }
+        self.diff |= rhs.diff;
     }
 }
 
@@ -167,6 +184,8 @@ impl From<&PrintIr> for PrintPassesOpts {
             r#final: value.r#final,
             modified_only: value.modified_only,
             passes: HashSet::from_iter(value.passes.iter().cloned()),
+            passes_before: HashSet::from_iter(value.passes_before.iter().cloned()),
+            diff: value.diff,
         }
     }
 }
@@ -186,7 +205,11 @@ pub struct BuildConfig {
     pub(crate) print_bytecode_spans: bool,
     pub(crate) print_ir: PrintIr,
     pub(crate) include_tests: bool,
+    // Whether to describe the functions, variables and types of the program for debuggers.
+    pub(crate) generate_debug_info: bool,
     pub(crate) optimization_level: OptLevel,
+    // Custom IR pass pipeline replacing the one selected by the optimization level.
+    pub(crate) pass_pipeline: Option<String>,
     pub time_phases: bool,
     pub profile: bool,
     pub metrics_outfile: Option<String>,
@@ -234,10 +257,12 @@ impl BuildConfig {
             print_bytecode_spans: false,
             print_ir: PrintIr::default(),
             include_tests: false,
+            generate_debug_info: false,
             time_phases: false,
             profile: false,
             metrics_outfile: None,
             optimization_level: OptLevel::Opt0,
+            pass_pipeline: None,
             lsp_mode: None,
         }
     }
@@ -300,6 +325,17 @@ impl BuildConfig {
         }
     }
 
+    /// Use the IR pass pipeline given in the `sway_ir::PassGroup::parse_pipeline` syntax
+    /// instead of the pipeline selected by the optimization level.
+    ///
+    /// Target specific lowering passes are still run after the pipeline.
+    pub fn with_pass_pipeline(self, pass_pipeline: Option<String>) -> Self {
+        Self {
+            pass_pipeline,
+            ..self
+        }
+    }
+
     /// Whether or not to include test functions in parsing, type-checking and codegen.
     ///
     /// This should be set to `true` by invocations like `forc test` or `forc check --tests`.
@@ -312,6 +348,19 @@ impl BuildConfig {
         }
     }
 
+    /// Whether or not to describe the functions, variables and types of the program in the source
+    /// map, for debuggers.
+    ///
+    /// This should be set to `true` by invocations like `forc build --output-debug` or `forc debug`.
+    ///
+    /// Default: `false`
+    pub fn with_generate_debug_info(self, generate_debug_info: bool) -> Self {
+        Self {
+            generate_debug_info,
+            ..self
+        }
+    }
+
     pub fn with_lsp_mode(self, lsp_mode: Option<LspConfig>) -> Self {
         Self { lsp_mode, ..self }
     }
@@ -43,7 +43,7 @@ fn is_entry_point(node: &TyAstNode, decl_engine: &DeclEngine, tree_type: &TreeTy
                     ..
                 } => {
                     let decl = decl_engine.get_function(decl_id);
-                    decl.is_entry() || decl.is_main() || decl.is_test()
+                    decl.is_entry() || decl.is_main() || decl.is_test() || decl.is_fuzz_test_entry()
                 }
                 _ => false,
             }
@@ -1,19 +1,30 @@
-use std::fs::File;
+use std::collections::HashMap;
 use std::os::unix::ffi::OsStringExt;
 use std::path::Path;
 
 use gimli::write::{
-    self, DebugLine, DebugLineStrOffsets, DebugStrOffsets, DwarfUnit, EndianVec, LineProgram,
-    LineString,
+    self, Address, AttributeValue, DwarfUnit, EndianVec, Expression, FileId, LineProgram,
+    LineString, Range, RangeList, Unit, UnitEntryId,
 };
 use gimli::{BigEndian, Encoding, LineEncoding};
 use sway_error::error::CompileError;
 use sway_types::Span;
 
-use crate::source_map::SourceMap;
+use crate::asm_generation::fuel::compiler_constants::LOCALS_BASE;
+use crate::source_map::{
+    DebugType, DebugTypeKind, FunctionDebugInfo, LocationRange, SourceMap, SourceMapSpan,
+    VariableDebugInfo, VariableLocation,
+};
 
-use object::write::Object;
+use object::write::elf;
 
+/// Writes the debug information of the compiled program to `out_file`, as the DWARF sections of
+/// an ELF object.
+///
+/// The compile unit holds the line number program, a subprogram for each compiled function with
+/// its arguments and local variables, the functions inlined into it and the types of the
+/// variables. Addresses are opcode indices, as in the source map, and registers are numbered as
+/// in the VM.
 pub fn write_dwarf(
     source_map: &SourceMap,
     primary_dir: &Path,
@@ -26,50 +37,92 @@ pub fn write_dwarf(
         address_size: 8,
     };
 
-    let program = build_line_number_program(encoding, primary_dir, primary_src, source_map)?;
-
-    program
-        .write(
-            &mut DebugLine::from(EndianVec::new(BigEndian)),
-            encoding,
-            &DebugLineStrOffsets::none(),
-            &DebugStrOffsets::none(),
-        )
-        .map_err(|err| {
-            sway_error::error::CompileError::InternalOwned(err.to_string(), Span::dummy())
-        })?;
-
     let mut dwarf = DwarfUnit::new(encoding);
-    dwarf.unit.line_program = program;
+    dwarf.unit.line_program =
+        build_line_number_program(encoding, primary_dir, primary_src, source_map)?;
+    build_debug_info_entries(&mut dwarf.unit, primary_dir, primary_src, source_map)?;
+
     // Write to new sections
     let mut debug_sections = write::Sections::new(EndianVec::new(BigEndian));
-    dwarf.write(&mut debug_sections).map_err(|err| {
-        sway_error::error::CompileError::InternalOwned(err.to_string(), Span::dummy())
+    dwarf.write(&mut debug_sections).map_err(internal_error)?;
+
+    let elf = write_elf(&debug_sections)?;
+    std::fs::write(out_file, elf).map_err(|err| {
+        internal_error(format!(
+            "failed to write debug info to {}: {err}",
+            out_file.display()
+        ))
     })?;
 
-    let file = File::create(out_file).unwrap();
-    let mut obj = Object::new(
-        object::BinaryFormat::Elf,
-        object::Architecture::X86_64,
-        object::Endianness::Big,
-    );
-    debug_sections
-        .for_each(|section_id, data| {
-            let sec = obj.add_section(
-                [].into(),
-                section_id.name().into(),
-                object::SectionKind::Other,
-            );
-            obj.set_section_data(sec, data.clone().into_vec(), 8);
-            Ok::<(), ()>(())
-        })
-        .unwrap();
+    Ok(())
+}
 
-    obj.write_stream(file).map_err(|err| {
-        sway_error::error::CompileError::InternalOwned(err.to_string(), Span::dummy())
+/// Writes the DWARF sections as the sections of a relocatable ELF object.
+///
+/// There is no ELF machine for the Fuel VM, so the object is tagged with `EM_NONE`, which the
+/// higher level object writer of the `object` crate does not support.
+fn write_elf(
+    debug_sections: &write::Sections<EndianVec<BigEndian>>,
+) -> Result<Vec<u8>, CompileError> {
+    let mut sections = Vec::new();
+    debug_sections.for_each(|section_id, data| {
+        sections.push((section_id.name(), data.slice()));
+        Ok::<(), CompileError>(())
     })?;
 
-    Ok(())
+    let mut buffer = Vec::new();
+    let mut writer = elf::Writer::new(object::Endianness::Big, true, &mut buffer);
+    writer.reserve_file_header();
+    writer.reserve_null_section_index();
+    let sections = sections
+        .into_iter()
+        .map(|(name, data)| {
+            let name = writer.add_section_name(name.as_bytes());
+            writer.reserve_section_index();
+            (name, data)
+        })
+        .collect::<Vec<_>>();
+    writer.reserve_shstrtab_section_index();
+    let sections = sections
+        .into_iter()
+        .map(|(name, data)| (name, writer.reserve(data.len(), 8), data))
+        .collect::<Vec<_>>();
+    writer.reserve_shstrtab();
+    writer.reserve_section_headers();
+
+    writer
+        .write_file_header(&elf::FileHeader {
+            os_abi: object::elf::ELFOSABI_NONE,
+            abi_version: 0,
+            e_type: object::elf::ET_REL,
+            e_machine: object::elf::EM_NONE,
+            e_entry: 0,
+            e_flags: 0,
+        })
+        .map_err(internal_error)?;
+    for (_, _, data) in &sections {
+        writer.write_align(8);
+        writer.write(data);
+    }
+    writer.write_shstrtab();
+    writer.write_null_section_header();
+    for (name, offset, data) in &sections {
+        writer.write_section_header(&elf::SectionHeader {
+            name: Some(*name),
+            sh_type: object::elf::SHT_PROGBITS,
+            sh_flags: 0,
+            sh_addr: 0,
+            sh_offset: *offset as u64,
+            sh_size: data.len() as u64,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 8,
+            sh_entsize: 0,
+        });
+    }
+    writer.write_shstrtab_section_header();
+
+    Ok(buffer)
 }
 
 fn build_line_number_program(
@@ -78,9 +131,9 @@ fn build_line_number_program(
     primary_src: &Path,
     source_map: &SourceMap,
 ) -> Result<LineProgram, CompileError> {
-    let primary_src = primary_src.strip_prefix(primary_dir).map_err(|err| {
-        sway_error::error::CompileError::InternalOwned(err.to_string(), Span::dummy())
-    })?;
+    let primary_src = primary_src
+        .strip_prefix(primary_dir)
+        .map_err(internal_error)?;
     let mut program = LineProgram::new(
         encoding,
         LineEncoding::default(),
@@ -89,50 +142,544 @@ fn build_line_number_program(
         None,
     );
 
-    program.begin_sequence(Some(write::Address::Constant(0)));
+    program.begin_sequence(Some(Address::Constant(0)));
 
     for (ix, span) in &source_map.map {
-        let (path, span) = span.to_span(&source_map.paths, &source_map.dependency_paths);
-
-        let dir = path
-            .parent()
-            .ok_or(sway_error::error::CompileError::InternalOwned(
-                "Path doesn't have a proper prefix".to_string(),
-                Span::dummy(),
-            ))?;
-        let file = path
-            .file_name()
-            .ok_or(sway_error::error::CompileError::InternalOwned(
-                "Path doesn't have proper filename".to_string(),
-                Span::dummy(),
-            ))?;
-
-        let dir_id = program.add_directory(LineString::String(
-            dir.as_os_str().as_encoded_bytes().into(),
-        ));
-        let file_id = program.add_file(
-            LineString::String(file.as_encoded_bytes().into()),
-            dir_id,
-            None,
-        );
-
-        program.generate_row();
+        let (file_id, range) = add_file(&mut program, source_map, span)?;
 
         let current_row = program.row();
-        current_row.line = span.start.line as u64;
-        current_row.column = span.start.col as u64;
+        current_row.line = range.start.line as u64;
+        current_row.column = range.start.col as u64;
         current_row.address_offset = *ix as u64;
         current_row.file = file_id;
+
+        program.generate_row();
     }
 
-    program.end_sequence(
-        source_map
-            .map
-            .last_key_value()
-            .map(|(key, _)| *key)
-            .unwrap_or_default() as u64
-            + 1,
-    );
+    program.end_sequence(code_end(source_map) as u64);
 
     Ok(program)
 }
+
+/// Adds the file of `span` to the line number program, and returns it with the location of the
+/// span in it.
+fn add_file(
+    program: &mut LineProgram,
+    source_map: &SourceMap,
+    span: &SourceMapSpan,
+) -> Result<(FileId, LocationRange), CompileError> {
+    let (path, range) = span.to_span(&source_map.paths, &source_map.dependency_paths);
+
+    let dir = path
+        .parent()
+        .ok_or(sway_error::error::CompileError::InternalOwned(
+            "Path doesn't have a proper prefix".to_string(),
+            Span::dummy(),
+        ))?;
+    let file = path
+        .file_name()
+        .ok_or(sway_error::error::CompileError::InternalOwned(
+            "Path doesn't have proper filename".to_string(),
+            Span::dummy(),
+        ))?;
+
+    let dir_id = program.add_directory(LineString::String(
+        dir.as_os_str().as_encoded_bytes().into(),
+    ));
+    let file_id = program.add_file(
+        LineString::String(file.as_encoded_bytes().into()),
+        dir_id,
+        None,
+    );
+
+    Ok((file_id, range))
+}
+
+/// The opcode index following the last instruction of the program.
+fn code_end(source_map: &SourceMap) -> usize {
+    let last_mapped = source_map
+        .map
+        .last_key_value()
+        .map(|(key, _)| *key + 1)
+        .unwrap_or_default();
+    source_map
+        .functions
+        .iter()
+        .map(|function| function.range.end)
+        .fold(last_mapped, usize::max)
+}
+
+/// Adds the compile unit attributes, and the entries of the functions and of their types, to the
+/// unit. The line number program must already be built, as the entries refer to its files.
+fn build_debug_info_entries(
+    unit: &mut Unit,
+    primary_dir: &Path,
+    primary_src: &Path,
+    source_map: &SourceMap,
+) -> Result<(), CompileError> {
+    let root = unit.root();
+    let compile_unit = unit.get_mut(root);
+    compile_unit.set(
+        gimli::DW_AT_name,
+        AttributeValue::String(primary_src.as_os_str().as_encoded_bytes().into()),
+    );
+    compile_unit.set(
+        gimli::DW_AT_comp_dir,
+        AttributeValue::String(primary_dir.as_os_str().as_encoded_bytes().into()),
+    );
+    compile_unit.set(
+        gimli::DW_AT_producer,
+        AttributeValue::String(format!("sway {}", env!("CARGO_PKG_VERSION")).into_bytes()),
+    );
+    compile_unit.set(
+        gimli::DW_AT_low_pc,
+        AttributeValue::Address(Address::Constant(0)),
+    );
+    compile_unit.set(
+        gimli::DW_AT_high_pc,
+        AttributeValue::Udata(code_end(source_map) as u64),
+    );
+
+    let mut entries = DebugInfoEntries {
+        unit,
+        source_map,
+        types: HashMap::new(),
+        inlined_functions: HashMap::new(),
+    };
+    for function in &source_map.functions {
+        entries.add_function(function)?;
+    }
+
+    Ok(())
+}
+
+/// Builds the debugging information entries of a unit, sharing the entries of the types and of
+/// the inlined functions.
+struct DebugInfoEntries<'a> {
+    unit: &'a mut Unit,
+    source_map: &'a SourceMap,
+    types: HashMap<DebugType, UnitEntryId>,
+    /// The abstract instances of the inlined functions, by name.
+    inlined_functions: HashMap<String, UnitEntryId>,
+}
+
+impl DebugInfoEntries<'_> {
+    fn add_function(&mut self, function: &FunctionDebugInfo) -> Result<(), CompileError> {
+        let root = self.unit.root();
+        let id = self.unit.add(root, gimli::DW_TAG_subprogram);
+        self.set_name(id, &function.name);
+        self.set_pc_range(id, &function.range);
+        if let Some(decl) = &function.decl {
+            self.set_location(
+                id,
+                gimli::DW_AT_decl_file,
+                gimli::DW_AT_decl_line,
+                None,
+                decl,
+            )?;
+        }
+
+        for variable in &function.variables {
+            self.add_variable(id, variable);
+        }
+
+        for inlined in &function.inlined {
+            let origin = self.inlined_function(&inlined.name, inlined.decl.as_ref())?;
+            let inlined_id = self.unit.add(id, gimli::DW_TAG_inlined_subroutine);
+            self.unit.get_mut(inlined_id).set(
+                gimli::DW_AT_abstract_origin,
+                AttributeValue::UnitRef(origin),
+            );
+            match inlined.ranges.as_slice() {
+                [range] => self.set_pc_range(inlined_id, range),
+                ranges => {
+                    let ranges = self.unit.ranges.add(RangeList(
+                        ranges
+                            .iter()
+                            .map(|range| Range::StartLength {
+                                begin: Address::Constant(range.start as u64),
+                                length: range.len() as u64,
+                            })
+                            .collect(),
+                    ));
+                    self.unit
+                        .get_mut(inlined_id)
+                        .set(gimli::DW_AT_ranges, AttributeValue::RangeListRef(ranges));
+                }
+            }
+            self.set_location(
+                inlined_id,
+                gimli::DW_AT_call_file,
+                gimli::DW_AT_call_line,
+                Some(gimli::DW_AT_call_column),
+                &inlined.call,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the abstract instance of the inlined function `name`, which its inlined calls
+    /// refer to.
+    fn inlined_function(
+        &mut self,
+        name: &str,
+        decl: Option<&SourceMapSpan>,
+    ) -> Result<UnitEntryId, CompileError> {
+        if let Some(id) = self.inlined_functions.get(name) {
+            return Ok(*id);
+        }
+
+        let root = self.unit.root();
+        let id = self.unit.add(root, gimli::DW_TAG_subprogram);
+        self.set_name(id, name);
+        self.unit.get_mut(id).set(
+            gimli::DW_AT_inline,
+            AttributeValue::Inline(gimli::DW_INL_inlined),
+        );
+        if let Some(decl) = decl {
+            self.set_location(
+                id,
+                gimli::DW_AT_decl_file,
+                gimli::DW_AT_decl_line,
+                None,
+                decl,
+            )?;
+        }
+
+        self.inlined_functions.insert(name.to_owned(), id);
+        Ok(id)
+    }
+
+    fn add_variable(&mut self, parent: UnitEntryId, variable: &VariableDebugInfo) {
+        // Only arguments are held in registers.
+        let tag = match variable.location {
+            VariableLocation::Register { .. } => gimli::DW_TAG_formal_parameter,
+            _ => gimli::DW_TAG_variable,
+        };
+        let ty = self.type_entry(&variable.ty);
+        let id = self.unit.add(parent, tag);
+        self.set_name(id, &variable.name);
+
+        let entry = self.unit.get_mut(id);
+        entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(ty));
+        match variable.location {
+            VariableLocation::Register { index } => {
+                let mut location = Expression::new();
+                location.op_reg(gimli::Register(index as u16));
+                entry.set(gimli::DW_AT_location, AttributeValue::Exprloc(location));
+            }
+            VariableLocation::Stack { offset } => {
+                let mut location = Expression::new();
+                location.op_breg(gimli::Register(LOCALS_BASE as u16), offset as i64);
+                entry.set(gimli::DW_AT_location, AttributeValue::Exprloc(location));
+            }
+            VariableLocation::Constant { value } => {
+                entry.set(gimli::DW_AT_const_value, AttributeValue::Udata(value));
+            }
+        }
+    }
+
+    /// Returns the entry describing `ty`, adding it and the entries of the types it contains if
+    /// needed.
+    fn type_entry(&mut self, ty: &DebugType) -> UnitEntryId {
+        if let Some(id) = self.types.get(ty) {
+            return *id;
+        }
+
+        let root = self.unit.root();
+        let id = match &ty.kind {
+            DebugTypeKind::Bool => self.base_type(gimli::DW_ATE_boolean),
+            DebugTypeKind::Uint { .. } | DebugTypeKind::Bytes32 => {
+                self.base_type(gimli::DW_ATE_unsigned)
+            }
+            DebugTypeKind::Opaque => self.unit.add(root, gimli::DW_TAG_unspecified_type),
+            DebugTypeKind::Pointer => self.unit.add(root, gimli::DW_TAG_pointer_type),
+            DebugTypeKind::Slice | DebugTypeKind::StringSlice => {
+                let (ptr, len) = (self.pointer_type(None), self.u64_type());
+                let id = self.unit.add(root, gimli::DW_TAG_structure_type);
+                self.add_member(id, "ptr", ptr, 0);
+                self.add_member(id, "len", len, 8);
+                id
+            }
+            DebugTypeKind::StringArray { len } => {
+                let elem = self.u8_type();
+                self.array_type(elem, *len)
+            }
+            DebugTypeKind::Array { elem, len } => {
+                let elem = self.type_entry(elem);
+                self.array_type(elem, *len)
+            }
+            DebugTypeKind::Struct { fields } => {
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        (
+                            field.name.as_str(),
+                            self.type_entry(&field.ty),
+                            field.offset,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let id = self.unit.add(root, gimli::DW_TAG_structure_type);
+                for (name, ty, offset) in fields {
+                    self.add_member(id, name, ty, offset);
+                }
+                id
+            }
+            // Enums are a tag followed by the value of the variant, described as a variant part
+            // discriminated by the tag.
+            DebugTypeKind::Enum { variants } => {
+                let tag_ty = self.u64_type();
+                let variants = variants
+                    .iter()
+                    .map(|variant| {
+                        let ty = match variant.ty.kind {
+                            DebugTypeKind::Opaque => None,
+                            _ => Some(self.type_entry(&variant.ty)),
+                        };
+                        (variant.name.as_str(), ty, variant.offset)
+                    })
+                    .collect::<Vec<_>>();
+                let id = self.unit.add(root, gimli::DW_TAG_structure_type);
+                let tag = self.add_member(id, "tag", tag_ty, 0);
+                let variant_part = self.unit.add(id, gimli::DW_TAG_variant_part);
+                self.unit
+                    .get_mut(variant_part)
+                    .set(gimli::DW_AT_discr, AttributeValue::UnitRef(tag));
+                for (idx, (name, ty, offset)) in variants.into_iter().enumerate() {
+                    let variant = self.unit.add(variant_part, gimli::DW_TAG_variant);
+                    self.unit
+                        .get_mut(variant)
+                        .set(gimli::DW_AT_discr_value, AttributeValue::Udata(idx as u64));
+                    match ty {
+                        Some(ty) => {
+                            self.add_member(variant, name, ty, offset);
+                        }
+                        None => self.set_name(variant, name),
+                    }
+                }
+                id
+            }
+            // `Vec`, `Bytes` and `String` start with a pointer to their buffer, followed by its
+            // capacity and by their length.
+            DebugTypeKind::Vec { .. } | DebugTypeKind::Bytes { .. } => {
+                let elem = match &ty.kind {
+                    DebugTypeKind::Vec { elem } => self.type_entry(elem),
+                    _ => self.u8_type(),
+                };
+                let (ptr, len) = (self.pointer_type(Some(elem)), self.u64_type());
+                let id = self.unit.add(root, gimli::DW_TAG_structure_type);
+                self.add_member(id, "ptr", ptr, 0);
+                self.add_member(id, "cap", len, 8);
+                self.add_member(id, "len", len, 16);
+                id
+            }
+        };
+
+        self.set_name(id, &ty.name);
+        self.unit
+            .get_mut(id)
+            .set(gimli::DW_AT_byte_size, AttributeValue::Udata(ty.size));
+        self.types.insert(ty.clone(), id);
+        id
+    }
+
+    fn base_type(&mut self, encoding: gimli::DwAte) -> UnitEntryId {
+        let root = self.unit.root();
+        let id = self.unit.add(root, gimli::DW_TAG_base_type);
+        self.unit
+            .get_mut(id)
+            .set(gimli::DW_AT_encoding, AttributeValue::Encoding(encoding));
+        id
+    }
+
+    fn u64_type(&mut self) -> UnitEntryId {
+        self.type_entry(&DebugType {
+            name: "u64".into(),
+            size: 8,
+            kind: DebugTypeKind::Uint { bits: 64 },
+        })
+    }
+
+    fn u8_type(&mut self) -> UnitEntryId {
+        self.type_entry(&DebugType {
+            name: "u8".into(),
+            size: 1,
+            kind: DebugTypeKind::Uint { bits: 8 },
+        })
+    }
+
+    /// Adds a pointer type, to values of type `pointee` if it is known.
+    fn pointer_type(&mut self, pointee: Option<UnitEntryId>) -> UnitEntryId {
+        let root = self.unit.root();
+        let id = self.unit.add(root, gimli::DW_TAG_pointer_type);
+        let entry = self.unit.get_mut(id);
+        entry.set(gimli::DW_AT_byte_size, AttributeValue::Udata(8));
+        if let Some(pointee) = pointee {
+            entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(pointee));
+        }
+        id
+    }
+
+    fn array_type(&mut self, elem: UnitEntryId, len: u64) -> UnitEntryId {
+        let root = self.unit.root();
+        let id = self.unit.add(root, gimli::DW_TAG_array_type);
+        self.unit
+            .get_mut(id)
+            .set(gimli::DW_AT_type, AttributeValue::UnitRef(elem));
+        let subrange = self.unit.add(id, gimli::DW_TAG_subrange_type);
+        self.unit
+            .get_mut(subrange)
+            .set(gimli::DW_AT_count, AttributeValue::Udata(len));
+        id
+    }
+
+    fn add_member(
+        &mut self,
+        parent: UnitEntryId,
+        name: &str,
+        ty: UnitEntryId,
+        offset: u64,
+    ) -> UnitEntryId {
+        let id = self.unit.add(parent, gimli::DW_TAG_member);
+        self.set_name(id, name);
+        let entry = self.unit.get_mut(id);
+        entry.set(gimli::DW_AT_type, AttributeValue::UnitRef(ty));
+        entry.set(
+            gimli::DW_AT_data_member_location,
+            AttributeValue::Udata(offset),
+        );
+        id
+    }
+
+    fn set_name(&mut self, id: UnitEntryId, name: &str) {
+        self.unit
+            .get_mut(id)
+            .set(gimli::DW_AT_name, AttributeValue::String(name.into()));
+    }
+
+    fn set_pc_range(&mut self, id: UnitEntryId, range: &std::ops::Range<usize>) {
+        let entry = self.unit.get_mut(id);
+        entry.set(
+            gimli::DW_AT_low_pc,
+            AttributeValue::Address(Address::Constant(range.start as u64)),
+        );
+        entry.set(
+            gimli::DW_AT_high_pc,
+            AttributeValue::Udata(range.len() as u64),
+        );
+    }
+
+    /// Sets the file, the line and optionally the column of `span` as the given attributes.
+    fn set_location(
+        &mut self,
+        id: UnitEntryId,
+        file_attr: gimli::DwAt,
+        line_attr: gimli::DwAt,
+        column_attr: Option<gimli::DwAt>,
+        span: &SourceMapSpan,
+    ) -> Result<(), CompileError> {
+        let (file_id, range) = add_file(&mut self.unit.line_program, self.source_map, span)?;
+        let entry = self.unit.get_mut(id);
+        entry.set(file_attr, AttributeValue::FileIndex(Some(file_id)));
+        entry.set(line_attr, AttributeValue::Udata(range.start.line as u64));
+        if let Some(column_attr) = column_attr {
+            entry.set(column_attr, AttributeValue::Udata(range.start.col as u64));
+        }
+        Ok(())
+    }
+}
+
+fn internal_error(err: impl ToString) -> CompileError {
+    sway_error::error::CompileError::InternalOwned(err.to_string(), Span::dummy())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source_map::PathIndex;
+    use object::read::elf::{ElfFile64, FileHeader};
+    use object::{Object, ObjectSection};
+    use sway_types::LineCol;
+
+    fn span(line: usize) -> SourceMapSpan {
+        SourceMapSpan {
+            path: PathIndex(0),
+            range: LocationRange {
+                start: LineCol { line, col: 5 },
+                end: LineCol { line, col: 10 },
+            },
+        }
+    }
+
+    #[test]
+    fn write_dwarf_reads_back() {
+        let dir = std::env::temp_dir().join(format!("sway-dwarf-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let src = dir.join("src").join("main.sw");
+        let out_file = dir.join("main.obj");
+
+        let source_map = SourceMap {
+            paths: vec![src.clone()],
+            map: [(0, span(3)), (1, span(4))].into_iter().collect(),
+            functions: vec![FunctionDebugInfo {
+                name: "main".to_string(),
+                range: 0..2,
+                decl: Some(span(2)),
+                variables: vec![VariableDebugInfo {
+                    name: "x".to_string(),
+                    ty: DebugType {
+                        name: "u64".to_string(),
+                        size: 8,
+                        kind: DebugTypeKind::Uint { bits: 64 },
+                    },
+                    location: VariableLocation::Stack { offset: 8 },
+                }],
+                inlined: vec![],
+            }],
+            ..Default::default()
+        };
+        write_dwarf(&source_map, &dir, &src, &out_file).unwrap();
+        let bytes = std::fs::read(&out_file).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let elf = ElfFile64::<object::Endianness>::parse(&*bytes).unwrap();
+        assert_eq!(
+            elf.elf_header().e_machine(elf.endian()),
+            object::elf::EM_NONE
+        );
+
+        let dwarf = gimli::Dwarf::load(|section_id| {
+            let data = elf
+                .section_by_name(section_id.name())
+                .map(|section| section.data().unwrap())
+                .unwrap_or_default();
+            Ok::<_, gimli::Error>(gimli::EndianSlice::new(data, BigEndian))
+        })
+        .unwrap();
+        let header = dwarf.units().next().unwrap().unwrap();
+        let unit = dwarf.unit(header).unwrap();
+
+        let mut names = vec![];
+        let mut entries = unit.entries();
+        while let Some((_, entry)) = entries.next_dfs().unwrap() {
+            if let Some(name) = entry.attr_value(gimli::DW_AT_name).unwrap() {
+                let name = dwarf.attr_string(&unit, name).unwrap();
+                names.push((entry.tag(), name.to_string_lossy().into_owned()));
+            }
+        }
+        assert!(names.contains(&(gimli::DW_TAG_subprogram, "main".to_string())));
+        assert!(names.contains(&(gimli::DW_TAG_variable, "x".to_string())));
+        assert!(names.contains(&(gimli::DW_TAG_base_type, "u64".to_string())));
+
+        let mut lines = vec![];
+        let mut rows = unit.line_program.clone().unwrap().rows();
+        while let Some((_, row)) = rows.next_row().unwrap() {
+            if !row.end_sequence() {
+                lines.push((row.address(), row.line().map(|line| line.get())));
+            }
+        }
+        assert_eq!(lines, vec![(0, Some(3)), (1, Some(4))]);
+    }
+}
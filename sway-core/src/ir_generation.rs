@@ -1,6 +1,7 @@
 pub(crate) mod compile;
 pub mod const_eval;
 mod convert;
+pub(crate) mod debug;
 mod function;
 mod lexical_map;
 mod purity;
@@ -10,6 +11,7 @@ mod types;
 use std::{
     collections::HashMap,
     hash::{DefaultHasher, Hasher},
+    sync::Arc,
 };
 
 use sway_error::error::CompileError;
@@ -120,13 +122,37 @@ impl CompiledFunctionCache {
 pub fn compile_program<'eng>(
     program: &ty::TyProgram,
     include_tests: bool,
+    generate_debug_info: bool,
     engines: &'eng Engines,
     experimental: ExperimentalFeatures,
 ) -> Result<Context<'eng>, Vec<CompileError>> {
     let declaration_engine = engines.de();
 
     let test_fns = match include_tests {
-        true => program.test_fns(declaration_engine).collect(),
+        true => {
+            // Parameterized tests are entered through their generated entries, which are
+            // compiled under the name and span of the test, as the generated code has no source
+            // file to locate the test in.
+            let fuzz_test_entries = program
+                .fuzz_test_entries(declaration_engine)
+                .collect::<HashMap<_, _>>();
+            program
+                .test_fns(declaration_engine)
+                .map(
+                    |(fn_decl, decl_ref)| match fuzz_test_entries.get(decl_ref.id()) {
+                        Some(entry) => {
+                            let entry = ty::TyFunctionDecl {
+                                name: fn_decl.name.clone(),
+                                span: fn_decl.span.clone(),
+                                ..(**entry).clone()
+                            };
+                            (Arc::new(entry), decl_ref)
+                        }
+                        None => (fn_decl, decl_ref),
+                    },
+                )
+                .collect()
+        }
         false => vec![],
     };
 
@@ -150,6 +176,7 @@ pub fn compile_program<'eng>(
         .collect();
 
     let mut ctx = Context::new(engines.se(), experimental);
+    ctx.generate_debug_info = generate_debug_info;
     ctx.program_kind = match kind {
         ty::TyProgramKind::Script { .. } => Kind::Script,
         ty::TyProgramKind::Predicate { .. } => Kind::Predicate,
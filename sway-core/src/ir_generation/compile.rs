@@ -467,20 +467,27 @@ pub(super) fn compile_tests(
     test_fns: &[(Arc<ty::TyFunctionDecl>, DeclRefFunction)],
     cache: &mut CompiledFunctionCache,
 ) -> Result<Vec<Function>, Vec<CompileError>> {
+    let is_entry = true;
+    let is_original_entry = !context.experimental.new_encoding;
     test_fns
         .iter()
-        .map(|(_ast_fn_decl, decl_ref)| {
-            compile_entry_function(
+        .map(|(ast_fn_decl, decl_ref)| {
+            // For parameterized tests, `ast_fn_decl` is the generated entry of the test.
+            compile_function(
                 engines,
                 context,
                 md_mgr,
                 module,
-                decl_ref.id(),
+                ast_fn_decl,
+                &ast_fn_decl.name,
                 logged_types_map,
                 messages_types_map,
+                is_entry,
+                is_original_entry,
                 Some(decl_ref.clone()),
                 cache,
             )
+            .map(|f| f.expect("test entry point should never contain generics"))
         })
         .collect()
 }
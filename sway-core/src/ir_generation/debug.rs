@@ -0,0 +1,161 @@
+//! Descriptions of the types of variables, emitted into the source map for debuggers.
+
+use crate::{
+    source_map::{DebugField, DebugType, DebugTypeKind},
+    type_system::{TypeId, TypeInfo},
+    Engines,
+};
+
+use super::convert::convert_resolved_typeid_no_span;
+
+use sway_ir::{Context, Type};
+use sway_types::integer_bits::IntegerBits;
+
+/// Describes the Sway type `type_id` and its layout in memory. Returns `None` for types which do
+/// not exist at runtime.
+pub(super) fn debug_type(
+    engines: &Engines,
+    context: &mut Context,
+    type_id: TypeId,
+) -> Option<DebugType> {
+    let (type_engine, decl_engine) = (engines.te(), engines.de());
+    let ir_type =
+        convert_resolved_typeid_no_span(type_engine, decl_engine, context, type_id).ok()?;
+    let name = engines.help_out(type_id).to_string();
+    let size = ir_type.size(context).in_bytes();
+
+    let kind = match &*type_engine.get_unaliased(type_id) {
+        TypeInfo::Boolean => DebugTypeKind::Bool,
+        TypeInfo::UnsignedInteger(IntegerBits::V256) | TypeInfo::B256 => DebugTypeKind::Bytes32,
+        TypeInfo::UnsignedInteger(bits) => DebugTypeKind::Uint {
+            bits: bits_of(*bits),
+        },
+        TypeInfo::Numeric => DebugTypeKind::Uint { bits: 64 },
+        TypeInfo::StringSlice => DebugTypeKind::StringSlice,
+        TypeInfo::StringArray(len) => DebugTypeKind::StringArray {
+            len: len.val() as u64,
+        },
+        TypeInfo::Array(elem, len) => DebugTypeKind::Array {
+            elem: Box::new(debug_type(engines, context, elem.type_id)?),
+            len: len.val() as u64,
+        },
+        TypeInfo::Tuple(fields) if fields.is_empty() => DebugTypeKind::Opaque,
+        TypeInfo::Tuple(fields) => DebugTypeKind::Struct {
+            fields: debug_fields(
+                engines,
+                context,
+                ir_type,
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, field)| (idx.to_string(), field.type_id)),
+            )?,
+        },
+        TypeInfo::Struct(decl_id) => {
+            let decl = decl_engine.get_struct(decl_id);
+            match decl.call_path.to_string().as_str() {
+                "std::vec::Vec" => DebugTypeKind::Vec {
+                    elem: Box::new(debug_type(
+                        engines,
+                        context,
+                        decl.type_parameters.first()?.type_id,
+                    )?),
+                },
+                "std::bytes::Bytes" => DebugTypeKind::Bytes { utf8: false },
+                "std::string::String" => DebugTypeKind::Bytes { utf8: true },
+                _ => DebugTypeKind::Struct {
+                    fields: debug_fields(
+                        engines,
+                        context,
+                        ir_type,
+                        decl.fields
+                            .iter()
+                            .map(|field| (field.name.to_string(), field.type_argument.type_id)),
+                    )?,
+                },
+            }
+        }
+        TypeInfo::Enum(decl_id) => {
+            let decl = decl_engine.get_enum(decl_id);
+            let has_union = ir_type.get_field_types(context).len() == 2;
+            let variants = decl
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(idx, variant)| {
+                    let ty = debug_type(engines, context, variant.type_argument.type_id)?;
+                    let offset = if has_union {
+                        ir_type.get_indexed_offset(context, &[1, idx as u64])?
+                    } else {
+                        8
+                    };
+                    Some(DebugField {
+                        name: variant.name.to_string(),
+                        offset,
+                        ty,
+                    })
+                })
+                .collect::<Option<_>>()?;
+            DebugTypeKind::Enum { variants }
+        }
+        TypeInfo::RawUntypedSlice => DebugTypeKind::Slice,
+        TypeInfo::Ref { .. } if ir_type.is_slice(context) => DebugTypeKind::Slice,
+        TypeInfo::RawUntypedPtr | TypeInfo::Ptr(_) | TypeInfo::Ref { .. } => DebugTypeKind::Pointer,
+        _ => DebugTypeKind::Opaque,
+    };
+
+    Some(DebugType { name, size, kind })
+}
+
+/// Describes the IR type `ty`, for values whose Sway type is not known, e.g. arguments of
+/// functions.
+pub(crate) fn ir_debug_type(context: &Context, ty: Type) -> DebugType {
+    let kind = if ty.is_bool(context) {
+        DebugTypeKind::Bool
+    } else if ty.is_uint_of(context, 256) || ty.is_b256(context) {
+        DebugTypeKind::Bytes32
+    } else if let Some(bits) = ty.get_uint_width(context) {
+        DebugTypeKind::Uint { bits }
+    } else if ty.is_string_slice(context) {
+        DebugTypeKind::StringSlice
+    } else if ty.is_slice(context) {
+        DebugTypeKind::Slice
+    } else if ty.is_ptr(context) {
+        DebugTypeKind::Pointer
+    } else {
+        DebugTypeKind::Opaque
+    };
+    DebugType {
+        name: ty.as_string(context),
+        size: ty.size(context).in_bytes(),
+        kind,
+    }
+}
+
+fn debug_fields(
+    engines: &Engines,
+    context: &mut Context,
+    ir_type: Type,
+    fields: impl Iterator<Item = (String, TypeId)>,
+) -> Option<Vec<DebugField>> {
+    fields
+        .enumerate()
+        .map(|(idx, (name, type_id))| {
+            Some(DebugField {
+                name,
+                offset: ir_type.get_indexed_offset(context, &[idx as u64])?,
+                ty: debug_type(engines, context, type_id)?,
+            })
+        })
+        .collect()
+}
+
+fn bits_of(bits: IntegerBits) -> u16 {
+    match bits {
+        IntegerBits::Eight => 8,
+        IntegerBits::Sixteen => 16,
+        IntegerBits::ThirtyTwo => 32,
+        IntegerBits::SixtyFour => 64,
+        IntegerBits::V256 => 256,
+    }
+}
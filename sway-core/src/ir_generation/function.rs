@@ -1,5 +1,6 @@
 use super::{
     convert::*,
+    debug::debug_type,
     lexical_map::LexicalMap,
     storage::{add_to_b256, get_storage_field_id, get_storage_key},
     types::*,
@@ -3369,6 +3370,14 @@ impl<'eng> FnCompiler<'eng> {
             .new_local_var(context, local_name.clone(), return_type, None, mutable)
             .map_err(|ir_error| CompileError::InternalOwned(ir_error.to_string(), Span::dummy()))?;
 
+        // Describe the variable for debuggers, under its name in the source code.
+        if context.generate_debug_info {
+            if let Some(ty) = debug_type(self.engines, context, body.return_type) {
+                let debug_var_md_idx = md_mgr.debug_var_to_md(context, name.as_str(), &ty);
+                local_var.set_metadata(context, debug_var_md_idx);
+            }
+        }
+
         // The name has now been added, so we can check if the initializer threw an error
         let val = init_val?;
 
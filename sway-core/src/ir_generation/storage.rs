@@ -23,7 +23,7 @@ enum InByte8Padding {
 /// Hands out storage keys using storage field names or an existing key.
 /// Basically returns sha256((0u8, "storage::<storage_namespace_name1>::<storage_namespace_name2>.<storage_field_name>"))
 /// or key if defined.
-pub(super) fn get_storage_key(
+pub(crate) fn get_storage_key(
     storage_field_names: Vec<String>,
     key: Option<U256>,
     experimental: ExperimentalFeatures,
@@ -25,6 +25,9 @@ use sway_types::{
     Ident, Named, Span, Spanned,
 };
 
+/// The prefix of the names of the generated entries of parameterized (fuzz) tests.
+pub const FUZZ_TEST_ENTRY_PREFIX: &str = "__fuzz_entry_";
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum TyFunctionDeclKind {
     Default,
@@ -425,6 +428,18 @@ impl TyFunctionDecl {
             .contains_key(&transform::AttributeKind::Test)
     }
 
+    /// Whether or not this function is a parameterized unit test, whose arguments are provided
+    /// by the test runner.
+    pub fn is_fuzz_test(&self) -> bool {
+        self.is_test() && !self.parameters.is_empty()
+    }
+
+    /// Whether or not this function is the generated entry of a parameterized unit test.
+    pub fn is_fuzz_test_entry(&self) -> bool {
+        matches!(self.kind, TyFunctionDeclKind::Test)
+            && self.name.as_str().starts_with(FUZZ_TEST_ENTRY_PREFIX)
+    }
+
     pub fn inline(&self) -> Option<Inline> {
         match self
             .attributes
@@ -1,7 +1,8 @@
 use crate::{
     engine_threading::*,
-    ir_generation::storage::get_storage_key_string,
-    language::parsed::StorageDeclaration,
+    fuel_prelude::fuel_types::Bytes32,
+    ir_generation::storage::{get_storage_key, get_storage_key_string},
+    language::{parsed::StorageDeclaration, Literal},
     transform::{self},
     ty::*,
     type_system::*,
@@ -13,7 +14,8 @@ use sway_error::{
     error::{CompileError, StructFieldUsageContext},
     handler::{ErrorEmitted, Handler},
 };
-use sway_types::{Ident, Named, Span, Spanned};
+use sway_features::ExperimentalFeatures;
+use sway_types::{u256::U256, Ident, Named, Span, Spanned};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TyStorageDecl {
@@ -267,6 +269,25 @@ impl TyStorageField {
                 .collect::<Vec<_>>(),
         )
     }
+
+    /// Returns the key of the storage slot the [TyStorageField] starts at, or `None` if the
+    /// key is given by an expression other than a `b256` literal.
+    pub fn storage_key(&self, experimental: ExperimentalFeatures) -> Option<Bytes32> {
+        let key = match &self.key_expression {
+            Some(key_expression) => match &key_expression.expression {
+                TyExpressionVariant::Literal(Literal::B256(key)) => Some(U256::from_be_bytes(key)),
+                _ => return None,
+            },
+            None => None,
+        };
+        let storage_field_names = self
+            .namespace_names
+            .iter()
+            .chain(std::iter::once(&self.name))
+            .map(|name| name.as_str().to_string())
+            .collect();
+        Some(get_storage_key(storage_field_names, key, experimental))
+    }
 }
 
 impl EqWithEngines for TyStorageField {}
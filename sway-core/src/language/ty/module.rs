@@ -119,6 +119,39 @@ impl TyModule {
         })
     }
 
+    /// All generated entries of parameterized tests within this module, paired with the
+    /// declaration id of the test they call.
+    pub fn fuzz_test_entries<'a: 'b, 'b>(
+        &'b self,
+        decl_engine: &'a DeclEngine,
+    ) -> impl '_ + Iterator<Item = (DeclId<TyFunctionDecl>, Arc<TyFunctionDecl>)> {
+        let fn_decls = self
+            .all_nodes
+            .iter()
+            .filter_map(|node| match &node.content {
+                TyAstNodeContent::Declaration(TyDecl::FunctionDecl(FunctionDecl { decl_id })) => {
+                    Some((*decl_id, decl_engine.get_function(decl_id)))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let fuzz_tests = fn_decls
+            .iter()
+            .filter(|(_, fn_decl)| fn_decl.is_fuzz_test())
+            .map(|(decl_id, fn_decl)| (*decl_id, fn_decl.name.clone()))
+            .collect::<Vec<_>>();
+        fn_decls
+            .into_iter()
+            .filter(|(_, fn_decl)| fn_decl.is_fuzz_test_entry())
+            .filter_map(move |(_, entry)| {
+                let test_name = entry.name.as_str().strip_prefix(FUZZ_TEST_ENTRY_PREFIX)?;
+                fuzz_tests
+                    .iter()
+                    .find(|(_, name)| name.as_str() == test_name)
+                    .map(|(decl_id, _)| (*decl_id, entry))
+            })
+    }
+
     /// All contract functions within this module.
     pub fn contract_fns<'a: 'b, 'b>(
         &'b self,
@@ -435,6 +435,18 @@ impl TyProgram {
             .chain(self.root.test_fns(decl_engine))
     }
 
+    /// All generated entries of parameterized tests, paired with the declaration id of the test
+    /// they call.
+    pub fn fuzz_test_entries<'a: 'b, 'b>(
+        &'b self,
+        decl_engine: &'a DeclEngine,
+    ) -> impl '_ + Iterator<Item = (DeclId<TyFunctionDecl>, Arc<TyFunctionDecl>)> {
+        self.root
+            .submodules_recursive()
+            .flat_map(|(_, submod)| submod.module.fuzz_test_entries(decl_engine))
+            .chain(self.root.fuzz_test_entries(decl_engine))
+    }
+
     pub fn check_deprecated(&self, engines: &Engines, handler: &Handler) {
         let mut allow_deprecated = AllowDeprecatedState::default();
         self.root
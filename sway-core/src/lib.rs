@@ -874,6 +874,7 @@ pub(crate) fn compile_ast_to_ir_to_asm(
     let mut ir = match ir_generation::compile_program(
         program,
         build_config.include_tests,
+        build_config.generate_debug_info,
         engines,
         experimental,
     ) {
@@ -908,11 +909,20 @@ pub(crate) fn compile_ast_to_ir_to_asm(
     register_known_passes(&mut pass_mgr);
     let mut pass_group = PassGroup::default();
 
-    match build_config.optimization_level {
-        OptLevel::Opt1 => {
+    match (&build_config.pass_pipeline, build_config.optimization_level) {
+        (Some(pipeline), _) => match PassGroup::parse_pipeline(pipeline, &pass_mgr) {
+            Ok(pipeline_group) => pass_group.append_group(pipeline_group),
+            Err(ir_error) => {
+                return Err(handler.emit_err(CompileError::InternalOwned(
+                    ir_error.to_string(),
+                    span::Span::dummy(),
+                )));
+            }
+        },
+        (None, OptLevel::Opt1) => {
             pass_group.append_group(create_o1_pass_group());
         }
-        OptLevel::Opt0 => {
+        (None, OptLevel::Opt0) => {
             // We run a function deduplication pass that only removes duplicate
             // functions when everything, including the metadata are identical.
             pass_group.append_pass(FN_DEDUP_DEBUG_PROFILE_NAME);
@@ -1,6 +1,7 @@
 use crate::{
     decl_engine::DeclId,
     language::{ty::TyFunctionDecl, CallPath, Inline, Purity},
+    source_map::DebugType,
 };
 
 use sway_ir::{Context, MetadataIndex, Metadatum, Value};
@@ -139,6 +140,21 @@ impl MetadataManager {
         })
     }
 
+    /// Returns the name and the type of the variable described by the debug information in the
+    /// `md_idx`, if any.
+    pub(crate) fn md_to_debug_var(
+        &mut self,
+        context: &Context,
+        md_idx: Option<MetadataIndex>,
+    ) -> Option<(String, DebugType)> {
+        Self::for_each_md_idx(context, md_idx, |md_idx| {
+            let fields = md_idx.get_content(context).unwrap_struct("debug_var", 2)?;
+            let name = fields[0].unwrap_string()?;
+            let ty = serde_json::from_str(fields[1].unwrap_string()?).ok()?;
+            Some((name.to_owned(), ty))
+        })
+    }
+
     pub(crate) fn md_to_purity(
         &mut self,
         context: &Context,
@@ -324,6 +340,22 @@ impl MetadataManager {
             })
     }
 
+    /// Describes a variable for debuggers. The type is kept as JSON, as it is only read back when
+    /// emitting the debug information.
+    pub(crate) fn debug_var_to_md(
+        &mut self,
+        context: &mut Context,
+        name: &str,
+        ty: &DebugType,
+    ) -> Option<MetadataIndex> {
+        let ty = serde_json::to_string(ty).ok()?;
+        Some(MetadataIndex::new_struct(
+            context,
+            "debug_var",
+            vec![Metadatum::String(name.to_owned()), Metadatum::String(ty)],
+        ))
+    }
+
     pub(crate) fn purity_to_md(
         &mut self,
         context: &mut Context,
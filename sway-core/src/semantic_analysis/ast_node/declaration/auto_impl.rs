@@ -767,6 +767,66 @@ where
         }
     }
 
+    /// Generates the entry of a parameterized (fuzz) test.
+    ///
+    /// The generated function decodes the arguments of the test from the script data and calls
+    /// the test with them. Test runners use it as the actual test entry point and provide the
+    /// encoded arguments as the script data.
+    pub(crate) fn generate_fuzz_test_entry(
+        &mut self,
+        engines: &Engines,
+        decl: &TyFunctionDecl,
+        handler: &Handler,
+    ) -> Result<TyAstNode, ErrorEmitted> {
+        let program_id = decl.span.source_id().map(|sid| sid.program_id());
+
+        let Some(args_types) = decl
+            .parameters
+            .iter()
+            .map(|x| Self::generate_type(engines, &x.type_argument))
+            .collect::<Option<Vec<String>>>()
+        else {
+            let err = handler.emit_err(CompileError::UnknownType {
+                span: Span::dummy(),
+            });
+            return Err(err);
+        };
+        let args_types = itertools::intersperse(args_types, ", ".into()).collect::<String>();
+        let args_types = format!("({args_types},)");
+
+        let expanded_args = itertools::intersperse(
+            decl.parameters
+                .iter()
+                .enumerate()
+                .map(|(i, _)| format!("args.{i}")),
+            ", ".into(),
+        )
+        .collect::<String>();
+
+        let prefix = ty::FUZZ_TEST_ENTRY_PREFIX;
+        let test_name = decl.name.as_str();
+        let code = format!(
+            "pub fn {prefix}{test_name}() {{
+                let args: {args_types} = decode_script_data::<{args_types}>();
+                {test_name}({expanded_args});
+            }}"
+        );
+
+        let entry_fn =
+            self.parse_fn_to_ty_ast_node(engines, program_id, FunctionDeclarationKind::Test, &code);
+
+        match entry_fn {
+            Ok(entry_fn) => Ok(entry_fn),
+            Err(gen_handler) => {
+                Self::check_core_is_missing(handler, &gen_handler);
+                Self::check_impl_is_missing(handler, &gen_handler);
+                Err(gen_handler.emit_err(CompileError::CouldNotGenerateEntry {
+                    span: decl.name.span(),
+                }))
+            }
+        }
+    }
+
     pub(crate) fn generate_script_entry(
         &mut self,
         engines: &Engines,
@@ -131,4 +131,15 @@ impl ty::TyTraitFn {
             kind: ty::TyFunctionDeclKind::Default,
         }
     }
+
+    /// Converts the [ty::TyTraitFn] into the value used as its selector in contract calls,
+    /// as done for the methods implementing it.
+    pub fn to_fn_selector_value(
+        &self,
+        handler: &Handler,
+        engines: &Engines,
+    ) -> Result<[u8; 4], ErrorEmitted> {
+        self.to_dummy_func(AbiMode::NonAbi, None)
+            .to_fn_selector_value(handler, engines)
+    }
 }
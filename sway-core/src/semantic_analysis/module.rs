@@ -457,6 +457,27 @@ impl ty::TyModule {
                 }
                 _ => {}
             }
+
+            // Parameterized tests get their arguments from the test runner, encoded in the
+            // script data, through a generated entry.
+            let fuzz_tests = all_nodes
+                .iter()
+                .filter_map(|x| match &x.content {
+                    ty::TyAstNodeContent::Declaration(ty::TyDecl::FunctionDecl(decl)) => {
+                        let fn_decl = engines.de().get_function(&decl.decl_id);
+                        fn_decl.is_fuzz_test().then_some(fn_decl)
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            for fuzz_test in fuzz_tests {
+                let mut fn_generator = auto_impl::EncodingAutoImplContext::new(&mut ctx).unwrap();
+                if let Ok(node) =
+                    fn_generator.generate_fuzz_test_entry(engines, &fuzz_test, handler)
+                {
+                    all_nodes.push(node)
+                }
+            }
         }
 
         #[allow(clippy::arc_with_non_send_sync)]
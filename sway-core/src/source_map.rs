@@ -24,6 +24,9 @@ pub struct SourceMap {
     /// Mapping from opcode index to source location
     // count of instructions, multiply the opcode by 4 to get the byte offset
     pub map: BTreeMap<usize, SourceMapSpan>,
+    /// The compiled functions, with their arguments and local variables.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub functions: Vec<FunctionDebugInfo>,
 }
 impl SourceMap {
     pub fn new() -> Self {
@@ -42,35 +45,173 @@ impl SourceMap {
     }
 
     pub fn insert(&mut self, source_engine: &SourceEngine, pc: usize, span: &Span) {
-        if let Some(source_id) = span.source_id() {
-            let path = source_engine.get_path(source_id);
-            let path_index = self
-                .paths
-                .iter()
-                .position(|p| *p == *path)
-                .unwrap_or_else(|| {
-                    self.paths.push((*path).to_owned());
-                    self.paths.len() - 1
-                });
-            self.map.insert(
-                pc,
-                SourceMapSpan {
-                    path: PathIndex(path_index),
-                    range: LocationRange {
-                        start: span.start_pos().line_col(),
-                        end: span.end_pos().line_col(),
-                    },
-                },
-            );
+        if let Some(source_map_span) = self.to_source_map_span(source_engine, span) {
+            self.map.insert(pc, source_map_span);
         }
     }
 
+    /// Converts `span` to a [SourceMapSpan], adding its path to the paths of the source map.
+    /// Returns `None` for spans without a source file.
+    pub fn to_source_map_span(
+        &mut self,
+        source_engine: &SourceEngine,
+        span: &Span,
+    ) -> Option<SourceMapSpan> {
+        let source_id = span.source_id()?;
+        let path = source_engine.get_path(source_id);
+        let path_index = self
+            .paths
+            .iter()
+            .position(|p| *p == *path)
+            .unwrap_or_else(|| {
+                self.paths.push((*path).to_owned());
+                self.paths.len() - 1
+            });
+        Some(SourceMapSpan {
+            path: PathIndex(path_index),
+            range: LocationRange {
+                start: span.start_pos().line_col(),
+                end: span.end_pos().line_col(),
+            },
+        })
+    }
+
     /// Inverse source mapping
     pub fn addr_to_span(&self, pc: usize) -> Option<(PathBuf, LocationRange)> {
         self.map
             .get(&pc)
             .map(|sms| sms.to_span(&self.paths, &self.dependency_paths))
     }
+
+    /// The function containing the opcode at `pc`.
+    pub fn addr_to_function(&self, pc: usize) -> Option<&FunctionDebugInfo> {
+        self.functions
+            .iter()
+            .find(|function| function.range.contains(&pc))
+    }
+}
+
+/// Debug information about a compiled function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionDebugInfo {
+    /// The name of the function.
+    pub name: String,
+    /// The opcode indices of the function.
+    pub range: std::ops::Range<usize>,
+    /// The declaration of the function.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decl: Option<SourceMapSpan>,
+    /// The arguments of the function, followed by its local variables, including the ones of the
+    /// functions inlined into it.
+    pub variables: Vec<VariableDebugInfo>,
+    /// The calls inlined into the function.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inlined: Vec<InlinedCallDebugInfo>,
+}
+
+/// Debug information about a call which was inlined into a function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InlinedCallDebugInfo {
+    /// The name of the inlined function.
+    pub name: String,
+    /// The declaration of the inlined function.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decl: Option<SourceMapSpan>,
+    /// The inlined call.
+    pub call: SourceMapSpan,
+    /// The opcode indices of the inlined code. Code inlined into inlined code is attributed to
+    /// the outermost call.
+    pub ranges: Vec<std::ops::Range<usize>>,
+}
+
+/// Debug information about an argument or a local variable of a function.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariableDebugInfo {
+    /// The name of the variable in the source code.
+    pub name: String,
+    /// The type of the variable and its layout in memory.
+    #[serde(rename = "type")]
+    pub ty: DebugType,
+    /// Where the value of the variable is while the function executes.
+    pub location: VariableLocation,
+}
+
+/// Where the value of a variable is while its function executes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VariableLocation {
+    /// In memory, at an offset in bytes from the locals base register of the function.
+    Stack { offset: u64 },
+    /// In a register. Registers are reused, so the value is only reliable until the last use of
+    /// the variable.
+    Register { index: u8 },
+    /// A constant which is not stored anywhere.
+    Constant { value: u64 },
+}
+
+/// A type and its layout in memory.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DebugType {
+    /// The name of the type, as written in the source code.
+    pub name: String,
+    /// The size of the type in bytes.
+    pub size: u64,
+    pub kind: DebugTypeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DebugTypeKind {
+    /// A value which is not shown, e.g. the unit type.
+    Opaque,
+    Bool,
+    /// An unsigned integer, stored in a byte for `u8` and in a word for `u16`, `u32` and `u64`.
+    Uint {
+        bits: u16,
+    },
+    /// A `b256` or an `u256`, stored in 32 bytes.
+    Bytes32,
+    /// A pointer, stored in a word.
+    Pointer,
+    /// A slice, stored as a pointer and a length.
+    Slice,
+    /// A string slice, stored as a pointer to the bytes of the string and its length.
+    StringSlice,
+    /// A string array of the given length.
+    StringArray {
+        len: u64,
+    },
+    Array {
+        elem: Box<DebugType>,
+        len: u64,
+    },
+    /// A struct or a tuple.
+    Struct {
+        fields: Vec<DebugField>,
+    },
+    /// An enum, stored as a tag word followed by the value of the variant.
+    Enum {
+        variants: Vec<DebugField>,
+    },
+    /// A `std::vec::Vec`, stored as a pointer to the elements, the capacity and the length.
+    Vec {
+        elem: Box<DebugType>,
+    },
+    /// A `std::bytes::Bytes` or a `std::string::String`, stored as a pointer to the bytes, the
+    /// capacity and the length.
+    Bytes {
+        utf8: bool,
+    },
+}
+
+/// A field of a struct or a variant of an enum.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DebugField {
+    pub name: String,
+    /// The offset of the field in bytes, from the start of the struct or of the enum.
+    pub offset: u64,
+    #[serde(rename = "type")]
+    pub ty: DebugType,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
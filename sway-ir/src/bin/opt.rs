@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     io::{BufReader, BufWriter, Read, Write},
     process::exit,
 };
@@ -6,8 +7,8 @@ use std::{
 use anyhow::anyhow;
 use sway_features::ExperimentalFeatures;
 use sway_ir::{
-    insert_after_each, register_known_passes, PassGroup, PassManager, MODULE_PRINTER_NAME,
-    MODULE_VERIFIER_NAME,
+    insert_after_each, register_known_passes, PassGroup, PassManager, PrintPassesOpts,
+    MODULE_PRINTER_NAME, MODULE_VERIFIER_NAME,
 };
 use sway_types::SourceEngine;
 
@@ -32,8 +33,8 @@ fn main() -> Result<(), anyhow::Error> {
 
     // Perform optimisation passes in order.
     let mut passes = PassGroup::default();
-    for pass in config.passes {
-        passes.append_pass(pass);
+    for pipeline in config.pipelines {
+        passes.append_group(pipeline);
     }
     if config.print_after_each {
         passes = insert_after_each(passes, MODULE_PRINTER_NAME);
@@ -41,7 +42,19 @@ fn main() -> Result<(), anyhow::Error> {
     if config.verify_after_each {
         passes = insert_after_each(passes, MODULE_VERIFIER_NAME);
     }
-    pass_mgr.run(&mut ir, &passes)?;
+    if config.print_ir_after.is_empty() && config.print_ir_before.is_empty() {
+        pass_mgr.run(&mut ir, &passes)?;
+    } else {
+        let print_opts = PrintPassesOpts {
+            initial: false,
+            r#final: false,
+            modified_only: false,
+            passes: config.print_ir_after,
+            passes_before: config.print_ir_before,
+            diff: config.print_ir_diff,
+        };
+        pass_mgr.run_with_print(&mut ir, &passes, &print_opts)?;
+    }
 
     // Write the output file or standard out.
     write_to_output(ir, &config.output_path)?;
@@ -88,10 +101,13 @@ struct Config {
 
     verify_after_each: bool,
     print_after_each: bool,
+    print_ir_after: HashSet<String>,
+    print_ir_before: HashSet<String>,
+    print_ir_diff: bool,
     _time_passes: bool,
     _stats: bool,
 
-    passes: Vec<&'static str>,
+    pipelines: Vec<PassGroup>,
 }
 
 // This is a little clumsy in that it needs to consume items from the iterator carefully in each
@@ -132,20 +148,32 @@ impl<'a, I: Iterator<Item = String>> ConfigBuilder<'a, I> {
                         self.cfg.print_after_each = true;
                         self.build_root()
                     }
+                    "-print-ir-diff" => {
+                        self.cfg.print_ir_diff = true;
+                        self.build_root()
+                    }
                     "-h" => {
                         print!(
-                            "Usage: opt [passname...] -i input_file -o output_file\n\n{}",
+                            "Usage: opt [pipeline...] -i input_file -o output_file\n\n{}",
                             self.pass_mgr.help_text()
                         );
+                        print!("\n\nA pipeline is a comma separated list of pass names and parenthesized sub-pipelines, e.g., `mem2reg,inline,(ccp,simplify-cfg)*`.");
+                        print!("\nAn element followed by `*` is repeated until it no longer modifies the IR, and by `*N` at most N times.");
+                        print!("\n\nThe IR can be printed around particular passes with -print-ir-before=<pass> and -print-ir-after=<pass>.");
+                        print!("\nWith -print-ir-diff, only the changes made by the pass are printed after it.");
                         print!("\n\nIn the absence of -i or -o options, input is taken from stdin and output is printed to stdout.\n");
                         exit(0);
                     }
 
                     name => {
-                        if matches!(opt.chars().next(), Some('-')) {
+                        if let Some(pass) = opt.strip_prefix("-print-ir-after=") {
+                            self.build_print_ir(pass, true)
+                        } else if let Some(pass) = opt.strip_prefix("-print-ir-before=") {
+                            self.build_print_ir(pass, false)
+                        } else if matches!(opt.chars().next(), Some('-')) {
                             Err(anyhow!("Unrecognised option '{opt}'."))
                         } else {
-                            self.build_pass(name)
+                            self.build_pipeline(name)
                         }
                     }
                 }
@@ -175,16 +203,29 @@ impl<'a, I: Iterator<Item = String>> ConfigBuilder<'a, I> {
         }
     }
 
-    fn build_pass(mut self, name: &str) -> Result<Config, anyhow::Error> {
-        if let Some(pass) = self.pass_mgr.lookup_registered_pass(name) {
-            self.cfg.passes.push(pass.name);
-            self.build_root()
-        } else {
-            Err(anyhow!(
+    fn build_pipeline(mut self, pipeline: &str) -> Result<Config, anyhow::Error> {
+        match PassGroup::parse_pipeline(pipeline, self.pass_mgr) {
+            Ok(group) => {
+                self.cfg.pipelines.push(group);
+                self.build_root()
+            }
+            Err(err) => Err(anyhow!("{err}\n\n{}", self.pass_mgr.help_text())),
+        }
+    }
+
+    fn build_print_ir(mut self, name: &str, after: bool) -> Result<Config, anyhow::Error> {
+        if self.pass_mgr.lookup_registered_pass(name).is_none() {
+            return Err(anyhow!(
                 "Unrecognised pass name '{name}'.\n\n{}",
                 self.pass_mgr.help_text()
-            ))
+            ));
+        }
+        if after {
+            self.cfg.print_ir_after.insert(name.to_owned());
+        } else {
+            self.cfg.print_ir_before.insert(name.to_owned());
         }
+        self.build_root()
     }
 }
 
@@ -42,6 +42,10 @@ pub struct Context<'eng> {
     next_unique_sym_tag: u64,
 
     pub experimental: ExperimentalFeatures,
+
+    /// Whether the compiler generates the debug info describing the variables of the program and
+    /// their types, which is expensive and only needed by debuggers.
+    pub generate_debug_info: bool,
 }
 
 impl<'eng> Context<'eng> {
@@ -59,6 +63,7 @@ impl<'eng> Context<'eng> {
             next_unique_sym_tag: Default::default(),
             program_kind: Kind::Contract,
             experimental,
+            generate_debug_info: false,
         };
         Type::create_basic_types(&mut def);
         def
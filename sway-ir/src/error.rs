@@ -15,6 +15,7 @@ pub enum IrError {
     RemoveMissingBlock(String),
     ValueNotFound(String),
     InconsistentParent(String, String, String),
+    InvalidPassPipeline(String, String),
 
     VerifyArgumentValueIsNotArgument(String),
     VerifyUnaryOpIncorrectArgType,
@@ -126,6 +127,9 @@ impl fmt::Display for IrError {
                     but found {found_parent}."
                 )
             }
+            IrError::InvalidPassPipeline(pipeline, reason) => {
+                write!(f, "Invalid pass pipeline '{pipeline}': {reason}.")
+            }
             IrError::VerifyArgumentValueIsNotArgument(callee) => write!(
                 f,
                 "Verification failed: Argument specifier for function '{callee}' is not an \
@@ -48,9 +48,23 @@ pub struct FunctionContent {
 
     pub local_storage: BTreeMap<String, LocalVar>, // BTree rather than Hash for deterministic ordering.
 
+    /// Calls which were inlined into this function, kept for debug information.
+    pub inlined_calls: Vec<InlinedCall>,
+
     next_label_idx: u64,
 }
 
+/// A call which was inlined into a function.
+#[derive(Clone, Debug)]
+pub struct InlinedCall {
+    /// The name of the inlined function.
+    pub callee: String,
+    /// The metadata of the inlined function, e.g. its span.
+    pub callee_metadata: Option<MetadataIndex>,
+    /// The metadata of the call, e.g. its span.
+    pub call_metadata: Option<MetadataIndex>,
+}
+
 impl Function {
     /// Return a new [`Function`] handle.
     ///
@@ -88,6 +102,7 @@ impl Function {
             selector,
             metadata,
             local_storage: BTreeMap::new(),
+            inlined_calls: Vec::new(),
             next_label_idx: 0,
         };
         let func = Function(context.functions.insert(content));
@@ -307,6 +322,16 @@ impl Function {
         context.functions[self.0].metadata
     }
 
+    /// Return the calls which were inlined into this function.
+    pub fn get_inlined_calls<'a>(&self, context: &'a Context) -> &'a [InlinedCall] {
+        &context.functions[self.0].inlined_calls
+    }
+
+    /// Remember a call which was inlined into this function.
+    pub fn add_inlined_call(&self, context: &mut Context, inlined_call: InlinedCall) {
+        context.functions[self.0].inlined_calls.push(inlined_call);
+    }
+
     /// Whether this function has a valid selector.
     pub fn has_selector(&self, context: &Context) -> bool {
         context.functions[self.0].selector.is_some()
@@ -500,6 +525,7 @@ impl Function {
                 old_var_content.initializer,
                 old_var_content.mutable,
             );
+            new_var.set_metadata(context, old_var_content.metadata);
             var_map.insert(old_var, new_var);
         }
         var_map
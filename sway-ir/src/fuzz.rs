@@ -0,0 +1,477 @@
+//! A generator of random, well-typed IR [`Module`]s.
+//!
+//! The generated modules are intended to be used as inputs when fuzzing the optimization passes,
+//! the verifier and the printer/parser round trip.  Every module produced here must pass
+//! [`Context::verify`], so any verification failure after running a pass over it points to a bug
+//! in that pass.
+//!
+//! Generation is fully deterministic for a given seed and [`RandomModuleConfig`], which allows a
+//! failing module to be reproduced from nothing but its seed.
+//!
+//! The generated control flow graphs are acyclic: each non-entry block is reachable from its
+//! immediate predecessor in creation order and may also be reached by a conditional branch from
+//! the block two places above it.  Values flow between blocks via block arguments, and only the
+//! values defined in the entry block (which dominates every other block) are otherwise shared.
+//! Every local is initialized, either by an initializer or by stores at the start of the entry
+//! block, before it may be loaded from.
+
+use crate::{
+    BinaryOpKind, Block, Constant, Context, Function, Kind, LocalVar, Module, Predicate, Type,
+    Value,
+};
+
+/// Limits used when generating a random [`Module`].
+#[derive(Clone, Debug)]
+pub struct RandomModuleConfig {
+    /// The maximum number of functions, including the entry function.
+    pub max_functions: usize,
+    /// The maximum number of arguments to a function.
+    pub max_fn_args: usize,
+    /// The maximum number of blocks in a function, including the entry block.
+    pub max_blocks: usize,
+    /// The maximum number of arguments to a non-entry block.
+    pub max_block_args: usize,
+    /// The maximum number of non-terminator instructions in a block.
+    pub max_instrs_per_block: usize,
+    /// The maximum number of local variables in a function.
+    pub max_locals: usize,
+}
+
+impl Default for RandomModuleConfig {
+    fn default() -> Self {
+        RandomModuleConfig {
+            max_functions: 4,
+            max_fn_args: 3,
+            max_blocks: 6,
+            max_block_args: 2,
+            max_instrs_per_block: 12,
+            max_locals: 4,
+        }
+    }
+}
+
+/// Generate a new random script [`Module`] in `context`, determined entirely by `seed` and
+/// `config`.
+///
+/// The module contains an entry function `main` and a number of helper functions which may call
+/// each other.  Calls are only ever made to previously generated functions so there is no
+/// recursion.
+pub fn generate_random_module(
+    context: &mut Context,
+    seed: u64,
+    config: &RandomModuleConfig,
+) -> Module {
+    let module = Module::new(context, Kind::Script);
+    let mut gen = ModuleGenerator {
+        rng: SplitMix64::new(seed),
+        config,
+        module,
+        functions: Vec::new(),
+    };
+
+    let num_helpers = gen.rng.below(config.max_functions.max(1));
+    for idx in 0..num_helpers {
+        let name = format!("fn{idx}");
+        gen.generate_function(context, name, false);
+    }
+    gen.generate_function(context, "main".to_owned(), true);
+
+    module
+}
+
+// -------------------------------------------------------------------------------------------------
+
+// A tiny and fast PRNG.  We deliberately avoid external crates here so that the sequence for a
+// given seed never changes due to a dependency update, which would invalidate recorded seeds.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // A value in `0..n`, or 0 if `n` is 0.
+    fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            0
+        } else {
+            (self.next_u64() % n as u64) as usize
+        }
+    }
+
+    // True with a probability of roughly `1 / n`.
+    fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        (!items.is_empty()).then(|| &items[self.below(items.len())])
+    }
+
+    // Small constants are much more interesting to the optimizer than huge ones, as they are more
+    // likely to collide and be folded.
+    fn interesting_u64(&mut self) -> u64 {
+        match self.below(4) {
+            0 => self.below(4) as u64,
+            1 => self.below(256) as u64,
+            2 => u64::MAX - self.below(4) as u64,
+            _ => self.next_u64(),
+        }
+    }
+}
+
+struct ModuleGenerator<'a> {
+    rng: SplitMix64,
+    config: &'a RandomModuleConfig,
+    module: Module,
+    functions: Vec<Function>,
+}
+
+impl ModuleGenerator<'_> {
+    fn random_scalar_type(&mut self, context: &Context) -> Type {
+        if self.rng.one_in(3) {
+            Type::get_bool(context)
+        } else {
+            Type::get_uint64(context)
+        }
+    }
+
+    fn random_local_type(&mut self, context: &mut Context) -> Type {
+        let u64_ty = Type::get_uint64(context);
+        let bool_ty = Type::get_bool(context);
+        match self.rng.below(4) {
+            0 => u64_ty,
+            1 => bool_ty,
+            2 => Type::new_struct(context, vec![u64_ty, bool_ty, u64_ty]),
+            _ => {
+                let len = 1 + self.rng.below(4) as u64;
+                Type::new_array(context, u64_ty, len)
+            }
+        }
+    }
+
+    fn random_constant(&mut self, context: &mut Context, ty: Type) -> Value {
+        if ty.is_bool(context) {
+            let b = self.rng.one_in(2);
+            Constant::get_bool(context, b)
+        } else {
+            let n = self.rng.interesting_u64();
+            Constant::get_uint(context, 64, n)
+        }
+    }
+
+    fn generate_function(&mut self, context: &mut Context, name: String, is_entry: bool) {
+        let args = if is_entry {
+            Vec::new()
+        } else {
+            (0..self.rng.below(self.config.max_fn_args + 1))
+                .map(|idx| (format!("a{idx}"), self.random_scalar_type(context), None))
+                .collect()
+        };
+        let return_type = self.random_scalar_type(context);
+        let function = Function::new(
+            context,
+            self.module,
+            name,
+            args,
+            return_type,
+            None,
+            false,
+            is_entry,
+            is_entry,
+            false,
+            None,
+        );
+
+        let locals = (0..self.rng.below(self.config.max_locals + 1))
+            .map(|idx| {
+                let ty = self.random_local_type(context);
+                let initializer = (ty.is_uint64(context) && self.rng.one_in(2)).then(|| {
+                    let n = self.rng.interesting_u64();
+                    Constant::new_uint(context, 64, n)
+                });
+                function.new_local_var(context, format!("l{idx}"), ty, initializer, true)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Local names are unique.");
+
+        // Create all the blocks up front, with their arguments, so they may be branched to.
+        let num_blocks = 1 + self.rng.below(self.config.max_blocks.max(1));
+        let mut blocks = vec![function.get_entry_block(context)];
+        for idx in 1..num_blocks {
+            let block = function.create_block(context, Some(format!("block{idx}")));
+            for _ in 0..self.rng.below(self.config.max_block_args + 1) {
+                let ty = self.random_scalar_type(context);
+                block.new_arg(context, ty);
+            }
+            blocks.push(block);
+        }
+
+        let mut entry_pool = Vec::new();
+        for (idx, block) in blocks.iter().enumerate() {
+            let mut pool = BlockGenerator {
+                block: *block,
+                locals: &locals,
+                values: entry_pool.clone(),
+            };
+            pool.values.extend(block.arg_iter(context).copied());
+            if idx == 0 {
+                pool.values
+                    .extend(function.args_iter(context).map(|(_, val)| *val));
+                // Locals are always written before they are read, as a load of an uninitialized
+                // local is undefined.
+                for local in &locals {
+                    self.initialize_local(context, *block, *local);
+                }
+            }
+
+            for _ in 0..self.rng.below(self.config.max_instrs_per_block + 1) {
+                self.generate_instruction(context, &mut pool);
+            }
+
+            // Terminate the block: return from the last one, otherwise fall through to the next
+            // block, possibly skipping over it with a conditional branch.
+            if idx + 1 == blocks.len() {
+                let ret_val = self.pick_value(context, &mut pool, return_type);
+                block.append(context).ret(ret_val, return_type);
+            } else if idx + 2 < blocks.len() && self.rng.one_in(2) {
+                let bool_ty = Type::get_bool(context);
+                let cond = self.pick_value(context, &mut pool, bool_ty);
+                let true_args = self.dest_args(context, &mut pool, blocks[idx + 1]);
+                let false_args = self.dest_args(context, &mut pool, blocks[idx + 2]);
+                block.append(context).conditional_branch(
+                    cond,
+                    blocks[idx + 1],
+                    blocks[idx + 2],
+                    true_args,
+                    false_args,
+                );
+            } else {
+                let args = self.dest_args(context, &mut pool, blocks[idx + 1]);
+                block.append(context).branch(blocks[idx + 1], args);
+            }
+
+            if idx == 0 {
+                // The entry block dominates every other block, so its values are usable anywhere.
+                entry_pool = pool.values;
+            }
+        }
+
+        self.functions.push(function);
+    }
+
+    // Store a constant to every scalar within `local`, unless it already has an initializer.
+    fn initialize_local(&mut self, context: &mut Context, block: Block, local: LocalVar) {
+        if local.get_initializer(context).is_some() {
+            return;
+        }
+        let local_ty = local.get_inner_type(context);
+        let local_ptr = block.append(context).get_local(local);
+        let elems = if local_ty.is_struct(context) {
+            local_ty
+                .get_field_types(context)
+                .into_iter()
+                .enumerate()
+                .map(|(idx, ty)| (Some(idx as u64), ty))
+                .collect::<Vec<_>>()
+        } else if local_ty.is_array(context) {
+            let elem_ty = local_ty.get_array_elem_type(context).unwrap();
+            let len = local_ty.get_array_len(context).unwrap();
+            (0..len).map(|idx| (Some(idx), elem_ty)).collect()
+        } else {
+            vec![(None, local_ty)]
+        };
+        for (idx, ty) in elems {
+            let ptr = match idx {
+                Some(idx) => block
+                    .append(context)
+                    .get_elem_ptr_with_idx(local_ptr, ty, idx),
+                None => local_ptr,
+            };
+            let val = self.random_constant(context, ty);
+            block.append(context).store(ptr, val);
+        }
+    }
+
+    fn dest_args(
+        &mut self,
+        context: &mut Context,
+        pool: &mut BlockGenerator,
+        dest: Block,
+    ) -> Vec<Value> {
+        let arg_tys = dest
+            .arg_iter(context)
+            .map(|arg| arg.get_type(context).unwrap())
+            .collect::<Vec<_>>();
+        arg_tys
+            .into_iter()
+            .map(|ty| self.pick_value(context, pool, ty))
+            .collect()
+    }
+
+    // Pick an existing value of type `ty`, or occasionally (or if there is none) a new constant.
+    fn pick_value(&mut self, context: &mut Context, pool: &mut BlockGenerator, ty: Type) -> Value {
+        let candidates = pool
+            .values
+            .iter()
+            .filter(|val| {
+                val.get_type(context)
+                    .is_some_and(|val_ty| val_ty.eq(context, &ty))
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        match self.rng.choose(&candidates) {
+            Some(val) if !self.rng.one_in(4) => *val,
+            _ => self.random_constant(context, ty),
+        }
+    }
+
+    // Get a pointer to a random scalar within a random local, if there are any locals.
+    fn pick_scalar_ptr(
+        &mut self,
+        context: &mut Context,
+        pool: &mut BlockGenerator,
+    ) -> Option<Value> {
+        let local = *self.rng.choose(pool.locals)?;
+        let local_ty = local.get_inner_type(context);
+        let local_ptr = pool.block.append(context).get_local(local);
+        if local_ty.is_struct(context) {
+            let field_tys = local_ty.get_field_types(context);
+            let field_idx = self.rng.below(field_tys.len());
+            Some(pool.block.append(context).get_elem_ptr_with_idx(
+                local_ptr,
+                field_tys[field_idx],
+                field_idx as u64,
+            ))
+        } else if local_ty.is_array(context) {
+            let elem_ty = local_ty.get_array_elem_type(context).unwrap();
+            let len = local_ty.get_array_len(context).unwrap();
+            let elem_idx = self.rng.below(len as usize) as u64;
+            Some(
+                pool.block
+                    .append(context)
+                    .get_elem_ptr_with_idx(local_ptr, elem_ty, elem_idx),
+            )
+        } else {
+            Some(local_ptr)
+        }
+    }
+
+    fn generate_instruction(&mut self, context: &mut Context, pool: &mut BlockGenerator) {
+        let u64_ty = Type::get_uint64(context);
+        let bool_ty = Type::get_bool(context);
+        let block = pool.block;
+
+        let new_val = match self.rng.below(8) {
+            0 | 1 => {
+                let op = *self
+                    .rng
+                    .choose(&[
+                        BinaryOpKind::Add,
+                        BinaryOpKind::Sub,
+                        BinaryOpKind::Mul,
+                        BinaryOpKind::Div,
+                        BinaryOpKind::Mod,
+                        BinaryOpKind::And,
+                        BinaryOpKind::Or,
+                        BinaryOpKind::Xor,
+                        BinaryOpKind::Lsh,
+                        BinaryOpKind::Rsh,
+                    ])
+                    .unwrap();
+                let lhs = self.pick_value(context, pool, u64_ty);
+                let rhs = self.pick_value(context, pool, u64_ty);
+                Some(block.append(context).binary_op(op, lhs, rhs))
+            }
+            2 => {
+                let pred = *self
+                    .rng
+                    .choose(&[
+                        Predicate::Equal,
+                        Predicate::LessThan,
+                        Predicate::GreaterThan,
+                    ])
+                    .unwrap();
+                let lhs = self.pick_value(context, pool, u64_ty);
+                let rhs = self.pick_value(context, pool, u64_ty);
+                Some(block.append(context).cmp(pred, lhs, rhs))
+            }
+            3 => self.pick_scalar_ptr(context, pool).map(|ptr| {
+                let val_ty = ptr.match_ptr_type(context).unwrap();
+                let val = self.pick_value(context, pool, val_ty);
+                block.append(context).store(ptr, val)
+            }),
+            4 => self
+                .pick_scalar_ptr(context, pool)
+                .map(|ptr| block.append(context).load(ptr)),
+            5 => {
+                // Copy between two locals of the same type, either as a typed value or as bytes.
+                let src = self.rng.choose(pool.locals).copied();
+                src.and_then(|src| {
+                    let src_ty = src.get_inner_type(context);
+                    let dsts = pool
+                        .locals
+                        .iter()
+                        .filter(|dst| dst.get_inner_type(context).eq(context, &src_ty))
+                        .copied()
+                        .collect::<Vec<_>>();
+                    let dst = *self.rng.choose(&dsts)?;
+                    let src_ptr = block.append(context).get_local(src);
+                    let dst_ptr = block.append(context).get_local(dst);
+                    Some(if self.rng.one_in(2) {
+                        block.append(context).mem_copy_val(dst_ptr, src_ptr)
+                    } else {
+                        let byte_len = src_ty.size(context).in_bytes();
+                        block
+                            .append(context)
+                            .mem_copy_bytes(dst_ptr, src_ptr, byte_len)
+                    })
+                })
+            }
+            6 => {
+                let callee = self.rng.choose(&self.functions).copied();
+                callee.map(|callee| {
+                    let arg_tys = callee
+                        .args_iter(context)
+                        .map(|(_, arg)| arg.get_type(context).unwrap())
+                        .collect::<Vec<_>>();
+                    let args = arg_tys
+                        .into_iter()
+                        .map(|ty| self.pick_value(context, pool, ty))
+                        .collect::<Vec<_>>();
+                    block.append(context).call(callee, &args)
+                })
+            }
+            _ => {
+                let lhs = self.pick_value(context, pool, bool_ty);
+                let rhs = self.pick_value(context, pool, bool_ty);
+                Some(block.append(context).cmp(Predicate::Equal, lhs, rhs))
+            }
+        };
+
+        // Only keep values which can be used as operands later.
+        if let Some(val) = new_val {
+            if val
+                .get_type(context)
+                .is_some_and(|ty| ty.is_uint64(context) || ty.is_bool(context))
+            {
+                pool.values.push(val);
+            }
+        }
+    }
+}
+
+// The state of the block currently being generated.
+struct BlockGenerator<'a> {
+    block: Block,
+    locals: &'a [LocalVar],
+    values: Vec<Value>,
+}
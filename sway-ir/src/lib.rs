@@ -51,6 +51,8 @@ pub mod error;
 pub use error::*;
 pub mod function;
 pub use function::*;
+pub mod fuzz;
+pub use fuzz::*;
 pub mod instruction;
 pub use instruction::*;
 pub mod irtype;
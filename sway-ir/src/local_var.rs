@@ -4,6 +4,7 @@ use crate::{
     constant::Constant,
     context::Context,
     irtype::{Type, TypeContent},
+    metadata::MetadataIndex,
     pretty::DebugWithContext,
 };
 
@@ -18,6 +19,7 @@ pub struct LocalVarContent {
     pub ptr_ty: Type,
     pub initializer: Option<Constant>,
     pub mutable: bool,
+    pub metadata: Option<MetadataIndex>,
 }
 
 impl LocalVar {
@@ -35,6 +37,7 @@ impl LocalVar {
             ptr_ty,
             initializer,
             mutable,
+            metadata: None,
         };
         LocalVar(context.local_vars.insert(content))
     }
@@ -66,4 +69,14 @@ impl LocalVar {
     pub fn set_mutable(&self, context: &mut Context, mutable: bool) {
         context.local_vars[self.0].mutable = mutable;
     }
+
+    /// Return the metadata attached to this local variable, e.g. its debug information.
+    pub fn get_metadata(&self, context: &Context) -> Option<MetadataIndex> {
+        context.local_vars[self.0].metadata
+    }
+
+    /// Attach metadata to this local variable.
+    pub fn set_metadata(&self, context: &mut Context, metadata: Option<MetadataIndex>) {
+        context.local_vars[self.0].metadata = metadata;
+    }
 }
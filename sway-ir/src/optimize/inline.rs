@@ -12,7 +12,7 @@ use crate::{
     call_graph,
     context::Context,
     error::IrError,
-    function::Function,
+    function::{Function, InlinedCall},
     instruction::{FuelVmInstruction, InstOp},
     irtype::Type,
     local_var::LocalVar,
@@ -331,6 +331,15 @@ pub fn inline_function_call(
     // inlined instructions.
     let metadata = context.values[call_site.0].metadata;
 
+    function.add_inlined_call(
+        context,
+        InlinedCall {
+            callee: inlined_function.get_name(context).to_owned(),
+            callee_metadata: inlined_function.get_metadata(context),
+            call_metadata: metadata,
+        },
+    );
+
     // Now remove the call altogether.
     context.values.remove(call_site.0);
 
@@ -368,7 +368,12 @@ fn local_copy_prop(
             get_gep_symbol(context, dst_val_ptr),
             get_gep_symbol(context, src_val_ptr),
         ) {
-            if escaped_symbols.contains(&dst_sym) || escaped_symbols.contains(&src_sym) {
+            // A copy within a symbol has nothing to propagate, and replacing loads from it with
+            // loads from the same symbol would never stop.
+            if dst_sym == src_sym
+                || escaped_symbols.contains(&dst_sym)
+                || escaped_symbols.contains(&src_sym)
+            {
                 return;
             }
             dest_to_copies
@@ -154,7 +154,12 @@ pub struct PrintPassesOpts {
     pub initial: bool,
     pub r#final: bool,
     pub modified_only: bool,
+    /// Passes after which the IR is printed.
     pub passes: HashSet<String>,
+    /// Passes before which the IR is printed.
+    pub passes_before: HashSet<String>,
+    /// Print the difference made by a pass instead of the whole IR after it.
+    pub diff: bool,
 }
 
 #[derive(Default)]
@@ -267,11 +272,7 @@ impl PassManager {
 
     /// Run the `passes` and return true if the `passes` modify the initial `ir`.
     pub fn run(&mut self, ir: &mut Context, passes: &PassGroup) -> Result<bool, IrError> {
-        let mut modified = false;
-        for pass in passes.flatten_pass_group() {
-            modified |= self.actually_run(ir, pass)?;
-        }
-        Ok(modified)
+        self.run_group(ir, passes, None)
     }
 
     /// Run the `passes` and return true if the `passes` modify the initial `ir`.
@@ -282,45 +283,74 @@ impl PassManager {
         passes: &PassGroup,
         print_opts: &PrintPassesOpts,
     ) -> Result<bool, IrError> {
-        // Empty IRs are result of compiling dependencies. We don't want to print those.
-        fn ir_is_empty(ir: &Context) -> bool {
-            ir.functions.is_empty()
-                && ir.blocks.is_empty()
-                && ir.values.is_empty()
-                && ir.local_vars.is_empty()
+        if print_opts.initial {
+            print_ir(ir, "// IR: Initial");
         }
 
-        fn print_ir_after_pass(ir: &Context, pass: &Pass) {
-            if !ir_is_empty(ir) {
-                println!("// IR: [{}] {}", pass.name, pass.descr);
-                println!("{ir}");
-            }
-        }
+        let modified = self.run_group(ir, passes, Some(print_opts))?;
 
-        fn print_initial_or_final_ir(ir: &Context, initial_or_final: &'static str) {
-            if !ir_is_empty(ir) {
-                println!("// IR: {initial_or_final}");
-                println!("{ir}");
-            }
+        if print_opts.r#final {
+            print_ir(ir, "// IR: Final");
         }
 
-        if print_opts.initial {
-            print_initial_or_final_ir(ir, "Initial");
-        }
+        Ok(modified)
+    }
 
+    fn run_group(
+        &mut self,
+        ir: &mut Context,
+        passes: &PassGroup,
+        print_opts: Option<&PrintPassesOpts>,
+    ) -> Result<bool, IrError> {
         let mut modified = false;
-        for pass in passes.flatten_pass_group() {
-            let modified_in_pass = self.actually_run(ir, pass)?;
+        for pass_or_group in &passes.0 {
+            modified |= match pass_or_group {
+                PassOrGroup::Pass(pass) => self.run_pass(ir, pass, print_opts)?,
+                PassOrGroup::Group(group) => self.run_group(ir, group, print_opts)?,
+                PassOrGroup::Repeat(group, max_iterations) => {
+                    let mut modified_in_repeat = false;
+                    for _ in 0..*max_iterations {
+                        if !self.run_group(ir, group, print_opts)? {
+                            break;
+                        }
+                        modified_in_repeat = true;
+                    }
+                    modified_in_repeat
+                }
+            };
+        }
+        Ok(modified)
+    }
 
-            if print_opts.passes.contains(pass) && (!print_opts.modified_only || modified_in_pass) {
-                print_ir_after_pass(ir, self.lookup_registered_pass(pass).unwrap());
-            }
+    fn run_pass(
+        &mut self,
+        ir: &mut Context,
+        pass: &'static str,
+        print_opts: Option<&PrintPassesOpts>,
+    ) -> Result<bool, IrError> {
+        let Some(print_opts) = print_opts else {
+            return self.actually_run(ir, pass);
+        };
+
+        let pass_t = self
+            .lookup_registered_pass(pass)
+            .expect("Unregistered pass");
+        let header = format!("[{}] {}", pass_t.name, pass_t.descr);
 
-            modified |= modified_in_pass;
+        if print_opts.passes_before.contains(pass) {
+            print_ir(ir, &format!("// IR: Before {header}"));
         }
 
-        if print_opts.r#final {
-            print_initial_or_final_ir(ir, "Final");
+        let print_after = print_opts.passes.contains(pass);
+        let ir_before = (print_after && print_opts.diff).then(|| ir.to_string());
+
+        let modified = self.actually_run(ir, pass)?;
+
+        if print_after && (!print_opts.modified_only || modified) {
+            match ir_before {
+                Some(ir_before) => print_ir_diff(ir, &ir_before, &format!("// IR: Diff {header}")),
+                None => print_ir(ir, &format!("// IR: {header}")),
+            }
         }
 
         Ok(modified)
@@ -331,6 +361,13 @@ impl PassManager {
         self.passes.get(name)
     }
 
+    /// Get the names of all the registered passes, in alphabetical order.
+    pub fn registered_pass_names(&self) -> Vec<&'static str> {
+        let mut names = self.passes.keys().copied().collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+
     pub fn help_text(&self) -> String {
         let summary = self
             .passes
@@ -343,6 +380,28 @@ impl PassManager {
     }
 }
 
+// Empty IRs are result of compiling dependencies. We don't want to print those.
+fn ir_is_empty(ir: &Context) -> bool {
+    ir.functions.is_empty()
+        && ir.blocks.is_empty()
+        && ir.values.is_empty()
+        && ir.local_vars.is_empty()
+}
+
+fn print_ir(ir: &Context, header: &str) {
+    if !ir_is_empty(ir) {
+        println!("{header}");
+        println!("{ir}");
+    }
+}
+
+fn print_ir_diff(ir: &Context, ir_before: &str, header: &str) {
+    if !ir_is_empty(ir) {
+        println!("{header}");
+        println!("{}", prettydiff::diff_lines(ir_before, &ir.to_string()));
+    }
+}
+
 /// A group of passes.
 /// Can contain sub-groups.
 #[derive(Default)]
@@ -352,23 +411,15 @@ pub struct PassGroup(Vec<PassOrGroup>);
 pub enum PassOrGroup {
     Pass(&'static str),
     Group(PassGroup),
+    /// A group which is run repeatedly until it no longer modifies the IR,
+    /// but at most the given number of times.
+    Repeat(PassGroup, usize),
 }
 
 impl PassGroup {
-    // Flatten a group of passes into an ordered list.
-    fn flatten_pass_group(&self) -> Vec<&'static str> {
-        let mut output = Vec::<&str>::new();
-        fn inner(output: &mut Vec<&str>, input: &PassGroup) {
-            for pass_or_group in &input.0 {
-                match pass_or_group {
-                    PassOrGroup::Pass(pass) => output.push(pass),
-                    PassOrGroup::Group(pg) => inner(output, pg),
-                }
-            }
-        }
-        inner(&mut output, self);
-        output
-    }
+    /// The maximum number of times a repeated group is run if the
+    /// pipeline specification doesn't provide an explicit limit.
+    pub const DEFAULT_MAX_REPETITIONS: usize = 8;
 
     /// Append a pass to this group.
     pub fn append_pass(&mut self, pass: &'static str) {
@@ -379,6 +430,145 @@ impl PassGroup {
     pub fn append_group(&mut self, group: PassGroup) {
         self.0.push(PassOrGroup::Group(group));
     }
+
+    /// Append a pass group which is run until it no longer modifies the IR,
+    /// but at most `max_iterations` times.
+    pub fn append_repeated_group(&mut self, group: PassGroup, max_iterations: usize) {
+        self.0.push(PassOrGroup::Repeat(group, max_iterations));
+    }
+
+    /// Parse a textual pass pipeline specification into a [PassGroup].
+    ///
+    /// A pipeline is a comma separated list of registered pass names and parenthesized
+    /// sub-pipelines. Any element can be followed by `*` to run it repeatedly until it
+    /// no longer modifies the IR (at most [Self::DEFAULT_MAX_REPETITIONS] times), or
+    /// by `*N` to run it at most `N` times.
+    ///
+    /// E.g., `mem2reg,inline,(ccp,simplify-cfg)*`.
+    pub fn parse_pipeline(pipeline: &str, pass_mgr: &PassManager) -> Result<PassGroup, IrError> {
+        let mut parser = PipelineParser {
+            input: pipeline,
+            pos: 0,
+            pass_mgr,
+        };
+        let group = parser.parse_sequence()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(group),
+            Some(')') => Err(parser.error("unmatched ')'")),
+            Some(ch) => Err(parser.error(&format!("unexpected '{ch}'"))),
+        }
+    }
+}
+
+struct PipelineParser<'a> {
+    input: &'a str,
+    pos: usize,
+    pass_mgr: &'a PassManager,
+}
+
+impl PipelineParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(ch) = self.peek().filter(|ch| ch.is_whitespace()) {
+            self.pos += ch.len_utf8();
+        }
+    }
+
+    fn error(&self, reason: &str) -> IrError {
+        IrError::InvalidPassPipeline(
+            self.input.to_string(),
+            format!("{reason} at offset {}", self.pos),
+        )
+    }
+
+    // sequence := element (',' element)*
+    fn parse_sequence(&mut self) -> Result<PassGroup, IrError> {
+        let mut group = PassGroup::default();
+        loop {
+            self.parse_element(&mut group)?;
+            self.skip_whitespace();
+            if self.peek() == Some(',') {
+                self.pos += 1;
+            } else {
+                return Ok(group);
+            }
+        }
+    }
+
+    // element := (pass-name | '(' sequence ')') ('*' number?)?
+    fn parse_element(&mut self, group: &mut PassGroup) -> Result<(), IrError> {
+        self.skip_whitespace();
+        let element = match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let sub_group = self.parse_sequence()?;
+                self.skip_whitespace();
+                if self.peek() != Some(')') {
+                    return Err(self.error("expected ')'"));
+                }
+                self.pos += 1;
+                PassOrGroup::Group(sub_group)
+            }
+            _ => PassOrGroup::Pass(self.parse_pass_name()?),
+        };
+
+        self.skip_whitespace();
+        if self.peek() == Some('*') {
+            self.pos += 1;
+            let max_iterations = self.parse_repetitions()?;
+            let repeated = match element {
+                PassOrGroup::Group(sub_group) => sub_group,
+                pass => PassGroup(vec![pass]),
+            };
+            group.append_repeated_group(repeated, max_iterations);
+        } else {
+            group.0.push(element);
+        }
+        Ok(())
+    }
+
+    fn parse_pass_name(&mut self) -> Result<&'static str, IrError> {
+        let (input, pass_mgr) = (self.input, self.pass_mgr);
+        let start = self.pos;
+        while let Some(ch) = self
+            .peek()
+            .filter(|ch| ch.is_ascii_alphanumeric() || *ch == '-' || *ch == '_')
+        {
+            self.pos += ch.len_utf8();
+        }
+        let name = &input[start..self.pos];
+        if name.is_empty() {
+            return Err(self.error("expected a pass name or '('"));
+        }
+        match pass_mgr.lookup_registered_pass(name) {
+            Some(pass) => Ok(pass.name),
+            None => {
+                self.pos = start;
+                Err(self.error(&format!("unknown pass '{name}'")))
+            }
+        }
+    }
+
+    fn parse_repetitions(&mut self) -> Result<usize, IrError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|ch| ch.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        match &self.input[start..self.pos] {
+            "" => Ok(PassGroup::DEFAULT_MAX_REPETITIONS),
+            digits => match digits.parse() {
+                Ok(0) | Err(_) => {
+                    self.pos = start;
+                    Err(self.error("the number of repetitions must be a positive integer"))
+                }
+                Ok(n) => Ok(n),
+            },
+        }
+    }
 }
 
 /// A convenience utility to register known passes.
@@ -444,6 +634,10 @@ pub fn insert_after_each(pg: PassGroup, pass: &'static str) -> PassGroup {
                 PassOrGroup::Group(group) => vec![PassOrGroup::Group(PassGroup(
                     insert_after_each_rec(group, pass),
                 ))],
+                PassOrGroup::Repeat(group, max_iterations) => vec![PassOrGroup::Repeat(
+                    PassGroup(insert_after_each_rec(group, pass)),
+                    max_iterations,
+                )],
                 PassOrGroup::Pass(_) => vec![p_o_g, PassOrGroup::Pass(pass)],
             })
             .collect()
@@ -451,3 +645,80 @@ pub fn insert_after_each(pg: PassGroup, pass: &'static str) -> PassGroup {
 
     PassGroup(insert_after_each_rec(pg, pass))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MODULE_VERIFIER_NAME;
+
+    fn parse(pipeline: &str) -> Result<PassGroup, IrError> {
+        let mut pass_mgr = PassManager::default();
+        register_known_passes(&mut pass_mgr);
+        PassGroup::parse_pipeline(pipeline, &pass_mgr)
+    }
+
+    // Render a parsed group back into the pipeline syntax, with explicit repetition counts.
+    fn render(group: &PassGroup) -> String {
+        group
+            .0
+            .iter()
+            .map(|pass_or_group| match pass_or_group {
+                PassOrGroup::Pass(pass) => pass.to_string(),
+                PassOrGroup::Group(group) => format!("({})", render(group)),
+                PassOrGroup::Repeat(group, max_iterations) => {
+                    format!("({})*{max_iterations}", render(group))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    #[test]
+    fn parse_valid_pipelines() {
+        for (pipeline, expected) in [
+            ("dce", "dce"),
+            ("mem2reg, inline ,dce", "mem2reg,inline,dce"),
+            (
+                "mem2reg,inline,(ccp,simplify-cfg)*",
+                "mem2reg,inline,(ccp,simplify-cfg)*8",
+            ),
+            ("(sroa,(mem2reg,dce)*2)", "(sroa,(mem2reg,dce)*2)"),
+            ("dce*3", "(dce)*3"),
+        ] {
+            let group = parse(pipeline).unwrap();
+            assert_eq!(render(&group), expected, "Pipeline: {pipeline}");
+        }
+    }
+
+    #[test]
+    fn parse_invalid_pipelines() {
+        for (pipeline, expected_error) in [
+            ("", "expected a pass name or '('"),
+            ("dce,", "expected a pass name or '('"),
+            ("not-a-pass", "unknown pass 'not-a-pass' at offset 0"),
+            ("(dce,sroa", "expected ')'"),
+            ("dce)", "unmatched ')'"),
+            (
+                "dce*0",
+                "the number of repetitions must be a positive integer",
+            ),
+            ("dce sroa", "unexpected 's'"),
+        ] {
+            let err = parse(pipeline).err().unwrap().to_string();
+            assert!(
+                err.contains(expected_error),
+                "Pipeline: {pipeline}, error: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn insert_after_each_in_repeated_groups() {
+        let group = parse("dce,(sroa,mem2reg)*").unwrap();
+        let group = insert_after_each(group, MODULE_VERIFIER_NAME);
+        assert_eq!(
+            render(&group),
+            "dce,module-verifier,(sroa,module-verifier,mem2reg,module-verifier)*8"
+        );
+    }
+}
@@ -6,11 +6,11 @@ use sway_ir::{
     create_arg_demotion_pass, create_ccp_pass, create_const_demotion_pass,
     create_const_folding_pass, create_cse_pass, create_dce_pass, create_dom_fronts_pass,
     create_dominators_pass, create_escaped_symbols_pass, create_mem2reg_pass,
-    create_memcpyopt_pass, create_misc_demotion_pass, create_postorder_pass,
-    create_ret_demotion_pass, create_simplify_cfg_pass, metadata_to_inline, optimize as opt,
-    register_known_passes, Context, Function, IrError, PassGroup, PassManager, Value, DCE_NAME,
-    FN_DCE_NAME, FN_DEDUP_DEBUG_PROFILE_NAME, FN_DEDUP_RELEASE_PROFILE_NAME, MEM2REG_NAME,
-    SROA_NAME,
+    create_memcpyopt_pass, create_misc_demotion_pass, create_o1_pass_group, create_postorder_pass,
+    create_ret_demotion_pass, create_simplify_cfg_pass, generate_random_module, metadata_to_inline,
+    optimize as opt, register_known_passes, Context, Function, IrError, PassGroup, PassManager,
+    RandomModuleConfig, Value, DCE_NAME, FN_DCE_NAME, FN_DEDUP_DEBUG_PROFILE_NAME,
+    FN_DEDUP_RELEASE_PROFILE_NAME, MEM2REG_NAME, SROA_NAME,
 };
 use sway_types::SourceEngine;
 
@@ -440,3 +440,99 @@ fn serialize() {
 }
 
 // -------------------------------------------------------------------------------------------------
+// Fuzz the passes, the verifier and the printer/parser round trip with randomly generated modules.
+//
+// By default a fixed range of seeds is used so the test is deterministic.  More modules may be
+// generated by setting `SWAY_IR_FUZZ_ITERATIONS`, and a single failing module can be reproduced
+// by setting `SWAY_IR_FUZZ_SEED` to the seed reported in the failure.
+
+fn fuzz_seeds() -> Vec<u64> {
+    if let Ok(seed) = std::env::var("SWAY_IR_FUZZ_SEED") {
+        return vec![seed
+            .parse()
+            .expect("SWAY_IR_FUZZ_SEED must be an unsigned integer.")];
+    }
+    let iterations = std::env::var("SWAY_IR_FUZZ_ITERATIONS")
+        .ok()
+        .and_then(|iterations| iterations.parse().ok())
+        .unwrap_or(64);
+    (0..iterations).collect()
+}
+
+fn check_round_trip(ir: &Context, source_engine: &SourceEngine, seed: u64, stage: &str) {
+    let printed = sway_ir::printer::to_string(ir);
+    let reparsed =
+        sway_ir::parser::parse(&printed, source_engine, ir.experimental).unwrap_or_else(|err| {
+            println!("{printed}");
+            panic!("Seed {seed}: failed to parse the printed IR after {stage}: {err}");
+        });
+    let reprinted = sway_ir::printer::to_string(&reparsed);
+    if printed != reprinted {
+        println!("{}", prettydiff::diff_lines(&printed, &reprinted));
+        panic!("Seed {seed}: printer/parser round trip is not stable after {stage}.");
+    }
+}
+
+fn fuzz_pass_group(
+    source_engine: &SourceEngine,
+    seed: u64,
+    stage: &str,
+    build_group: impl Fn() -> PassGroup,
+) {
+    let config = RandomModuleConfig::default();
+    let mut ir = Context::new(source_engine, ExperimentalFeatures::default());
+    generate_random_module(&mut ir, seed, &config);
+    let input = sway_ir::printer::to_string(&ir);
+
+    let mut pass_mgr = PassManager::default();
+    register_known_passes(&mut pass_mgr);
+    pass_mgr.run(&mut ir, &build_group()).unwrap_or_else(|err| {
+        println!("{input}");
+        panic!("Seed {seed}: running {stage} failed: {err}");
+    });
+
+    let ir = ir.verify().unwrap_or_else(|err| {
+        println!("{input}");
+        panic!("Seed {seed}: verification failed after {stage}: {err}");
+    });
+    check_round_trip(&ir, source_engine, seed, stage);
+}
+
+#[test]
+fn fuzz_passes() {
+    let source_engine = SourceEngine::default();
+
+    let pass_names = {
+        let mut pass_mgr = PassManager::default();
+        register_known_passes(&mut pass_mgr);
+        pass_mgr.registered_pass_names()
+    };
+
+    for seed in fuzz_seeds() {
+        // The generated module itself must be valid and survive a round trip.
+        let mut ir = Context::new(&source_engine, ExperimentalFeatures::default());
+        generate_random_module(&mut ir, seed, &RandomModuleConfig::default());
+        let input = sway_ir::printer::to_string(&ir);
+        let ir = ir.verify().unwrap_or_else(|err| {
+            println!("{input}");
+            panic!("Seed {seed}: the generated module failed verification: {err}");
+        });
+        check_round_trip(&ir, &source_engine, seed, "generation");
+
+        for &pass in &pass_names {
+            fuzz_pass_group(&source_engine, seed, pass, || {
+                let mut pass_group = PassGroup::default();
+                pass_group.append_pass(pass);
+                pass_group
+            });
+        }
+        fuzz_pass_group(
+            &source_engine,
+            seed,
+            "the O1 pass group",
+            create_o1_pass_group,
+        );
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
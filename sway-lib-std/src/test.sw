@@ -0,0 +1,449 @@
+//! Cheatcodes for changing the environment of unit tests run by `forc test`.
+//!
+//! Cheatcodes are executed by `forc test` through the `ecal` instruction, and are not available
+//! on a network, where `ecal` panics.
+//!
+//! Setting the block height, the timestamp or the caller, and minting coins to an address,
+//! changes the transaction executing the test. These cheatcodes apply to the whole test, no matter
+//! where they are called: `forc test` runs the test again in the changed environment.
+//! Minting coins to a contract and accessing storage slots take effect immediately.
+//!
+//! Tests of predicates declare the transaction spending the predicate with `predicate_coin`,
+//! `predicate_message`, `set_predicate_data`, `add_witness`, `add_output` and `add_change_output`,
+//! and read with `verify_predicate` whether `main` returns `true` for that transaction.
+library;
+
+use ::address::Address;
+use ::alloc::alloc;
+use ::asset_id::AssetId;
+use ::bytes::Bytes;
+use ::contract_id::ContractId;
+use ::identity::Identity;
+use core::codec::*;
+
+const SET_BLOCK_HEIGHT: u64 = 0;
+const SET_TIMESTAMP: u64 = 1;
+const SET_CALLER: u64 = 2;
+const MINT_TO_CONTRACT: u64 = 3;
+const MINT_TO_ADDRESS: u64 = 4;
+const STORE: u64 = 5;
+const LOAD: u64 = 6;
+const EXPECT_LOG: u64 = 7;
+const PREDICATE_COIN: u64 = 8;
+const PREDICATE_MESSAGE: u64 = 9;
+const PREDICATE_DATA: u64 = 10;
+const ADD_WITNESS: u64 = 11;
+const ADD_OUTPUT: u64 = 12;
+const ADD_CHANGE_OUTPUT: u64 = 13;
+const VERIFY_PREDICATE: u64 = 14;
+
+/// Sets the height of the block the test is executed in.
+///
+/// # Arguments
+///
+/// * `height`: [u32] - The block height returned by `std::block::height`.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{block::height, test::set_block_height};
+///
+/// #[test]
+/// fn test_time_lock() {
+///     set_block_height(1000);
+///     assert(height() == 1000);
+/// }
+/// ```
+pub fn set_block_height(height: u32) {
+    asm(id: SET_BLOCK_HEIGHT, height: height) {
+        ecal id height zero zero;
+    }
+}
+
+/// Sets the TAI64 timestamp of the block the test is executed in.
+///
+/// # Additional Information
+///
+/// `forc test` derives the timestamp of a block from its height, with a block every 10 seconds
+/// since the Unix epoch. Setting the timestamp sets the block height of the latest block produced
+/// at or before `timestamp`.
+///
+/// # Arguments
+///
+/// * `timestamp`: [u64] - The TAI64 timestamp returned by `std::block::timestamp`.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{block::timestamp, test::set_timestamp};
+///
+/// #[test]
+/// fn test_deadline() {
+///     let deadline = 0x4000_0000_3b9a_ca25;
+///     set_timestamp(deadline);
+///     assert(timestamp() == deadline);
+/// }
+/// ```
+pub fn set_timestamp(timestamp: u64) {
+    asm(id: SET_TIMESTAMP, timestamp: timestamp) {
+        ecal id timestamp zero zero;
+    }
+}
+
+/// Sets the address owning the coin inputs of the transaction executing the test.
+///
+/// # Additional Information
+///
+/// The address is returned by `std::auth::caller_address` in the test, and by
+/// `std::auth::msg_sender` in the contracts it calls directly.
+///
+/// # Arguments
+///
+/// * `caller`: [Address] - The address calling from the test.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{auth::caller_address, test::set_caller};
+///
+/// #[test]
+/// fn test_owner_only() {
+///     let owner = Address::from(0x0000000000000000000000000000000000000000000000000000000000000001);
+///     set_caller(owner);
+///     assert(caller_address().unwrap() == owner);
+/// }
+/// ```
+pub fn set_caller(caller: Address) {
+    asm(id: SET_CALLER, caller: caller.bits()) {
+        ecal id caller zero zero;
+    }
+}
+
+/// Mints `amount` coins of `asset_id` to `recipient`.
+///
+/// # Additional Information
+///
+/// Coins minted to a contract are added to its balance immediately. Coins minted to an address
+/// are added as a coin input to the transaction executing the test. Unless the address is the
+/// caller, this makes `std::auth::caller_address` fail for the test.
+///
+/// # Arguments
+///
+/// * `recipient`: [Identity] - The contract or address receiving the coins.
+/// * `asset_id`: [AssetId] - The asset of the coins.
+/// * `amount`: [u64] - The amount of coins to mint.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{context::balance_of, test::mint};
+///
+/// #[test]
+/// fn test_mint() {
+///     let contract_id = ContractId::from(CONTRACT_ID);
+///     mint(Identity::ContractId(contract_id), AssetId::base(), 100);
+///     assert(balance_of(contract_id, AssetId::base()) == 100);
+/// }
+/// ```
+pub fn mint(recipient: Identity, asset_id: AssetId, amount: u64) {
+    match recipient {
+        Identity::ContractId(contract_id) => {
+            asm(
+                id: MINT_TO_CONTRACT,
+                target: contract_id.bits(),
+                asset: asset_id.bits(),
+                amount: amount,
+            ) {
+                ecal id target asset amount;
+            }
+        },
+        Identity::Address(address) => {
+            asm(
+                id: MINT_TO_ADDRESS,
+                address: address.bits(),
+                asset: asset_id.bits(),
+                amount: amount,
+            ) {
+                ecal id address asset amount;
+            }
+        },
+    }
+}
+
+/// Writes `value` to the storage slot `key` of the contract `contract_id`.
+///
+/// # Arguments
+///
+/// * `contract_id`: [ContractId] - The contract owning the storage slot.
+/// * `key`: [b256] - The key of the storage slot.
+/// * `value`: [b256] - The value to write.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test::{load, store};
+///
+/// #[test]
+/// fn test_storage() {
+///     store(ContractId::from(CONTRACT_ID), b256::zero(), b256::max());
+///     assert(load(ContractId::from(CONTRACT_ID), b256::zero()) == b256::max());
+/// }
+/// ```
+pub fn store(contract_id: ContractId, key: b256, value: b256) {
+    asm(id: STORE, target: contract_id.bits(), key: key, value: value) {
+        ecal id target key value;
+    }
+}
+
+/// Reads the storage slot `key` of the contract `contract_id`.
+///
+/// # Arguments
+///
+/// * `contract_id`: [ContractId] - The contract owning the storage slot.
+/// * `key`: [b256] - The key of the storage slot.
+///
+/// # Returns
+///
+/// * [b256] - The value of the storage slot, or zero if it is not set.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test::load;
+///
+/// #[test]
+/// fn test_storage() {
+///     let value = load(ContractId::from(CONTRACT_ID), b256::zero());
+///     assert(value == b256::zero());
+/// }
+/// ```
+pub fn load(contract_id: ContractId, key: b256) -> b256 {
+    let value = b256::zero();
+    asm(id: LOAD, target: contract_id.bits(), key: key, value: value) {
+        ecal id target key value;
+        value: b256
+    }
+}
+
+/// Expects the test to log `value`.
+///
+/// # Additional Information
+///
+/// The test fails if none of the logs it emits, before or after the call, holds the encoding of
+/// `value`.
+///
+/// # Arguments
+///
+/// * `value`: [T] - The value expected to be logged.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test::expect_log;
+///
+/// #[test]
+/// fn test_event() {
+///     expect_log(42);
+///     log(42);
+/// }
+/// ```
+#[cfg(experimental_new_encoding = true)]
+pub fn expect_log<T>(value: T)
+where
+    T: AbiEncode,
+{
+    let data = encode(value);
+    asm(id: EXPECT_LOG, data: data.ptr(), len: data.number_of_bytes()) {
+        ecal id data len zero;
+    }
+}
+
+/// Adds a coin owned by the predicate under test to the transaction spending it.
+///
+/// # Arguments
+///
+/// * `asset_id`: [AssetId] - The asset of the coin.
+/// * `amount`: [u64] - The amount of the coin.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test::{predicate_coin, verify_predicate};
+///
+/// #[test]
+/// fn test_spend_base_asset() {
+///     predicate_coin(AssetId::base(), 100);
+///     assert(verify_predicate());
+/// }
+/// ```
+pub fn predicate_coin(asset_id: AssetId, amount: u64) {
+    asm(id: PREDICATE_COIN, asset: asset_id.bits(), amount: amount) {
+        ecal id asset amount zero;
+    }
+}
+
+/// Adds a message sent to the predicate under test to the transaction spending it.
+///
+/// # Arguments
+///
+/// * `amount`: [u64] - The amount of base asset of the message.
+/// * `data`: [Bytes] - The data of the message. A message without data is a coin.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{bytes::Bytes, test::{predicate_message, verify_predicate}};
+///
+/// #[test]
+/// fn test_spend_message() {
+///     predicate_message(100, Bytes::new());
+///     assert(verify_predicate());
+/// }
+/// ```
+pub fn predicate_message(amount: u64, data: Bytes) {
+    asm(id: PREDICATE_MESSAGE, amount: amount, data: data.ptr(), len: data.len()) {
+        ecal id amount data len;
+    }
+}
+
+/// Sets the predicate data of the inputs of the predicate under test, i.e. the arguments of its
+/// `main` function.
+///
+/// # Arguments
+///
+/// * `data`: [T] - The arguments of `main`, as a tuple if there are several of them.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test::{predicate_coin, set_predicate_data, verify_predicate};
+///
+/// #[test]
+/// fn test_wrong_password() {
+///     predicate_coin(AssetId::base(), 100);
+///     set_predicate_data(42);
+///     assert(!verify_predicate());
+/// }
+/// ```
+#[cfg(experimental_new_encoding = true)]
+pub fn set_predicate_data<T>(data: T)
+where
+    T: AbiEncode,
+{
+    let data = encode(data);
+    asm(id: PREDICATE_DATA, data: data.ptr(), len: data.number_of_bytes()) {
+        ecal id data len zero;
+    }
+}
+
+/// Adds a witness to the transaction spending the predicate under test.
+///
+/// # Arguments
+///
+/// * `data`: [Bytes] - The data of the witness.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::{bytes::Bytes, test::{add_witness, predicate_coin, verify_predicate}};
+///
+/// #[test]
+/// fn test_signed() {
+///     predicate_coin(AssetId::base(), 100);
+///     add_witness(Bytes::from(SIGNATURE));
+///     assert(verify_predicate());
+/// }
+/// ```
+pub fn add_witness(data: Bytes) {
+    asm(id: ADD_WITNESS, data: data.ptr(), len: data.len()) {
+        ecal id data len zero;
+    }
+}
+
+/// Adds a coin output to the transaction spending the predicate under test.
+///
+/// # Arguments
+///
+/// * `recipient`: [Address] - The recipient of the coins.
+/// * `asset_id`: [AssetId] - The asset of the coins.
+/// * `amount`: [u64] - The amount of coins.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test::{add_output, predicate_coin, verify_predicate};
+///
+/// #[test]
+/// fn test_pay_owner() {
+///     predicate_coin(AssetId::base(), 100);
+///     add_output(OWNER, AssetId::base(), 100);
+///     assert(verify_predicate());
+/// }
+/// ```
+pub fn add_output(recipient: Address, asset_id: AssetId, amount: u64) {
+    asm(
+        id: ADD_OUTPUT,
+        recipient: recipient.bits(),
+        asset: asset_id.bits(),
+        amount: amount,
+    ) {
+        ecal id recipient asset amount;
+    }
+}
+
+/// Adds a change output to the transaction spending the predicate under test.
+///
+/// # Arguments
+///
+/// * `recipient`: [Address] - The recipient of the change.
+/// * `asset_id`: [AssetId] - The asset of the change.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test::{add_change_output, predicate_coin, verify_predicate};
+///
+/// #[test]
+/// fn test_change_to_owner() {
+///     predicate_coin(AssetId::base(), 100);
+///     add_change_output(OWNER, AssetId::base());
+///     assert(verify_predicate());
+/// }
+/// ```
+pub fn add_change_output(recipient: Address, asset_id: AssetId) {
+    asm(id: ADD_CHANGE_OUTPUT, recipient: recipient.bits(), asset: asset_id.bits()) {
+        ecal id recipient asset zero;
+    }
+}
+
+/// Verifies the predicate under test against the transaction declared by the test.
+///
+/// # Additional Information
+///
+/// `forc test` verifies the predicate as done when estimating the gas used by predicates, and
+/// reports the result and the gas used. Like the environment cheatcodes, the transaction applies
+/// to the whole test: it must be declared before calling `verify_predicate`, which returns `false`
+/// until `forc test` runs the test again with the declared transaction.
+///
+/// # Returns
+///
+/// * [bool] - Whether `main` returns `true` for all the inputs of the predicate.
+///
+/// # Examples
+///
+/// ```sway
+/// use std::test::{predicate_coin, verify_predicate};
+///
+/// #[test]
+/// fn test_spend() {
+///     predicate_coin(AssetId::base(), 100);
+///     assert(verify_predicate());
+/// }
+/// ```
+pub fn verify_predicate() -> bool {
+    let verified = alloc::<u64>(1);
+    asm(id: VERIFY_PREDICATE, verified: verified) {
+        ecal id verified zero zero;
+    }
+    verified.read::<u64>() == 1
+}
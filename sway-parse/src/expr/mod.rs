@@ -840,10 +840,12 @@ impl ParseToEnd for ExprArrayDescriptor {
 
 impl Parse for MatchBranch {
     fn parse(parser: &mut Parser) -> ParseResult<MatchBranch> {
+        let leading_pipe_token = parser.take();
         let pattern = parser.parse()?;
         let fat_right_arrow_token = parser.parse()?;
         let kind = parser.parse()?;
         Ok(MatchBranch {
+            leading_pipe_token,
             pattern,
             fat_right_arrow_token,
             kind,
@@ -126,6 +126,7 @@ define_op_codes!(
     (S256, S256Opcode, "s256", (addr, data, size)),
     /* Other Instructions */
     (Flag, FlagOpcode, "flag", (value)),
+    (Ecal, EcalOpcode, "ecal", (reg_a, reg_b, reg_c, reg_d)),
     (Gm, GmOpcode, "gm", (ret, op)),
     (Gtf, GtfOpcode, "gtf", (ret, index, tx_field_id)),
     /* Non-VM Instructions */
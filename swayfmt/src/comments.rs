@@ -1,6 +1,7 @@
 use crate::{
+    config::manifest::Config,
     formatter::FormattedCode,
-    parse::parse_snippet,
+    parse::{lex, parse_snippet},
     utils::map::{
         byte_span::{ByteSpan, LeafSpans},
         comments::CommentMap,
@@ -8,8 +9,8 @@ use crate::{
     Format, Formatter, FormatterError,
 };
 use ropey::Rope;
-use std::{fmt::Write, ops::Range};
-use sway_ast::token::{Comment, CommentKind};
+use std::{fmt::Write, ops::Range, sync::Arc};
+use sway_ast::token::{Comment, CommentKind, CommentedTokenTree, CommentedTree};
 use sway_types::{Span, Spanned};
 
 pub type UnformattedCode = String;
@@ -24,7 +25,20 @@ pub struct CommentsContext {
 }
 
 impl CommentsContext {
-    pub fn new(map: CommentMap, unformatted_code: UnformattedCode) -> Self {
+    pub fn new(mut map: CommentMap, unformatted_code: UnformattedCode) -> Self {
+        // The lexer only tells block comments apart by whether they span several lines, so a
+        // `/* */` comment on a line of its own is anchored to the line below, like a `//` one.
+        for comment in map.values_mut() {
+            let span = comment.span().start()..comment.span().end();
+            if comment.comment_kind == CommentKind::Inlined
+                && matches!(
+                    comment_line_indent(&unformatted_code, &span),
+                    Some((_, true))
+                )
+            {
+                comment.comment_kind = CommentKind::Newlined;
+            }
+        }
         Self {
             map,
             unformatted_code,
@@ -349,7 +363,7 @@ fn insert_after_span(
                     }
                 }
                 CommentKind::Inlined => {
-                    if !formatted_code[..from.end].ends_with(' ') {
+                    if !formatted_code[..from.end + offset].ends_with([' ', '(', '[']) {
                         write!(comment_str, " ")?;
                     }
                     write!(comment_str, "{}", comment.span().as_str())?;
@@ -387,6 +401,345 @@ fn insert_after_span(
     Ok(comment_str.chars().count())
 }
 
+/// Rewrites the comments of the formatted code as set in the comments [Config]: `/* */` comments
+/// that end their line are converted to `//` comments, and `//`, `///` and `//!` comments that
+/// go past `comment_width` are wrapped.
+///
+/// This is done once the code is formatted, as the room a comment has depends on its final
+/// indentation.
+pub fn rewrite_comments(
+    formatted_code: &mut FormattedCode,
+    config: &Config,
+) -> Result<(), FormatterError> {
+    if config.comments.normalize_comments {
+        let replacements = comment_spans(formatted_code)?
+            .into_iter()
+            .filter_map(|span| {
+                let (indent, own_line) = comment_line_indent(formatted_code, &span)?;
+                let comment = &formatted_code[span.clone()];
+                let normalized = normalize_block_comment(comment, indent)?;
+                (own_line || !normalized.contains('\n')).then_some((span, normalized))
+            })
+            .collect::<Vec<_>>();
+        replace_ranges(formatted_code, replacements);
+    }
+
+    if config.comments.wrap_comments {
+        // Consecutive line comments with the same prefix and indentation are wrapped together.
+        let mut blocks: Vec<(&str, &str, Vec<Range<usize>>)> = Vec::new();
+        for span in comment_spans(formatted_code)? {
+            let Some((indent, true)) = comment_line_indent(formatted_code, &span) else {
+                continue;
+            };
+            let Some(prefix) = line_comment_prefix(&formatted_code[span.clone()]) else {
+                continue;
+            };
+            match blocks.last_mut() {
+                Some((block_prefix, block_indent, spans))
+                    if *block_prefix == prefix
+                        && *block_indent == indent
+                        && formatted_code[spans[spans.len() - 1].end..span.start]
+                            == format!("\n{indent}") =>
+                {
+                    spans.push(span)
+                }
+                _ => blocks.push((prefix, indent, vec![span])),
+            }
+        }
+
+        let replacements = blocks
+            .into_iter()
+            .filter_map(|(prefix, indent, spans)| {
+                let lines = spans
+                    .iter()
+                    .map(|span| {
+                        let content = &formatted_code[span.start + prefix.len()..span.end];
+                        content.strip_prefix(' ').unwrap_or(content)
+                    })
+                    .collect::<Vec<_>>();
+                // As in rustfmt, the comment width includes the indentation of the comment.
+                let width = config
+                    .comments
+                    .comment_width
+                    .min(config.whitespace.max_width)
+                    .saturating_sub(indent.chars().count());
+                let wrapped = wrap_comment(&lines, width.saturating_sub(prefix.len() + 1));
+                if wrapped == lines {
+                    return None;
+                }
+                let wrapped = wrapped
+                    .iter()
+                    .map(|line| {
+                        if line.is_empty() {
+                            prefix.to_string()
+                        } else {
+                            format!("{prefix} {line}")
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(&format!("\n{indent}"));
+                Some((spans[0].start..spans[spans.len() - 1].end, wrapped))
+            })
+            .collect::<Vec<_>>();
+        replace_ranges(formatted_code, replacements);
+    }
+
+    Ok(())
+}
+
+/// The spans of all the comments of the code, doc comments included, in order.
+fn comment_spans(code: &str) -> Result<Vec<Range<usize>>, FormatterError> {
+    fn collect(tree: &CommentedTokenTree, spans: &mut Vec<Range<usize>>) {
+        match tree {
+            CommentedTokenTree::Comment(comment) => {
+                spans.push(comment.span.start()..comment.span.end())
+            }
+            CommentedTokenTree::Tree(CommentedTree::DocComment(doc_comment)) => {
+                spans.push(doc_comment.span.start()..doc_comment.span.end())
+            }
+            CommentedTokenTree::Tree(CommentedTree::Group(group)) => {
+                for tree in group.token_stream.token_trees() {
+                    collect(tree, spans);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut spans = Vec::new();
+    for tree in lex(&Arc::from(code))?.token_trees() {
+        collect(tree, &mut spans);
+    }
+    spans.sort_by_key(|span| span.start);
+    Ok(spans)
+}
+
+/// Returns the indentation of the line a comment starts on and whether the comment is alone on its
+/// line, or `None` if code follows the comment on its line.
+fn comment_line_indent<'a>(code: &'a str, span: &Range<usize>) -> Option<(&'a str, bool)> {
+    let line_start = code[..span.start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = code[span.end..]
+        .find('\n')
+        .map_or(code.len(), |i| span.end + i);
+    if !code[span.end..line_end].trim().is_empty() {
+        return None;
+    }
+    let before = &code[line_start..span.start];
+    let indent = &before[..before.len() - before.trim_start().len()];
+    Some((indent, before.trim().is_empty()))
+}
+
+/// The prefix of a line comment: `//`, or `///` and `//!` for doc comments.
+fn line_comment_prefix(comment: &str) -> Option<&'static str> {
+    if comment.starts_with("//!") {
+        Some("//!")
+    } else if comment.starts_with("///") && !comment.starts_with("////") {
+        Some("///")
+    } else if comment.starts_with("//") {
+        Some("//")
+    } else {
+        None
+    }
+}
+
+/// Converts a `/* */` comment to `//` comments, continued at `indent`. Returns `None` for doc
+/// block comments and comments containing other block comments.
+fn normalize_block_comment(comment: &str, indent: &str) -> Option<String> {
+    if (comment.starts_with("/**") && comment != "/**/") || comment.starts_with("/*!") {
+        return None;
+    }
+    let content = comment.strip_prefix("/*")?.strip_suffix("*/")?;
+    if content.contains("/*") || content.contains("*/") {
+        return None;
+    }
+
+    let mut lines = content.lines().map(str::trim_end).collect::<Vec<_>>();
+    // Drop the lines the `/*` and `*/` delimiters stand on alone.
+    let mut follows_delimiter = true;
+    while lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+        follows_delimiter = false;
+    }
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    // Drop the `*` decorating the start of the lines, if they all have one.
+    if lines.len() > 1 && lines.iter().all(|line| line.trim_start().starts_with('*')) {
+        lines = lines
+            .into_iter()
+            .map(|line| line.trim_start()[1..].trim_end())
+            .collect();
+    }
+    if lines.is_empty() {
+        return Some("//".to_string());
+    }
+    // Keep the indentation of the lines relative to each other, but for the line following the
+    // `/*`, whose indentation is lost.
+    let common_indent = lines
+        .iter()
+        .skip(usize::from(follows_delimiter))
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    Some(
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let line = if i == 0 && follows_delimiter {
+                    line.trim_start()
+                } else {
+                    line.get(common_indent..).unwrap_or_default()
+                };
+                if line.is_empty() {
+                    "//".to_string()
+                } else {
+                    format!("// {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(&format!("\n{indent}")),
+    )
+}
+
+/// Wraps the lines of a comment to `width` chars. Words carried over from a wrapped line are
+/// joined to the next one when it continues the same paragraph.
+///
+/// Markdown code blocks, headings, tables, quotes and indented code are left as is, and the
+/// wrapped items of lists are continued under their text.
+fn wrap_comment(lines: &[&str], width: usize) -> Vec<String> {
+    let mut wrapped = Vec::new();
+    let mut in_code_block = false;
+    // The words carried over from the previous line, with the indentation to continue them at.
+    let mut carried: Option<(String, Vec<&str>)> = None;
+
+    for line in lines {
+        let text = line.trim_start();
+        let indent = &line[..line.len() - text.len()];
+        let is_fence = text.starts_with("```") || text.starts_with("~~~");
+        let is_verbatim = in_code_block
+            || is_fence
+            || text.is_empty()
+            || text.starts_with(['#', '|', '>'])
+            || indent.len() >= 4;
+        let list_marker = list_marker_len(text);
+
+        let mut words = Vec::new();
+        if let Some((carried_indent, carried_words)) = carried.take() {
+            if !is_verbatim && list_marker.is_none() && carried_indent == indent {
+                words = carried_words;
+            } else {
+                wrapped.extend(fill(
+                    &carried_indent,
+                    &carried_indent,
+                    &carried_words,
+                    width,
+                ));
+            }
+        }
+
+        if is_verbatim {
+            in_code_block ^= is_fence;
+            wrapped.push(line.to_string());
+            continue;
+        }
+        if words.is_empty() && line.chars().count() <= width {
+            wrapped.push(line.to_string());
+            continue;
+        }
+
+        let (first_indent, indent) = match list_marker {
+            Some(len) => (
+                format!("{indent}{} ", &text[..len]),
+                format!("{indent}{}", " ".repeat(len + 1)),
+            ),
+            None => (indent.to_string(), indent.to_string()),
+        };
+        let text = list_marker.map_or(text, |len| text[len..].trim_start());
+        words.extend(text.split_whitespace());
+
+        let mut broken = break_words(&first_indent, &indent, &words, width);
+        if broken.len() == 1 {
+            wrapped.extend(fill(&first_indent, &indent, &words, width));
+        } else {
+            // The last line is carried over, to be joined to the next line of the paragraph.
+            let last = broken.pop().expect("there are several lines");
+            for (i, line) in broken.into_iter().enumerate() {
+                let line_indent = if i == 0 { &first_indent } else { &indent };
+                wrapped.push(format!("{line_indent}{}", line.join(" ")));
+            }
+            carried = Some((indent, last));
+        }
+    }
+
+    if let Some((indent, words)) = carried {
+        wrapped.extend(fill(&indent, &indent, &words, width));
+    }
+    wrapped
+}
+
+/// Fills the words into lines of at most `width` chars, starting the first line at `first_indent`
+/// and the others at `indent`.
+fn fill(first_indent: &str, indent: &str, words: &[&str], width: usize) -> Vec<String> {
+    break_words(first_indent, indent, words, width)
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let line_indent = if i == 0 { first_indent } else { indent };
+            format!("{line_indent}{}", line.join(" "))
+        })
+        .collect()
+}
+
+/// Breaks the words into the lines they fill. Words longer than the width get a line of their own.
+fn break_words<'a>(
+    first_indent: &str,
+    indent: &str,
+    words: &[&'a str],
+    width: usize,
+) -> Vec<Vec<&'a str>> {
+    let mut lines = vec![Vec::new()];
+    let mut line_width = first_indent.chars().count();
+    for word in words {
+        let word_width = word.chars().count();
+        let line = lines.last_mut().expect("there is always a line");
+        if !line.is_empty() && line_width + 1 + word_width > width {
+            lines.push(vec![*word]);
+            line_width = indent.chars().count() + word_width;
+        } else {
+            if !line.is_empty() {
+                line_width += 1;
+            }
+            line.push(*word);
+            line_width += word_width;
+        }
+    }
+    lines
+}
+
+/// The length of the marker of a markdown list item, e.g. `-` or `1.`, if the text starts one.
+fn list_marker_len(text: &str) -> Option<usize> {
+    let marker_len = if text.starts_with(['-', '*', '+']) {
+        1
+    } else {
+        let digits = text.chars().take_while(char::is_ascii_digit).count();
+        if digits == 0 || !text[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        digits + 1
+    };
+    text[marker_len..].starts_with(' ').then_some(marker_len)
+}
+
+/// Replaces the given ordered, non-overlapping ranges of the code.
+fn replace_ranges(code: &mut FormattedCode, replacements: Vec<(Range<usize>, String)>) {
+    for (range, text) in replacements.into_iter().rev() {
+        code.replace_range(range, &text);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::utils::map::byte_span::ByteSpan;
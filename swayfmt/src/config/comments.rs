@@ -5,7 +5,7 @@ use crate::{config::user_opts::CommentsOptions, constants::DEFAULT_MAX_COMMENT_W
 pub struct Comments {
     /// Break comments to fit on the line.
     pub wrap_comments: bool,
-    /// Maximum length of comments. No effect unless wrap_comments = true.
+    /// Maximum length of comments, indentation included. No effect unless wrap_comments = true.
     pub comment_width: usize,
     /// Convert /* */ comments to // comments where possible
     pub normalize_comments: bool,
@@ -7,7 +7,7 @@ pub struct Ordering {
     pub reorder_imports: bool,
     /// Reorder module statements alphabetically in group.
     pub reorder_modules: bool,
-    /// Reorder `impl` items.
+    /// Put the associated types and consts of `impl`s before their methods.
     pub reorder_impl_items: bool,
 }
 
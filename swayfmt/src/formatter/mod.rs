@@ -1,8 +1,12 @@
 use self::shape::Shape;
-use crate::comments::{write_comments, CommentsContext};
+use crate::comments::{rewrite_comments, write_comments, CommentsContext};
+use crate::module::reorder::reorder_items;
 use crate::parse::parse_file;
 use crate::utils::map::comments::CommentMap;
-use crate::utils::map::{newline::handle_newlines, newline_style::apply_newline_style};
+use crate::utils::map::{
+    newline::{handle_blank_lines_between_items, handle_newlines},
+    newline_style::apply_newline_style,
+};
 pub use crate::{
     config::manifest::Config,
     error::{ConfigError, FormatterError},
@@ -82,6 +86,7 @@ impl Formatter {
     }
 
     pub fn format(&mut self, src: Arc<str>) -> Result<FormattedCode, FormatterError> {
+        let src = reorder_items(src, &self.config)?;
         let annotated_module = parse_file(src)?;
         self.format_module(&annotated_module)
     }
@@ -130,6 +135,12 @@ impl Formatter {
             self,
         )?;
 
+        // Bound the blank lines between items
+        handle_blank_lines_between_items(&mut formatted_code, self)?;
+
+        // Normalize and wrap comments, now that their indentation is known
+        rewrite_comments(&mut formatted_code, &self.config)?;
+
         // Replace newlines with specified `NewlineStyle`
         apply_newline_style(
             self.config.whitespace.newline_style,
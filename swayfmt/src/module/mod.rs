@@ -13,6 +13,7 @@ use sway_ast::{
 use sway_types::Spanned;
 
 pub(crate) mod item;
+pub(crate) mod reorder;
 pub(crate) mod submodule;
 
 impl Format for Module {
@@ -0,0 +1,629 @@
+//! Reordering, grouping and merging of the `use` and `mod` items of a module, and reordering of
+//! the items of `impl`s.
+//!
+//! This is done on the source, before it is formatted, so that the comments attached to the
+//! items move along with them.
+
+use crate::{
+    config::{
+        imports::{GroupImports, ImportGranularity},
+        manifest::Config,
+    },
+    parse::parse_file,
+    FormatterError,
+};
+use std::{cmp::Ordering, ops::Range, sync::Arc};
+use sway_ast::{Item, ItemImpl, ItemImplItem, ItemKind, ItemUse, UseTree};
+use sway_types::Spanned;
+
+/// Reorders, groups and merges the runs of consecutive `use` and `mod` items of `src`, and
+/// reorders the items of `impl`s, as set in the [Config]. Returns `src` as is if there is nothing
+/// to do.
+pub(crate) fn reorder_items(src: Arc<str>, config: &Config) -> Result<Arc<str>, FormatterError> {
+    let rewrite_imports = config.ordering.reorder_imports
+        || !matches!(config.imports.group_imports, GroupImports::Preserve)
+        || !matches!(
+            config.imports.imports_granularity,
+            ImportGranularity::Preserve
+        );
+    if !rewrite_imports && !config.ordering.reorder_modules && !config.ordering.reorder_impl_items {
+        return Ok(src);
+    }
+
+    let module = parse_file(src.clone())?.value;
+    let mut replacements: Vec<(Range<usize>, String)> = Vec::new();
+    let mut prev_end = module.semicolon_token.span().end();
+    let mut items = module.items.iter().peekable();
+    while let Some(item) = items.next() {
+        let kind = RunKind::of(item);
+        let mut run = vec![item];
+        while let Some(next) = items.next_if(|next| kind.is_some() && RunKind::of(next) == kind) {
+            run.push(next);
+        }
+
+        let rewrite = match kind {
+            Some(RunKind::Use) => rewrite_imports,
+            Some(RunKind::Submodule) => config.ordering.reorder_modules,
+            None => false,
+        };
+        if rewrite {
+            let ranges = run
+                .iter()
+                .map(|item| item_start(item)..item.span().end())
+                .collect::<Vec<_>>();
+            if let Some((range, mut entries)) = run_entries(&src, prev_end, &ranges) {
+                for (entry, item) in entries.iter_mut().zip(&run) {
+                    set_key(&src, entry, item);
+                }
+                let groups = match kind {
+                    Some(RunKind::Use) => import_groups(entries, config),
+                    _ => submodule_groups(entries),
+                };
+                replacements.push((range, render_groups(groups)));
+            }
+        }
+        if let ItemKind::Impl(item_impl) = &item.value {
+            if config.ordering.reorder_impl_items {
+                replacements.extend(reorder_impl_items(&src, item_impl));
+            }
+        }
+        prev_end = run.last().expect("runs are not empty").span().end();
+    }
+
+    let mut reordered = src.to_string();
+    for (range, text) in replacements.into_iter().rev() {
+        reordered.replace_range(range, &text);
+    }
+    if *reordered == *src {
+        Ok(src)
+    } else {
+        Ok(reordered.into())
+    }
+}
+
+/// Puts the associated types of the `impl` first, then its associated consts and then its
+/// methods, keeping the blank lines between the items where they are.
+fn reorder_impl_items(src: &str, item_impl: &ItemImpl) -> Option<(Range<usize>, String)> {
+    let items = &item_impl.contents.inner;
+    let ranges = items
+        .iter()
+        .map(|item| {
+            let start = match item.attribute_list.first() {
+                Some(attribute) => attribute.span().start(),
+                None => item.value.span().start(),
+            };
+            let end = match &item.value {
+                ItemImplItem::Fn(item_fn) => item_fn.span().end(),
+                ItemImplItem::Const(item_const) => item_const.semicolon_token.span().end(),
+                ItemImplItem::Type(trait_type) => trait_type.semicolon_token.span().end(),
+            };
+            start..end
+        })
+        .collect::<Vec<_>>();
+    let (range, entries) = run_entries(src, item_impl.contents.span().start() + 1, &ranges)?;
+
+    let separators = entries
+        .iter()
+        .map(|entry| entry.new_group)
+        .collect::<Vec<_>>();
+    let mut entries = entries.into_iter().zip(items).collect::<Vec<_>>();
+    entries.sort_by_key(|(_, item)| match item.value {
+        ItemImplItem::Type(_) => 0,
+        ItemImplItem::Const(_) => 1,
+        ItemImplItem::Fn(_) => 2,
+    });
+    let entries = entries
+        .into_iter()
+        .zip(separators)
+        .map(|((entry, _), new_group)| Entry { new_group, ..entry })
+        .collect::<Vec<_>>();
+
+    Some((range, render_groups(source_groups(entries))))
+}
+
+/// The kinds of items that are reordered together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RunKind {
+    Use,
+    Submodule,
+}
+
+impl RunKind {
+    fn of(item: &Item) -> Option<Self> {
+        match item.value {
+            ItemKind::Use(_) => Some(Self::Use),
+            ItemKind::Submodule(_) => Some(Self::Submodule),
+            _ => None,
+        }
+    }
+}
+
+/// An item of a run, along with the comments attached to it.
+#[derive(Debug, Clone)]
+struct Entry {
+    /// The comment lines right above the item.
+    leading: Vec<String>,
+    /// The source of the item, including its attributes.
+    text: String,
+    /// The comment following the item on the same line.
+    trailing: Option<String>,
+    /// Whether a blank line separates the item from the previous one of the run.
+    new_group: bool,
+    /// The key the entries are sorted by.
+    key: String,
+    /// The imports of a `use` item that can be merged with others.
+    imports: Option<Imports>,
+}
+
+/// The flattened paths of a `use` item, e.g. `std::{a, b::c}` gives `[std, a]` and `[std, b, c]`.
+#[derive(Debug, Clone)]
+struct Imports {
+    is_pub: bool,
+    paths: Vec<Vec<String>>,
+}
+
+/// Splits the run of items at the given ranges into entries, attaching the comments between the
+/// items to them. Returns `None` if the comments cannot be attached unambiguously, in which case
+/// the run is left as is.
+fn run_entries(
+    src: &str,
+    prev_end: usize,
+    items: &[Range<usize>],
+) -> Option<(Range<usize>, Vec<Entry>)> {
+    if items.is_empty() {
+        return None;
+    }
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut run_start = 0;
+    let mut gap_start = prev_end;
+    for (i, item) in items.iter().enumerate() {
+        let start = item.start;
+        let (trailing, rest) = split_trailing_comment(&src[gap_start..start])?;
+        if let Some(prev) = entries.last_mut() {
+            prev.trailing = trailing;
+        }
+
+        // The first line is the end of the line of the previous item, the last one is the
+        // indentation of this item.
+        let lines = rest.split('\n').collect::<Vec<_>>();
+        let middle = match lines.len() {
+            0..=2 => &[][..],
+            len => &lines[1..len - 1],
+        };
+        let (leading, new_group) = if i == 0 {
+            // Only the comments right above the first item belong to the run.
+            let count = middle
+                .iter()
+                .rev()
+                .take_while(|line| !line.trim().is_empty())
+                .count();
+            let leading = &middle[middle.len() - count..];
+            let indent = if lines.len() > 1 {
+                lines[lines.len() - 1].len()
+            } else {
+                0
+            };
+            run_start = start - indent - leading.iter().map(|line| line.len() + 1).sum::<usize>();
+            (leading, false)
+        } else {
+            let first = middle.iter().position(|line| !line.trim().is_empty());
+            let last = middle.iter().rposition(|line| !line.trim().is_empty());
+            let leading = match (first, last) {
+                (Some(first), Some(last)) => &middle[first..=last],
+                _ => &[][..],
+            };
+            (leading, middle.iter().any(|line| line.trim().is_empty()))
+        };
+        let leading = leading
+            .iter()
+            .map(|line| line.trim_end().to_string())
+            .collect::<Vec<_>>();
+        if !is_balanced(&leading.join("\n")) {
+            return None;
+        }
+
+        entries.push(Entry {
+            leading,
+            text: src[item.clone()].to_string(),
+            trailing: None,
+            new_group,
+            key: String::new(),
+            imports: None,
+        });
+        gap_start = item.end;
+    }
+
+    // The comment on the line of the last item moves along with it.
+    let line_end = src[gap_start..]
+        .find('\n')
+        .map_or(src.len(), |offset| gap_start + offset);
+    let line = src[gap_start..line_end].trim();
+    let mut run_end = gap_start;
+    if line.starts_with("//") {
+        entries.last_mut()?.trailing = Some(line.to_string());
+        run_end = line_end;
+    }
+
+    Some((run_start..run_end, entries))
+}
+
+/// Sets the sort key of the entry of a `use` or `mod` item, and the imports of `use` items that
+/// can be merged with others.
+fn set_key(src: &str, entry: &mut Entry, item: &Item) {
+    match &item.value {
+        ItemKind::Use(item_use) => {
+            let tree_span = item_use.tree.span();
+            entry.key = strip_whitespace(&src[tree_span.start()..tree_span.end()]);
+            if item_use.root_import.is_some() {
+                entry.key.insert_str(0, "::");
+            }
+            let mergeable = item.attribute_list.is_empty()
+                && item_use.root_import.is_none()
+                && !entry.text.contains("//")
+                && !entry.text.contains("/*");
+            if mergeable {
+                entry.imports = use_imports(item_use);
+            }
+        }
+        ItemKind::Submodule(submodule) => entry.key = submodule.name.as_str().to_string(),
+        _ => {}
+    }
+}
+
+/// The start of an item, including its attributes and visibility.
+fn item_start(item: &Item) -> usize {
+    let start = item.span().start();
+    match &item.value {
+        ItemKind::Submodule(submodule) => submodule
+            .visibility
+            .as_ref()
+            .map_or(start, |pub_token| start.min(pub_token.span().start())),
+        _ => start,
+    }
+}
+
+/// Splits the comment on the first line of the `gap` from the rest of it. Returns `None` if the
+/// first line holds anything but a single comment.
+fn split_trailing_comment(gap: &str) -> Option<(Option<String>, &str)> {
+    let line_end = gap.find('\n').unwrap_or(gap.len());
+    let line = gap[..line_end].trim();
+    if line.is_empty() {
+        Some((None, gap))
+    } else if line.starts_with("//")
+        || (line.starts_with("/*") && line.ends_with("*/") && is_balanced(line))
+    {
+        Some((Some(line.to_string()), &gap[line_end..]))
+    } else {
+        None
+    }
+}
+
+/// Whether all the block comments opened in `comments` are closed.
+fn is_balanced(comments: &str) -> bool {
+    comments.matches("/*").count() == comments.matches("*/").count()
+}
+
+fn strip_whitespace(text: &str) -> String {
+    text.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Flattens the tree of the `use` item into the paths it imports. Returns `None` if the tree has
+/// errors or imports nothing.
+fn use_imports(item_use: &ItemUse) -> Option<Imports> {
+    fn flatten(tree: &UseTree, prefix: &mut Vec<String>, paths: &mut Vec<Vec<String>>) -> bool {
+        match tree {
+            UseTree::Group { imports } => {
+                return imports
+                    .get()
+                    .into_iter()
+                    .all(|tree| flatten(tree, prefix, paths));
+            }
+            UseTree::Name { name } => {
+                paths.push([&prefix[..], &[name.as_str().to_string()]].concat())
+            }
+            UseTree::Rename { name, alias, .. } => paths.push(
+                [
+                    &prefix[..],
+                    &[format!("{} as {}", name.as_str(), alias.as_str())],
+                ]
+                .concat(),
+            ),
+            UseTree::Glob { .. } => paths.push([&prefix[..], &["*".to_string()]].concat()),
+            UseTree::Path {
+                prefix: name,
+                suffix,
+                ..
+            } => {
+                prefix.push(name.as_str().to_string());
+                let flattened = flatten(suffix, prefix, paths);
+                prefix.pop();
+                return flattened;
+            }
+            UseTree::Error { .. } => return false,
+        }
+        true
+    }
+
+    let mut paths = Vec::new();
+    if !flatten(&item_use.tree, &mut Vec::new(), &mut paths) || paths.is_empty() {
+        return None;
+    }
+    Some(Imports {
+        is_pub: item_use.visibility.is_some(),
+        paths,
+    })
+}
+
+/// Groups, merges and sorts the entries of a run of `use` items.
+fn import_groups(entries: Vec<Entry>, config: &Config) -> Vec<Vec<Entry>> {
+    let groups = match config.imports.group_imports {
+        GroupImports::Preserve => source_groups(entries),
+        GroupImports::StdExternalCrate => {
+            let mut groups = vec![Vec::new(), Vec::new(), Vec::new()];
+            for entry in entries {
+                let root = entry
+                    .key
+                    .trim_start_matches("::")
+                    .split("::")
+                    .next()
+                    .unwrap_or_default();
+                let group = match root {
+                    "std" | "core" | "alloc" => 0,
+                    "self" | "crate" | "super" => 2,
+                    _ => 1,
+                };
+                groups[group].push(entry);
+            }
+            groups
+        }
+        GroupImports::One => vec![entries],
+    };
+
+    groups
+        .into_iter()
+        .filter(|group| !group.is_empty())
+        .map(|group| {
+            let mut group = merge_imports(group, config.imports.imports_granularity);
+            if config.ordering.reorder_imports {
+                group.sort_by(|a, b| compare_paths(&a.key, &b.key));
+            }
+            group
+        })
+        .collect()
+}
+
+/// Groups and sorts the entries of a run of `mod` items.
+fn submodule_groups(entries: Vec<Entry>) -> Vec<Vec<Entry>> {
+    let mut groups = source_groups(entries);
+    for group in groups.iter_mut() {
+        group.sort_by_key(|entry| entry.key.to_lowercase());
+    }
+    groups
+}
+
+/// Splits the entries into the groups separated by blank lines in the source.
+fn source_groups(entries: Vec<Entry>) -> Vec<Vec<Entry>> {
+    let mut groups: Vec<Vec<Entry>> = Vec::new();
+    for entry in entries {
+        match groups.last_mut() {
+            Some(group) if !entry.new_group => group.push(entry),
+            _ => groups.push(vec![entry]),
+        }
+    }
+    groups
+}
+
+/// Merges or splits the imports of the entries to the given granularity. The merged imports take
+/// the place of the first entry they come from, while the entries that cannot be merged, e.g.
+/// because they have attributes or comments inside them, are left as is.
+fn merge_imports(entries: Vec<Entry>, granularity: ImportGranularity) -> Vec<Entry> {
+    if matches!(granularity, ImportGranularity::Preserve) {
+        return entries;
+    }
+
+    /// The imports merged into a single `use` item.
+    struct Merged {
+        is_pub: bool,
+        key: Vec<String>,
+        paths: Vec<Vec<String>>,
+        leading: Vec<String>,
+        trailing: Vec<String>,
+    }
+
+    enum Slot {
+        Entry(Entry),
+        Merged(usize),
+    }
+
+    let mut slots = Vec::new();
+    let mut merged: Vec<Merged> = Vec::new();
+    for entry in entries {
+        let Some(imports) = &entry.imports else {
+            slots.push(Slot::Entry(entry));
+            continue;
+        };
+
+        let mut comments = Some((entry.leading.clone(), entry.trailing.clone()));
+        for path in &imports.paths {
+            let key = match granularity {
+                ImportGranularity::Crate => path[..1].to_vec(),
+                ImportGranularity::Module => path[..path.len() - 1].to_vec(),
+                ImportGranularity::Item => path.clone(),
+                ImportGranularity::One | ImportGranularity::Preserve => Vec::new(),
+            };
+            let index = match merged
+                .iter()
+                .position(|merged| merged.is_pub == imports.is_pub && merged.key == key)
+            {
+                Some(index) => index,
+                None => {
+                    merged.push(Merged {
+                        is_pub: imports.is_pub,
+                        key,
+                        paths: Vec::new(),
+                        leading: Vec::new(),
+                        trailing: Vec::new(),
+                    });
+                    slots.push(Slot::Merged(merged.len() - 1));
+                    merged.len() - 1
+                }
+            };
+            let merged = &mut merged[index];
+            if !merged.paths.contains(path) {
+                merged.paths.push(path.clone());
+            }
+            if let Some((leading, trailing)) = comments.take() {
+                merged.leading.extend(leading);
+                merged.trailing.extend(trailing);
+            }
+        }
+    }
+
+    let mut merged = merged.into_iter().map(Some).collect::<Vec<_>>();
+    slots
+        .into_iter()
+        .filter_map(|slot| match slot {
+            Slot::Entry(entry) => Some(entry),
+            Slot::Merged(index) => {
+                let Merged {
+                    is_pub,
+                    paths,
+                    mut leading,
+                    mut trailing,
+                    ..
+                } = merged[index].take()?;
+                // A single trailing comment stays on the line of the item, several of them go
+                // above it.
+                let trailing = if trailing.len() > 1 {
+                    leading.append(&mut trailing);
+                    None
+                } else {
+                    trailing.pop()
+                };
+                let tree = render_tree(paths);
+                Some(Entry {
+                    leading,
+                    text: format!("{}use {tree};", if is_pub { "pub " } else { "" }),
+                    trailing,
+                    new_group: false,
+                    key: strip_whitespace(&tree),
+                    imports: None,
+                })
+            }
+        })
+        .collect()
+}
+
+/// Renders the paths as a single use tree, e.g. `[std, a]` and `[std, b, c]` give
+/// `std::{a, b::c}`.
+fn render_tree(mut paths: Vec<Vec<String>>) -> String {
+    dedup(&mut paths);
+    if let [path] = &paths[..] {
+        return path.join("::");
+    }
+
+    let first = paths[0][0].clone();
+    if paths.iter().all(|path| path.len() > 1 && path[0] == first) {
+        let rest = paths.into_iter().map(|path| path[1..].to_vec()).collect();
+        return format!("{first}::{}", render_tree(rest));
+    }
+
+    let mut subtrees: Vec<(String, Vec<Vec<String>>)> = Vec::new();
+    for path in paths {
+        match subtrees.iter_mut().find(|(name, _)| *name == path[0]) {
+            Some((_, paths)) => paths.push(path),
+            None => subtrees.push((path[0].clone(), vec![path])),
+        }
+    }
+    let mut trees = subtrees
+        .into_iter()
+        .map(|(name, paths)| {
+            if paths.len() == 1 {
+                return paths[0].join("::");
+            }
+            // Importing the module itself along with its items gives `module::{self, item}`.
+            let rest = paths
+                .into_iter()
+                .map(|path| match path.len() {
+                    1 => vec!["self".to_string()],
+                    _ => path[1..].to_vec(),
+                })
+                .collect();
+            match render_tree(rest).as_str() {
+                "self" => name,
+                tree => format!("{name}::{tree}"),
+            }
+        })
+        .collect::<Vec<_>>();
+    if let [tree] = &trees[..] {
+        return tree.clone();
+    }
+    trees.sort_by(|a, b| compare_paths(&strip_whitespace(a), &strip_whitespace(b)));
+    format!("{{{}}}", trees.join(", "))
+}
+
+fn dedup(paths: &mut Vec<Vec<String>>) {
+    let mut seen = Vec::new();
+    paths.retain(|path| {
+        let is_new = !seen.contains(path);
+        if is_new {
+            seen.push(path.clone());
+        }
+        is_new
+    });
+}
+
+/// Compares two paths segment by segment, with `self` first, groups and globs last and the other
+/// names ordered case-insensitively.
+fn compare_paths(a: &str, b: &str) -> Ordering {
+    fn rank(segment: &str) -> u8 {
+        match segment {
+            "self" => 0,
+            "*" => 3,
+            _ if segment.starts_with('{') => 2,
+            _ => 1,
+        }
+    }
+
+    let mut a_segments = a.split("::");
+    let mut b_segments = b.split("::");
+    loop {
+        match (a_segments.next(), b_segments.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => {
+                let ordering = rank(a)
+                    .cmp(&rank(b))
+                    .then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+                    .then_with(|| a.cmp(b));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+/// Renders the groups of entries, separated by blank lines.
+fn render_groups(groups: Vec<Vec<Entry>>) -> String {
+    groups
+        .into_iter()
+        .filter(|group| !group.is_empty())
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|entry| {
+                    let mut lines = entry.leading;
+                    lines.push(match entry.trailing {
+                        Some(trailing) => format!("{} {trailing}", entry.text),
+                        None => entry.text,
+                    });
+                    lines.join("\n")
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
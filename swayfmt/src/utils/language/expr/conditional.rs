@@ -1,5 +1,6 @@
 use crate::{
     comments::write_comments,
+    config::expr::MatchArmLeadingPipe,
     formatter::{
         shape::{ExprKind, LineStyle},
         *,
@@ -12,7 +13,9 @@ use crate::{
 use std::{fmt::Write, ops::Range};
 use sway_ast::{
     expr::LoopControlFlow,
-    keywords::{ElseToken, EqToken, FatRightArrowToken, IfToken, Keyword, LetToken, Token},
+    keywords::{
+        ElseToken, EqToken, FatRightArrowToken, IfToken, Keyword, LetToken, PipeToken, Token,
+    },
     CommaToken, IfCondition, IfExpr, MatchBranch, MatchBranchKind,
 };
 use sway_types::{ast::Delimiter, Spanned};
@@ -289,6 +292,14 @@ impl Format for MatchBranch {
         formatted_code: &mut FormattedCode,
         formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
+        let leading_pipe = match formatter.config.expressions.match_arm_leading_pipe {
+            MatchArmLeadingPipe::Always => true,
+            MatchArmLeadingPipe::Never => false,
+            MatchArmLeadingPipe::Preserve => self.leading_pipe_token.is_some(),
+        };
+        if leading_pipe {
+            write!(formatted_code, "{} ", PipeToken::AS_STR)?;
+        }
         self.pattern.format(formatted_code, formatter)?;
         write!(formatted_code, " {} ", FatRightArrowToken::AS_STR)?;
         self.kind.format(formatted_code, formatter)?;
@@ -422,6 +433,8 @@ impl LeafSpans for IfCondition {
 
 impl LeafSpans for MatchBranch {
     fn leaf_spans(&self) -> Vec<ByteSpan> {
+        // The leading pipe is left out, as it can be added or removed by the formatter, which
+        // would offset the leaf spans of the formatted code from the unformatted ones.
         let mut collected_spans = Vec::new();
         collected_spans.append(&mut self.pattern.leaf_spans());
         collected_spans.push(ByteSpan::from(self.fat_right_arrow_token.span()));
@@ -1,4 +1,5 @@
 use crate::{
+    config::literals::HexLiteralCase,
     formatter::*,
     utils::map::byte_span::{ByteSpan, LeafSpans},
 };
@@ -9,7 +10,7 @@ impl Format for Literal {
     fn format(
         &self,
         formatted_code: &mut FormattedCode,
-        _formatter: &mut Formatter,
+        formatter: &mut Formatter,
     ) -> Result<(), FormatterError> {
         match self {
             Self::String(lit_string) => write!(formatted_code, "\"{}\"", lit_string.parsed)?,
@@ -48,7 +49,20 @@ impl Format for Literal {
                         write!(formatted_code, "{}", int_type)?;
                     }
                 } else {
-                    write!(formatted_code, "{}", lit_int.span.as_str())?;
+                    let lit = lit_int.span.as_str();
+                    // Hex literals, `b256` ones included, can have their digits cased.
+                    match (
+                        lit.strip_prefix("0x"),
+                        formatter.config.literals.hex_literal_case,
+                    ) {
+                        (Some(digits), HexLiteralCase::Upper) => {
+                            write!(formatted_code, "0x{}", digits.to_uppercase())?
+                        }
+                        (Some(digits), HexLiteralCase::Lower) => {
+                            write!(formatted_code, "0x{}", digits.to_lowercase())?
+                        }
+                        _ => write!(formatted_code, "{}", lit)?,
+                    }
                     if let Some((_, ty_span)) = &lit_int.ty_opt {
                         write!(formatted_code, "{}", ty_span.as_str())?;
                     }
@@ -1,10 +1,11 @@
 use anyhow::Result;
 use ropey::{str_utils::byte_to_char_idx, Rope};
-use std::{collections::BTreeMap, fmt::Display, sync::Arc};
-use sway_ast::Module;
+use std::{collections::BTreeMap, fmt::Display, ops::Range, sync::Arc};
+use sway_ast::{attribute::Annotated, ItemKind, Module};
+use sway_types::Spanned;
 
 use crate::{
-    constants::NEW_LINE,
+    constants::{DEFAULT_BLANK_LINES_LOWER_BOUND, DEFAULT_BLANK_LINES_UPPER_BOUND, NEW_LINE},
     formatter::{FormattedCode, Formatter},
     parse::parse_file,
     utils::map::byte_span::{ByteSpan, LeafSpans},
@@ -111,8 +112,13 @@ pub fn handle_newlines(
     formatted_code: &mut FormattedCode,
     formatter: &Formatter,
 ) -> Result<(), FormatterError> {
-    // Get newline threshold from config
-    let newline_threshold = formatter.config.whitespace.newline_threshold;
+    // Get newline threshold from config. The blank lines between items are bounded afterwards by
+    // `handle_blank_lines_between_items`, so as many as it allows are kept here.
+    let newline_threshold = formatter
+        .config
+        .whitespace
+        .newline_threshold
+        .max(formatter.config.items.blank_lines_upper_bound);
     // Collect ByteSpan -> NewlineSequence mapping from unformatted input.
     //
     // We remove the extra whitespace the beginning of a file before creating a map of newlines.
@@ -386,6 +392,141 @@ fn first_newline_sequence_in_span(
     None
 }
 
+/// Bounds the number of blank lines between items to the `blank_lines_lower_bound` and
+/// `blank_lines_upper_bound` of the items config. This applies to the items of the module and to
+/// the items in the bodies of its `impl`s, `trait`s and `abi`s.
+pub fn handle_blank_lines_between_items(
+    formatted_code: &mut FormattedCode,
+    formatter: &Formatter,
+) -> Result<(), FormatterError> {
+    let upper_bound = formatter.config.items.blank_lines_upper_bound;
+    let lower_bound = formatter
+        .config
+        .items
+        .blank_lines_lower_bound
+        .min(upper_bound);
+    // The formatter already keeps at most one blank line between items, and keeps none where
+    // there are none.
+    if upper_bound == DEFAULT_BLANK_LINES_UPPER_BOUND
+        && lower_bound == DEFAULT_BLANK_LINES_LOWER_BOUND
+    {
+        return Ok(());
+    }
+
+    let module = parse_file(Arc::from(formatted_code.as_str()))?.value;
+    let mut item_lists = vec![module.items.iter().map(annotated_range).collect::<Vec<_>>()];
+    for item in &module.items {
+        match &item.value {
+            ItemKind::Impl(item_impl) => {
+                item_lists.push(
+                    item_impl
+                        .contents
+                        .inner
+                        .iter()
+                        .map(annotated_range)
+                        .collect(),
+                );
+            }
+            ItemKind::Trait(item_trait) => {
+                item_lists.push(
+                    item_trait
+                        .trait_items
+                        .inner
+                        .iter()
+                        .map(annotated_range)
+                        .collect(),
+                );
+                if let Some(trait_defs) = &item_trait.trait_defs_opt {
+                    item_lists.push(trait_defs.inner.iter().map(annotated_range).collect());
+                }
+            }
+            ItemKind::Abi(item_abi) => {
+                item_lists.push(
+                    item_abi
+                        .abi_items
+                        .inner
+                        .iter()
+                        .map(annotated_range)
+                        .collect(),
+                );
+                if let Some(abi_defs) = &item_abi.abi_defs_opt {
+                    item_lists.push(abi_defs.inner.iter().map(annotated_range).collect());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut replacements = item_lists
+        .iter()
+        .flat_map(|items| items.windows(2))
+        .filter_map(|items| {
+            let between = items[0].end..items[1].start;
+            bound_blank_lines(&formatted_code[between.clone()], lower_bound, upper_bound)
+                .map(|text| (between, text))
+        })
+        .collect::<Vec<_>>();
+    replacements.sort_by_key(|(range, _)| range.start);
+    for (range, text) in replacements.into_iter().rev() {
+        formatted_code.replace_range(range, &text);
+    }
+
+    Ok(())
+}
+
+fn annotated_range<T: Spanned>(item: &Annotated<T>) -> Range<usize> {
+    let start = match item.attribute_list.first() {
+        Some(attribute) => attribute.span().start(),
+        None => item.value.span().start(),
+    };
+    start..item.value.span().end()
+}
+
+/// Bounds the runs of blank lines in the code between two items. Returns `None` if the code is
+/// left as is.
+///
+/// The code starts with the end of the line of the first item and ends with the start of the line
+/// of the second one, and only holds whitespace and comments in between. Missing blank lines are
+/// added right after the line of the first item, before the comments of the second one.
+fn bound_blank_lines(between: &str, lower_bound: usize, upper_bound: usize) -> Option<String> {
+    // Blank lines in block comments are part of the comments.
+    if between.contains("/*") {
+        return None;
+    }
+    let lines = between.split('\n').collect::<Vec<_>>();
+    let [first, middle @ .., last] = &lines[..] else {
+        return None;
+    };
+
+    let mut bounded = Vec::new();
+    let mut blank_lines = 0;
+    let mut most_blank_lines = 0;
+    for &line in middle {
+        if line.trim().is_empty() {
+            blank_lines += 1;
+            if blank_lines <= upper_bound {
+                bounded.push(line);
+            }
+        } else {
+            blank_lines = 0;
+            bounded.push(line);
+        }
+        most_blank_lines = most_blank_lines.max(blank_lines.min(upper_bound));
+    }
+    if most_blank_lines < lower_bound {
+        let leading_blank_lines = bounded
+            .iter()
+            .take_while(|line| line.trim().is_empty())
+            .count();
+        for _ in leading_blank_lines..lower_bound {
+            bounded.insert(0, "");
+        }
+    }
+
+    let bounded = [&[*first][..], &bounded, &[*last]].concat().join("\n");
+    (bounded != between).then_some(bounded)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::utils::map::{byte_span::ByteSpan, newline::first_newline_sequence_in_span};
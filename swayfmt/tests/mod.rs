@@ -1,6 +1,14 @@
 use indoc::indoc;
 use std::sync::Arc;
-use swayfmt::{config::user_def::FieldAlignment, Formatter};
+use swayfmt::{
+    config::{
+        expr::MatchArmLeadingPipe,
+        imports::{GroupImports, ImportGranularity},
+        literals::HexLiteralCase,
+        user_def::FieldAlignment,
+    },
+    Formatter,
+};
 use test_macros::assert_eq_pretty;
 
 /// Takes a configured formatter as input and formats a given input and checks the actual output against an
@@ -2013,9 +2021,9 @@ fn test_comments_after_deps() {
         indoc! {r#"
         library;
 
-        use std::{u256::U256, vec::*};
-        use ::utils::vec::sort;
         use ::utils::numbers::*;
+        use ::utils::vec::sort;
+        use std::{u256::U256, vec::*};
 
         // pub fn aggregate_results(results: Vec<Vec<U256>>) -> Vec<U256> {
         //     let mut aggregated = Vec::new();
@@ -3218,6 +3226,231 @@ fn use_sorting_items() {
     );
 }
 
+#[test]
+fn reorder_imports() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_imports = true;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        use std::vec::Vec;
+        // The hashing functions.
+        use std::hash::*;
+        use std::alloc::alloc; // Allocates memory.
+
+        use utils::Z;
+        use utils::a;
+
+        fn foo() {}
+        "#},
+        indoc! {r#"
+        library;
+
+        use std::alloc::alloc; // Allocates memory.
+        // The hashing functions.
+        use std::hash::*;
+        use std::vec::Vec;
+
+        use utils::a;
+        use utils::Z;
+
+        fn foo() {}
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn reorder_imports_keeps_runs() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_imports = true;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        use std::vec::Vec;
+        use std::hash::*;
+
+        fn foo() {}
+
+        use std::b256::*;
+        use std::assert::assert;
+        "#},
+        indoc! {r#"
+        library;
+
+        use std::hash::*;
+        use std::vec::Vec;
+
+        fn foo() {}
+
+        use std::assert::assert;
+        use std::b256::*;
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn group_imports_std_external_crate() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_imports = true;
+    formatter.config.imports.group_imports = GroupImports::StdExternalCrate;
+    check_with_formatter(
+        indoc! {r#"
+        contract;
+
+        use self::inner::Thing;
+        use my_lib::helpers::*;
+        use std::hash::sha256;
+
+        use core::ops::Eq;
+        use another_lib::Other;
+        "#},
+        indoc! {r#"
+        contract;
+
+        use core::ops::Eq;
+        use std::hash::sha256;
+
+        use another_lib::Other;
+        use my_lib::helpers::*;
+
+        use self::inner::Thing;
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn group_imports_one() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.group_imports = GroupImports::One;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        use std::hash::sha256;
+
+        use my_lib::helpers::*;
+        "#},
+        indoc! {r#"
+        library;
+
+        use my_lib::helpers::*;
+        use std::hash::sha256;
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn imports_granularity_crate() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_imports = true;
+    formatter.config.imports.imports_granularity = ImportGranularity::Crate;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        use std::hash::sha256;
+        use std::vec::Vec;
+        // Hashing.
+        use std::hash::keccak256;
+        use std::vec;
+        pub use my_lib::Thing;
+        #[allow(dead_code)]
+        use std::bytes::Bytes;
+        "#},
+        indoc! {r#"
+        library;
+
+        pub use my_lib::Thing;
+        #[allow(dead_code)]
+        use std::bytes::Bytes;
+        // Hashing.
+        use std::{hash::{keccak256, sha256}, vec::{self, Vec}};
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn imports_granularity_module() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.imports_granularity = ImportGranularity::Module;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        use std::{hash::{keccak256, sha256}, vec::Vec};
+        use std::hash::Hash;
+        "#},
+        indoc! {r#"
+        library;
+
+        use std::hash::{Hash, keccak256, sha256};
+        use std::vec::Vec;
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn imports_granularity_item() {
+    let mut formatter = Formatter::default();
+    formatter.config.imports.imports_granularity = ImportGranularity::Item;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        use std::{hash::{keccak256, sha256}, vec::Vec}; // Used everywhere.
+        pub use std::hash::Hash;
+        "#},
+        indoc! {r#"
+        library;
+
+        pub use std::hash::Hash;
+        use std::hash::keccak256; // Used everywhere.
+        use std::hash::sha256;
+        use std::vec::Vec;
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn reorder_modules() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_modules = true;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        mod errors;
+        // Events logged by the contract.
+        pub mod events;
+        mod admin;
+
+        mod utils;
+        mod data_structures;
+        "#},
+        indoc! {r#"
+        library;
+
+        mod admin;
+        mod errors;
+        // Events logged by the contract.
+        pub mod events;
+
+        mod data_structures;
+        mod utils;
+        "#},
+        &mut formatter,
+    );
+}
+
 #[test]
 fn whitespace_after_doccomment() {
     check(
@@ -3489,3 +3722,288 @@ fn contract_for_loop() {
         "#},
     );
 }
+
+#[test]
+fn wrap_comments() {
+    let mut formatter = Formatter::default();
+    formatter.config.comments.wrap_comments = true;
+    formatter.config.comments.comment_width = 60;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        /// Returns the sum of the two numbers, which is computed by adding the second number to the first one.
+        ///
+        /// # Examples
+        ///
+        /// ```sway
+        /// let sum = add(1, 2); // a code line that is long enough to go past the comment width for sure
+        /// ```
+        fn add(a: u64, b: u64) -> u64 {
+            // The addition overflows when the result does not fit into sixty four bits and then the VM panics.
+            a + b
+        }
+
+        abi Calculator {
+            /// # Arguments
+            ///
+            /// * `a`: [u64] - The first number, which the second one is added to by the function.
+            /// * `b`: [u64] - The second number.
+            fn add(a: u64, b: u64) -> u64;
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        /// Returns the sum of the two numbers, which is computed by
+        /// adding the second number to the first one.
+        ///
+        /// # Examples
+        ///
+        /// ```sway
+        /// let sum = add(1, 2); // a code line that is long enough to go past the comment width for sure
+        /// ```
+        fn add(a: u64, b: u64) -> u64 {
+            // The addition overflows when the result does not fit
+            // into sixty four bits and then the VM panics.
+            a + b
+        }
+
+        abi Calculator {
+            /// # Arguments
+            ///
+            /// * `a`: [u64] - The first number, which the second
+            ///   one is added to by the function.
+            /// * `b`: [u64] - The second number.
+            fn add(a: u64, b: u64) -> u64;
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn normalize_comments() {
+    let mut formatter = Formatter::default();
+    formatter.config.comments.normalize_comments = true;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        /* Adds the numbers. */
+        fn add(a: u64, b: u64) -> u64 {
+            /*
+             * The addition panics
+             * on overflow.
+             */
+            let sum = a + b; /* The sum. */
+            add_one(/* inlined */ sum)
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        // Adds the numbers.
+        fn add(a: u64, b: u64) -> u64 {
+            // The addition panics
+            // on overflow.
+            let sum = a + b; // The sum.
+            add_one(/* inlined */ sum)
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn hex_literal_case_upper() {
+    let mut formatter = Formatter::default();
+    formatter.config.literals.hex_literal_case = HexLiteralCase::Upper;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        const MASK: u64 = 0xff_ff;
+        const ID: b256 = 0x018f59fe434b323a5054e7bb41de983f4926a3c5d3e4e1f9f33b5f0f0e611889;
+        const BITS: u8 = 0b1010;
+        "#},
+        indoc! {r#"
+        library;
+
+        const MASK: u64 = 0xFF_FF;
+        const ID: b256 = 0x018F59FE434B323A5054E7BB41DE983F4926A3C5D3E4E1F9F33B5F0F0E611889;
+        const BITS: u8 = 0b1010;
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn hex_literal_case_lower() {
+    let mut formatter = Formatter::default();
+    formatter.config.literals.hex_literal_case = HexLiteralCase::Lower;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        const MASK: u64 = 0xFF_FF;
+        const ID: b256 = 0x018F59FE434B323A5054E7BB41DE983F4926A3C5D3E4E1F9F33B5F0F0E611889;
+        "#},
+        indoc! {r#"
+        library;
+
+        const MASK: u64 = 0xff_ff;
+        const ID: b256 = 0x018f59fe434b323a5054e7bb41de983f4926a3c5d3e4e1f9f33b5f0f0e611889;
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn reorder_impl_items() {
+    let mut formatter = Formatter::default();
+    formatter.config.ordering.reorder_impl_items = true;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        impl Counter for Contract {
+            fn count() -> u64 {
+                Self::START
+            }
+
+            // The first count.
+            const START: u64 = 1;
+            type Count = u64;
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        impl Counter for Contract {
+            type Count = u64;
+
+            // The first count.
+            const START: u64 = 1;
+            fn count() -> u64 {
+                Self::START
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn blank_lines_upper_bound() {
+    let mut formatter = Formatter::default();
+    formatter.config.items.blank_lines_upper_bound = 2;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo() {}
+
+
+
+        // Not a foo.
+        fn bar() {}
+        fn baz() {}
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo() {}
+
+
+        // Not a foo.
+        fn bar() {}
+        fn baz() {}
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn blank_lines_lower_bound() {
+    let mut formatter = Formatter::default();
+    formatter.config.items.blank_lines_lower_bound = 1;
+    check_with_formatter(
+        indoc! {r#"
+        library;
+
+        fn foo() {}
+        // Not a foo.
+        fn bar() {}
+        impl Foo {
+            const FOO: u64 = 0;
+            fn foo() {}
+        }
+        "#},
+        indoc! {r#"
+        library;
+
+        fn foo() {}
+
+        // Not a foo.
+        fn bar() {}
+
+        impl Foo {
+            const FOO: u64 = 0;
+
+            fn foo() {}
+        }
+        "#},
+        &mut formatter,
+    );
+}
+
+#[test]
+fn match_arm_leading_pipe() {
+    let unformatted = indoc! {r#"
+        library;
+
+        fn is_primary(color: Color) -> bool {
+            match color {
+                | Color::Red | Color::Blue => true,
+                Color::Green => false,
+            }
+        }
+        "#};
+
+    let mut formatter = Formatter::default();
+    formatter.config.expressions.match_arm_leading_pipe = MatchArmLeadingPipe::Never;
+    check_with_formatter(
+        unformatted,
+        indoc! {r#"
+        library;
+
+        fn is_primary(color: Color) -> bool {
+            match color {
+                Color::Red | Color::Blue => true,
+                Color::Green => false,
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+
+    formatter.config.expressions.match_arm_leading_pipe = MatchArmLeadingPipe::Always;
+    check_with_formatter(
+        unformatted,
+        indoc! {r#"
+        library;
+
+        fn is_primary(color: Color) -> bool {
+            match color {
+                | Color::Red | Color::Blue => true,
+                | Color::Green => false,
+            }
+        }
+        "#},
+        &mut formatter,
+    );
+
+    formatter.config.expressions.match_arm_leading_pipe = MatchArmLeadingPipe::Preserve;
+    check_with_formatter(unformatted, unformatted, &mut formatter);
+}
@@ -278,7 +278,14 @@ pub(super) async fn run(
 
                 // Compile to IR.
                 let include_tests = true;
-                let mut ir = compile_program(typed_program, include_tests, &engines, experimental)
+                let generate_debug_info = false;
+                let mut ir = compile_program(
+                    typed_program,
+                    include_tests,
+                    generate_debug_info,
+                    &engines,
+                    experimental,
+                )
                     .unwrap_or_else(|e| {
                         use sway_types::span::Spanned;
                         let e = e[0].clone();
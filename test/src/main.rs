@@ -66,7 +66,7 @@ struct Cli {
     update_output_files: bool,
 
     /// Print out the specified IR (separate options with comma), if the verbose option is on
-    #[arg(long, num_args(1..=18), value_parser = clap::builder::PossibleValuesParser::new(PrintIrCliOpt::cli_options()))]
+    #[arg(long, num_args(1..=19), value_parser = clap::builder::PossibleValuesParser::new(PrintIrCliOpt::cli_options()))]
     print_ir: Option<Vec<String>>,
 
     /// Print out the specified ASM (separate options with comma), if the verbose option is on